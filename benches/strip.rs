@@ -0,0 +1,155 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use strip_diacritics::{
+    map_char,
+    tables::{strip_latin1, DIACRITICS_MAPPING, DIACRITICS_SORTED},
+    CharDiacriticExt, StrDiacriticExt, StripOptions,
+};
+
+const LATIN: &str = "Árvíztűrő tükörfúrógép, façade, crème brûlée, naïve coöperation, \
+                     smörgåsbord, jalapeño, Ærøskøbing, Šibenik, Łódź, Dvořák. ";
+
+const ENGLISH: &str = "The quick brown fox jumps over the lazy dog while the café \
+                       down the street serves coffee, tea and pastries to everyone. ";
+
+const GREEK: &str = "Ἐν ἀρχῇ ἦν ὁ λόγος, καὶ ὁ λόγος ἦν πρὸς τὸν θεόν, καὶ θεὸς ἦν ὁ λόγος. ";
+
+// Each table stays a single CharMap rather than per-block sub-maps: the 22
+// distinct non-ASCII chars of LATIN touch 37 of the 310 cache lines of
+// DIACRITICS_MAPPING's disps and entries (19.3 KiB in all), against 23 of
+// 40 for a map of its 259 keys up to U+024F. The 14 lines saved are under
+// 1 KiB, and this bench ran at the same speed either way.
+fn latin(c: &mut Criterion) {
+    let input = LATIN.repeat(64);
+    let mut group = c.benchmark_group("latin");
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("strip_diacritics", |b| {
+        b.iter(|| black_box(&input).strip_diacritics())
+    });
+    group.finish();
+}
+
+fn long_tail(c: &mut Criterion) {
+    let input = format!("é{}", ENGLISH.replace("café", "cafe").repeat(512));
+    let mut group = c.benchmark_group("long_tail");
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("strip_diacritics", |b| {
+        b.iter(|| black_box(&input).strip_diacritics())
+    });
+    let mut buf = String::with_capacity(input.len());
+    group.bench_function("strip_diacritics_into", |b| {
+        b.iter(|| {
+            buf.clear();
+            black_box(&input).strip_diacritics_into(&mut buf);
+        })
+    });
+    group.finish();
+}
+
+fn ascii(c: &mut Criterion) {
+    let line = ENGLISH.replace("café", "cafe");
+    let input = line.repeat((1 << 20) / line.len());
+    let mut group = c.benchmark_group("ascii_1mb");
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("strip_diacritics", |b| {
+        b.iter(|| black_box(&input).strip_diacritics())
+    });
+    group.bench_function("has_diacritics", |b| {
+        b.iter(|| black_box(&input).has_diacritics())
+    });
+    group.finish();
+}
+
+fn scan_strategies(c: &mut Criterion) {
+    let chars = StripOptions::new().char_scan_only(true);
+    for (name, text) in [("english", ENGLISH), ("latin", LATIN), ("greek", GREEK)] {
+        let input = text.repeat(64);
+        let mut group = c.benchmark_group(format!("scan/{name}"));
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_function("auto", |b| b.iter(|| black_box(&input).strip_diacritics()));
+        group.bench_function("char_scan_only", |b| {
+            b.iter(|| black_box(&input).strip_diacritics_with(chars))
+        });
+        group.finish();
+    }
+}
+
+// map_char uses the dense BMP table with the dense-table feature, and the
+// sorted entries with binary-search: compare runs with and without them.
+fn eu_diacritics(c: &mut Criterion) {
+    let input = LATIN
+        .chars()
+        .filter(|c| !c.is_ascii())
+        .chain("e\u{301}o\u{308}a\u{30a}".chars())
+        .collect::<Vec<_>>()
+        .repeat(16);
+    let mut group = c.benchmark_group("eu_diacritics");
+    group.throughput(Throughput::Elements(input.len() as u64));
+    group.bench_function("map_char", |b| {
+        b.iter(|| {
+            black_box(&input)
+                .iter()
+                .filter_map(|&c| map_char(c))
+                .count()
+        })
+    });
+    group.bench_function("phf", |b| {
+        b.iter(|| {
+            black_box(&input)
+                .iter()
+                .filter_map(|&c| match c.is_diacritic() {
+                    true => Some(""),
+                    false => DIACRITICS_MAPPING.get(c).map(|r| r.as_str()),
+                })
+                .count()
+        })
+    });
+    group.bench_function("binary_search", |b| {
+        b.iter(|| {
+            black_box(&input)
+                .iter()
+                .filter_map(|&c| match c.is_diacritic() {
+                    true => Some(""),
+                    false => DIACRITICS_SORTED
+                        .binary_search_by_key(&c, |&(k, _)| k)
+                        .ok()
+                        .map(|i| DIACRITICS_SORTED[i].1),
+                })
+                .count()
+        })
+    });
+    group.finish();
+}
+
+fn latin1_lookup(c: &mut Criterion) {
+    let input = ('\u{80}'..='\u{17f}').collect::<Vec<_>>();
+    let mut group = c.benchmark_group("latin1_lookup");
+    group.throughput(Throughput::Elements(input.len() as u64));
+    group.bench_function("strip_latin1", |b| {
+        b.iter(|| {
+            black_box(&input)
+                .iter()
+                .filter_map(|&c| strip_latin1(c))
+                .count()
+        })
+    });
+    group.bench_function("char_map_get", |b| {
+        b.iter(|| {
+            black_box(&input)
+                .iter()
+                .filter_map(|&c| DIACRITICS_MAPPING.get(c))
+                .count()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    ascii,
+    eu_diacritics,
+    latin,
+    latin1_lookup,
+    long_tail,
+    scan_strategies
+);
+criterion_main!(benches);
@@ -1,26 +1,20 @@
-use std::{collections::HashMap, fmt, str::FromStr};
+use std::{collections::HashMap, fmt, ops::RangeInclusive, path::PathBuf, str::FromStr};
 
-use const_format::formatcp;
-
-#[path = "../../src/is_diacritic.rs"]
-mod is_diacritic;
-use is_diacritic::is_diacritic;
-
-const UNICODE_VERSION: (u32, u32, u32) = (15, 0, 0);
-const UCD_URL: &str = formatcp!(
-    "https://www.unicode.org/Public/{}.{}.{}/ucd/",
-    UNICODE_VERSION.0,
-    UNICODE_VERSION.1,
-    UNICODE_VERSION.2
-);
+// Overridable with `--unicode-version X.Y.Z` or the UNICODE_VERSION env var
+const UNICODE_VERSION: UnicodeVersion = UnicodeVersion(15, 0, 0);
+const UCD_URL: &str = "https://www.unicode.org/Public/";
 
 // Constants from Unicode 9.0.0 Section 3.12 Conjoining Jamo Behavior
 // http://www.unicode.org/versions/Unicode9.0.0/ch03.pdf#M9.32468.Heading.310.Combining.Jamo.Behavior
 const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
 const L_COUNT: u32 = 19;
 const V_COUNT: u32 = 21;
 const T_COUNT: u32 = 28;
-const S_COUNT: u32 = L_COUNT * V_COUNT * T_COUNT;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
 
 #[derive(Debug)]
 struct StrError(&'static str);
@@ -175,28 +169,93 @@ impl Category {
     }
 }
 
-fn fetch<S: AsRef<str>>(file: S) -> Result<String, Box<dyn std::error::Error>> {
-    let url = format!("{}{}", UCD_URL, file.as_ref());
-    Ok(ureq::get(&url).call()?.into_string()?)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct UnicodeVersion(u32, u32, u32);
+
+impl FromStr for UnicodeVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut it = s.split('.').map(|n| {
+            if !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()) {
+                n.parse::<u32>().ok()
+            } else {
+                None
+            }
+        });
+        match (it.next(), it.next(), it.next(), it.next()) {
+            (Some(Some(major)), Some(Some(minor)), Some(Some(update)), None) => {
+                Ok(Self(major, minor, update))
+            }
+            _ => Err(format!("Invalid Unicode version {:?}, expected X.Y.Z", s)),
+        }
+    }
+}
+
+impl fmt::Display for UnicodeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.0, self.1, self.2)
+    }
+}
+
+// Where the UCD files are read from
+#[derive(Debug)]
+enum Ucd {
+    Network(UnicodeVersion),
+    // A local copy of the ucd/ directory, whatever version it holds
+    Local(PathBuf),
+}
+
+impl Ucd {
+    fn fetch<S: AsRef<str>>(&self, file: S) -> Result<String, Box<dyn std::error::Error>> {
+        match self {
+            Ucd::Network(version) => {
+                let url = format!("{}{}/ucd/{}", UCD_URL, version, file.as_ref());
+                match ureq::get(&url).call() {
+                    Ok(res) => Ok(res.into_string()?),
+                    Err(ureq::Error::Status(404, _)) => {
+                        Err(format!("Unicode {} not found: {} does not exist", version, url).into())
+                    }
+                    Err(err) => Err(err.into()),
+                }
+            }
+            Ucd::Local(dir) => {
+                let path = dir.join(file.as_ref());
+                std::fs::read_to_string(&path)
+                    .map_err(|err| format!("{}: {}", path.display(), err).into())
+            }
+        }
+    }
 }
 
 #[allow(clippy::type_complexity)]
-fn load_unicode_data() -> Result<
+fn load_unicode_data(
+    ucd: &Ucd,
+) -> Result<
     (
+        HashMap<u32, String>,
         HashMap<u32, u8>,
         HashMap<u32, Vec<u32>>,
+        HashMap<u32, String>,
         HashMap<u32, Vec<u32>>,
+        Vec<u32>,
+        HashMap<u32, u32>,
     ),
     Box<dyn std::error::Error>,
 > {
+    let mut names: HashMap<u32, String> = HashMap::new();
     let mut combining_classes: HashMap<u32, u8> = HashMap::new();
     let mut compat_decomp: HashMap<u32, Vec<u32>> = HashMap::new();
+    let mut compat_tags: HashMap<u32, String> = HashMap::new();
     let mut canon_decomp: HashMap<u32, Vec<u32>> = HashMap::new();
+    let mut marks: Vec<u32> = Vec::new();
+    let mut lowercase: HashMap<u32, u32> = HashMap::new();
 
-    for line in fetch("UnicodeData.txt")?.lines() {
+    for line in ucd.fetch("UnicodeData.txt")?.lines() {
         let mut it = line.split(';');
         let hack = unsafe { std::str::from_utf8_unchecked(&[]) };
-        let (mut ch, mut category, mut cc, mut decomp) = (hack, hack, hack, hack);
+        let (mut ch, mut name, mut category, mut cc, mut decomp, mut lower) =
+            (hack, hack, hack, hack, hack, hack);
         for i in 0..15 {
             let p = if let Some(p) = it.next() {
                 p
@@ -208,6 +267,9 @@ fn load_unicode_data() -> Result<
                 0 => {
                     ch = p;
                 }
+                1 => {
+                    name = p;
+                }
                 2 => {
                     category = p;
                 }
@@ -217,6 +279,9 @@ fn load_unicode_data() -> Result<
                 5 => {
                     decomp = p;
                 }
+                13 => {
+                    lower = p;
+                }
                 _ => (),
             }
         }
@@ -230,16 +295,25 @@ fn load_unicode_data() -> Result<
             }
         };
 
+        names.insert(ch, name.to_string());
+
         if cc != "0" {
             combining_classes.insert(ch, cc.parse()?);
         }
 
+        if !lower.is_empty() {
+            lowercase.insert(ch, u32::from_str_radix(lower, 16)?);
+        }
+
         if let Some(decomp) = decomp.strip_prefix('<') {
+            let (tag, decomp) = decomp
+                .split_once('>')
+                .ok_or(StrError("Invalid decomposition"))?;
+            compat_tags.insert(ch, tag.to_string());
             compat_decomp.insert(
                 ch,
                 decomp
                     .split_whitespace()
-                    .skip(1)
                     .map(|c| u32::from_str_radix(c, 16))
                     .collect::<Result<_, _>>()?,
             );
@@ -256,9 +330,101 @@ fn load_unicode_data() -> Result<
         let category: Category = category.parse()?;
 
         assert_ne!(category, Category::Unassigned);
+
+        if matches!(category, Category::NonspacingMark | Category::EnclosingMark) {
+            marks.push(ch);
+        }
+    }
+
+    Ok((
+        names,
+        combining_classes,
+        compat_decomp,
+        compat_tags,
+        canon_decomp,
+        marks,
+        lowercase,
+    ))
+}
+
+// Unconditional full uppercase mappings that expand to several chars, see
+// https://www.unicode.org/reports/tr44/#SpecialCasing.txt
+fn load_special_casing(ucd: &Ucd) -> Result<HashMap<u32, Vec<u32>>, Box<dyn std::error::Error>> {
+    let mut uppercase = HashMap::new();
+
+    for line in ucd.fetch("SpecialCasing.txt")?.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields = line.split(';').map(str::trim).collect::<Vec<_>>();
+        if fields.len() < 4 {
+            return Err(StrError("Invalid line").into());
+        }
+        // Language- or context-dependent mapping
+        if fields.get(4).is_some_and(|c| !c.is_empty()) {
+            continue;
+        }
+
+        let upper = fields[3]
+            .split_whitespace()
+            .map(|c| u32::from_str_radix(c, 16))
+            .collect::<Result<Vec<_>, _>>()?;
+        if upper.len() > 1 {
+            uppercase.insert(u32::from_str_radix(fields[0], 16)?, upper);
+        }
     }
 
-    Ok((combining_classes, compat_decomp, canon_decomp))
+    Ok(uppercase)
+}
+
+// The source (c1) and NFKD (c5) columns of every test case in the given
+// parts (all of them if `None`), see
+// https://www.unicode.org/reports/tr44/#NormalizationTest.txt
+#[allow(clippy::type_complexity)]
+fn load_normalization_test(
+    ucd: &Ucd,
+    parts: Option<&[u32]>,
+) -> Result<Vec<(Vec<u32>, Vec<u32>)>, Box<dyn std::error::Error>> {
+    let mut tests = Vec::new();
+    let mut selected = true;
+
+    for line in ucd.fetch("NormalizationTest.txt")?.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if let Some(part) = line.strip_prefix("@Part") {
+            let part = part.parse::<u32>()?;
+            selected = parts.is_none_or(|parts| parts.contains(&part));
+            continue;
+        }
+        if line.is_empty() || !selected {
+            continue;
+        }
+        let fields = line.split(';').collect::<Vec<_>>();
+        if fields.len() < 5 {
+            return Err(StrError("Invalid line").into());
+        }
+        let parse = |field: &str| {
+            field
+                .split_whitespace()
+                .map(|c| u32::from_str_radix(c, 16))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        tests.push((parse(fields[0])?, parse(fields[4])?));
+    }
+
+    Ok(tests)
+}
+
+fn to_mapping(mapping: &HashMap<u32, Vec<u32>>) -> HashMap<char, Box<str>> {
+    mapping
+        .iter()
+        .map(|(&k, v)| {
+            (
+                char::from_u32(k).unwrap(),
+                v.iter().map(|&c| char::from_u32(c).unwrap()).collect(),
+            )
+        })
+        .collect()
 }
 
 #[allow(clippy::type_complexity)]
@@ -345,31 +511,68 @@ fn compute_fully_decomposed(
         }
     }
 
+    check_fixed_point(&canon, &compat)?;
+
     Ok((canon, compat))
 }
 
+// A decomposition must not contain anything that decomposes any further,
+// otherwise a step of the recursion got lost.
+fn check_fixed_point(
+    canon: &HashMap<u32, Vec<u32>>,
+    compat: &HashMap<u32, Vec<u32>>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    for (compatible, map) in [(false, canon), (true, compat)] {
+        for (&k, v) in map {
+            if let Some(&ch) = v
+                .iter()
+                .find(|ch| canon.contains_key(ch) || (compatible && compat.contains_key(ch)))
+            {
+                return Err(format!(
+                    "Decomposition of U+{:04X} is not a fixed point: U+{:04X} still decomposes",
+                    k, ch
+                )
+                .into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn sort_codepoints(chars: &[u32], combining_classes: &HashMap<u32, u8>) -> Vec<char> {
     let mut buf = Vec::<(u8, u32)>::new();
+    // Where the marks following the last starter begin
+    let mut run = 0;
     for (class, ch) in chars
         .iter()
         .copied()
         .map(|c| (combining_classes.get(&c).copied().unwrap_or_default(), c))
     {
         if class == 0 {
-            buf.sort_by_key(|x| x.0);
+            buf[run..].sort_by_key(|x| x.0);
+            run = buf.len() + 1;
         }
         buf.push((class, ch));
     }
+    let run = run.min(buf.len());
+    buf[run..].sort_by_key(|x| x.0);
     buf.into_iter()
         .map(|x| unsafe { char::from_u32_unchecked(x.1) })
         .collect()
 }
 
-fn filter_diacritics<I: IntoIterator<Item = char>>(iter: I) -> Option<Vec<char>> {
+// Diacritics are the nonspacing (Mn) and enclosing (Me) marks, `marks` being
+// sorted.
+fn is_diacritic(marks: &[u32], ch: char) -> bool {
+    marks.binary_search(&(ch as u32)).is_ok()
+}
+
+fn filter_diacritics<I: IntoIterator<Item = char>>(marks: &[u32], iter: I) -> Option<Vec<char>> {
     let mut ok = false;
     let mut buf = Vec::new();
     for ch in iter {
-        if is_diacritic(ch) {
+        if is_diacritic(marks, ch) {
             ok = true;
         } else {
             buf.push(ch);
@@ -402,47 +605,451 @@ fn codepoints_to_utf8(chars: &[char]) -> String {
 }
 
 fn add_mapping(
-    src: HashMap<u32, Vec<u32>>,
+    src: &HashMap<u32, Vec<u32>>,
     combining_classes: &HashMap<u32, u8>,
+    marks: &[u32],
     dst: &mut HashMap<char, Box<str>>,
 ) {
-    for (k, v) in src {
+    for (&k, v) in src {
         let k = unsafe { char::from_u32_unchecked(k) };
-        if !is_diacritic(k) {
-            if let Some(chars) = filter_diacritics(sort_codepoints(&v, combining_classes)) {
+        if !is_diacritic(marks, k) {
+            if let Some(chars) = filter_diacritics(marks, sort_codepoints(v, combining_classes)) {
                 dst.insert(k, codepoints_to_utf8(&chars).into_boxed_str());
             }
         }
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let mapping = {
-        let (combining_classes, compat_decomp, canon_decomp) = load_unicode_data()?;
-        let (canon_decomp, compat_decomp) = compute_fully_decomposed(canon_decomp, compat_decomp)?;
-        let mut mapping = HashMap::<char, Box<str>>::new();
-        add_mapping(canon_decomp, &combining_classes, &mut mapping);
-        add_mapping(compat_decomp, &combining_classes, &mut mapping);
-        mapping
+// Canonical singletons carry no diacritic but are mere duplicates of another
+// code point: CJK compatibility ideographs, OHM SIGN, KELVIN SIGN, ...
+// Those folding further by compatibility (EN QUAD → EN SPACE → SPACE) are
+// left to the compatibility table.
+fn add_singleton_mapping(
+    src: &HashMap<u32, Vec<u32>>,
+    compat: &HashMap<u32, Vec<u32>>,
+    marks: &[u32],
+    dst: &mut HashMap<char, Box<str>>,
+) {
+    for (&k, v) in src {
+        if compat.get(&k).is_some_and(|c| c != v) {
+            continue;
+        }
+        let k = unsafe { char::from_u32_unchecked(k) };
+        if let [c] = v[..] {
+            if !is_diacritic(marks, k) && !dst.contains_key(&k) {
+                dst.insert(k, char::from_u32(c).unwrap().to_string().into_boxed_str());
+            }
+        }
+    }
+}
+
+fn add_compatibility_mapping(
+    src: &HashMap<u32, Vec<u32>>,
+    combining_classes: &HashMap<u32, u8>,
+    marks: &[u32],
+    diacritics: &HashMap<char, Box<str>>,
+    dst: &mut HashMap<char, Box<str>>,
+) {
+    for (&k, v) in src {
+        let k = unsafe { char::from_u32_unchecked(k) };
+        if !is_diacritic(marks, k) && !diacritics.contains_key(&k) {
+            let chars = sort_codepoints(v, combining_classes);
+            dst.insert(k, codepoints_to_utf8(&chars).into_boxed_str());
+        }
+    }
+}
+
+struct Annotations<'a> {
+    names: &'a HashMap<u32, String>,
+    compat_decomp: &'a HashMap<u32, Vec<u32>>,
+}
+
+impl<'a> Annotations<'a> {
+    fn describe(&self, ch: char) -> String {
+        format!(
+            "U+{:04X} {} ({})",
+            ch as u32,
+            self.names.get(&(ch as u32)).map_or("", String::as_str),
+            if self.compat_decomp.contains_key(&(ch as u32)) {
+                "compatibility"
+            } else {
+                "canonical"
+            }
+        )
+    }
+}
+
+// Every distinct value of the maps once, sorted with "" first: entries
+// store a 2-byte index into it instead of a 16-byte &str.
+fn intern(maps: &[&HashMap<char, Box<str>>]) -> Vec<Box<str>> {
+    let mut pool = maps
+        .iter()
+        .flat_map(|m| m.values().cloned())
+        .chain(std::iter::once("".into()))
+        .collect::<Vec<_>>();
+    pool.sort_unstable();
+    pool.dedup();
+    assert!(
+        pool.len() <= u16::MAX as usize,
+        "too many values for u16 ids"
+    );
+    pool
+}
+
+fn pool_index(pool: &[Box<str>], value: &str) -> u16 {
+    pool.binary_search_by(|v| (**v).cmp(value))
+        .expect("value missing from the pool") as u16
+}
+
+fn print_pool(pool: &[Box<str>]) {
+    print!(
+        "
+        // The values of the generated maps, see `crate::Replacement`.
+        pub(crate) const REPLACEMENTS: &[&str] = &["
+    );
+    for value in pool {
+        print!(
+            "
+            {:?},",
+            value
+        );
+    }
+    println!(
+        "
+        ];"
+    );
+}
+
+// One `source;expected` line per distinct test case, where the expected
+// string is the NFKD without its diacritics: what stripping with the
+// compatibility and Hangul options must give. Cases with a canonical
+// decomposition that has no diacritic to drop, like the two-part vowel
+// signs of Indic scripts, are left out: those stay composed.
+fn normalization_fixture(
+    tests: &[(Vec<u32>, Vec<u32>)],
+    parts: Option<&[u32]>,
+    canon_decomp: &HashMap<u32, Vec<u32>>,
+    marks: &[u32],
+) -> String {
+    let is_mark = |c: u32| is_diacritic(marks, char::from_u32(c).unwrap());
+    let kept_composed = |c: &u32| {
+        canon_decomp
+            .get(c)
+            .is_some_and(|d| d.len() > 1 && !d.iter().copied().any(is_mark))
+    };
+    let hex = |chars: &mut dyn Iterator<Item = &u32>| {
+        chars
+            .map(|c| format!("{:04X}", c))
+            .collect::<Vec<_>>()
+            .join(" ")
     };
-    let mut keys = Vec::with_capacity(mapping.len());
-    let mut values = Vec::with_capacity(mapping.len());
-    let (mut min, mut max): (Option<char>, Option<char>) = (None, None);
-    for (k, v) in mapping {
-        keys.push(k);
-        values.push(v);
-        min = Some(min.map_or(k, |min| min.min(k)));
-        max = Some(max.map_or(k, |max| max.max(k)));
-    }
-    let range = min.expect("Empty data")..=max.expect("Empty data");
+    let source = match parts {
+        Some(parts) => format!(
+            "Part{} of NormalizationTest.txt",
+            parts
+                .iter()
+                .map(u32::to_string)
+                .collect::<Vec<_>>()
+                .join(", Part")
+        ),
+        None => "NormalizationTest.txt".to_string(),
+    };
+    let mut out = format!(
+        "# Generated from {} by the generator, do not edit.\n\
+         # source; NFKD without nonspacing and enclosing marks\n",
+        source
+    );
+    let mut seen = std::collections::HashSet::new();
+    for (source, nfkd) in tests {
+        if source.iter().any(kept_composed) || !seen.insert(source) {
+            continue;
+        }
+        let mut expected = nfkd.iter().filter(|&&c| !is_mark(c));
+        out.push_str(&format!(
+            "{};{}\n",
+            hex(&mut source.iter()),
+            hex(&mut expected)
+        ));
+    }
+    out
+}
+
+// Runs of consecutive chars sharing a nonzero canonical combining class
+fn print_combining_classes(combining_classes: &HashMap<u32, u8>) {
+    let mut classes = combining_classes
+        .iter()
+        .map(|(&cp, &class)| (cp, class))
+        .collect::<Vec<_>>();
+    classes.sort_unstable();
+    let mut ranges: Vec<(RangeInclusive<u32>, u8)> = Vec::new();
+    for (cp, class) in classes {
+        match ranges.last_mut() {
+            Some((last, c)) if *last.end() + 1 == cp && *c == class => *last = *last.start()..=cp,
+            _ => ranges.push((cp..=cp, class)),
+        }
+    }
+
+    print!(
+        "
+        // See `crate::reorder_combining_marks`.
+        #[cfg(feature = \"alloc\")]
+        pub(crate) const COMBINING_CLASSES: &[(char, char, u8)] = &["
+    );
+    for (range, class) in ranges {
+        print!(
+            "
+            ({:?}, {:?}, {}),",
+            char::from_u32(*range.start()).unwrap(),
+            char::from_u32(*range.end()).unwrap(),
+            class
+        );
+    }
+    println!(
+        "
+        ];"
+    );
+}
+
+fn print_ranges(name: &str, codepoints: &[u32]) {
+    let mut ranges: Vec<RangeInclusive<u32>> = Vec::new();
+    for &cp in codepoints {
+        match ranges.last_mut() {
+            Some(last) if *last.end() + 1 == cp => *last = *last.start()..=cp,
+            _ => ranges.push(cp..=cp),
+        }
+    }
+
+    print!(
+        "
+        pub const {}: &[core::ops::RangeInclusive<char>] = &[",
+        name
+    );
+    for range in ranges {
+        print!(
+            "
+            {:?}..={:?},",
+            char::from_u32(*range.start()).unwrap(),
+            char::from_u32(*range.end()).unwrap()
+        );
+    }
+    println!(
+        "
+        ];"
+    );
+}
+
+// What map_char gives for every BMP char, in two levels: the high byte
+// picks one of the distinct 256-entry blocks, the low byte an entry of it,
+// which is 0 or 1 + the index of the replacement in REPLACEMENTS.
+fn print_dense(marks: &[u32], diacritics: &HashMap<char, Box<str>>, pool: &[Box<str>]) {
+    let mut blocks = vec![[0u16; 256]];
+    let mut index = [0u8; 256];
+    for hi in 0..256u32 {
+        let mut block = [0u16; 256];
+        for lo in 0..256u32 {
+            let Some(ch) = char::from_u32(hi << 8 | lo) else {
+                continue;
+            };
+            let value = if is_diacritic(marks, ch) {
+                Some("")
+            } else {
+                diacritics.get(&ch).map(|v| &**v)
+            };
+            if let Some(value) = value {
+                block[lo as usize] = pool_index(pool, value)
+                    .checked_add(1)
+                    .expect("too many values for u16 ids");
+            }
+        }
+        index[hi as usize] = match blocks.iter().position(|b| *b == block) {
+            Some(i) => i,
+            None => {
+                blocks.push(block);
+                blocks.len() - 1
+            }
+        }
+        .try_into()
+        .expect("too many blocks for u8 ids");
+    }
+
+    println!("#[cfg(feature = \"dense-table\")]");
+    println!("static DENSE_INDEX: [u8; 256] = {:?};", index);
+    println!();
+    println!("#[cfg(feature = \"dense-table\")]");
+    println!("static DENSE_BLOCKS: [[u16; 256]; {}] = [", blocks.len());
+    for block in &blocks {
+        println!("    {:?},", block);
+    }
+    println!("];");
+    println!(
+        "
+        /// What [`map_char`](crate::map_char) gives for a BMP char, with two
+        /// array lookups instead of a binary search and a hash.
+        #[cfg(feature = \"dense-table\")]
+        #[inline]
+        pub(crate) fn map_bmp(c: u16) -> Option<&'static str> {{
+            match DENSE_BLOCKS[DENSE_INDEX[(c >> 8) as usize] as usize][(c & 0xff) as usize] {{
+                0 => None,
+                i => Some(REPLACEMENTS[i as usize - 1]),
+            }}
+        }}"
+    );
+}
+
+fn print_sorted(mapping: &HashMap<char, Box<str>>, pool: &[Box<str>]) {
+    let mut entries = mapping.iter().collect::<Vec<_>>();
+    entries.sort_unstable();
+
+    print!(
+        "
+        /// The entries of [`DIACRITICS_MAPPING`] sorted by char, for a binary
+        /// search instead of a hash: [`map_char`](crate::map_char) looks up
+        /// this one with the `binary-search` feature.
+        pub const DIACRITICS_SORTED: &[(char, &str)] = &["
+    );
+    for (k, v) in entries {
+        print!(
+            "
+            ({:?}, REPLACEMENTS[{}]),",
+            k,
+            pool_index(pool, v)
+        );
+    }
+    println!(
+        "
+        ];"
+    );
+}
+
+fn print_reverse_lookup() {
+    println!(
+        "
+        /// The chars that [`DIACRITICS_MAPPING`] folds to exactly `target`,
+        /// lazily found by a linear scan. They come in table order, which
+        /// follows no particular order of chars.
+        pub fn reverse_lookup(target: &str) -> impl Iterator<Item = char> + '_ {{
+            DIACRITICS_MAPPING
+                .entries()
+                .filter(move |(_, v)| v.as_str() == target)
+                .map(|(k, _)| k)
+        }}"
+    );
+}
+
+// Hangul syllables have no decomposition in UnicodeData.txt, it's
+// algorithmic: every syllable is L V or L V T conjoining jamo.
+fn hangul_jamo(s_index: u32) -> Vec<char> {
+    let l = L_BASE + s_index / N_COUNT;
+    let v = V_BASE + (s_index % N_COUNT) / T_COUNT;
+    let t = T_BASE + s_index % T_COUNT;
+    [l, v, t]
+        .into_iter()
+        .filter(|&c| c != T_BASE)
+        .map(|c| char::from_u32(c).unwrap())
+        .collect()
+}
+
+fn print_hangul() {
+    println!(
+        "
+        // The conjoining jamo of every Hangul syllable in order, 3 UTF-8
+        // bytes each: L V for the first syllable of each row, L V T after.
+        #[cfg(feature = \"hangul\")]
+        const HANGUL_JAMO: &str = \"\\"
+    );
+    for row in 0..S_COUNT / T_COUNT {
+        let jamo = (row * T_COUNT..(row + 1) * T_COUNT)
+            .flat_map(hangul_jamo)
+            .collect::<String>();
+        println!("{}\\", jamo);
+    }
+    println!(
+        "\";
+
+        /// The conjoining jamo (`U+1100..=U+11FF`) that the precomposed Hangul
+        /// syllable `ch` decomposes to, `None` outside `U+{:04X}..=U+{:04X}`.
+        #[cfg(feature = \"hangul\")]
+        pub fn decompose_hangul(ch: char) -> Option<&'static str> {{
+            let s = (ch as u32).checked_sub(0x{:X})?;
+            if s >= {} {{
+                return None;
+            }}
+            // Every LV syllable before this one is 3 bytes shorter
+            let start = (s * 9 - s.div_ceil({}) * 3) as usize;
+            let len = if s % {} == 0 {{ 6 }} else {{ 9 }};
+            Some(&HANGUL_JAMO[start..start + len])
+        }}",
+        S_BASE,
+        S_BASE + S_COUNT - 1,
+        S_BASE,
+        S_COUNT,
+        T_COUNT,
+        T_COUNT
+    );
+}
+
+fn print_latin1_match(mapping: &HashMap<char, Box<str>>, annotations: Option<&Annotations>) {
+    let mut entries = mapping
+        .iter()
+        .filter(|(&k, _)| ('\u{80}'..='\u{17f}').contains(&k))
+        .filter_map(|(&k, v)| {
+            let mut chars = v.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some((k, c)),
+                _ => None,
+            }
+        })
+        .collect::<Vec<_>>();
+    entries.sort_unstable();
+
+    print!(
+        "
+        /// `match`-based lookup of [`DIACRITICS_MAPPING`] restricted to the
+        /// entries in Latin-1 Supplement and Latin Extended-A
+        /// (`U+0080..=U+017F`) that fold to a single char. Every other char,
+        /// including marks that are dropped and letters that expand to
+        /// several chars, returns `None`.
+        pub const fn strip_latin1(ch: char) -> Option<char> {{
+            match ch {{"
+    );
+    for (k, v) in entries {
+        if let Some(annotations) = annotations {
+            print!(
+                "
+                // {}",
+                annotations.describe(k)
+            );
+        }
+        print!(
+            "
+                {:?} => Some({:?}),",
+            k, v
+        );
+    }
+    println!(
+        "
+                _ => None,
+            }}
+        }}"
+    );
+}
+
+fn print_char_map(
+    name: &str,
+    mapping: HashMap<char, Box<str>>,
+    pool: &[Box<str>],
+    annotations: Option<&Annotations>,
+) {
+    let (keys, values): (Vec<_>, Vec<_>) = mapping.into_iter().unzip();
+    let range = *keys.iter().min().expect("Empty data")..=*keys.iter().max().expect("Empty data");
     let state = phf_generator::generate_hash(&keys);
 
     print!(
-        "pub const DIACRITICS_MAPPING: crate::phf::CharMap<&'static str> = crate::phf::CharMap {{
+        "pub const {}: crate::phf::CharMap<crate::Replacement> = crate::phf::CharMap {{
     range: {:?},
     key: {:?},
     disps: &[",
-        range, state.key
+        name, range, state.key
     );
 
     for &(d1, d2) in &state.disps {
@@ -460,10 +1067,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     for &idx in &state.map {
+        if let Some(annotations) = annotations {
+            print!(
+                "
+        // {}",
+                annotations.describe(keys[idx])
+            );
+        }
         print!(
             "
-        ({:?}, {:?}),",
-            &keys[idx], &values[idx]
+        ({:?}, crate::Replacement({})),",
+            &keys[idx],
+            pool_index(pool, &values[idx])
         );
     }
 
@@ -472,6 +1087,361 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     ],
 }};"
     );
+}
+
+#[derive(Debug)]
+struct Args {
+    debug_comments: bool,
+    unicode_version: UnicodeVersion,
+    ucd_dir: Option<PathBuf>,
+    normalization_fixture: Option<PathBuf>,
+    normalization_parts: Option<Vec<u32>>,
+}
+
+impl Args {
+    fn parse() -> Result<Self, Box<dyn std::error::Error>> {
+        let mut args = Self {
+            debug_comments: false,
+            unicode_version: match std::env::var("UNICODE_VERSION") {
+                Ok(v) => v.parse()?,
+                Err(_) => UNICODE_VERSION,
+            },
+            ucd_dir: None,
+            normalization_fixture: None,
+            normalization_parts: None,
+        };
+        let mut it = std::env::args().skip(1);
+        while let Some(arg) = it.next() {
+            match arg.as_str() {
+                // annotate every entry with its char name and decomposition type
+                "--debug-comments" => args.debug_comments = true,
+                "--unicode-version" => {
+                    let v = it.next().ok_or("--unicode-version needs a value")?;
+                    args.unicode_version = v.parse()?;
+                }
+                // read the UCD files from a local directory, no network
+                "--ucd-dir" => {
+                    let dir = it.next().ok_or("--ucd-dir needs a value")?;
+                    args.ucd_dir = Some(dir.into());
+                }
+                // also write the stripped NormalizationTest.txt cases there
+                "--normalization-fixture" => {
+                    let path = it.next().ok_or("--normalization-fixture needs a value")?;
+                    args.normalization_fixture = Some(path.into());
+                }
+                // only take these comma separated @Part numbers into the fixture
+                "--normalization-parts" => {
+                    let parts = it.next().ok_or("--normalization-parts needs a value")?;
+                    args.normalization_parts =
+                        Some(parts.split(',').map(str::parse).collect::<Result<_, _>>()?);
+                }
+                _ => return Err(format!("Unknown argument: {}", arg).into()),
+            }
+        }
+        Ok(args)
+    }
+
+    fn ucd(&self) -> Ucd {
+        match &self.ucd_dir {
+            Some(dir) => Ucd::Local(dir.clone()),
+            None => Ucd::Network(self.unicode_version),
+        }
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse()?;
+    let ucd = args.ucd();
+    let (names, combining_classes, compat_decomp, compat_tags, canon_decomp, mut marks, lowercase) =
+        load_unicode_data(&ucd)?;
+    // Variation selectors are Mn, but select a glyph rather than mark
+    // anything: they're left to strip_variation_selectors. The kana voicing
+    // marks make another kana, が isn't an accented か, and are kept too.
+    marks.retain(|cp| {
+        !(0xFE00..=0xFE0F).contains(cp)
+            && !(0xE0100..=0xE01EF).contains(cp)
+            && !(0x3099..=0x309A).contains(cp)
+    });
+    marks.sort_unstable();
+    let special_uppercase = load_special_casing(&ucd)?;
+    // Lowercasing the full uppercase gives the caseless form, ß → SS → ss
+    let special_case_folding = special_uppercase
+        .iter()
+        .map(|(&k, v)| {
+            (
+                k,
+                v.iter().map(|c| *lowercase.get(c).unwrap_or(c)).collect(),
+            )
+        })
+        .collect::<HashMap<_, Vec<_>>>();
+    let (canon_decomp, compat_decomp) = compute_fully_decomposed(canon_decomp, compat_decomp)?;
+    let (diacritics, compatibility) = {
+        let mut diacritics = HashMap::<char, Box<str>>::new();
+        add_mapping(&canon_decomp, &combining_classes, &marks, &mut diacritics);
+        add_singleton_mapping(&canon_decomp, &compat_decomp, &marks, &mut diacritics);
+        add_mapping(&compat_decomp, &combining_classes, &marks, &mut diacritics);
+        let mut compatibility = HashMap::<char, Box<str>>::new();
+        add_compatibility_mapping(
+            &compat_decomp,
+            &combining_classes,
+            &marks,
+            &diacritics,
+            &mut compatibility,
+        );
+        (diacritics, compatibility)
+    };
+    // Vulgar fractions with an ASCII slash instead of U+2044 FRACTION SLASH
+    let fractions = compat_decomp
+        .iter()
+        .filter(|(_, v)| v.contains(&0x2044))
+        .map(|(&k, v)| {
+            let v = v.iter().map(|&c| if c == 0x2044 { '/' as u32 } else { c });
+            (k, v.collect())
+        })
+        .chain(std::iter::once((0x2044, vec!['/' as u32])))
+        .collect::<HashMap<_, Vec<_>>>();
+    // The <font> variants of letters and digits: Mathematical Alphanumeric
+    // Symbols, and the Letterlike Symbols filling the holes they leave
+    let math_alpha = compat_decomp
+        .iter()
+        .filter(|&(&k, v)| match v[..] {
+            [c] if (0x1D400..=0x1D7FF).contains(&k) => c != k,
+            [c] if (0x2100..=0x214F).contains(&k) => {
+                char::from_u32(c).is_some_and(|c| c.is_ascii_alphanumeric())
+            }
+            _ => false,
+        })
+        .map(|(&k, v)| (k, v.clone()))
+        .collect::<HashMap<_, Vec<_>>>();
+    // The <wide> and <narrow> forms of ASCII: fullwidth letters, digits and
+    // punctuation, and IDEOGRAPHIC SPACE
+    let fullwidth = compat_decomp
+        .iter()
+        .filter(|&(k, v)| {
+            compat_tags
+                .get(k)
+                .is_some_and(|t| t == "wide" || t == "narrow")
+                && v.iter().all(|&c| c < 0x80)
+        })
+        .map(|(&k, v)| (k, v.clone()))
+        .collect::<HashMap<_, Vec<_>>>();
+    // The <compat> decompositions of Latin ligatures: ﬁ → fi, Ĳ → IJ. Those
+    // without any decomposition, like æ and œ, are left to the crate
+    let ligatures = compat_decomp
+        .iter()
+        .filter(|&(k, _)| {
+            compat_tags.get(k).is_some_and(|t| t == "compat")
+                && names[k].starts_with("LATIN ")
+                && names[k].contains(" LIGATURE ")
+        })
+        .map(|(&k, v)| (k, v.clone()))
+        .collect::<HashMap<_, Vec<_>>>();
+    let annotations = args.debug_comments.then_some(Annotations {
+        names: &names,
+        compat_decomp: &compat_decomp,
+    });
+
+    let (max_chars, max_len) = diacritics
+        .values()
+        .chain(compatibility.values())
+        .fold((0, 0), |(chars, len), v| {
+            (chars.max(v.chars().count()), len.max(v.len()))
+        });
+
+    let fractions = to_mapping(&fractions);
+    let math_alpha = to_mapping(&math_alpha);
+    let fullwidth = to_mapping(&fullwidth);
+    let ligatures = to_mapping(&ligatures);
+    let special_uppercase = to_mapping(&special_uppercase);
+    let special_case_folding = to_mapping(&special_case_folding);
+    let pool = intern(&[
+        &diacritics,
+        &compatibility,
+        &fractions,
+        &math_alpha,
+        &fullwidth,
+        &ligatures,
+        &special_uppercase,
+        &special_case_folding,
+    ]);
+
+    println!("pub const MAX_REPLACEMENT_CHARS: usize = {};", max_chars);
+    println!();
+    println!("pub const MAX_REPLACEMENT_LEN: usize = {};", max_len);
+    println!();
+    print_pool(&pool);
+    println!();
+    print_latin1_match(&diacritics, annotations.as_ref());
+    println!();
+    print_ranges("COMBINING_MARKS", &marks);
+    println!();
+    print_combining_classes(&combining_classes);
+    println!();
+    print_dense(&marks, &diacritics, &pool);
+    println!();
+    print_sorted(&diacritics, &pool);
+    println!();
+    print_char_map(
+        "DIACRITICS_MAPPING",
+        diacritics,
+        &pool,
+        annotations.as_ref(),
+    );
+    println!();
+    print_reverse_lookup();
+    println!();
+    print_char_map(
+        "COMPATIBILITY_MAPPING",
+        compatibility,
+        &pool,
+        annotations.as_ref(),
+    );
+    println!();
+    print_char_map("FRACTION_MAPPING", fractions, &pool, annotations.as_ref());
+    println!();
+    print_char_map(
+        "MATH_ALPHA_MAPPING",
+        math_alpha,
+        &pool,
+        annotations.as_ref(),
+    );
+    println!();
+    print_char_map("FULLWIDTH_MAPPING", fullwidth, &pool, annotations.as_ref());
+    println!();
+    print_char_map("LIGATURE_MAPPING", ligatures, &pool, annotations.as_ref());
+    println!();
+    print_char_map(
+        "SPECIAL_UPPERCASE",
+        special_uppercase,
+        &pool,
+        annotations.as_ref(),
+    );
+    println!();
+    print_char_map(
+        "SPECIAL_CASE_FOLDING",
+        special_case_folding,
+        &pool,
+        annotations.as_ref(),
+    );
+    println!();
+    print_hangul();
+
+    if let Some(path) = &args.normalization_fixture {
+        let parts = args.normalization_parts.as_deref();
+        let tests = load_normalization_test(&ucd, parts)?;
+        std::fs::write(
+            path,
+            normalization_fixture(&tests, parts, &canon_decomp, &marks),
+        )?;
+    }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unicode_version() {
+        assert_eq!("15.0.0".parse(), Ok(UnicodeVersion(15, 0, 0)));
+        assert_eq!("6.3.10".parse(), Ok(UnicodeVersion(6, 3, 10)));
+        assert_eq!(UnicodeVersion(15, 1, 0).to_string(), "15.1.0");
+    }
+
+    #[test]
+    fn invalid_unicode_version() {
+        for s in [
+            "", "15", "15.0", "15.0.0.0", "15..0", "15.0.x", "+15.0.0", " 15.0.0", "v15.0.0",
+        ] {
+            assert!(s.parse::<UnicodeVersion>().is_err(), "{:?}", s);
+        }
+    }
+
+    fn fixture() -> Ucd {
+        Ucd::Local(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/ucd"))
+    }
+
+    #[test]
+    fn hangul() {
+        assert_eq!(hangul_jamo(0), ['\u{1100}', '\u{1161}']);
+        // 각
+        assert_eq!(hangul_jamo(1), ['\u{1100}', '\u{1161}', '\u{11A8}']);
+        // 힣
+        assert_eq!(
+            hangul_jamo(S_COUNT - 1),
+            ['\u{1112}', '\u{1175}', '\u{11C2}']
+        );
+    }
+
+    #[test]
+    fn interning() {
+        let a = HashMap::from([('à', "a".into()), ('á', "a".into()), ('é', "e".into())]);
+        let b = HashMap::from([('ǆ', "dz".into()), ('ä', "a".into())]);
+        let pool = intern(&[&a, &b]);
+        assert_eq!(pool, ["", "a", "dz", "e"].map(Box::from));
+        assert!(pool.len() - 1 < a.len() + b.len());
+        assert_eq!(pool_index(&pool, "dz"), 2);
+    }
+
+    #[test]
+    fn local_ucd() {
+        let (names, combining_classes, compat_decomp, compat_tags, canon_decomp, marks, lowercase) =
+            load_unicode_data(&fixture()).unwrap();
+        assert_eq!(names.len(), 6);
+        assert_eq!(names[&0xC0], "LATIN CAPITAL LETTER A WITH GRAVE");
+        assert_eq!(combining_classes, HashMap::from([(0x300, 230)]));
+        assert_eq!(canon_decomp[&0xC0], [0x41, 0x300]);
+        assert_eq!(compat_decomp[&0xBD], [0x31, 0x2044, 0x32]);
+        assert_eq!(compat_tags, HashMap::from([(0xBD, "fraction".to_string())]));
+        assert_eq!(marks, [0x300]);
+        assert_eq!(lowercase[&0x41], 0x61);
+    }
+
+    #[test]
+    fn normalization_test() {
+        let part0 = load_normalization_test(&fixture(), Some(&[0])).unwrap();
+        assert_eq!(part0, [(vec![0xC0, 0x300], vec![0x41, 0x300, 0x300])]);
+        assert_eq!(
+            load_normalization_test(&fixture(), Some(&[1, 2]))
+                .unwrap()
+                .len(),
+            4
+        );
+
+        let tests = load_normalization_test(&fixture(), None).unwrap();
+        assert_eq!(tests.len(), 5);
+        assert_eq!(tests[0], (vec![0xC0, 0x300], vec![0x41, 0x300, 0x300]));
+        assert_eq!(tests[1], (vec![0xBD], vec![0x31, 0x2044, 0x32]));
+
+        let canon_decomp = HashMap::from([
+            (0xC0, vec![0x41, 0x300]),
+            (0xE0, vec![0x61, 0x300]),
+            (0xDDC, vec![0xDD9, 0xDCF]),
+        ]);
+        let fixture = normalization_fixture(&tests, None, &canon_decomp, &[0x300]);
+        assert!(fixture.starts_with("# Generated from NormalizationTest.txt by"));
+        assert!(
+            normalization_fixture(&part0, Some(&[0, 1]), &canon_decomp, &[0x300])
+                .starts_with("# Generated from Part0, Part1 of NormalizationTest.txt by")
+        );
+        assert_eq!(
+            fixture
+                .lines()
+                .filter(|l| !l.starts_with('#'))
+                .collect::<Vec<_>>(),
+            [
+                "00C0 0300;0041",
+                "00BD;0031 2044 0032",
+                "00C0;0041",
+                "00E0;0061"
+            ]
+        );
+    }
+
+    #[test]
+    fn local_ucd_missing_file() {
+        let err = load_special_casing(&fixture()).unwrap_err().to_string();
+        assert!(err.contains("SpecialCasing.txt"), "{}", err);
+    }
+}
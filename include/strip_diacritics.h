@@ -0,0 +1,38 @@
+/* C interface of the strip-diacritics crate, built with the `capi` feature. */
+#ifndef STRIP_DIACRITICS_H
+#define STRIP_DIACRITICS_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* A required pointer is null. */
+#define SD_ERR_NULL (-1)
+/* The input isn't valid UTF-8. */
+#define SD_ERR_UTF8 (-2)
+
+/*
+ * Strips the NUL-terminated UTF-8 `input` into `out`, a buffer of `out_len`
+ * bytes, NUL included. Returns the stripped length without the NUL, which
+ * only fits if it's less than `out_len`, otherwise `out` holds an empty
+ * string. `out` may be NULL when `out_len` is 0, to size a buffer.
+ * Returns SD_ERR_NULL or SD_ERR_UTF8 on error.
+ */
+ptrdiff_t sd_strip_diacritics(const char *input, char *out, size_t out_len);
+
+/*
+ * Strips `input` into a new string, to be released with sd_free(), never
+ * with free(). Returns NULL if `input` is NULL or isn't valid UTF-8.
+ */
+char *sd_strip_diacritics_alloc(const char *input);
+
+/* Releases a string from sd_strip_diacritics_alloc(), NULL is a no-op. */
+void sd_free(char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
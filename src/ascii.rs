@@ -0,0 +1,95 @@
+const WORD: usize = core::mem::size_of::<usize>();
+const HIGH_BITS: usize = usize::from_ne_bytes([0x80; WORD]);
+
+/// The index of the first byte of `bytes` that isn't ASCII.
+#[inline]
+pub(crate) fn first_non_ascii(bytes: &[u8]) -> Option<usize> {
+    // Between accented letters ASCII runs are often a few bytes long, too
+    // short to pay for the setup of a wide scan.
+    let head = &bytes[..bytes.len().min(WORD)];
+    match head.iter().position(|b| !b.is_ascii()) {
+        Some(i) => Some(i),
+        None => wide(&bytes[head.len()..]).map(|i| head.len() + i),
+    }
+}
+
+// Tests the high bit of a whole word at a time.
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+#[inline]
+fn wide(bytes: &[u8]) -> Option<usize> {
+    words(bytes)
+}
+
+// Tests 16 bytes at a time with SSE2, which every x86_64 CPU has.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+#[inline]
+fn wide(bytes: &[u8]) -> Option<usize> {
+    use core::arch::x86_64::{__m128i, _mm_loadu_si128, _mm_movemask_epi8};
+
+    let mut chunks = bytes.chunks_exact(16);
+    let mut i = 0;
+    for chunk in &mut chunks {
+        // SAFETY: SSE2 is part of the x86_64 baseline, the chunk is 16
+        // bytes long and loadu has no alignment requirement.
+        let high = unsafe { _mm_movemask_epi8(_mm_loadu_si128(chunk.as_ptr().cast::<__m128i>())) };
+        if high != 0 {
+            return Some(i + high.trailing_zeros() as usize);
+        }
+        i += 16;
+    }
+    words(chunks.remainder()).map(|j| i + j)
+}
+
+#[inline]
+fn words(bytes: &[u8]) -> Option<usize> {
+    let mut chunks = bytes.chunks_exact(WORD);
+    let mut i = 0;
+    for chunk in &mut chunks {
+        let high = usize::from_ne_bytes(chunk.try_into().unwrap()) & HIGH_BITS;
+        if high != 0 {
+            // The first byte in memory order
+            let bits = if cfg!(target_endian = "little") {
+                high.trailing_zeros()
+            } else {
+                high.leading_zeros()
+            };
+            return Some(i + bits as usize / 8);
+        }
+        i += WORD;
+    }
+    chunks
+        .remainder()
+        .iter()
+        .position(|b| !b.is_ascii())
+        .map(|j| i + j)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_position() {
+        let mut bytes = [b'a'; 80];
+        for len in 0..bytes.len() {
+            for start in 0..len.min(WORD) {
+                assert_eq!(first_non_ascii(&bytes[start..len]), None);
+                assert_eq!(wide(&bytes[start..len]), None);
+                assert_eq!(words(&bytes[start..len]), None);
+            }
+        }
+        for pos in 0..bytes.len() {
+            for high in [0x80, 0xc3, 0xff] {
+                bytes[pos] = high;
+                bytes[bytes.len() - 1] = high;
+                for start in 0..=pos.min(WORD) {
+                    let expected = Some(pos - start);
+                    assert_eq!(first_non_ascii(&bytes[start..]), expected, "{pos} {start}");
+                    assert_eq!(wide(&bytes[start..]), expected, "{pos} {start}");
+                    assert_eq!(words(&bytes[start..]), expected, "{pos} {start}");
+                }
+            }
+            bytes[pos] = b'a';
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+use crate::StrDiacriticExt;
+
+/// Strips the UTF-8 parts of `bytes`, passing every invalid sequence
+/// through untouched, without validating the whole input upfront.
+///
+/// Borrows when nothing changes, invalid sequences included.
+pub fn strip_diacritics_bytes(bytes: &[u8]) -> Cow<'_, [u8]> {
+    let mut chunks = bytes.utf8_chunks();
+    let mut buf = loop {
+        let chunk = match chunks.next() {
+            Some(chunk) => chunk,
+            None => return Cow::Borrowed(bytes),
+        };
+        if let Cow::Owned(stripped) = chunk.valid().strip_diacritics() {
+            let start = chunk.valid().as_ptr() as usize - bytes.as_ptr() as usize;
+            let mut buf = Vec::with_capacity(bytes.len());
+            buf.extend_from_slice(&bytes[..start]);
+            buf.extend_from_slice(stripped.as_bytes());
+            buf.extend_from_slice(chunk.invalid());
+            break buf;
+        }
+    };
+    for chunk in chunks {
+        buf.extend_from_slice(chunk.valid().strip_diacritics().as_bytes());
+        buf.extend_from_slice(chunk.invalid());
+    }
+    Cow::Owned(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borrowed() {
+        for b in [
+            &b""[..],
+            b"plain ascii",
+            "Ærøskøbing".as_bytes(),
+            b"bad \xff\xc3",
+        ] {
+            assert!(matches!(strip_diacritics_bytes(b), Cow::Borrowed(s) if s == b));
+        }
+    }
+
+    #[test]
+    fn owned() {
+        assert_eq!(
+            strip_diacritics_bytes("Crème brûlée".as_bytes()),
+            &b"Creme brulee"[..]
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            strip_diacritics_bytes(b"\xffcaf\xc3\xa9 \xed\xa0\x80 d\xc3\xa9j\xc3"),
+            &b"\xffcafe \xed\xa0\x80 dej\xc3"[..]
+        );
+    }
+}
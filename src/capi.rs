@@ -0,0 +1,156 @@
+//! A C interface, behind the `capi` feature. Build a static or dynamic
+//! library out of it with
+//! `cargo rustc --release --features capi --crate-type staticlib` (or
+//! `cdylib`), and declare the functions from `include/strip_diacritics.h`.
+//!
+//! Strings go in as NUL-terminated UTF-8. Invalid UTF-8 isn't repaired but
+//! rejected with [`SD_ERR_UTF8`], and null pointers are checked, never
+//! dereferenced.
+
+use std::{
+    ffi::{c_char, CStr, CString},
+    ptr,
+};
+
+use crate::{CStrDiacriticExt, StrDiacriticExt};
+
+/// A required pointer is null.
+pub const SD_ERR_NULL: isize = -1;
+/// The input isn't valid UTF-8.
+pub const SD_ERR_UTF8: isize = -2;
+
+/// Strips `input` into `out`, a buffer of `out_len` bytes, NUL included.
+///
+/// Returns the length of the stripped string without its NUL, like
+/// `snprintf`: it only fits if that's less than `out_len`. Otherwise `out`
+/// holds an empty string, or is left alone when `out_len` is 0, in which
+/// case `out` may be null: call it with `(input, NULL, 0)` to size a
+/// buffer. Returns [`SD_ERR_NULL`] if `input`, or `out` while `out_len`
+/// isn't 0, is null, and [`SD_ERR_UTF8`] if `input` isn't valid UTF-8.
+///
+/// # Safety
+///
+/// `input` must be null or point to a NUL-terminated string, and `out`
+/// must be null or valid for writes of `out_len` bytes. They must not
+/// overlap.
+#[no_mangle]
+pub unsafe extern "C" fn sd_strip_diacritics(
+    input: *const c_char,
+    out: *mut c_char,
+    out_len: usize,
+) -> isize {
+    if input.is_null() || (out.is_null() && out_len != 0) {
+        return SD_ERR_NULL;
+    }
+    let Ok(s) = CStr::from_ptr(input).to_str() else {
+        return SD_ERR_UTF8;
+    };
+    if out_len == 0 {
+        return s.stripped_len() as isize;
+    }
+
+    let out = std::slice::from_raw_parts_mut(out.cast::<u8>(), out_len);
+    match s.strip_diacritics_write(&mut out[..out_len - 1]) {
+        Ok(len) => {
+            out[len] = 0;
+            len as isize
+        }
+        Err(err) => {
+            out[0] = 0;
+            err.required as isize
+        }
+    }
+}
+
+/// Strips `input` into a new NUL-terminated string, to be released with
+/// [`sd_free`], never with `free`. Returns null if `input` is null or isn't
+/// valid UTF-8.
+///
+/// # Safety
+///
+/// `input` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn sd_strip_diacritics_alloc(input: *const c_char) -> *mut c_char {
+    if input.is_null() {
+        return ptr::null_mut();
+    }
+    match CStr::from_ptr(input).strip_diacritics() {
+        Ok(s) => s.into_raw(),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Releases a string returned by [`sd_strip_diacritics_alloc`]. Does
+/// nothing if `s` is null.
+///
+/// # Safety
+///
+/// `s` must be null or a pointer returned by [`sd_strip_diacritics_alloc`]
+/// that wasn't freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn sd_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_buffer() {
+        let input = c"Crème brûlée";
+        let mut buf = [0x7f as c_char; 16];
+        unsafe {
+            assert_eq!(sd_strip_diacritics(input.as_ptr(), ptr::null_mut(), 0), 12);
+            assert_eq!(
+                sd_strip_diacritics(input.as_ptr(), buf.as_mut_ptr(), 13),
+                12
+            );
+            assert_eq!(CStr::from_ptr(buf.as_ptr()), c"Creme brulee");
+
+            // No room for the NUL
+            assert_eq!(
+                sd_strip_diacritics(input.as_ptr(), buf.as_mut_ptr(), 12),
+                12
+            );
+            assert_eq!(CStr::from_ptr(buf.as_ptr()), c"");
+
+            assert_eq!(sd_strip_diacritics(c"".as_ptr(), buf.as_mut_ptr(), 1), 0);
+            assert_eq!(buf[0], 0);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let mut buf = [0 as c_char; 8];
+        unsafe {
+            assert_eq!(
+                sd_strip_diacritics(ptr::null(), buf.as_mut_ptr(), 8),
+                SD_ERR_NULL
+            );
+            assert_eq!(
+                sd_strip_diacritics(c"é".as_ptr(), ptr::null_mut(), 8),
+                SD_ERR_NULL
+            );
+            assert_eq!(
+                sd_strip_diacritics(c"caf\xc3".as_ptr(), buf.as_mut_ptr(), 8),
+                SD_ERR_UTF8
+            );
+            assert!(sd_strip_diacritics_alloc(ptr::null()).is_null());
+            assert!(sd_strip_diacritics_alloc(c"caf\xc3".as_ptr()).is_null());
+            sd_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn alloc() {
+        unsafe {
+            let s = sd_strip_diacritics_alloc(c"déjà vu".as_ptr());
+            assert!(!s.is_null());
+            assert_eq!(CStr::from_ptr(s), c"deja vu");
+            sd_free(s);
+        }
+    }
+}
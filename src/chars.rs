@@ -0,0 +1,90 @@
+use core::{iter::FusedIterator, str::Chars};
+
+use crate::{tables::MAX_REPLACEMENT_CHARS, CharDiacriticExt};
+
+/// Lazily stripped chars, see
+/// [`StrDiacriticExt::strip_diacritics_chars`](crate::StrDiacriticExt::strip_diacritics_chars).
+#[derive(Debug, Clone)]
+pub struct StripDiacritics<'a> {
+    chars: Chars<'a>,
+    pending: Chars<'static>,
+}
+
+impl<'a> StripDiacritics<'a> {
+    #[inline]
+    pub(crate) fn new(s: &'a str) -> Self {
+        Self {
+            chars: s.chars(),
+            pending: "".chars(),
+        }
+    }
+}
+
+impl Iterator for StripDiacritics<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.pending.next() {
+                return Some(c);
+            }
+            let c = self.chars.next()?;
+            match c.strip_diacritics() {
+                Some(t) => self.pending = t.chars(),
+                None => return Some(c),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every source char may be a dropped mark.
+        let (lo, hi) = self.pending.size_hint();
+        let rest = self.chars.size_hint().1;
+        (
+            lo,
+            hi.zip(rest.and_then(|n| n.checked_mul(MAX_REPLACEMENT_CHARS)))
+                .and_then(|(a, b)| a.checked_add(b)),
+        )
+    }
+}
+
+impl FusedIterator for StripDiacritics<'_> {}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::string::String;
+
+    use super::*;
+
+    use crate::StrDiacriticExt;
+
+    #[test]
+    fn matches_strip() {
+        for s in [
+            "",
+            "plain",
+            "Crème brûlée",
+            "\u{301}\u{302}",
+            "e\u{301}ǆ",
+            "ﬁ ǲ",
+        ] {
+            assert_eq!(
+                StripDiacritics::new(s).collect::<String>(),
+                s.strip_diacritics(),
+                "{s:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn adaptors() {
+        let mut it = StripDiacritics::new("ǆé\u{301}x");
+        assert_eq!(it.next(), Some('d'));
+        assert_eq!(it.size_hint().0, 1);
+        assert_eq!(it.by_ref().take(2).collect::<String>(), "ze");
+        assert_eq!(it.next(), Some('x'));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+    }
+}
@@ -0,0 +1,298 @@
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StripOptions<'a> {
+    pub(crate) compatibility: bool,
+    pub(crate) strip_variation_selectors: bool,
+    pub(crate) normalize_line_separators: bool,
+    pub(crate) german_sharp_s: bool,
+    pub(crate) fractions: bool,
+    pub(crate) math_alpha: bool,
+    pub(crate) ligatures: bool,
+    pub(crate) fullwidth: bool,
+    #[cfg(feature = "hangul")]
+    pub(crate) hangul_jamo: bool,
+    pub(crate) non_ascii: NonAsciiPolicy,
+    pub(crate) overrides: Option<OverrideMap<'a>>,
+    pub(crate) char_scan_only: bool,
+    pub(crate) display: bool,
+    pub(crate) recompose: bool,
+}
+
+impl<'a> StripOptions<'a> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            compatibility: false,
+            strip_variation_selectors: false,
+            normalize_line_separators: false,
+            german_sharp_s: false,
+            fractions: false,
+            math_alpha: false,
+            ligatures: false,
+            fullwidth: false,
+            #[cfg(feature = "hangul")]
+            hangul_jamo: false,
+            non_ascii: NonAsciiPolicy::Keep,
+            overrides: None,
+            char_scan_only: false,
+            display: false,
+            recompose: false,
+        }
+    }
+
+    /// Also fold characters through their compatibility decomposition when it
+    /// carries no diacritic: fullwidth forms (`Ａ` → `A`, `０` → `0`),
+    /// ligatures (`ﬁ` → `fi`), superscripts, enclosed alphanumerics, ...
+    ///
+    /// A character may expand to several, up to
+    /// [`MAX_REPLACEMENT_CHARS`](crate::tables::MAX_REPLACEMENT_CHARS):
+    /// `⑴` → `(1)`, `⒈` → `1.`, `㈱` → `(株)`, `Ⅻ` → `XII`, `℅` → `c/o`,
+    /// `№` → `No`.
+    ///
+    /// Superscripts and subscripts lose their position (`x²` → `x2`,
+    /// `H₂O` → `H2O`) and their minus signs fold to `U+2212 MINUS SIGN`,
+    /// not to an ASCII hyphen. None of this happens without the option.
+    ///
+    /// Halfwidth katakana fold to their fullwidth katakana, not to ASCII
+    /// (`ｶ` → `カ`).
+    #[inline]
+    pub const fn compatibility(mut self, yes: bool) -> Self {
+        self.compatibility = yes;
+        self
+    }
+
+    /// Also drop variation selectors (`U+FE00..=U+FE0F`,
+    /// `U+E0100..=U+E01EF`), like the VS15/VS16 emoji presentation
+    /// selectors and CJK ideographic variation sequences. They aren't
+    /// diacritics, so they are kept by default.
+    #[inline]
+    pub const fn strip_variation_selectors(mut self, yes: bool) -> Self {
+        self.strip_variation_selectors = yes;
+        self
+    }
+
+    /// Also fold line endings to `\n`: `U+2028 LINE SEPARATOR`,
+    /// `U+2029 PARAGRAPH SEPARATOR`, `U+0085 NEXT LINE`, a lone `\r` and,
+    /// when stripping a whole string, `\r\n`.
+    #[inline]
+    pub const fn normalize_line_separators(mut self, yes: bool) -> Self {
+        self.normalize_line_separators = yes;
+        self
+    }
+
+    /// Also fold the German sharp s, which has no decomposition, the way
+    /// German spells it without the letter: `ß` → `ss` and `ẞ` → `SS`.
+    #[inline]
+    pub const fn german_sharp_s(mut self, yes: bool) -> Self {
+        self.german_sharp_s = yes;
+        self
+    }
+
+    /// Also fold vulgar fractions to ASCII through their compatibility
+    /// decomposition, with `U+2044 FRACTION SLASH` becoming `/`: `½` → `1/2`,
+    /// `⅞` → `7/8`, `⅟` → `1/`. A lone fraction slash becomes `/` too.
+    ///
+    /// [`compatibility`](Self::compatibility) alone keeps the fraction slash
+    /// (`½` → `1⁄2`).
+    #[inline]
+    pub const fn fractions(mut self, yes: bool) -> Self {
+        self.fractions = yes;
+        self
+    }
+
+    /// Also fold the styled letters and digits of Mathematical Alphanumeric
+    /// Symbols (`U+1D400..=U+1D7FF`) to their plain form: bold `𝐇𝐞𝐥𝐥𝐨` →
+    /// `Hello`, italic `𝑥` → `x`, script `𝒮` → `S`, double-struck `𝟙` → `1`.
+    /// Their Greek letters fold to Greek ones (`𝛂` → `α`). The Letterlike
+    /// Symbols that fill the holes of the block fold too (`ℬ` → `B`,
+    /// `ℝ` → `R`, `ℎ` → `h`), so every style of every letter is covered.
+    ///
+    /// [`compatibility`](Self::compatibility) folds these as well, along
+    /// with everything else that has a compatibility decomposition.
+    #[inline]
+    pub const fn math_alpha(mut self, yes: bool) -> Self {
+        self.math_alpha = yes;
+        self
+    }
+
+    /// Also spell out Latin ligatures, which have no decomposition
+    /// (`æ` → `ae`, `Œ` → `OE`, `ß` → `ss`) or only a compatibility one
+    /// (`ﬀ` → `ff`, `ﬁ` → `fi`, `ﬂ` → `fl`, `ﬃ` → `ffi`, `ﬄ` → `ffl`,
+    /// `ﬅ` and `ﬆ` → `st`, `ĳ` → `ij`), without the rest of
+    /// [`compatibility`](Self::compatibility).
+    #[inline]
+    pub const fn ligatures(mut self, yes: bool) -> Self {
+        self.ligatures = yes;
+        self
+    }
+
+    /// Also fold the fullwidth forms of ASCII (`U+FF01..=U+FF5E`) that CJK
+    /// input methods produce, and `U+3000 IDEOGRAPHIC SPACE`, to ASCII:
+    /// `Ｈｅｌｌｏ` → `Hello`, `０１２` → `012`, without the rest of
+    /// [`compatibility`](Self::compatibility).
+    #[inline]
+    pub const fn fullwidth(mut self, yes: bool) -> Self {
+        self.fullwidth = yes;
+        self
+    }
+
+    /// Also decompose precomposed Hangul syllables into their conjoining
+    /// jamo (`각` → `ᄀ` `ᅡ` `ᆨ`), which are kept as is: Korean text has no
+    /// diacritics to strip and isn't romanized. Without the option syllables
+    /// are left alone.
+    ///
+    /// Behind the `hangul` feature, as the table of jamo takes about 100 KB.
+    #[cfg(feature = "hangul")]
+    #[inline]
+    pub const fn hangul_jamo(mut self, yes: bool) -> Self {
+        self.hangul_jamo = yes;
+        self
+    }
+
+    /// What to do with chars that are still outside ASCII once stripped,
+    /// [`NonAsciiPolicy::Keep`] by default.
+    ///
+    /// # Panics
+    ///
+    /// If the placeholder of [`NonAsciiPolicy::Replace`] isn't ASCII.
+    #[inline]
+    pub const fn non_ascii(mut self, policy: NonAsciiPolicy) -> Self {
+        if let NonAsciiPolicy::Replace(c) = policy {
+            assert!(c.is_ascii(), "the placeholder must be ASCII");
+        }
+        self.non_ascii = policy;
+        self
+    }
+
+    /// Looks chars up in `map` before anything else, the built-in mappings
+    /// and the other options only applying to the chars it misses. Mapping
+    /// a char to `""` drops it, mapping it to itself keeps it.
+    #[inline]
+    pub const fn overrides(mut self, map: OverrideMap<'a>) -> Self {
+        self.overrides = Some(map);
+        self
+    }
+
+    /// Strips for display rather than search: only chars whose entry in
+    /// [`DIACRITICS_MAPPING`](crate::tables::DIACRITICS_MAPPING) is
+    /// non-empty ASCII are folded (`é` → `e`, `ǆ` → `dz`). Combining marks
+    /// are kept, as are accented letters of other scripts (`ώ`, `й`), so
+    /// text where marks matter, like Arabic harakat or Devanagari vowel
+    /// signs, renders unchanged.
+    ///
+    /// The other options still apply on top of it.
+    #[inline]
+    pub const fn display(mut self, yes: bool) -> Self {
+        self.display = yes;
+        self
+    }
+
+    /// Keeps precomposed chars whose entry in
+    /// [`DIACRITICS_MAPPING`](crate::tables::DIACRITICS_MAPPING) isn't ASCII
+    /// as they are, instead of leaving their bare base behind: `ñ` → `n`,
+    /// but `ώ`, `й` and `ǣ` stay whole rather than becoming `ω`, `и` and
+    /// `æ`. Canonical singletons without an ASCII target, like `Ω` (U+2126)
+    /// or CJK compatibility ideographs, are kept too.
+    ///
+    /// Unlike [`display`](Self::display), combining marks are still
+    /// dropped, and the decision is made on each char alone: a decomposed
+    /// `ω◌́` isn't recomposed and loses its mark. Ligature folding applies
+    /// before the check, so `ǣ` becomes `ae` with
+    /// [`ligatures`](Self::ligatures).
+    #[inline]
+    pub const fn recompose(mut self, yes: bool) -> Self {
+        self.recompose = yes;
+        self
+    }
+
+    /// Disables the heuristic sampling the start of a string to decide
+    /// whether to skip over ASCII runs a byte at a time, and always decodes
+    /// every char instead. The output is the same either way.
+    #[inline]
+    pub const fn char_scan_only(mut self, yes: bool) -> Self {
+        self.char_scan_only = yes;
+        self
+    }
+}
+
+/// The borrowed table of [`StripOptions::overrides`]: a slice of
+/// `(char, replacement)` pairs sorted by char, searched by bisection.
+///
+/// Nothing is allocated, so it can be built at runtime without `std`, from
+/// any collection of entries, or in a `const`:
+///
+/// ```
+/// use strip_diacritics::{CharDiacriticExt, OverrideMap, StripOptions};
+///
+/// const CURRENCIES: OverrideMap = OverrideMap::from_sorted(&[('$', "USD"), ('€', "EUR")]);
+///
+/// let options = StripOptions::new().overrides(CURRENCIES);
+/// assert_eq!('€'.strip_diacritics_with(options), Some("EUR"));
+/// assert_eq!('à'.strip_diacritics_with(options), Some("a"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OverrideMap<'a>(&'a [(char, &'a str)]);
+
+impl<'a> OverrideMap<'a> {
+    /// Sorts `entries` in place to build the map. Which of the duplicates of
+    /// a char wins is unspecified.
+    #[inline]
+    pub fn new(entries: &'a mut [(char, &'a str)]) -> Self {
+        entries.sort_unstable_by_key(|&(c, _)| c);
+        Self(entries)
+    }
+
+    /// Builds the map from entries already sorted by char.
+    ///
+    /// # Panics
+    ///
+    /// If `entries` aren't sorted, or hold a char twice.
+    pub const fn from_sorted(entries: &'a [(char, &'a str)]) -> Self {
+        let mut i = 1;
+        while i < entries.len() {
+            assert!(
+                (entries[i - 1].0 as u32) < entries[i].0 as u32,
+                "the entries must be sorted by char, without duplicates"
+            );
+            i += 1;
+        }
+        Self(entries)
+    }
+
+    #[inline]
+    pub fn get(&self, c: char) -> Option<&'a str> {
+        self.0
+            .binary_search_by_key(&c, |&(k, _)| k)
+            .ok()
+            .map(|i| self.0[i].1)
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The entries, sorted by char.
+    #[inline]
+    pub const fn entries(&self) -> &'a [(char, &'a str)] {
+        self.0
+    }
+}
+
+/// What [`StripOptions::non_ascii`] does with a char whose stripped form
+/// isn't pure ASCII. The whole replacement counts: with compatibility
+/// folding, `㈱` → `(株)` is dropped or replaced as a whole.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NonAsciiPolicy {
+    /// Keep it, as stripped.
+    #[default]
+    Keep,
+    /// Drop it.
+    Drop,
+    /// Replace it with an ASCII placeholder.
+    Replace(char),
+}
@@ -0,0 +1,52 @@
+use std::{
+    ffi::{CStr, CString},
+    str::Utf8Error,
+};
+
+use crate::StrDiacriticExt;
+
+pub trait CStrDiacriticExt {
+    /// Strips the UTF-8 content of a C string into a new [`CString`].
+    ///
+    /// Fails only if the content isn't valid UTF-8: no replacement contains
+    /// a NUL byte, so the result is always a valid C string.
+    fn strip_diacritics(&self) -> Result<CString, Utf8Error>;
+}
+
+impl CStrDiacriticExt for CStr {
+    fn strip_diacritics(&self) -> Result<CString, Utf8Error> {
+        let s = self.to_str()?;
+        if !s.has_diacritics() {
+            return Ok(self.to_owned());
+        }
+        // Sized exactly, NUL included: CString would otherwise reallocate to
+        // append the NUL or to shrink to fit.
+        let len = s.stripped_len();
+        let mut buf = vec![0; len + 1];
+        let written = s.strip_diacritics_write(&mut buf[..len]);
+        assert_eq!(written.ok(), Some(len), "stripped_len must be exact");
+        // The content comes from a `CStr` and no replacement holds a NUL, so
+        // the only NUL is `buf[len]`: this can't fail, only check it.
+        Ok(CString::from_vec_with_nul(buf).expect("stripping added a NUL byte"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let s = CString::new("Crème brûlée").unwrap();
+        assert_eq!(
+            s.as_c_str().strip_diacritics().unwrap().as_c_str(),
+            c"Creme brulee"
+        );
+        assert_eq!(c"plain".strip_diacritics().unwrap().as_c_str(), c"plain");
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        assert!(c"caf\xc3".strip_diacritics().is_err());
+    }
+}
@@ -0,0 +1,71 @@
+use core::fmt::{self, Write};
+
+use crate::StrDiacriticExt;
+
+/// Formats its string stripped, writing into the formatter a slice at a
+/// time without allocating, for format strings and logging macros:
+/// `log::info!("user {}", Stripped(&name))`.
+///
+/// `Debug` quotes and escapes the stripped string like `str` does. Width,
+/// fill and precision are ignored.
+///
+/// ```
+/// use strip_diacritics::Stripped;
+///
+/// assert_eq!(format!("{}", Stripped("Ömer")), "Omer");
+/// assert_eq!(format!("{:?}", Stripped("Ömer")), "\"Omer\"");
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Stripped<T: AsRef<str>>(pub T);
+
+impl<T: AsRef<str>> fmt::Display for Stripped<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.as_ref().strip_diacritics_to_fmt(f)
+    }
+}
+
+impl<T: AsRef<str>> fmt::Debug for Stripped<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        self.0.as_ref().strip_diacritics_to_fmt(&mut Escape(f))?;
+        f.write_char('"')
+    }
+}
+
+// Escapes like the `Debug` of `str`, which leaves single quotes alone.
+struct Escape<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+impl Write for Escape<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match c {
+                '\'' => self.0.write_char(c)?,
+                _ => write!(self.0, "{}", c.escape_debug())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::{format, string::String};
+
+    use super::*;
+
+    #[test]
+    fn format() {
+        assert_eq!(format!("{}", Stripped("Ömer")), "Omer");
+        assert_eq!(format!("[{}]", Stripped(String::from("plain"))), "[plain]");
+        assert_eq!(format!("{}", Stripped("e\u{301}ǆ")), "edz");
+    }
+
+    #[test]
+    fn debug() {
+        for s in ["Ömer", "", "l'été \"ok\"\n\t\\", "a\u{301}\u{200b}"] {
+            let stripped = crate::StrDiacriticExt::strip_diacritics(s);
+            assert_eq!(format!("{:?}", Stripped(s)), format!("{:?}", stripped));
+        }
+    }
+}
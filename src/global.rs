@@ -0,0 +1,93 @@
+//! A process-wide mapping installed at runtime, e.g. loaded from a
+//! configuration file, that replaces the built-in
+//! [`DIACRITICS_MAPPING`](crate::tables::DIACRITICS_MAPPING).
+//!
+//! The mapping can be replaced at any time, e.g. when the file changes, or
+//! removed to go back to the built-in one. Installing and reading it are
+//! thread safe: a strip runs entirely with the mapping it started with,
+//! and the next one sees the new mapping, never a partial one. The lock is
+//! only held to swap or clone an [`Arc`], never while stripping.
+
+use std::{
+    borrow::Cow,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use crate::{phf::OwnedCharMap, CharDiacriticExt, StrDiacriticExt};
+
+static GLOBAL_MAPPING: RwLock<Option<Arc<OwnedCharMap<String>>>> = RwLock::new(None);
+
+fn replace(map: Option<Arc<OwnedCharMap<String>>>) -> Option<Arc<OwnedCharMap<String>>> {
+    // Nothing panics while the lock is held, so a poisoned one is still sound
+    let mut slot = GLOBAL_MAPPING
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    std::mem::replace(&mut *slot, map)
+}
+
+/// Installs the global mapping, returning the one it replaces. The hash is
+/// built before taking the lock, so readers are never kept waiting on it.
+pub fn set_global_mapping<I>(entries: I) -> Option<Arc<OwnedCharMap<String>>>
+where
+    I: IntoIterator<Item = (char, String)>,
+{
+    replace(Some(Arc::new(entries.into_iter().collect())))
+}
+
+/// Removes the global mapping, returning it, so that
+/// [`strip_diacritics_global`] strips like the built-in mapping again.
+pub fn clear_global_mapping() -> Option<Arc<OwnedCharMap<String>>> {
+    replace(None)
+}
+
+/// The mapping installed at the time of the call, which stays usable after
+/// it's replaced.
+#[inline]
+pub fn global_mapping() -> Option<Arc<OwnedCharMap<String>>> {
+    GLOBAL_MAPPING
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// Strips `s` with the installed mapping, falling back to
+/// [`StrDiacriticExt::strip_diacritics`] when none is set. Combining marks
+/// are dropped either way.
+pub fn strip_diacritics_global(s: &str) -> Cow<'_, str> {
+    match global_mapping() {
+        Some(map) => crate::strip_by(s, |c| {
+            if c.is_diacritic() {
+                Some("")
+            } else {
+                map.get(c).map(String::as_str)
+            }
+        }),
+        None => s.strip_diacritics(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reload() {
+        assert_eq!(strip_diacritics_global("€ café"), "€ cafe");
+
+        let previous = set_global_mapping([('€', "EUR".to_string()), ('é', "e".to_string())]);
+        assert!(previous.is_none());
+        assert_eq!(strip_diacritics_global("€ café"), "EUR cafe");
+        assert_eq!(strip_diacritics_global("ne\u{301}e à"), "nee à");
+
+        let held = global_mapping().unwrap();
+        let previous = set_global_mapping([('€', "E".to_string())]).unwrap();
+        assert!(Arc::ptr_eq(&previous, &held));
+        assert_eq!(held.len(), 2);
+        assert_eq!(strip_diacritics_global("€ café"), "E café");
+        assert_eq!(global_mapping().unwrap().len(), 1);
+
+        assert_eq!(clear_global_mapping().unwrap().len(), 1);
+        assert!(global_mapping().is_none());
+        assert_eq!(strip_diacritics_global("€ café"), "€ cafe");
+    }
+}
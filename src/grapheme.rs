@@ -0,0 +1,66 @@
+use alloc::borrow::Cow;
+use core::iter::FusedIterator;
+
+use unicode_segmentation::{Graphemes, UnicodeSegmentation};
+
+use crate::StrDiacriticExt;
+
+/// Extended grapheme clusters paired with their stripped form, see
+/// [`StrDiacriticExt::strip_diacritics_grapheme_pairs`](crate::StrDiacriticExt::strip_diacritics_grapheme_pairs).
+#[derive(Debug, Clone)]
+pub struct GraphemePairs<'a> {
+    graphemes: Graphemes<'a>,
+}
+
+impl<'a> GraphemePairs<'a> {
+    #[inline]
+    pub(crate) fn new(s: &'a str) -> Self {
+        Self {
+            graphemes: s.graphemes(true),
+        }
+    }
+}
+
+impl<'a> Iterator for GraphemePairs<'a> {
+    type Item = (&'a str, Cow<'a, str>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.graphemes.next().map(|g| (g, g.strip_diacritics()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.graphemes.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for GraphemePairs<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.graphemes
+            .next_back()
+            .map(|g| (g, g.strip_diacritics()))
+    }
+}
+
+impl<'a> FusedIterator for GraphemePairs<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairs() {
+        assert_eq!(
+            GraphemePairs::new("ả\u{303}\u{323}b ǆé").collect::<Vec<_>>(),
+            [
+                ("ả\u{303}\u{323}", Cow::Borrowed("a")),
+                ("b", Cow::Borrowed("b")),
+                (" ", Cow::Borrowed(" ")),
+                ("ǆ", Cow::Borrowed("dz")),
+                ("é", Cow::Borrowed("e")),
+            ]
+        );
+    }
+}
@@ -1,4 +1,26 @@
+use core::cmp::Ordering;
+
+use crate::tables::COMBINING_MARKS;
+
+/// Whether `ch` is a nonspacing (`Mn`) or enclosing (`Me`) mark, which
+/// stripping always drops. Variation selectors are `Mn` too but select a
+/// glyph rather than mark anything, so they're left to
+/// [`StripOptions::strip_variation_selectors`](crate::StripOptions::strip_variation_selectors).
+/// The kana voicing marks `U+3099..=U+309A` are kept as well, so that NFD
+/// kana recompose (`か` `◌゙` → `が`) rather than losing their voicing.
 #[inline]
 pub fn is_diacritic(ch: char) -> bool {
-    ('\u{0300}'..='\u{036f}').contains(&ch)
+    // No mark comes before the Combining Diacritical Marks block.
+    ch >= '\u{300}'
+        && COMBINING_MARKS
+            .binary_search_by(|r| {
+                if *r.end() < ch {
+                    Ordering::Less
+                } else if *r.start() > ch {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
 }
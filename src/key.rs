@@ -0,0 +1,85 @@
+use alloc::{
+    borrow::{Borrow, Cow},
+    string::String,
+};
+use core::fmt;
+
+use crate::StrDiacriticExt;
+
+/// An accent-insensitive map key.
+///
+/// The string is stripped once on construction, so `Eq`, `Ord` and `Hash`
+/// are those of the stripped string and stay consistent with
+/// `Borrow<str>`: a map keyed by `NormalizedKey` can be queried with a
+/// `&str`, as long as the query is already stripped.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NormalizedKey(String);
+
+impl NormalizedKey {
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl From<&str> for NormalizedKey {
+    #[inline]
+    fn from(s: &str) -> Self {
+        Self(s.strip_diacritics().into_owned())
+    }
+}
+
+impl From<String> for NormalizedKey {
+    fn from(s: String) -> Self {
+        match s.strip_diacritics() {
+            Cow::Owned(stripped) => Self(stripped),
+            Cow::Borrowed(_) => Self(s),
+        }
+    }
+}
+
+impl Borrow<str> for NormalizedKey {
+    #[inline]
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for NormalizedKey {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for NormalizedKey {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeMap, string::ToString};
+
+    use super::*;
+
+    #[test]
+    fn btree_map() {
+        let mut map = BTreeMap::new();
+        map.insert(NormalizedKey::from("café"), 1);
+        map.insert(NormalizedKey::from("cafe".to_string()), 2);
+        map.insert(NormalizedKey::from("Ärger"), 3);
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get("cafe"), Some(&2));
+        assert_eq!(map.get("Arger"), Some(&3));
+        assert_eq!(map.get("Ärger"), None);
+    }
+}
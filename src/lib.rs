@@ -1,17 +1,768 @@
-use std::borrow::Cow;
+#![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String, sync::Arc, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::{convert::Infallible, ops::Range};
+
+mod ascii;
+#[cfg(feature = "alloc")]
+mod bytes;
+#[cfg(feature = "capi")]
+pub mod capi;
+mod chars;
+mod config;
+#[cfg(feature = "std")]
+mod cstr;
+mod display;
+#[cfg(feature = "runtime-table")]
+pub mod global;
+#[cfg(feature = "grapheme")]
+mod grapheme;
 mod is_diacritic;
+#[cfg(feature = "alloc")]
+mod key;
+#[cfg(feature = "alloc")]
+mod normalize;
+#[cfg(feature = "std")]
+mod os_str;
 pub mod phf;
+#[cfg(feature = "alloc")]
+mod reorder;
+mod replacement;
+#[cfg(feature = "alloc")]
+mod reversible;
+#[cfg(feature = "alloc")]
+mod runs;
+mod segments;
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "alloc")]
+mod string;
 pub mod tables;
+#[cfg(feature = "alloc")]
+mod tokens;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "alloc")]
+pub use bytes::strip_diacritics_bytes;
+pub use chars::StripDiacritics;
+pub use config::{NonAsciiPolicy, OverrideMap, StripOptions};
+#[cfg(feature = "std")]
+pub use cstr::CStrDiacriticExt;
+pub use display::Stripped;
+#[cfg(feature = "grapheme")]
+pub use grapheme::GraphemePairs;
+#[cfg(feature = "alloc")]
+pub use key::NormalizedKey;
+#[cfg(feature = "alloc")]
+pub use normalize::Normalize;
+#[cfg(feature = "std")]
+pub use os_str::OsStrDiacriticExt;
+#[cfg(feature = "alloc")]
+pub use reorder::reorder_combining_marks;
+pub use replacement::Replacement;
+#[cfg(feature = "alloc")]
+pub use reversible::{restore, Edit, ReverseInfo};
+#[cfg(feature = "alloc")]
+pub use runs::{Run, Runs};
+pub use segments::{Segment, Segments};
+#[cfg(feature = "std")]
+pub use stream::{strip_diacritics_bounded, strip_diacritics_lines};
+#[cfg(feature = "alloc")]
+pub use string::{StringDiacriticExt, StrippedString};
+#[cfg(feature = "alloc")]
+pub use tokens::Tokens;
 
 pub trait CharDiacriticExt {
     fn is_diacritic(&self) -> bool;
 
+    /// Besides dropping diacritics, folds canonical singletons to the char
+    /// they duplicate, as canonical decomposition does: CJK compatibility
+    /// ideographs to their unified ideograph (`豈` U+F900 → `豈` U+8C48),
+    /// `Ω` OHM SIGN to `Ω`, `K` KELVIN SIGN to `K`.
+    ///
+    /// A dropped char gives `Some("")`, see [`classify`](Self::classify) to
+    /// match on it instead.
     fn strip_diacritics(&self) -> Option<&'static str>;
+
+    fn strip_diacritics_with<'a>(&self, options: StripOptions<'a>) -> Option<&'a str>;
+
+    /// Like [`strip_diacritics`](Self::strip_diacritics), with the common
+    /// single-char replacement already decoded.
+    fn strip_diacritics_one(&self) -> StripOne;
+
+    /// The single char this is replaced with, `None` when it's unchanged,
+    /// dropped or expanded: [`strip_diacritics_one`](Self::strip_diacritics_one)
+    /// tells those apart.
+    fn strip_diacritics_char(&self) -> Option<char>;
+
+    /// [`strip_diacritics`](Self::strip_diacritics) with its three outcomes
+    /// spelled out: `None` is [`Strip::Unchanged`], `Some("")` is
+    /// [`Strip::Removed`] and any other `Some(t)` is [`Strip::Mapped`].
+    fn classify(&self) -> Strip;
+}
+
+/// What [`CharDiacriticExt::strip_diacritics`] replaces `c` with, `None`
+/// meaning it's kept as is.
+///
+/// ```
+/// use strip_diacritics::map_char;
+///
+/// assert_eq!(map_char('é'), Some("e"));
+/// assert_eq!(map_char('\u{301}'), Some(""));
+/// assert_eq!(map_char('a'), None);
+/// ```
+#[inline]
+pub fn map_char(c: char) -> Option<&'static str> {
+    #[cfg(feature = "dense-table")]
+    if let Ok(c) = u16::try_from(c as u32) {
+        return tables::map_bmp(c);
+    }
+    if is_diacritic::is_diacritic(c) {
+        return Some("");
+    }
+    #[cfg(feature = "binary-search")]
+    return tables::DIACRITICS_SORTED
+        .binary_search_by_key(&c, |&(k, _)| k)
+        .ok()
+        .map(|i| tables::DIACRITICS_SORTED[i].1);
+    #[cfg(not(feature = "binary-search"))]
+    tables::DIACRITICS_MAPPING.get(c).map(|r| r.as_str())
+}
+
+/// The sorted, disjoint ranges of combining marks that
+/// [`CharDiacriticExt::is_diacritic`] matches and stripping always drops:
+/// every nonspacing (`Mn`) and enclosing (`Me`) mark, except for the
+/// variation selectors `U+FE00..=U+FE0F` and `U+E0100..=U+E01EF` and the
+/// kana voicing marks `U+3099..=U+309A`. The latter are kept so that NFD
+/// kana recompose (`か` `◌゙` → `が`) rather than losing their voicing.
+#[inline]
+pub fn diacritic_ranges() -> &'static [core::ops::RangeInclusive<char>] {
+    tables::COMBINING_MARKS
+}
+
+/// Checks the built-in tables at runtime: besides the layout of each map
+/// (see [`CharMap::is_consistent`](phf::CharMap::is_consistent)),
+/// that no key is ASCII or a diacritic and that no replacement contains a
+/// diacritic. It walks every entry, so call it once at startup at most.
+pub fn verify_tables() -> bool {
+    use tables::{COMPATIBILITY_MAPPING, DIACRITICS_MAPPING};
+
+    [&DIACRITICS_MAPPING, &COMPATIBILITY_MAPPING]
+        .into_iter()
+        .all(|map| {
+            map.is_consistent()
+                && map.entries().all(|(k, v)| {
+                    !k.is_ascii() && !k.is_diacritic() && !v.chars().any(|c| c.is_diacritic())
+                })
+        })
+}
+
+/// Strips every string of `iter` independently, borrowing the ones that
+/// don't change, e.g. the fields of a CSV record.
+///
+/// ```
+/// use std::borrow::Cow;
+/// use strip_diacritics::strip_diacritics_all;
+///
+/// let fields: Vec<_> = strip_diacritics_all(["Zoë", "42"]).collect();
+/// assert_eq!(fields, ["Zoe", "42"]);
+/// assert!(matches!(fields[1], Cow::Borrowed(_)));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn strip_diacritics_all<'a, I>(iter: I) -> impl Iterator<Item = Cow<'a, str>>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    iter.into_iter().map(str::strip_diacritics)
+}
+
+/// Drops the combining marks of `s` in place, shifting the rest left, and
+/// returns the shortened string. The freed tail of `s` is filled with NUL
+/// bytes, so that `s` stays valid UTF-8. Works on any slice of a larger
+/// buffer.
+///
+/// Only marks are removed: chars that stripping maps, like the precomposed
+/// `é`, are left as they are, as a replacement could be longer than its
+/// char. Strip a `String` with
+/// [`StringDiacriticExt::strip_diacritics_in_place`] for those.
+///
+/// ```
+/// use strip_diacritics::strip_marks_in_place;
+///
+/// let mut s = String::from("e\u{301}f\u{302}!");
+/// assert_eq!(strip_marks_in_place(&mut s), "ef!");
+/// assert_eq!(s, "ef!\0\0\0\0");
+/// ```
+pub fn strip_marks_in_place(s: &mut str) -> &str {
+    let Some(start) = s.find(is_diacritic::is_diacritic) else {
+        return s;
+    };
+
+    // SAFETY: whole chars are moved left, everything from `read` on is
+    // still the original UTF-8, and the tail from `write` on is overwritten
+    // with NULs, so `s` is valid UTF-8 again before it's read.
+    let bytes = unsafe { s.as_bytes_mut() };
+    let (mut read, mut write) = (start, start);
+    while read < bytes.len() {
+        let c = unsafe { core::str::from_utf8_unchecked(&bytes[read..]) }
+            .chars()
+            .next()
+            .unwrap();
+        let end = read + c.len_utf8();
+        if !is_diacritic::is_diacritic(c) {
+            bytes.copy_within(read..end, write);
+            write += end - read;
+        }
+        read = end;
+    }
+    bytes[write..].fill(0);
+    &s[..write]
+}
+
+/// What [`CharDiacriticExt::classify`] says stripping does to a char.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Strip {
+    Unchanged,
+    /// A diacritic, dropped altogether.
+    Removed,
+    /// Replaced with a non-empty string.
+    Mapped(&'static str),
+}
+
+/// What [`CharDiacriticExt::strip_diacritics_one`] does to a char.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StripOne {
+    Unchanged,
+    Dropped,
+    One(char),
+    Many(&'static str),
+}
+
+/// Returned by [`StrDiacriticExt::strip_diacritics_limited`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputTooLong {
+    pub len: usize,
+    pub max_len: usize,
 }
 
+impl core::fmt::Display for InputTooLong {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "input of {} bytes exceeds the limit of {} bytes",
+            self.len, self.max_len
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InputTooLong {}
+
+/// Returned by [`StrDiacriticExt::strip_diacritics_write`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    pub required: usize,
+}
+
+impl core::fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "buffer too small, {} bytes required", self.required)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferTooSmall {}
+
+/// Implemented on `str`, `String`, `Cow<str>`, `Box<str>`, `Rc<str>` and
+/// `Arc<str>`, so that generic code bounded on it accepts owned and shared
+/// strings too. The returned [`Cow`] always borrows from `self`, which must
+/// outlive it.
+///
+/// Every method is provided on top of [`as_str`](Self::as_str), the only
+/// one to implement for another string type.
 pub trait StrDiacriticExt {
-    fn strip_diacritics(&self) -> Cow<str>;
+    /// The string the other methods work on.
+    fn as_str(&self) -> &str;
+
+    /// Drops every nonspacing (`Mn`) and enclosing (`Me`) mark, decomposed
+    /// or part of a precomposed letter, whatever the script: besides Latin
+    /// accents (`é` → `e`), this drops Greek breathings, Hebrew points
+    /// (`שׁ` → `ש`), Arabic harakat and Indic nuktas (`क़` → `क`), which
+    /// can change a word in these scripts.
+    ///
+    /// Variation selectors are kept, see
+    /// [`StripOptions::strip_variation_selectors`], and so are the kana
+    /// voicing marks, which make another kana rather than accent one: `が`
+    /// stays `が`. Spacing marks (`Mc`), like most Indic vowel signs, are
+    /// kept too.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn strip_diacritics(&self) -> Cow<'_, str> {
+        self.strip_diacritics_with(StripOptions::new())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn strip_diacritics_with(&self, options: StripOptions<'_>) -> Cow<'_, str> {
+        let s = self.as_str();
+        let map = |c: char| c.strip_diacritics_with(options);
+        if !options.normalize_line_separators {
+            // No other option but overrides maps an ASCII char.
+            let skip_ascii = !options.char_scan_only && options.overrides.is_none();
+            return if skip_ascii && looks_ascii_heavy(s) {
+                strip_with(s, |s| next_diacritic_skipping_ascii(s, map))
+            } else {
+                strip_by(s, map)
+            };
+        }
+        if !s.contains("\r\n") {
+            return strip_by(s, map);
+        }
+
+        let mut lines = s.split("\r\n");
+        let mut buf = String::with_capacity(s.len());
+        buf.push_str(&strip_by(lines.next().unwrap_or_default(), map));
+        for line in lines {
+            buf.push('\n');
+            buf.push_str(&strip_by(line, map));
+        }
+        Cow::Owned(buf)
+    }
+
+    /// Strips, along with whether any char changed, in which case the
+    /// result is always owned: an unchanged string is borrowed.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn strip_diacritics_checked(&self) -> (Cow<'_, str>, bool) {
+        let stripped = self.strip_diacritics();
+        let changed = matches!(stripped, Cow::Owned(_));
+        (stripped, changed)
+    }
+
+    /// Strips, recording every replaced char so that [`restore`] can
+    /// rebuild the original.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn strip_diacritics_reversible(&self) -> (String, ReverseInfo) {
+        reversible::strip_reversible(self.as_str())
+    }
+
+    /// Starts a [`Normalize`] builder, to pick options then an output type:
+    /// `s.normalize().german_sharp_s(true).to_slug()`.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn normalize(&self) -> Normalize<'_> {
+        Normalize::new(self.as_str())
+    }
+
+    /// Fails without doing any work if `self` is longer than `max_len`
+    /// bytes, to bound what untrusted input can make us allocate.
+    #[cfg(feature = "alloc")]
+    fn strip_diacritics_limited(&self, max_len: usize) -> Result<Cow<'_, str>, InputTooLong> {
+        let s = self.as_str();
+        if s.len() > max_len {
+            return Err(InputTooLong {
+                len: s.len(),
+                max_len,
+            });
+        }
+        Ok(self.strip_diacritics())
+    }
+
+    /// Writes the stripped UTF-8 into `out` and returns its length. When it
+    /// doesn't fit, the error carries the required length, which
+    /// [`stripped_len`](Self::stripped_len) also gives upfront, and the
+    /// content of `out` is unspecified.
+    fn strip_diacritics_write(&self, out: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let mut len = 0;
+        let written = strip_pieces(self.as_str(), |piece| {
+            let dst = out.get_mut(len..len + piece.len()).ok_or(())?;
+            dst.copy_from_slice(piece.as_bytes());
+            len += piece.len();
+            Ok(())
+        });
+        match written {
+            Ok(()) => Ok(len),
+            Err(()) => Err(BufferTooSmall {
+                required: self.stripped_len(),
+            }),
+        }
+    }
+
+    /// Like [`strip_diacritics_write`](Self::strip_diacritics_write), but
+    /// returns the filled part of `buf` as a `str`, for short strings kept
+    /// on the stack. On error `buf` is zeroed, never left holding a partial
+    /// output.
+    fn strip_diacritics_buf<'b>(&self, buf: &'b mut [u8]) -> Result<&'b str, BufferTooSmall> {
+        match self.strip_diacritics_write(buf) {
+            // SAFETY: the first `len` bytes are the stripped UTF-8.
+            Ok(len) => Ok(unsafe { core::str::from_utf8_unchecked(&buf[..len]) }),
+            Err(err) => {
+                buf.fill(0);
+                Err(err)
+            }
+        }
+    }
+
+    /// Writes the stripped string into `w` a slice at a time, without any
+    /// intermediate allocation.
+    fn strip_diacritics_to_fmt<W: core::fmt::Write + ?Sized>(
+        &self,
+        w: &mut W,
+    ) -> core::fmt::Result {
+        strip_pieces(self.as_str(), |piece| w.write_str(piece))
+    }
+
+    /// Writes the stripped UTF-8 into `w` a slice at a time, returning the
+    /// number of bytes written.
+    #[cfg(feature = "std")]
+    fn strip_diacritics_to_writer<W: std::io::Write + ?Sized>(
+        &self,
+        w: &mut W,
+    ) -> std::io::Result<usize> {
+        let mut len = 0;
+        strip_pieces(self.as_str(), |piece| {
+            len += piece.len();
+            w.write_all(piece.as_bytes())
+        })?;
+        Ok(len)
+    }
+
+    #[inline]
+    fn stripped_len(&self) -> usize {
+        self.stripped_len_with(StripOptions::new())
+    }
+
+    fn stripped_len_with(&self, options: StripOptions<'_>) -> usize {
+        let s = self.as_str();
+        let len = s
+            .chars()
+            .map(|c| {
+                c.strip_diacritics_with(options)
+                    .map_or(c.len_utf8(), str::len)
+            })
+            .sum::<usize>();
+        if options.normalize_line_separators {
+            len - s.matches("\r\n").count()
+        } else {
+            len
+        }
+    }
+
+    /// Saturates at 0: a few CJK compatibility ideographs (`U+FA6C` → `U+242EE`)
+    /// fold to a longer char, so a string made mostly of them can grow.
+    #[inline]
+    fn bytes_saved(&self) -> usize {
+        self.as_str().len().saturating_sub(self.stripped_len())
+    }
+
+    /// Compatibility folding may expand a character (`㎏` → `kg`), hence the
+    /// signed result.
+    #[inline]
+    fn bytes_saved_with(&self, options: StripOptions<'_>) -> isize {
+        self.as_str().len() as isize - self.stripped_len_with(options) as isize
+    }
+
+    /// Whether stripping would change anything, stopping at the first char
+    /// that would.
+    #[inline]
+    fn has_diacritics(&self) -> bool {
+        next_diacritic_skipping_ascii(self.as_str(), |c| c.strip_diacritics()).is_some()
+    }
+
+    /// How many chars stripping would change, dropped marks included.
+    fn count_diacritics(&self) -> usize {
+        let mut count = 0;
+        let mut rest: &str = self.as_str();
+        while let Some((_, _, r)) = next_diacritic_skipping_ascii(rest, |c| c.strip_diacritics()) {
+            count += 1;
+            rest = r;
+        }
+        count
+    }
+
+    /// Lazily strips the string one char at a time, without allocating.
+    #[inline]
+    fn strip_diacritics_chars(&self) -> StripDiacritics<'_> {
+        StripDiacritics::new(self.as_str())
+    }
+
+    /// Splits the string into maximal runs of unchanged and changed
+    /// characters, the latter paired with their stripped form.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn strip_diacritics_runs(&self) -> Runs<'_> {
+        Runs::new(self.as_str())
+    }
+
+    /// Walks the string one replaced char at a time, each [`Segment`]
+    /// holding the unchanged text before it, then ends with the unchanged
+    /// tail: for before/after diffs.
+    #[inline]
+    fn diacritic_segments(&self) -> Segments<'_> {
+        Segments::new(self.as_str())
+    }
+
+    /// Pairs every extended grapheme cluster with its stripped form.
+    #[cfg(feature = "grapheme")]
+    #[inline]
+    fn strip_diacritics_grapheme_pairs(&self) -> GraphemePairs<'_> {
+        GraphemePairs::new(self.as_str())
+    }
+
+    /// Splits the string on the chars matching `is_sep` and strips each
+    /// token, allocating only for the tokens that change. Empty tokens are
+    /// skipped.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn strip_diacritics_tokens<F: Fn(char) -> bool>(&self, is_sep: F) -> Tokens<'_, F> {
+        Tokens::new(self.as_str(), is_sep)
+    }
+
+    /// Always allocates, even when nothing changes: an `Arc<str>` can't
+    /// borrow.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn strip_diacritics_arc(&self) -> Arc<str> {
+        Arc::from(self.strip_diacritics())
+    }
+
+    /// Lowercases and strips in a single pass, for case-insensitive keys.
+    /// Chars are lowercased first, so `İ` → `i̇` → `i`.
+    ///
+    /// Unlike [`str::to_lowercase`], `Σ` always lowercases to `σ`, never to
+    /// the final `ς`, so that a word's key doesn't depend on what follows it.
+    #[cfg(feature = "alloc")]
+    fn strip_diacritics_lower(&self) -> Cow<'_, str> {
+        let s = self.as_str();
+        let mut buf: Option<String> = None;
+        for (i, c) in s.char_indices() {
+            let lower = c.to_lowercase();
+            let buf = match &mut buf {
+                Some(buf) => buf,
+                None if lower.clone().eq([c]) && c.strip_diacritics().is_none() => continue,
+                None => {
+                    let mut b = String::with_capacity(s.len());
+                    b.push_str(&s[..i]);
+                    buf.insert(b)
+                }
+            };
+            for l in lower {
+                match l.strip_diacritics() {
+                    Some(t) => buf.push_str(t),
+                    None => buf.push(l),
+                }
+            }
+        }
+        match buf {
+            Some(buf) => Cow::Owned(buf),
+            None => Cow::Borrowed(s),
+        }
+    }
+
+    /// A search key, built in a single pass: lowercased and stripped like
+    /// [`strip_diacritics_lower`](Self::strip_diacritics_lower), with every
+    /// run of ASCII whitespace collapsed to a single space and none left at
+    /// either end.
+    #[cfg(feature = "alloc")]
+    fn search_key(&self) -> String {
+        let s = self.as_str();
+        let mut buf = String::with_capacity(s.len());
+        let mut space = false;
+        for c in s.chars() {
+            if c.is_ascii_whitespace() {
+                space = !buf.is_empty();
+                continue;
+            }
+            for l in c.to_lowercase() {
+                let mut tmp = [0; 4];
+                let out = match l.strip_diacritics() {
+                    Some(t) => t,
+                    None => l.encode_utf8(&mut tmp),
+                };
+                // A dropped mark doesn't end a whitespace run
+                if out.is_empty() {
+                    continue;
+                }
+                if space {
+                    buf.push(' ');
+                    space = false;
+                }
+                buf.push_str(out);
+            }
+        }
+        buf
+    }
+
+    /// Builds an identifier matching `[A-Za-z_][A-Za-z0-9_]*`: the string
+    /// is stripped with compatibility folding, then every char left outside
+    /// `[A-Za-z0-9_]` becomes a `_`. A leading digit gets a `_` prefix and
+    /// an empty string gives `_`.
+    #[cfg(feature = "alloc")]
+    fn to_ascii_identifier(&self) -> String {
+        let s = self.as_str();
+        let options = StripOptions::new().compatibility(true);
+        let mut buf = String::with_capacity(s.len() + 1);
+        for c in s.chars() {
+            let mut tmp = [0; 4];
+            let folded = match c.strip_diacritics_with(options) {
+                Some(t) => t,
+                None => c.encode_utf8(&mut tmp),
+            };
+            buf.extend(folded.chars().map(|c| {
+                if c.is_ascii_alphanumeric() || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            }));
+        }
+        if buf.is_empty() || buf.starts_with(|c: char| c.is_ascii_digit()) {
+            buf.insert(0, '_');
+        }
+        buf
+    }
+
+    /// Strips everything but the `protected` byte ranges, e.g. the spans of
+    /// URLs matched by a regex, which are copied verbatim.
+    ///
+    /// # Panics
+    ///
+    /// If the ranges aren't sorted and non-overlapping, or don't lie on char
+    /// boundaries.
+    #[cfg(feature = "alloc")]
+    fn strip_diacritics_except_matches(&self, protected: &[Range<usize>]) -> Cow<'_, str> {
+        let s = self.as_str();
+        let mut buf: Option<String> = None;
+        let mut pos = 0;
+        for r in protected
+            .iter()
+            .cloned()
+            .chain(core::iter::once(s.len()..s.len()))
+        {
+            assert!(
+                pos <= r.start && r.start <= r.end,
+                "protected ranges must be sorted and non-overlapping"
+            );
+            assert!(
+                s.is_char_boundary(r.start) && s.is_char_boundary(r.end),
+                "protected ranges must lie on char boundaries"
+            );
+
+            match (&mut buf, s[pos..r.start].strip_diacritics()) {
+                (None, Cow::Borrowed(_)) => (),
+                (None, Cow::Owned(stripped)) => {
+                    let mut b = String::with_capacity(s.len());
+                    b.push_str(&s[..pos]);
+                    b.push_str(&stripped);
+                    buf = Some(b);
+                }
+                (Some(b), stripped) => b.push_str(&stripped),
+            }
+            if let Some(b) = &mut buf {
+                b.push_str(&s[r.clone()]);
+            }
+            pos = r.end;
+        }
+
+        match buf {
+            Some(buf) => Cow::Owned(buf),
+            None => Cow::Borrowed(s),
+        }
+    }
+
+    /// Strips with compatibility folding into pure ASCII: every char that
+    /// doesn't entirely fold to ASCII is dropped and reported, along with
+    /// its byte offset in `self`.
+    #[cfg(feature = "alloc")]
+    fn strip_to_ascii_report(&self) -> (String, Vec<(usize, char)>) {
+        let s = self.as_str();
+        let options = StripOptions::new().compatibility(true);
+        let mut buf = String::with_capacity(s.len());
+        let mut dropped = Vec::new();
+        for (i, c) in s.char_indices() {
+            if c.is_ascii() {
+                buf.push(c);
+                continue;
+            }
+            match c.strip_diacritics_with(options) {
+                Some(t) if t.is_ascii() => buf.push_str(t),
+                _ => dropped.push((i, c)),
+            }
+        }
+        (buf, dropped)
+    }
+
+    /// Appends the stripped string to `buf`, to build one string out of
+    /// many fragments.
+    #[cfg(feature = "alloc")]
+    fn strip_diacritics_into(&self, buf: &mut String) {
+        let s = self.as_str();
+        buf.reserve(s.len());
+        let Ok(()) = strip_pieces::<Infallible, _>(s, |piece| {
+            buf.push_str(piece);
+            Ok(())
+        });
+    }
+
+    /// Clears `out` and fills it with the stripped UTF-8 bytes, keeping its
+    /// capacity for reuse across calls.
+    #[cfg(feature = "alloc")]
+    fn strip_diacritics_into_bytes(&self, out: &mut Vec<u8>) {
+        out.clear();
+        let Ok(()) = strip_pieces::<Infallible, _>(self.as_str(), |piece| {
+            out.extend_from_slice(piece.as_bytes());
+            Ok(())
+        });
+    }
+
+    /// Strips `target`, which must be equal to `self`, by replacing only the
+    /// changed runs (see [`strip_diacritics_runs`](Self::strip_diacritics_runs))
+    /// in place. Unchanged text is never rewritten, though it may be moved.
+    #[cfg(feature = "alloc")]
+    fn apply_strip(&self, target: &mut String) {
+        debug_assert_eq!(target.as_str(), self.as_str());
+        let mut pos = 0;
+        for run in self.strip_diacritics_runs() {
+            match run {
+                Run::Unchanged(src) => pos += src.len(),
+                Run::Changed { src, out } => {
+                    target.replace_range(pos..pos + src.len(), &out);
+                    pos += out.len();
+                }
+            }
+        }
+    }
+
+    /// Strips into a [`CompactString`](compact_str::CompactString), which
+    /// keeps results of up to 24 bytes inline.
+    #[cfg(feature = "compact_str")]
+    fn strip_diacritics_compact(&self) -> compact_str::CompactString {
+        let mut buf = compact_str::CompactString::default();
+        let Ok(()) = strip_pieces::<Infallible, _>(self.as_str(), |piece| {
+            buf.push_str(piece);
+            Ok(())
+        });
+        buf
+    }
+
+    /// Strips and escapes `&`, `<`, `>` and `"` for HTML in a single pass.
+    #[cfg(feature = "html")]
+    #[inline]
+    fn strip_diacritics_html_escaped(&self) -> Cow<'_, str> {
+        strip_by(self.as_str(), |c| match c {
+            '&' => Some("&amp;"),
+            '<' => Some("&lt;"),
+            '>' => Some("&gt;"),
+            '"' => Some("&quot;"),
+            _ => c.strip_diacritics(),
+        })
+    }
 }
 
 impl CharDiacriticExt for char {
@@ -19,55 +770,355 @@ impl CharDiacriticExt for char {
         is_diacritic::is_diacritic(*self)
     }
 
+    #[inline]
     fn strip_diacritics(&self) -> Option<&'static str> {
-        if self.is_diacritic() {
-            return Some("");
+        map_char(*self)
+    }
+
+    fn strip_diacritics_with<'a>(&self, options: StripOptions<'a>) -> Option<&'a str> {
+        let stripped = strip_char_with(*self, options);
+        match options.non_ascii {
+            NonAsciiPolicy::Keep => stripped,
+            _ if stripped.map_or(self.is_ascii(), str::is_ascii) => stripped,
+            NonAsciiPolicy::Drop => Some(""),
+            NonAsciiPolicy::Replace(c) => Some(ascii_str(c)),
+        }
+    }
+
+    fn strip_diacritics_one(&self) -> StripOne {
+        let Some(t) = self.strip_diacritics() else {
+            return StripOne::Unchanged;
+        };
+        let mut chars = t.chars();
+        match (chars.next(), chars.next()) {
+            (None, _) => StripOne::Dropped,
+            (Some(c), None) => StripOne::One(c),
+            _ => StripOne::Many(t),
+        }
+    }
+
+    #[inline]
+    fn strip_diacritics_char(&self) -> Option<char> {
+        match self.strip_diacritics_one() {
+            StripOne::One(c) => Some(c),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn classify(&self) -> Strip {
+        match self.strip_diacritics() {
+            None => Strip::Unchanged,
+            Some("") => Strip::Removed,
+            Some(t) => Strip::Mapped(t),
         }
-        crate::tables::DIACRITICS_MAPPING.get(*self).copied()
     }
 }
 
-fn next_diacritic(s: &str) -> Option<(&str, &'static str, &str)> {
+fn strip_char_with(ch: char, options: StripOptions<'_>) -> Option<&str> {
+    if let Some(overrides) = options.overrides {
+        if let Some(t) = overrides.get(ch) {
+            return Some(t);
+        }
+    }
+    if options.strip_variation_selectors && is_variation_selector(ch) {
+        return Some("");
+    }
+    if options.normalize_line_separators && is_line_separator(ch) {
+        return Some("\n");
+    }
+    if options.fractions {
+        if let Some(t) = crate::tables::FRACTION_MAPPING.get(ch) {
+            return Some(t.as_str());
+        }
+    }
+    if options.math_alpha {
+        if let Some(t) = crate::tables::MATH_ALPHA_MAPPING.get(ch) {
+            return Some(t.as_str());
+        }
+    }
+    if options.ligatures {
+        if let Some(t) = ligature(ch) {
+            return Some(t);
+        }
+    }
+    if options.fullwidth {
+        if let Some(t) = crate::tables::FULLWIDTH_MAPPING.get(ch) {
+            return Some(t.as_str());
+        }
+    }
+    #[cfg(feature = "hangul")]
+    if options.hangul_jamo {
+        if let Some(t) = crate::tables::decompose_hangul(ch) {
+            return Some(t);
+        }
+    }
+    let stripped = if options.display {
+        crate::tables::DIACRITICS_MAPPING
+            .get(ch)
+            .map(|r| r.as_str())
+            .filter(|t| !t.is_empty() && t.is_ascii())
+    } else {
+        ch.strip_diacritics()
+    };
+    match stripped {
+        Some(t) => {
+            // ǽ → æ → ae
+            let t = if options.ligatures {
+                let mut chars = t.chars();
+                match (chars.next().and_then(ligature), chars.next()) {
+                    (Some(l), None) => l,
+                    _ => t,
+                }
+            } else {
+                t
+            };
+            // ώ stays whole rather than losing its accent to ω
+            if options.recompose && !t.is_ascii() {
+                None
+            } else {
+                Some(t)
+            }
+        }
+        // ẞ is the uppercase of ß, its full uppercase being SS
+        None if options.german_sharp_s && ch == 'ß' => crate::tables::SPECIAL_CASE_FOLDING
+            .get('ß')
+            .map(|r| r.as_str()),
+        None if options.german_sharp_s && ch == 'ẞ' => crate::tables::SPECIAL_UPPERCASE
+            .get('ß')
+            .map(|r| r.as_str()),
+        None if options.compatibility => crate::tables::COMPATIBILITY_MAPPING
+            .get(ch)
+            .map(|r| r.as_str()),
+        None => None,
+    }
+}
+
+const ASCII_BYTES: [u8; 128] = {
+    let mut bytes = [0; 128];
+    let mut i = 0;
+    while i < bytes.len() {
+        bytes[i] = i as u8;
+        i += 1;
+    }
+    bytes
+};
+
+// Every ASCII char, to slice placeholders from.
+const ASCII: &str = match core::str::from_utf8(&ASCII_BYTES) {
+    Ok(s) => s,
+    Err(_) => unreachable!(),
+};
+
+#[inline]
+fn ascii_str(c: char) -> &'static str {
+    &ASCII[c as usize..c as usize + 1]
+}
+
+#[inline]
+fn is_variation_selector(ch: char) -> bool {
+    ('\u{fe00}'..='\u{fe0f}').contains(&ch) || ('\u{e0100}'..='\u{e01ef}').contains(&ch)
+}
+
+// The ligatures without any decomposition, the others being generated
+#[inline]
+fn ligature(ch: char) -> Option<&'static str> {
+    match ch {
+        'æ' => Some("ae"),
+        'Æ' => Some("AE"),
+        'œ' => Some("oe"),
+        'Œ' => Some("OE"),
+        'ß' => Some("ss"),
+        _ => crate::tables::LIGATURE_MAPPING.get(ch).map(|r| r.as_str()),
+    }
+}
+
+#[inline]
+fn is_line_separator(ch: char) -> bool {
+    matches!(ch, '\r' | '\u{85}' | '\u{2028}' | '\u{2029}')
+}
+
+fn next_diacritic<'t, F>(s: &str, map: F) -> Option<(&str, &'t str, &str)>
+where
+    F: Fn(char) -> Option<&'t str>,
+{
     for (i, c) in s.char_indices() {
-        if let Some(t) = c.strip_diacritics() {
+        if let Some(t) = map(c) {
             return Some((&s[..i], t, &s[(i + c.len_utf8())..]));
         }
     }
     None
 }
 
-impl StrDiacriticExt for str {
-    fn strip_diacritics(&self) -> Cow<str> {
-        let (mut buf, mut rest) = match next_diacritic(self) {
-            Some((init, cont, rest)) => {
-                let mut buf = String::with_capacity(init.len() + cont.len());
-                buf.push_str(init);
-                buf.push_str(cont);
-                (buf, rest)
-            }
-            None => return Cow::Borrowed(self),
+// Only valid for maps leaving every ASCII char unchanged: ASCII runs are
+// skipped a word at a time, without decoding chars. Inlined into the
+// strip loop, the ASCII search optimizes worse on long runs.
+#[inline(never)]
+fn next_diacritic_skipping_ascii<'t, F>(s: &str, map: F) -> Option<(&str, &'t str, &str)>
+where
+    F: Fn(char) -> Option<&'t str>,
+{
+    let mut i = 0;
+    while let Some(skip) = ascii::first_non_ascii(&s.as_bytes()[i..]) {
+        i += skip;
+        let c = s[i..].chars().next()?;
+        if let Some(t) = map(c) {
+            return Some((&s[..i], t, &s[(i + c.len_utf8())..]));
+        }
+        i += c.len_utf8();
+    }
+    None
+}
+
+const SAMPLE_LEN: usize = 64;
+
+// Samples the head of `s` to guess whether skipping ASCII runs pays off:
+// on mostly non-ASCII text it's just an extra branch per char.
+#[inline]
+fn looks_ascii_heavy(s: &str) -> bool {
+    let sample = &s.as_bytes()[..s.len().min(SAMPLE_LEN)];
+    sample.iter().filter(|b| !b.is_ascii()).count() * 2 <= sample.len()
+}
+
+// Feeds `write` the stripped string a slice at a time, the unchanged text
+// before each replaced char then its replacement, and the unchanged tail
+// last, scanning like `strip_diacritics` does.
+pub(crate) fn strip_pieces<E, W>(s: &str, write: W) -> Result<(), E>
+where
+    W: FnMut(&str) -> Result<(), E>,
+{
+    pieces_by(s, |c| c.strip_diacritics(), looks_ascii_heavy(s), write)
+}
+
+// Like `strip_pieces`, scanning like `strip_diacritics_with` does.
+#[cfg(feature = "alloc")]
+pub(crate) fn strip_pieces_with<E, W>(
+    s: &str,
+    options: StripOptions<'_>,
+    mut write: W,
+) -> Result<(), E>
+where
+    W: FnMut(&str) -> Result<(), E>,
+{
+    let map = |c: char| c.strip_diacritics_with(options);
+    if !options.normalize_line_separators {
+        // No other option but overrides maps an ASCII char.
+        let skip_ascii =
+            !options.char_scan_only && options.overrides.is_none() && looks_ascii_heavy(s);
+        return pieces_by(s, map, skip_ascii, write);
+    }
+
+    let mut lines = s.split("\r\n");
+    pieces_by(lines.next().unwrap_or_default(), map, false, &mut write)?;
+    for line in lines {
+        write("\n")?;
+        pieces_by(line, map, false, &mut write)?;
+    }
+    Ok(())
+}
+
+fn pieces_by<'t, E, M, W>(s: &str, map: M, skip_ascii: bool, mut write: W) -> Result<(), E>
+where
+    M: Fn(char) -> Option<&'t str> + Copy,
+    W: FnMut(&str) -> Result<(), E>,
+{
+    let mut rest = s;
+    loop {
+        let next = if skip_ascii {
+            next_diacritic_skipping_ascii(rest, map)
+        } else {
+            next_diacritic(rest, map)
         };
+        let Some((init, cont, r)) = next else {
+            return write(rest);
+        };
+        write(init)?;
+        write(cont)?;
+        rest = r;
+    }
+}
 
-        while !rest.is_empty() {
-            rest = match next_diacritic(rest) {
-                Some((init, cont, r)) => {
-                    buf.push_str(init);
-                    buf.push_str(cont);
-                    r
-                }
-                None => {
-                    buf.push_str(rest);
-                    &rest[..rest.len()]
-                }
-            };
-        }
+#[cfg(feature = "alloc")]
+pub(crate) fn strip_by<'t, F>(s: &str, map: F) -> Cow<'_, str>
+where
+    F: Fn(char) -> Option<&'t str> + Copy,
+{
+    strip_with(s, |s| next_diacritic(s, map))
+}
 
-        Cow::Owned(buf)
+#[cfg(feature = "alloc")]
+fn strip_with<'t, N>(s: &str, next: N) -> Cow<'_, str>
+where
+    N: Fn(&str) -> Option<(&str, &'t str, &str)>,
+{
+    let Some((init, cont, mut rest)) = next(s) else {
+        return Cow::Borrowed(s);
+    };
+
+    let mut buf = String::with_capacity(s.len());
+    buf.push_str(init);
+    buf.push_str(cont);
+    while let Some((init, cont, r)) = next(rest) {
+        buf.push_str(init);
+        buf.push_str(cont);
+        rest = r;
+    }
+    buf.push_str(rest);
+
+    Cow::Owned(buf)
+}
+
+impl StrDiacriticExt for str {
+    #[inline]
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl StrDiacriticExt for String {
+    #[inline]
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl StrDiacriticExt for Cow<'_, str> {
+    #[inline]
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl StrDiacriticExt for alloc::boxed::Box<str> {
+    #[inline]
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl StrDiacriticExt for alloc::rc::Rc<str> {
+    #[inline]
+    fn as_str(&self) -> &str {
+        self
     }
 }
 
-#[cfg(test)]
+#[cfg(feature = "alloc")]
+impl StrDiacriticExt for Arc<str> {
+    #[inline]
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
+    use alloc::{boxed::Box, format, rc::Rc, string::ToString, vec};
+
     use super::*;
 
     #[test]
@@ -79,4 +1130,855 @@ mod tests {
     fn eu_diacritics() {
         assert_eq!("TÅRÖÄàèéìòù".strip_diacritics(), "TAROAaeeiou");
     }
+
+    // Every char outside the surrogates, split in SHARDS interleaved parts
+    // run as separate tests. Replacements must be empty for marks only,
+    // bounded, free of controls and marks, and already stripped.
+    fn sweep<const SHARD: u32>() {
+        const SHARDS: u32 = 8;
+        let maps = [
+            &tables::COMPATIBILITY_MAPPING,
+            &tables::FRACTION_MAPPING,
+            &tables::MATH_ALPHA_MAPPING,
+            &tables::FULLWIDTH_MAPPING,
+            &tables::LIGATURE_MAPPING,
+            &tables::SPECIAL_UPPERCASE,
+            &tables::SPECIAL_CASE_FOLDING,
+        ];
+        for c in (SHARD..=char::MAX as u32)
+            .step_by(SHARDS as usize)
+            .filter_map(char::from_u32)
+        {
+            if let Some(t) = map_char(c) {
+                assert_eq!(t.is_empty(), c.is_diacritic(), "{c:?} => {t:?}");
+                assert!(t.len() <= tables::MAX_REPLACEMENT_LEN, "{c:?}");
+                assert!(t.chars().count() <= tables::MAX_REPLACEMENT_CHARS, "{c:?}");
+                for d in t.chars() {
+                    assert!(!d.is_control() && !d.is_diacritic(), "{c:?} => {t:?}");
+                    assert_eq!(map_char(d), None, "{c:?} => {t:?}");
+                }
+            }
+            for map in maps {
+                if let Some(t) = map.get(c) {
+                    assert!(!t.is_empty() && t.len() <= tables::MAX_REPLACEMENT_LEN);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sweep_0() {
+        sweep::<0>();
+    }
+
+    #[test]
+    fn sweep_1() {
+        sweep::<1>();
+    }
+
+    #[test]
+    fn sweep_2() {
+        sweep::<2>();
+    }
+
+    #[test]
+    fn sweep_3() {
+        sweep::<3>();
+    }
+
+    #[test]
+    fn sweep_4() {
+        sweep::<4>();
+    }
+
+    #[test]
+    fn sweep_5() {
+        sweep::<5>();
+    }
+
+    #[test]
+    fn sweep_6() {
+        sweep::<6>();
+    }
+
+    #[test]
+    fn sweep_7() {
+        sweep::<7>();
+    }
+
+    #[test]
+    fn arc() {
+        let s: Arc<str> = "Šibenik".strip_diacritics_arc();
+        assert_eq!(&*s, "Sibenik");
+        assert_eq!(&*"plain".strip_diacritics_arc(), "plain");
+    }
+
+    #[test]
+    fn tables_are_consistent() {
+        assert!(verify_tables());
+    }
+
+    #[test]
+    fn canonical_singletons() {
+        for (compat, unified) in [
+            ('\u{f900}', "\u{8c48}"),
+            ('\u{f91d}', "\u{6b04}"),
+            ('\u{fa10}', "\u{585a}"),
+            ('\u{2f800}', "\u{4e3d}"),
+            ('\u{2126}', "\u{3a9}"),
+            ('\u{212a}', "K"),
+        ] {
+            assert_eq!(compat.strip_diacritics(), Some(unified), "{compat:?}");
+        }
+        assert_eq!('\u{8c48}'.strip_diacritics(), None);
+        // EN QUAD is canonically EN SPACE, but only folds to a space by
+        // compatibility.
+        assert_eq!('\u{2000}'.strip_diacritics(), None);
+        assert_eq!(
+            '\u{2000}'.strip_diacritics_with(StripOptions::new().compatibility(true)),
+            Some(" ")
+        );
+    }
+
+    #[test]
+    fn ranges() {
+        let ranges = diacritic_ranges();
+        assert!(ranges.windows(2).all(|w| w[0].end() < w[1].start()));
+        for c in ranges.iter().cloned().flatten() {
+            assert!(c.is_diacritic());
+            assert_eq!(c.strip_diacritics(), Some(""));
+        }
+        assert!(!'\u{2ff}'.is_diacritic());
+        assert!(!'\u{370}'.is_diacritic());
+        for c in ['\u{fe00}', '\u{fe0f}', '\u{e0100}', '\u{e01ef}'] {
+            assert!(!ranges.iter().any(|r| r.contains(&c)), "{c:?}");
+        }
+    }
+
+    #[test]
+    fn display() {
+        const DISPLAY: StripOptions = StripOptions::new().display(true);
+        let s = "Café Zoë, مَرْحَبًا, हिन्दी, क\u{93c}, Ἀθῆναι, a\u{301}";
+        assert_eq!(
+            s.strip_diacritics_with(DISPLAY),
+            "Cafe Zoe, مَرْحَبًا, हिन्दी, क\u{93c}, Ἀθῆναι, a\u{301}"
+        );
+        assert_eq!('ǆ'.strip_diacritics_with(DISPLAY), Some("dz"));
+        assert_eq!('\u{301}'.strip_diacritics_with(DISPLAY), None);
+    }
+
+    #[test]
+    fn recompose() {
+        const RECOMPOSE: StripOptions = StripOptions::new().recompose(true);
+        let s = "Señor ώρα, й, ǣ, \u{2126}, e\u{301}, \u{3c9}\u{301}, ǆ";
+        assert_eq!(
+            s.strip_diacritics_with(RECOMPOSE),
+            "Senor ώρα, й, ǣ, \u{2126}, e, ω, dz"
+        );
+        assert_eq!(
+            s.strip_diacritics_with(RECOMPOSE.ligatures(true)),
+            "Senor ώρα, й, ae, \u{2126}, e, ω, dz"
+        );
+        assert_eq!('ñ'.strip_diacritics_with(RECOMPOSE), Some("n"));
+        assert_eq!('й'.strip_diacritics_with(RECOMPOSE), None);
+        assert_eq!('\u{301}'.strip_diacritics_with(RECOMPOSE), Some(""));
+    }
+
+    #[test]
+    fn scan_strategies() {
+        let map = |c: char| c.strip_diacritics();
+        for s in [
+            "",
+            "plain ascii",
+            "Crème brûlée",
+            "é",
+            "ascii then é",
+            "Ελληνικά ÆØÅ ǆ",
+            "e\u{301}\u{302}x",
+        ] {
+            assert_eq!(
+                strip_with(s, |s| next_diacritic_skipping_ascii(s, map)),
+                strip_by(s, map)
+            );
+        }
+        assert!(looks_ascii_heavy("plain ascii"));
+        assert!(looks_ascii_heavy("Crème brûlée is a dessert"));
+        assert!(!looks_ascii_heavy("Ελληνικά"));
+    }
+
+    #[test]
+    fn write() {
+        let mut buf = [0; 16];
+        let len = "Crème brûlée"
+            .strip_diacritics_write(&mut buf[..12])
+            .unwrap();
+        assert_eq!(&buf[..len], b"Creme brulee");
+        assert_eq!(
+            "Crème brûlée".strip_diacritics_write(&mut buf[..11]),
+            Err(BufferTooSmall { required: 12 })
+        );
+        assert_eq!(
+            "plain text here!!".strip_diacritics_write(&mut buf),
+            Err(BufferTooSmall { required: 17 })
+        );
+        assert_eq!("".strip_diacritics_write(&mut []), Ok(0));
+    }
+
+    #[test]
+    fn buf() {
+        let mut buf = [0xff; 16];
+        assert_eq!(
+            "Crème brûlée".strip_diacritics_buf(&mut buf[..12]),
+            Ok("Creme brulee")
+        );
+        assert_eq!("".strip_diacritics_buf(&mut []), Ok(""));
+
+        let mut buf = [0xff; 16];
+        assert_eq!(
+            "Crème brûlée".strip_diacritics_buf(&mut buf[..11]),
+            Err(BufferTooSmall { required: 12 })
+        );
+        assert_eq!(buf[..11], [0; 11]);
+        assert_eq!(buf[11..], [0xff; 5]);
+
+        // U+FA6C folds to the 4-byte U+242EE: 3 bytes of input need 4
+        let mut buf = [0; 4];
+        assert_eq!(
+            "\u{fa6c}".strip_diacritics_buf(&mut buf[..3]),
+            Err(BufferTooSmall { required: 4 })
+        );
+        assert_eq!("\u{fa6c}".strip_diacritics_buf(&mut buf), Ok("\u{242ee}"));
+    }
+
+    #[test]
+    fn to_fmt() {
+        for s in ["", "plain", "Crème brûlée", "e\u{301}\u{302}ǆ", "déjà vu"] {
+            let mut out = String::from("> ");
+            s.strip_diacritics_to_fmt(&mut out).unwrap();
+            assert_eq!(out, format!("> {}", s.strip_diacritics()));
+        }
+
+        struct Count(usize);
+        impl core::fmt::Write for Count {
+            fn write_str(&mut self, _: &str) -> core::fmt::Result {
+                self.0 += 1;
+                Ok(())
+            }
+        }
+        let mut count = Count(0);
+        "no diacritics here"
+            .strip_diacritics_to_fmt(&mut count)
+            .unwrap();
+        assert_eq!(count.0, 1);
+    }
+
+    #[test]
+    fn checked() {
+        let (stripped, changed) = "plain ascii".strip_diacritics_checked();
+        assert!(!changed);
+        assert!(matches!(stripped, Cow::Borrowed("plain ascii")));
+        assert_eq!(
+            "ß Æ".strip_diacritics_checked(),
+            (Cow::Borrowed("ß Æ"), false)
+        );
+        assert_eq!("".strip_diacritics_checked(), (Cow::Borrowed(""), false));
+
+        let (stripped, changed) = "Crème brûlée".strip_diacritics_checked();
+        assert!(changed);
+        assert!(matches!(stripped, Cow::Owned(ref s) if s == "Creme brulee"));
+        assert!("e\u{301}".strip_diacritics_checked().1);
+    }
+
+    #[test]
+    fn limited() {
+        assert_eq!("déjà".strip_diacritics_limited(6).unwrap(), "deja");
+        let err = "déjà".strip_diacritics_limited(5).unwrap_err();
+        assert_eq!(err, InputTooLong { len: 6, max_len: 5 });
+        assert_eq!(
+            err.to_string(),
+            "input of 6 bytes exceeds the limit of 5 bytes"
+        );
+    }
+
+    #[test]
+    fn smart_pointers() {
+        fn strip<S: StrDiacriticExt + ?Sized>(s: &S) -> Cow<'_, str> {
+            s.strip_diacritics()
+        }
+
+        let boxed: Box<str> = "Šibenik".into();
+        assert_eq!(boxed.strip_diacritics(), "Sibenik");
+        assert_eq!(strip(&boxed), "Sibenik");
+        let rc: Rc<str> = "plain".into();
+        assert!(matches!(rc.strip_diacritics(), Cow::Borrowed(s) if core::ptr::eq(s, &*rc)));
+        assert!(matches!(strip(&rc), Cow::Borrowed(s) if core::ptr::eq(s, &*rc)));
+        let arc: Arc<str> = "déjà".into();
+        assert_eq!(arc.strip_diacritics(), "deja");
+        assert_eq!(strip(&arc), "deja");
+        assert!(arc.has_diacritics() && !rc.has_diacritics());
+    }
+
+    #[test]
+    fn strip_all() {
+        let fields: Vec<&str> = "Zoë,plain,,São Paulo,42".split(',').collect();
+        let stripped = strip_diacritics_all(fields.iter().copied()).collect::<Vec<_>>();
+        assert_eq!(stripped, ["Zoe", "plain", "", "Sao Paulo", "42"]);
+        for (field, out) in fields.iter().zip(&stripped) {
+            match out {
+                Cow::Borrowed(s) => assert!(core::ptr::eq(*s, *field)),
+                Cow::Owned(_) => assert!(field.has_diacritics()),
+            }
+        }
+        assert!(matches!(stripped[1], Cow::Borrowed(_)));
+        assert_eq!(strip_diacritics_all(Vec::new()).count(), 0);
+    }
+
+    #[test]
+    fn owned_receivers() {
+        fn strip<S: StrDiacriticExt + ?Sized>(s: &S) -> Cow<'_, str> {
+            s.strip_diacritics()
+        }
+
+        let string = String::from("Šibenik");
+        assert_eq!(string.strip_diacritics(), "Sibenik");
+        assert_eq!(strip(&string), "Sibenik");
+        let cow: Cow<str> = Cow::Owned(String::from("plain"));
+        assert!(matches!(strip(&cow), Cow::Borrowed(s) if core::ptr::eq(s, &*cow)));
+        assert_eq!(Cow::<str>::Owned("déjà".into()).strip_diacritics(), "deja");
+        assert_eq!(strip(&Cow::Borrowed("déjà")), "deja");
+        assert_eq!(strip("déjà"), "deja");
+    }
+
+    #[test]
+    fn ascii_identifier() {
+        assert_eq!("Crème brûlée!".to_ascii_identifier(), "Creme_brulee_");
+        assert_eq!("snake_case".to_ascii_identifier(), "snake_case");
+        assert_eq!("2ème étage".to_ascii_identifier(), "_2eme_etage");
+        assert_eq!("ﬁ①ø".to_ascii_identifier(), "fi1_");
+        assert_eq!("".to_ascii_identifier(), "_");
+        assert_eq!("\u{301}".to_ascii_identifier(), "_");
+    }
+
+    #[test]
+    fn except_matches() {
+        let s = "café https://exämple.com/é déjà";
+        let url = 6..s.find(" déjà").unwrap();
+        assert_eq!(
+            s.strip_diacritics_except_matches(core::slice::from_ref(&url)),
+            "cafe https://exämple.com/é deja"
+        );
+        assert_eq!(
+            s.strip_diacritics_except_matches(&[0..6, url]),
+            "café https://exämple.com/é deja"
+        );
+        assert!(matches!(
+            "plain é".strip_diacritics_except_matches(&[0..0, 6..8]),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn except_matches_overlapping() {
+        "abcdef".strip_diacritics_except_matches(&[0..3, 2..4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn except_matches_boundary() {
+        "é".strip_diacritics_except_matches(&[0..0, 0..1]);
+    }
+
+    #[test]
+    fn apply_strip() {
+        for s in ["", "plain", "Crème brûlée", "e\u{301}ǆ", "déjà vu"] {
+            let mut target = s.to_string();
+            s.apply_strip(&mut target);
+            assert_eq!(target, s.strip_diacritics());
+        }
+    }
+
+    #[test]
+    fn sharp_s() {
+        const GERMAN: StripOptions = StripOptions::new().german_sharp_s(true);
+        assert_eq!("Straße STRAẞE".strip_diacritics(), "Straße STRAẞE");
+        assert_eq!(
+            "Straße STRAẞE".strip_diacritics_with(GERMAN),
+            "Strasse STRASSE"
+        );
+        assert_eq!('ß'.strip_diacritics_with(GERMAN), Some("ss"));
+        assert_eq!('ẞ'.strip_diacritics_with(GERMAN), Some("SS"));
+        assert_eq!(
+            'ẞ'.strip_diacritics_with(GERMAN.compatibility(true)),
+            Some("SS")
+        );
+        assert_eq!(
+            'ẞ'.strip_diacritics_with(StripOptions::new().compatibility(true)),
+            None
+        );
+    }
+
+    #[cfg(feature = "compact_str")]
+    #[test]
+    fn compact() {
+        let s = "Crème brûlée".strip_diacritics_compact();
+        assert_eq!(s, "Creme brulee");
+        assert!(!s.is_heap_allocated());
+        // Longer than 24 bytes, but not once stripped.
+        let s = "Crème brûlée déjà ǆ".strip_diacritics_compact();
+        assert_eq!(s, "Creme brulee deja dz");
+        assert!(!s.is_heap_allocated());
+        let long = "Árvíztűrő tükörfúrógép, façade";
+        let s = long.strip_diacritics_compact();
+        assert_eq!(s, long.strip_diacritics());
+        assert!(s.is_heap_allocated());
+    }
+
+    #[cfg(feature = "html")]
+    #[test]
+    fn html_escaped() {
+        assert_eq!(
+            "café & <b>".strip_diacritics_html_escaped(),
+            "cafe &amp; &lt;b&gt;"
+        );
+        assert_eq!(
+            r#"a "b""#.strip_diacritics_html_escaped(),
+            "a &quot;b&quot;"
+        );
+        assert!(matches!(
+            "plain".strip_diacritics_html_escaped(),
+            Cow::Borrowed("plain")
+        ));
+    }
+
+    #[test]
+    fn line_separators() {
+        const LINES: StripOptions = StripOptions::new().normalize_line_separators(true);
+        let s = "crème\r\nbrûlée\u{2028}déjà\u{2029}vu\rnaïve\u{85}end\r\n";
+        assert_eq!(
+            s.strip_diacritics_with(LINES),
+            "creme\nbrulee\ndeja\nvu\nnaive\nend\n"
+        );
+        assert_eq!(s.stripped_len_with(LINES), 31);
+        assert_eq!(
+            s.strip_diacritics(),
+            "creme\r\nbrulee\u{2028}deja\u{2029}vu\rnaive\u{85}end\r\n"
+        );
+        assert_eq!("a\nb".strip_diacritics_with(LINES), "a\nb");
+    }
+
+    #[test]
+    fn into() {
+        let mut buf = String::from("> ");
+        "Crème ".strip_diacritics_into(&mut buf);
+        "brûlée".strip_diacritics_into(&mut buf);
+        "!".strip_diacritics_into(&mut buf);
+        assert_eq!(buf, "> Creme brulee!");
+    }
+
+    #[test]
+    fn into_bytes() {
+        let mut out = Vec::with_capacity(64);
+        for s in ["", "plain", "Crème brûlée", "e\u{301}", "ǆ"] {
+            s.strip_diacritics_into_bytes(&mut out);
+            assert_eq!(core::str::from_utf8(&out), Ok(&*s.strip_diacritics()));
+            assert_eq!(out.capacity(), 64);
+        }
+    }
+
+    #[test]
+    fn strip_one() {
+        assert_eq!('a'.strip_diacritics_one(), StripOne::Unchanged);
+        assert_eq!('\u{301}'.strip_diacritics_one(), StripOne::Dropped);
+        assert_eq!('é'.strip_diacritics_one(), StripOne::One('e'));
+        assert_eq!('ǆ'.strip_diacritics_one(), StripOne::Many("dz"));
+
+        assert_eq!('a'.strip_diacritics_char(), None);
+        assert_eq!('\u{301}'.strip_diacritics_char(), None);
+        assert_eq!('é'.strip_diacritics_char(), Some('e'));
+        assert_eq!('\u{2126}'.strip_diacritics_char(), Some('\u{3a9}'));
+        assert_eq!('ǆ'.strip_diacritics_char(), None);
+    }
+
+    #[test]
+    fn marks_in_place() {
+        let mut s = String::from("e\u{301}f\u{302}");
+        assert_eq!(strip_marks_in_place(&mut s), "ef");
+        assert_eq!(s, "ef\0\0\0\0");
+
+        // Within a larger buffer, mapped chars untouched
+        let mut s = String::from("[é a\u{300}\u{20dd}b] tail\u{301}");
+        assert_eq!(strip_marks_in_place(&mut s[1..11]), "é ab");
+        assert_eq!(s, "[é ab\0\0\0\0\0] tail\u{301}");
+
+        let mut s = String::from("plain é");
+        assert_eq!(strip_marks_in_place(&mut s), "plain é");
+        assert_eq!(strip_marks_in_place(&mut String::new()), "");
+        let mut s = String::from("\u{301}\u{302}");
+        assert_eq!(strip_marks_in_place(&mut s), "");
+    }
+
+    #[test]
+    fn classify() {
+        assert_eq!('\u{301}'.classify(), Strip::Removed);
+        assert_eq!('\u{20dd}'.classify(), Strip::Removed);
+        assert_eq!('é'.classify(), Strip::Mapped("e"));
+        assert_eq!('ǆ'.classify(), Strip::Mapped("dz"));
+        assert_eq!('a'.classify(), Strip::Unchanged);
+        assert_eq!('ß'.classify(), Strip::Unchanged);
+    }
+
+    #[test]
+    fn ascii_report() {
+        assert_eq!(
+            "Crème Ｂｒûlée".strip_to_ascii_report(),
+            ("Creme Brulee".to_string(), vec![])
+        );
+        assert_eq!(
+            "Ærø ½ €5".strip_to_ascii_report(),
+            (
+                "r  5".to_string(),
+                vec![(0, 'Æ'), (3, 'ø'), (6, '½'), (9, '€')]
+            )
+        );
+    }
+
+    #[test]
+    fn bytes_saved() {
+        assert_eq!("aeiouy".bytes_saved(), 0);
+        assert_eq!("àèé".stripped_len(), 3);
+        assert_eq!("àèé".bytes_saved(), 3);
+        assert_eq!("e\u{301}".bytes_saved(), 2);
+        assert_eq!("\u{fa6c}".bytes_saved(), 0);
+
+        let options = StripOptions::new().compatibility(true);
+        assert_eq!("㎏".stripped_len_with(options), 2);
+        assert_eq!("㎏".bytes_saved_with(options), 1);
+        assert_eq!("\u{fdfa}".bytes_saved_with(options), 3 - 33);
+    }
+
+    #[test]
+    fn ligatures() {
+        const LIGATURES: StripOptions = StripOptions::new().ligatures(true);
+        for (c, t) in [
+            ('æ', "ae"),
+            ('Æ', "AE"),
+            ('œ', "oe"),
+            ('Œ', "OE"),
+            ('ß', "ss"),
+            ('ﬀ', "ff"),
+            ('ﬁ', "fi"),
+            ('ﬂ', "fl"),
+            ('ﬃ', "ffi"),
+            ('ﬄ', "ffl"),
+            ('ﬅ', "st"),
+            ('ﬆ', "st"),
+            ('Ĳ', "IJ"),
+            ('ĳ', "ij"),
+        ] {
+            assert_eq!(c.strip_diacritics(), None, "{c}");
+            assert_eq!(c.strip_diacritics_with(LIGATURES), Some(t), "{c}");
+        }
+
+        // Generated from the same decompositions as the compatibility table
+        let compatibility = StripOptions::new().compatibility(true);
+        assert_eq!(tables::LIGATURE_MAPPING.len(), 9);
+        for (c, t) in tables::LIGATURE_MAPPING.entries() {
+            assert_eq!(tables::COMPATIBILITY_MAPPING.get(c), Some(t), "{c}");
+            assert_eq!(
+                c.strip_diacritics_with(LIGATURES),
+                c.strip_diacritics_with(compatibility),
+                "{c}"
+            );
+        }
+
+        let s = "Ærøskøbing, œuvre, Straße, ﬁne ǽ";
+        assert_eq!(s.strip_diacritics(), "Ærøskøbing, œuvre, Straße, ﬁne æ");
+        assert_eq!(
+            s.strip_diacritics_with(LIGATURES),
+            "AErøskøbing, oeuvre, Strasse, fine ae"
+        );
+    }
+
+    #[cfg(feature = "hangul")]
+    #[test]
+    fn hangul_jamo() {
+        const JAMO: StripOptions = StripOptions::new().hangul_jamo(true);
+        for (c, t) in [
+            ('가', "\u{1100}\u{1161}"),
+            ('각', "\u{1100}\u{1161}\u{11a8}"),
+            ('갛', "\u{1100}\u{1161}\u{11c2}"),
+            ('개', "\u{1100}\u{1162}"),
+            ('힣', "\u{1112}\u{1175}\u{11c2}"),
+        ] {
+            assert_eq!(c.strip_diacritics(), None, "{c}");
+            assert_eq!(c.strip_diacritics_with(JAMO), Some(t), "{c}");
+        }
+        for c in [
+            '\u{abff}', '\u{d7a4}', '\u{1100}', '\u{1161}', '\u{11a8}', 'ㄱ',
+        ] {
+            assert_eq!(c.strip_diacritics_with(JAMO), None, "{c}");
+        }
+
+        assert!(matches!("한국어".strip_diacritics(), Cow::Borrowed(_)));
+        let s = "한국어 café";
+        assert_eq!(s.strip_diacritics(), "한국어 cafe");
+        assert_eq!(
+            s.strip_diacritics_with(JAMO),
+            "\u{1112}\u{1161}\u{11ab}\u{1100}\u{116e}\u{11a8}\u{110b}\u{1165} cafe"
+        );
+    }
+
+    #[test]
+    fn non_ascii() {
+        let s = "Crème, Москва ё 🙂 ǆ";
+        let options = StripOptions::new();
+        assert_eq!(
+            s.strip_diacritics_with(options.non_ascii(NonAsciiPolicy::Keep)),
+            "Creme, Москва е 🙂 dz"
+        );
+        assert_eq!(
+            s.strip_diacritics_with(options.non_ascii(NonAsciiPolicy::Drop)),
+            "Creme,    dz"
+        );
+        assert_eq!(
+            s.strip_diacritics_with(options.non_ascii(NonAsciiPolicy::Replace('?'))),
+            "Creme, ?????? ? ? dz"
+        );
+
+        let options = options.compatibility(true);
+        assert_eq!('㈱'.strip_diacritics_with(options), Some("(株)"));
+        let drop = options.non_ascii(NonAsciiPolicy::Drop);
+        assert_eq!('㈱'.strip_diacritics_with(drop), Some(""));
+        assert_eq!('㎏'.strip_diacritics_with(drop), Some("kg"));
+        assert_eq!('a'.strip_diacritics_with(drop), None);
+    }
+
+    #[test]
+    #[should_panic = "the placeholder must be ASCII"]
+    fn non_ascii_placeholder() {
+        let _ = StripOptions::new().non_ascii(NonAsciiPolicy::Replace('�'));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn overrides() {
+        use std::collections::HashMap;
+
+        let table: HashMap<char, String> = [
+            ('€', "EUR"),
+            ('é', "e'"),
+            ('ü', "ue"),
+            ('\u{301}', "'"),
+            ('&', " and "),
+            ('x', ""),
+        ]
+        .into_iter()
+        .map(|(c, t)| (c, t.to_string()))
+        .collect();
+        let mut entries: Vec<(char, &str)> = table.iter().map(|(&c, t)| (c, t.as_str())).collect();
+        let map = OverrideMap::new(&mut entries);
+        assert_eq!(map.len(), 6);
+        assert_eq!(map.get('€'), Some("EUR"));
+        assert_eq!(map.get('a'), None);
+
+        let options = StripOptions::new().overrides(map);
+        assert_eq!(
+            "Crème brûlée & café au lait, 10€, Müller, e\u{301}, taxi"
+                .strip_diacritics_with(options),
+            "Creme brule'e  and  cafe' au lait, 10EUR, Mueller, e', tai"
+        );
+        assert_eq!('ö'.strip_diacritics_with(options), Some("o"));
+        assert_eq!('€'.strip_diacritics_with(options), Some("EUR"));
+        assert_eq!('$'.strip_diacritics_with(options), None);
+        assert_eq!(options, StripOptions::new().overrides(map));
+        assert_ne!(options, StripOptions::new());
+    }
+
+    #[test]
+    #[should_panic = "the entries must be sorted by char, without duplicates"]
+    fn overrides_unsorted() {
+        let _ = OverrideMap::from_sorted(&[('€', "EUR"), ('$', "USD")]);
+    }
+
+    #[test]
+    fn sinks() {
+        // Both scans: ASCII-heavy and mostly non-ASCII heads
+        let ascii_heavy = format!("{}Crème brûlée e\u{301}", "plain ".repeat(20));
+        let non_ascii = "ÀÉÎÕÜ ǆ ÀÉÎÕÜ Ærø plain".repeat(4);
+        for s in ["", "plain", "é", ascii_heavy.as_str(), non_ascii.as_str()] {
+            let expected = s.strip_diacritics();
+
+            let mut into = String::from(">");
+            s.strip_diacritics_into(&mut into);
+            assert_eq!(into[1..], *expected);
+            let mut bytes = Vec::new();
+            s.strip_diacritics_into_bytes(&mut bytes);
+            assert_eq!(bytes, expected.as_bytes());
+            let mut fmt = String::new();
+            s.strip_diacritics_to_fmt(&mut fmt).unwrap();
+            assert_eq!(fmt, expected);
+            let mut out = [0; 256];
+            let len = s.strip_diacritics_write(&mut out).unwrap();
+            assert_eq!(&out[..len], expected.as_bytes());
+            #[cfg(feature = "std")]
+            {
+                let mut writer = Vec::new();
+                assert_eq!(s.strip_diacritics_to_writer(&mut writer).unwrap(), len);
+                assert_eq!(writer, expected.as_bytes());
+            }
+        }
+    }
+
+    #[test]
+    fn unchanged_tail() {
+        // The loop used to re-slice the whole remainder as its tail, never
+        // ending once a diacritic was followed by unchanged text
+        for (s, expected) in [
+            ("é!", "e!"),
+            ("café au lait", "cafe au lait"),
+            ("e\u{301}abc", "eabc"),
+            ("ǆ x", "dz x"),
+        ] {
+            assert_eq!(s.strip_diacritics(), expected);
+            assert_eq!(s.strip_diacritics_with(StripOptions::new()), expected);
+        }
+    }
+
+    #[test]
+    fn long_tail() {
+        let tail = "plain ASCII text ".repeat(4096);
+        for options in [
+            StripOptions::new(),
+            StripOptions::new().char_scan_only(true),
+        ] {
+            let s = format!("é{tail}");
+            let stripped = s.strip_diacritics_with(options);
+            assert_eq!(stripped.len(), s.len() - 1);
+            assert!(stripped.starts_with("eplain") && stripped.ends_with(&*tail));
+        }
+    }
+
+    #[test]
+    fn ascii_runs() {
+        // Non-ASCII chars at every offset around the scan's word and
+        // 16-byte chunk boundaries, some mapped and some kept.
+        let chars = StripOptions::new().char_scan_only(true);
+        for len in 0..40 {
+            for c in ['é', 'ß', '\u{301}', 'ǆ', '🙂'] {
+                let run = "a".repeat(len);
+                let s = format!("{run}{c}{run}{c}{run}");
+                let expected = s.strip_diacritics_with(chars);
+                assert_eq!(s.strip_diacritics(), expected, "{s:?}");
+                assert_eq!(s.has_diacritics(), expected != s, "{s:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn has_diacritics() {
+        assert!(!"".has_diacritics());
+        assert!(!"plain ASCII, even with \r\n".has_diacritics());
+        assert!(!"Ærøskøbing, Москва".has_diacritics());
+        assert!("e\u{301}".has_diacritics());
+        assert!("Crème".has_diacritics());
+        assert!("long ASCII prefix before the only ǆ".has_diacritics());
+    }
+
+    #[test]
+    fn count_diacritics() {
+        assert_eq!("".count_diacritics(), 0);
+        assert_eq!("plain ASCII, Ærøskøbing".count_diacritics(), 0);
+        assert_eq!("éàǆ\u{301}\u{302}".count_diacritics(), 5);
+        assert_eq!("Crème brûlée, e\u{301}".count_diacritics(), 4);
+    }
+
+    #[test]
+    fn lower() {
+        assert!(matches!(
+            "already lowercase, ß ı".strip_diacritics_lower(),
+            Cow::Borrowed("already lowercase, ß ı")
+        ));
+        // Turkish dotted and dotless i
+        assert_eq!("İSTANBUL Iğdır".strip_diacritics_lower(), "istanbul igdır");
+        assert_eq!("STRAẞE Straße".strip_diacritics_lower(), "straße straße");
+        assert_eq!("Crème BRÛLÉE ǅ".strip_diacritics_lower(), "creme brulee dz");
+        assert_eq!("ὈΔΥΣΣΕΎΣ".strip_diacritics_lower(), "οδυσσευσ");
+        for s in ["ÀÉÎ õü", "Ǆemal", "ΆΈΉ"] {
+            assert_eq!(
+                s.strip_diacritics_lower(),
+                s.to_lowercase().strip_diacritics(),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn search_key() {
+        assert_eq!("  CAFÉ   AU  LAIT ".search_key(), "cafe au lait");
+        assert_eq!(
+            "Crème\tBRÛLÉE\r\n\nǄemal".search_key(),
+            "creme brulee dzemal"
+        );
+        assert_eq!("already clean".search_key(), "already clean");
+        assert_eq!(" \u{301} a \u{301} \u{300}b\u{302} ".search_key(), "a b");
+        // Only ASCII whitespace collapses
+        assert_eq!("a\u{a0} b".search_key(), "a\u{a0} b");
+        assert_eq!("".search_key(), "");
+        assert_eq!(" \n\t ".search_key(), "");
+    }
+
+    #[test]
+    fn variation_selectors() {
+        let s = "☺\u{fe0f} ☺\u{fe0e} 葛\u{e0100} é";
+        assert_eq!(s.strip_diacritics(), "☺\u{fe0f} ☺\u{fe0e} 葛\u{e0100} e");
+
+        let options = StripOptions::new().strip_variation_selectors(true);
+        assert_eq!(s.strip_diacritics_with(options), "☺ ☺ 葛 e");
+    }
+
+    #[test]
+    fn fullwidth_ascii() {
+        let options = StripOptions::new().compatibility(true);
+        for ch in '\u{ff01}'..='\u{ff5e}' {
+            let ascii = char::from_u32(ch as u32 - 0xfee0).unwrap();
+            assert_eq!(
+                ch.strip_diacritics_with(options),
+                Some(ascii.to_string().as_str())
+            );
+        }
+        assert_eq!(
+            "Ｈｅｌｌｏ ０１２".strip_diacritics_with(options),
+            "Hello 012"
+        );
+        assert_eq!("Ｈｅｌｌｏ".strip_diacritics(), "Ｈｅｌｌｏ");
+
+        // The same fold on its own
+        let fullwidth = StripOptions::new().fullwidth(true);
+        for ch in ('\u{ff01}'..='\u{ff5e}').chain(['\u{3000}']) {
+            assert_eq!(
+                ch.strip_diacritics_with(fullwidth),
+                ch.strip_diacritics_with(options)
+            );
+        }
+        assert_eq!(
+            "Ｈｅｌｌｏ\u{3000}Ｗｏｒｌｄ！ ｃａｆé ﬁ ｶ".strip_diacritics_with(fullwidth),
+            "Hello World! cafe ﬁ ｶ"
+        );
+        assert_eq!('\u{ff5f}'.strip_diacritics_with(fullwidth), None);
+        assert_eq!('\u{ffe5}'.strip_diacritics_with(fullwidth), None);
+
+        // Generated from the same decompositions as the compatibility table
+        assert_eq!(tables::FULLWIDTH_MAPPING.len(), 95);
+        for (ch, t) in tables::FULLWIDTH_MAPPING.entries() {
+            assert_eq!(tables::COMPATIBILITY_MAPPING.get(ch), Some(t), "{ch:?}");
+        }
+    }
+
+    #[test]
+    fn halfwidth_katakana() {
+        let options = StripOptions::new().compatibility(true);
+        assert_eq!("ｶﾀｶﾅ".strip_diacritics_with(options), "カタカナ");
+        assert_eq!("ｶﾀｶﾅ".strip_diacritics(), Cow::Borrowed("ｶﾀｶﾅ"));
+    }
 }
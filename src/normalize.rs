@@ -0,0 +1,175 @@
+use alloc::{borrow::Cow, string::String, sync::Arc};
+use core::fmt;
+
+use crate::{NonAsciiPolicy, StrDiacriticExt, StripOptions};
+
+/// Builder choosing the options and output type of a strip, see
+/// [`StrDiacriticExt::normalize`](crate::StrDiacriticExt::normalize).
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use strip_diacritics::StrDiacriticExt;
+///
+/// let n = "Crème Brûlée für Straße".normalize().german_sharp_s(true);
+///
+/// assert_eq!(n.to_string(), "Creme Brulee fur Strasse");
+/// assert_eq!(n.to_cow(), "Creme Brulee fur Strasse");
+/// assert!(matches!("plain".normalize().to_cow(), Cow::Borrowed("plain")));
+/// assert_eq!(&*n.into_arc(), "Creme Brulee fur Strasse");
+///
+/// let mut html = String::from("<p>");
+/// n.write_to(&mut html).unwrap();
+/// assert_eq!(html, "<p>Creme Brulee fur Strasse");
+///
+/// assert_eq!(n.to_slug(), "creme-brulee-fur-strasse");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Normalize<'a> {
+    input: &'a str,
+    options: StripOptions<'a>,
+}
+
+impl<'a> Normalize<'a> {
+    #[inline]
+    pub(crate) fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            options: StripOptions::new(),
+        }
+    }
+
+    /// Replaces all the [`StripOptions`] at once.
+    #[inline]
+    pub fn options(mut self, options: StripOptions<'a>) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// See [`StripOptions::compatibility`].
+    #[inline]
+    pub fn compatibility(mut self, yes: bool) -> Self {
+        self.options = self.options.compatibility(yes);
+        self
+    }
+
+    /// See [`StripOptions::german_sharp_s`].
+    #[inline]
+    pub fn german_sharp_s(mut self, yes: bool) -> Self {
+        self.options = self.options.german_sharp_s(yes);
+        self
+    }
+
+    /// See [`StripOptions::non_ascii`].
+    #[inline]
+    pub fn non_ascii(mut self, policy: NonAsciiPolicy) -> Self {
+        self.options = self.options.non_ascii(policy);
+        self
+    }
+
+    /// Drops every char still outside ASCII once stripped, a shorthand for
+    /// [`non_ascii(NonAsciiPolicy::Drop)`](Self::non_ascii).
+    #[inline]
+    pub fn ascii_only(self, yes: bool) -> Self {
+        self.non_ascii(if yes {
+            NonAsciiPolicy::Drop
+        } else {
+            NonAsciiPolicy::Keep
+        })
+    }
+
+    /// Borrows the input when nothing changes.
+    #[inline]
+    pub fn to_cow(&self) -> Cow<'a, str> {
+        self.input.strip_diacritics_with(self.options)
+    }
+
+    /// Always allocates, like
+    /// [`strip_diacritics_arc`](crate::StrDiacriticExt::strip_diacritics_arc).
+    #[inline]
+    pub fn into_arc(self) -> Arc<str> {
+        self.to_cow().into()
+    }
+
+    /// Writes the stripped string into `sink` a slice at a time, without
+    /// any intermediate allocation.
+    #[inline]
+    pub fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        crate::strip_pieces_with(self.input, self.options, |piece| sink.write_str(piece))
+    }
+
+    /// Lowercase ASCII letters and digits, with every other run of chars
+    /// turned into a single `-`, trimmed at both ends: `Crème Brûlée!` →
+    /// `creme-brulee`. Non-ASCII survivors count as separators.
+    pub fn to_slug(&self) -> String {
+        let mut slug = String::with_capacity(self.input.len());
+        for word in self
+            .to_cow()
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|w| !w.is_empty())
+        {
+            if !slug.is_empty() {
+                slug.push('-');
+            }
+            slug.extend(word.chars().map(|c| c.to_ascii_lowercase()));
+        }
+        slug
+    }
+}
+
+impl fmt::Display for Normalize<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::OverrideMap;
+
+    #[test]
+    fn terminals() {
+        let n = "Straße « Crème Brûlée » ﬁn"
+            .normalize()
+            .german_sharp_s(true);
+        assert_eq!(n.to_string(), "Strasse « Creme Brulee » ﬁn");
+        assert_eq!(n.ascii_only(true).to_cow(), "Strasse  Creme Brulee  n");
+        assert_eq!(
+            &*n.compatibility(true).into_arc(),
+            "Strasse « Creme Brulee » fin"
+        );
+        let mut sink = String::from("> ");
+        n.ascii_only(true).write_to(&mut sink).unwrap();
+        assert_eq!(sink, "> Strasse  Creme Brulee  n");
+        assert_eq!(n.to_slug(), "strasse-creme-brulee-n");
+        assert_eq!(
+            n.non_ascii(NonAsciiPolicy::Replace('?')).to_cow(),
+            "Strasse ? Creme Brulee ? ?n"
+        );
+        assert_eq!(
+            n.ascii_only(true).ascii_only(false).to_string(),
+            n.to_string()
+        );
+        for options in [
+            StripOptions::new(),
+            StripOptions::new().normalize_line_separators(true),
+            StripOptions::new().char_scan_only(true),
+            StripOptions::new().overrides(OverrideMap::from_sorted(&[('a', "4"), ('€', "EUR")])),
+        ] {
+            let n = "Crème\r\nbrûlée\r à 10€\u{2028}"
+                .normalize()
+                .options(options);
+            let mut sink = String::new();
+            n.write_to(&mut sink).unwrap();
+            assert_eq!(sink, n.to_cow(), "{options:?}");
+        }
+        assert!(matches!(
+            "plain".normalize().to_cow(),
+            Cow::Borrowed("plain")
+        ));
+    }
+}
@@ -0,0 +1,54 @@
+use std::{
+    borrow::Cow,
+    ffi::{OsStr, OsString},
+};
+
+pub trait OsStrDiacriticExt {
+    /// Strips the UTF-8 parts of an OS string, passing everything else
+    /// through unchanged.
+    ///
+    /// On Unix that's any byte sequence which isn't valid UTF-8. On Windows
+    /// OS strings are WTF-8 internally, where the only non-UTF-8 sequences
+    /// are unpaired surrogates: they are kept as is while the text around
+    /// them is stripped.
+    fn strip_diacritics(&self) -> Cow<'_, OsStr>;
+}
+
+impl OsStrDiacriticExt for OsStr {
+    fn strip_diacritics(&self) -> Cow<'_, OsStr> {
+        match crate::strip_diacritics_bytes(self.as_encoded_bytes()) {
+            Cow::Borrowed(_) => Cow::Borrowed(self),
+            // SAFETY: only valid UTF-8 substrings were replaced, by valid
+            // UTF-8.
+            Cow::Owned(buf) => Cow::Owned(unsafe { OsString::from_encoded_bytes_unchecked(buf) }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utf8() {
+        let plain = OsStr::new("report-2024.txt");
+        assert!(matches!(plain.strip_diacritics(), Cow::Borrowed(s) if s == plain));
+        assert_eq!(
+            OsStr::new("Crème brûlée.txt").strip_diacritics(),
+            OsStr::new("Creme brulee.txt")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn invalid_utf8() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let s = OsStr::from_bytes(b"caf\xc3\xa9 \xff\xfe.txt");
+        assert_eq!(s.strip_diacritics().as_bytes(), b"cafe \xff\xfe.txt");
+        let s = OsStr::from_bytes(b"plain\xff");
+        assert!(matches!(s.strip_diacritics(), Cow::Borrowed(_)));
+        let s = OsStr::from_bytes(b"e\xcc\x81\xc3");
+        assert_eq!(s.strip_diacritics().as_bytes(), b"e\xc3");
+    }
+}
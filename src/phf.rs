@@ -1,10 +1,26 @@
-use std::{fmt, iter::FusedIterator};
-
-use phf_shared::HashKey;
-
+use core::{fmt, iter::FusedIterator};
+
+/// The key of [`CharMap`]'s hash, the same as `phf_shared::HashKey`.
+pub type HashKey = u64;
+
+/// A perfect hash map keyed by `char`.
+///
+/// The `raw_*` accessors expose its layout as a stable format, so the same
+/// generated data can be looked up from other languages. A lookup of `c`:
+///
+/// 1. misses if `c` is outside [`raw_range`](Self::raw_range);
+/// 2. hashes the 4 little-endian bytes of `c as u32` with SipHash-1-3,
+///    128-bit output, keyed with `(0, raw_key)`, and splits the result as
+///    `g = (h1 >> 32) as u32`, `f1 = h1 as u32`, `f2 = h2 as u32`;
+/// 3. takes `(d1, d2) = raw_disps[g % raw_disps.len()]` and the index
+///    `(d2 + f1 * d1 + f2) % raw_entries.len()`, in wrapping `u32`
+///    arithmetic;
+/// 4. hits if the key of `raw_entries[index]` is `c`.
+///
+/// This is `phf_shared::hash` followed by `phf_shared::get_index`.
 pub struct CharMap<V: 'static> {
     #[doc(hidden)]
-    pub range: std::ops::RangeInclusive<char>,
+    pub range: core::ops::RangeInclusive<char>,
     #[doc(hidden)]
     pub key: HashKey,
     #[doc(hidden)]
@@ -24,6 +40,242 @@ impl<V: 'static> CharMap<V> {
         self.len() == 0
     }
 
+    /// The smallest key, the range of a map being tight.
+    #[inline]
+    pub const fn first_key(&self) -> Option<char> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(*self.range.start())
+        }
+    }
+
+    /// The largest key, the range of a map being tight.
+    #[inline]
+    pub const fn last_key(&self) -> Option<char> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(*self.range.end())
+        }
+    }
+
+    #[inline]
+    pub fn key_range(&self) -> Option<core::ops::RangeInclusive<char>> {
+        Some(self.first_key()?..=self.last_key()?)
+    }
+
+    /// The range a key must fall in, empty (`start > end`) for an empty map.
+    #[inline]
+    pub const fn raw_range(&self) -> &core::ops::RangeInclusive<char> {
+        &self.range
+    }
+
+    #[inline]
+    pub const fn raw_key(&self) -> &HashKey {
+        &self.key
+    }
+
+    #[inline]
+    pub const fn raw_disps(&self) -> &'static [(u32, u32)] {
+        self.disps
+    }
+
+    /// The entries in hash order.
+    #[inline]
+    pub const fn raw_entries(&self) -> &'static [(char, V)] {
+        self.entries
+    }
+
+    #[inline]
+    pub const fn contains_key(&self, key: char) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Usable in `const` items, the hash being computed by a `const`
+    /// SipHash-1-3.
+    pub const fn get(&self, key: char) -> Option<&V> {
+        match self.get_entry(key) {
+            Some((_, v)) => Some(v),
+            None => None,
+        }
+    }
+
+    pub const fn get_entry(&self, key: char) -> Option<(char, &V)> {
+        lookup(&self.range, self.key, self.disps, self.entries, key)
+    }
+
+    /// Checks the layout invariants: every key hashes to its own slot and
+    /// the range is tight. This is O(n), meant to run once at startup to
+    /// catch a corrupted or badly built map.
+    pub fn is_consistent(&self) -> bool {
+        let slots_ok = self.entries.iter().all(|entry| {
+            self.get_entry(entry.0)
+                .is_some_and(|(_, v)| core::ptr::eq(v, &entry.1))
+        });
+        let range_ok = match (self.keys().min(), self.keys().max()) {
+            (Some(min), Some(max)) => self.range == (min..=max),
+            _ => self.range.is_empty(),
+        };
+        slots_ok && range_ok
+    }
+
+    #[inline]
+    pub fn entries(&self) -> Entries<'_, V> {
+        Entries {
+            iter: self.entries.iter(),
+        }
+    }
+
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, V> {
+        Keys {
+            iter: self.entries.iter(),
+        }
+    }
+
+    #[inline]
+    pub fn values(&self) -> Values<'_, V> {
+        Values {
+            iter: self.entries.iter(),
+        }
+    }
+}
+
+const fn lookup<'a, V>(
+    range: &core::ops::RangeInclusive<char>,
+    hash_key: HashKey,
+    disps: &[(u32, u32)],
+    entries: &'a [(char, V)],
+    key: char,
+) -> Option<(char, &'a V)> {
+    if !contains(range, key) {
+        return None;
+    }
+
+    // phf_shared::get_index
+    let (g, f1, f2) = hash(key, hash_key);
+    let (d1, d2) = disps[(g % disps.len() as u32) as usize];
+    let index = d2.wrapping_add(f1.wrapping_mul(d1)).wrapping_add(f2) % entries.len() as u32;
+    let entry = &entries[index as usize];
+    if key == entry.0 {
+        Some((entry.0, &entry.1))
+    } else {
+        None
+    }
+}
+
+// `RangeInclusive::contains` isn't const.
+#[inline]
+const fn contains(range: &core::ops::RangeInclusive<char>, key: char) -> bool {
+    *range.start() <= key && key <= *range.end()
+}
+
+// phf_shared::hash, whose SipHasher isn't const: SipHash-1-3 with 128-bit
+// output over the 4 little-endian bytes of `key`, keyed with
+// `(0, hash_key)`, split into `(g, f1, f2)`.
+const fn hash(key: char, hash_key: HashKey) -> (u32, u32, u32) {
+    const fn round(v: &mut [u64; 4]) {
+        v[0] = v[0].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(13) ^ v[0];
+        v[0] = v[0].rotate_left(32);
+        v[2] = v[2].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(16) ^ v[2];
+        v[0] = v[0].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(21) ^ v[0];
+        v[2] = v[2].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(17) ^ v[2];
+        v[2] = v[2].rotate_left(32);
+    }
+
+    let mut v = [
+        0x736f6d6570736575,
+        hash_key ^ 0x646f72616e646f6d ^ 0xee,
+        0x6c7967656e657261,
+        hash_key ^ 0x7465646279746573,
+    ];
+    // The only block: 4 bytes of message, its length in the top byte.
+    let b = (4 << 56) | key as u64;
+    v[3] ^= b;
+    round(&mut v);
+    v[0] ^= b;
+    v[2] ^= 0xee;
+    round(&mut v);
+    round(&mut v);
+    round(&mut v);
+    let h1 = v[0] ^ v[1] ^ v[2] ^ v[3];
+    v[1] ^= 0xdd;
+    round(&mut v);
+    round(&mut v);
+    round(&mut v);
+    let h2 = v[0] ^ v[1] ^ v[2] ^ v[3];
+    ((h1 >> 32) as u32, h1 as u32, h2 as u32)
+}
+
+impl<'a, V> IntoIterator for &'a CharMap<V> {
+    type Item = (char, &'a V);
+    type IntoIter = Entries<'a, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries()
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for CharMap<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.entries()).finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V: 'static> CharMap<V> {
+    /// Builds a map at runtime. Its storage is leaked to get the `'static`
+    /// layout shared with the generated tables, so it's meant for maps that
+    /// live as long as the program. Later duplicates of a key win.
+    #[inline]
+    pub fn leak<I: IntoIterator<Item = (char, V)>>(entries: I) -> Self {
+        entries.into_iter().collect::<OwnedCharMap<V>>().leak()
+    }
+
+    /// Copies the entries matching `pred` into a new map.
+    pub fn filter<F: Fn(char, &V) -> bool>(&self, pred: F) -> OwnedCharMap<V>
+    where
+        V: Clone,
+    {
+        filter(self.entries(), pred)
+    }
+}
+
+#[cfg(feature = "std")]
+fn filter<V: Clone, F: Fn(char, &V) -> bool>(entries: Entries<'_, V>, pred: F) -> OwnedCharMap<V> {
+    entries
+        .filter(|&(k, v)| pred(k, v))
+        .map(|(k, v)| (k, v.clone()))
+        .collect()
+}
+
+/// A [`CharMap`] built at runtime, owning its storage.
+#[cfg(feature = "std")]
+pub struct OwnedCharMap<V: 'static> {
+    range: core::ops::RangeInclusive<char>,
+    key: HashKey,
+    disps: Box<[(u32, u32)]>,
+    entries: Box<[(char, V)]>,
+}
+
+#[cfg(feature = "std")]
+impl<V: 'static> OwnedCharMap<V> {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     #[inline]
     pub fn contains_key(&self, key: char) -> bool {
         self.get(key).is_some()
@@ -34,44 +286,184 @@ impl<V: 'static> CharMap<V> {
     }
 
     pub fn get_entry(&self, key: char) -> Option<(char, &V)> {
-        if !self.range.contains(&key) {
-            return None;
-        }
-
-        let hashes = phf_shared::hash(&key, &self.key);
-        let index = phf_shared::get_index(&hashes, self.disps, self.entries.len());
-        let entry = &self.entries[index as usize];
-        if key == entry.0 {
-            Some((entry.0, &entry.1))
-        } else {
-            None
-        }
+        lookup(&self.range, self.key, &self.disps, &self.entries, key)
     }
 
     #[inline]
-    pub fn entries(&self) -> Entries<V> {
+    pub fn entries(&self) -> Entries<'_, V> {
         Entries {
             iter: self.entries.iter(),
         }
     }
 
     #[inline]
-    pub fn keys(&self) -> Keys<V> {
+    pub fn keys(&self) -> Keys<'_, V> {
         Keys {
             iter: self.entries.iter(),
         }
     }
 
     #[inline]
-    pub fn values(&self) -> Values<V> {
+    pub fn values(&self) -> Values<'_, V> {
         Values {
             iter: self.entries.iter(),
         }
     }
+
+    /// Copies the entries matching `pred` into a new map.
+    pub fn filter<F: Fn(char, &V) -> bool>(&self, pred: F) -> OwnedCharMap<V>
+    where
+        V: Clone,
+    {
+        filter(self.entries(), pred)
+    }
+
+    pub fn leak(self) -> CharMap<V> {
+        CharMap {
+            range: self.range,
+            key: self.key,
+            disps: Box::leak(self.disps),
+            entries: Box::leak(self.entries),
+        }
+    }
+}
+
+/// Later duplicates of a key win.
+#[cfg(feature = "std")]
+impl<V: 'static> FromIterator<(char, V)> for OwnedCharMap<V> {
+    fn from_iter<I: IntoIterator<Item = (char, V)>>(iter: I) -> Self {
+        let entries = iter
+            .into_iter()
+            .collect::<std::collections::BTreeMap<_, _>>();
+        let keys = entries.keys().copied().collect::<Vec<_>>();
+        let range = match (keys.first(), keys.last()) {
+            (Some(&min), Some(&max)) => min..=max,
+            _ => '\u{1}'..='\u{0}',
+        };
+        let state = phf_generator::generate_hash(&keys);
+
+        let mut entries = entries.into_iter().map(Some).collect::<Vec<_>>();
+        let entries = state
+            .map
+            .iter()
+            .map(|&i| entries[i].take().unwrap())
+            .collect();
+
+        Self {
+            range,
+            key: state.key,
+            disps: state.disps.into_boxed_slice(),
+            entries,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V: 'static> IntoIterator for OwnedCharMap<V> {
+    type Item = (char, V);
+    type IntoIter = std::vec::IntoIter<(char, V)>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_vec().into_iter()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, V> IntoIterator for &'a OwnedCharMap<V> {
+    type Item = (char, &'a V);
+    type IntoIter = Entries<'a, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V: Clone> Clone for OwnedCharMap<V> {
+    fn clone(&self) -> Self {
+        Self {
+            range: self.range.clone(),
+            key: self.key,
+            disps: self.disps.clone(),
+            entries: self.entries.clone(),
+        }
+    }
 }
 
-pub struct Entries<'a, V> {
-    iter: std::slice::Iter<'a, (char, V)>,
+#[cfg(feature = "std")]
+impl<V: PartialEq> PartialEq for OwnedCharMap<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.entries().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V: Eq> Eq for OwnedCharMap<V> {}
+
+#[cfg(feature = "std")]
+impl<V: fmt::Debug> fmt::Debug for OwnedCharMap<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.entries()).finish()
+    }
+}
+
+/// Serializes the entries as a map sorted by key: the hash layout changes
+/// whenever the keys do, so it's left out and only the logical pairs kept.
+#[cfg(feature = "serde")]
+impl<V: serde::Serialize> serde::Serialize for CharMap<V> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_sorted(self.entries(), serializer)
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "std"))]
+impl<V: serde::Serialize> serde::Serialize for OwnedCharMap<V> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_sorted(self.entries(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+fn serialize_sorted<V: serde::Serialize, S: serde::Serializer>(
+    entries: Entries<'_, V>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut entries = entries.collect::<alloc::vec::Vec<_>>();
+    entries.sort_unstable_by_key(|&(k, _)| k);
+    serializer.collect_map(entries)
+}
+
+/// Deserializes a map keyed by `char`, building the hash at runtime. Later
+/// duplicates of a key win. [`OwnedCharMap::leak`] turns it into a
+/// [`CharMap`].
+#[cfg(all(feature = "serde", feature = "std"))]
+impl<'de, V: serde::Deserialize<'de> + 'static> serde::Deserialize<'de> for OwnedCharMap<V> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor<V>(core::marker::PhantomData<V>);
+
+        impl<'de, V: serde::Deserialize<'de> + 'static> serde::de::Visitor<'de> for Visitor<V> {
+            type Value = OwnedCharMap<V>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map keyed by char")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<Self::Value, A::Error> {
+                core::iter::from_fn(|| map.next_entry().transpose()).collect()
+            }
+        }
+
+        deserializer.deserialize_map(Visitor(core::marker::PhantomData))
+    }
+}
+
+pub struct Entries<'a, V: 'static> {
+    iter: core::slice::Iter<'a, (char, V)>,
 }
 
 impl<'a, V> Clone for Entries<'a, V> {
@@ -117,8 +509,8 @@ impl<'a, V: fmt::Debug> fmt::Debug for Entries<'a, V> {
     }
 }
 
-pub struct Keys<'a, V> {
-    iter: std::slice::Iter<'a, (char, V)>,
+pub struct Keys<'a, V: 'static> {
+    iter: core::slice::Iter<'a, (char, V)>,
 }
 
 impl<'a, V> Clone for Keys<'a, V> {
@@ -164,8 +556,8 @@ impl<'a, V: fmt::Debug> fmt::Debug for Keys<'a, V> {
     }
 }
 
-pub struct Values<'a, V> {
-    iter: std::slice::Iter<'a, (char, V)>,
+pub struct Values<'a, V: 'static> {
+    iter: core::slice::Iter<'a, (char, V)>,
 }
 
 impl<'a, V> Clone for Values<'a, V> {
@@ -210,3 +602,88 @@ impl<'a, V: fmt::Debug> fmt::Debug for Values<'a, V> {
         f.debug_list().entries(self.clone()).finish()
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn owned_round_trip() {
+        let map = [
+            ('é', "e".to_string()),
+            ('ß', "ss".to_string()),
+            ('é', "E".to_string()),
+        ]
+        .into_iter()
+        .collect::<OwnedCharMap<_>>();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get('é').map(String::as_str), Some("E"));
+        assert_eq!(map.get('e'), None);
+
+        let pairs = map.clone().into_iter().collect::<Vec<(char, String)>>();
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs.into_iter().collect::<OwnedCharMap<_>>(), map);
+    }
+
+    #[test]
+    fn owned_empty() {
+        let map = std::iter::empty::<(char, ())>().collect::<OwnedCharMap<_>>();
+        assert!(map.is_empty());
+        assert_eq!(map.get('a'), None);
+        assert_eq!(map.leak().first_key(), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let map = CharMap::leak([('ü', "u"), ('é', "e"), ('ñ', "n")]);
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"{"é":"e","ñ":"n","ü":"u"}"#);
+
+        let owned = serde_json::from_str::<OwnedCharMap<String>>(&json).unwrap();
+        assert_eq!(owned.len(), 3);
+        assert_eq!(owned.get('ñ').map(String::as_str), Some("n"));
+        assert_eq!(serde_json::to_string(&owned).unwrap(), json);
+        assert_eq!(serde_json::to_string(&owned.leak()).unwrap(), json);
+
+        let empty = serde_json::from_str::<OwnedCharMap<u8>>("{}").unwrap();
+        assert!(empty.is_empty());
+        assert!(serde_json::from_str::<OwnedCharMap<u8>>(r#"{"ab":1}"#).is_err());
+        assert!(serde_json::from_str::<OwnedCharMap<u8>>("[]").is_err());
+    }
+
+    #[test]
+    fn const_hash() {
+        for hash_key in [0, 1, 12913932095322966823, u64::MAX] {
+            for c in ('\0'..=char::MAX).step_by(97).chain(['\u{10ffff}']) {
+                let expected = phf_shared::hash(&c, &hash_key);
+                assert_eq!(
+                    hash(c, hash_key),
+                    (expected.g, expected.f1, expected.f2),
+                    "{c:?} {hash_key}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn consistency() {
+        let map = CharMap::leak([('é', 'e'), ('ñ', 'n'), ('ü', 'u')]);
+        assert!(map.is_consistent());
+        assert!(CharMap::leak(std::iter::empty::<(char, ())>()).is_consistent());
+
+        let loose = CharMap {
+            range: 'a'..='ü',
+            ..map
+        };
+        assert!(!loose.is_consistent());
+
+        let mut entries = map.entries.to_vec();
+        entries.swap(0, 1);
+        let shuffled = CharMap {
+            entries: entries.leak(),
+            ..map
+        };
+        assert!(!shuffled.is_consistent());
+    }
+}
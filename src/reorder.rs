@@ -0,0 +1,109 @@
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::cmp::Ordering;
+
+use crate::tables::COMBINING_CLASSES;
+
+/// The canonical combining class of `c`, 0 for starters.
+pub(crate) fn combining_class(c: char) -> u8 {
+    // Every combining char is above U+02FF
+    if c < '\u{300}' {
+        return 0;
+    }
+    COMBINING_CLASSES
+        .binary_search_by(|&(start, end, _)| {
+            if end < c {
+                Ordering::Less
+            } else if start > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .map_or(0, |i| COMBINING_CLASSES[i].2)
+}
+
+/// Puts the combining marks following each starter in canonical order,
+/// stably sorted by canonical combining class, as canonical decomposition
+/// does: `a◌́◌̣` (U+0301 is class 230, U+0323 class 220) becomes `a◌̣◌́`.
+/// Borrows when the marks are already in order.
+///
+/// Stripping handles marks one by one, so their order never changes what
+/// it drops. This is for comparing or storing text from sources that don't
+/// normalize.
+pub fn reorder_combining_marks(s: &str) -> Cow<'_, str> {
+    let mut prev = 0;
+    let in_order = s.chars().all(|c| {
+        let class = combining_class(c);
+        let ok = class == 0 || prev <= class;
+        prev = class;
+        ok
+    });
+    if in_order {
+        return Cow::Borrowed(s);
+    }
+
+    let mut buf = String::with_capacity(s.len());
+    let mut marks = Vec::new();
+    for c in s.chars() {
+        match combining_class(c) {
+            0 => {
+                flush(&mut marks, &mut buf);
+                buf.push(c);
+            }
+            class => marks.push((class, c)),
+        }
+    }
+    flush(&mut marks, &mut buf);
+    Cow::Owned(buf)
+}
+
+fn flush(marks: &mut Vec<(u8, char)>, buf: &mut String) {
+    marks.sort_by_key(|&(class, _)| class);
+    buf.extend(marks.drain(..).map(|(_, c)| c));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::StrDiacriticExt;
+
+    #[test]
+    fn classes() {
+        assert_eq!(combining_class('a'), 0);
+        assert_eq!(combining_class('é'), 0);
+        assert_eq!(combining_class('\u{301}'), 230);
+        assert_eq!(combining_class('\u{323}'), 220);
+        assert_eq!(combining_class('\u{315}'), 232);
+        assert_eq!(combining_class('\u{1d165}'), 216);
+        assert_eq!(combining_class('\u{10ffff}'), 0);
+    }
+
+    #[test]
+    fn reorder() {
+        // Dot below (220) belongs before the acute (230)
+        let misordered = "a\u{301}\u{323} e\u{301}\u{323}\u{302}x";
+        let canonical = "a\u{323}\u{301} e\u{323}\u{301}\u{302}x";
+        assert_eq!(reorder_combining_marks(misordered), canonical);
+        assert_eq!(
+            reorder_combining_marks(misordered).strip_diacritics(),
+            canonical.strip_diacritics()
+        );
+        assert!(matches!(
+            reorder_combining_marks(canonical),
+            Cow::Borrowed(_)
+        ));
+
+        // Stable within a class, marks before the first starter included
+        assert_eq!(
+            reorder_combining_marks("\u{301}\u{323}\u{300}\u{316}a"),
+            "\u{323}\u{316}\u{301}\u{300}a"
+        );
+        // The run ends at each starter
+        assert_eq!(
+            reorder_combining_marks("a\u{301}b\u{323}\u{301}\u{323}"),
+            "a\u{301}b\u{323}\u{323}\u{301}"
+        );
+        assert!(matches!(reorder_combining_marks(""), Cow::Borrowed("")));
+    }
+}
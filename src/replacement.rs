@@ -0,0 +1,123 @@
+use core::{cmp::Ordering, fmt, hash, ops::Deref};
+
+/// A value of the generated maps, like
+/// [`DIACRITICS_MAPPING`](crate::tables::DIACRITICS_MAPPING): an index into
+/// a pool holding every distinct replacement string once, which keeps each
+/// map entry to 8 bytes. It derefs to its string, see [`as_str`](Self::as_str).
+///
+/// Equal replacements always have the same index, so comparing them is as
+/// cheap as comparing integers. They order and hash like their strings.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Replacement(pub(crate) u16);
+
+impl Replacement {
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        crate::tables::REPLACEMENTS[self.0 as usize]
+    }
+}
+
+impl Deref for Replacement {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Replacement {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<Replacement> for &'static str {
+    #[inline]
+    fn from(r: Replacement) -> Self {
+        r.as_str()
+    }
+}
+
+impl PartialEq<str> for Replacement {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Replacement {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialOrd for Replacement {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Replacement {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl hash::Hash for Replacement {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl fmt::Debug for Replacement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for Replacement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Replacement {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::{format, string::ToString};
+
+    use super::*;
+
+    use crate::tables::REPLACEMENTS;
+
+    #[test]
+    fn pool() {
+        // Sorted and deduplicated, so that equal indices mean equal strings
+        assert!(REPLACEMENTS.windows(2).all(|w| w[0] < w[1]));
+        assert!(REPLACEMENTS.len() <= usize::from(u16::MAX));
+        assert_eq!(REPLACEMENTS[0], "");
+    }
+
+    #[test]
+    fn compare() {
+        let (e, ae) = (Replacement(0), Replacement(1));
+        assert_eq!(e, "");
+        assert_ne!(ae, "");
+        assert!(e < ae);
+        assert_eq!(&*ae, REPLACEMENTS[1]);
+        assert_eq!(ae.to_string(), REPLACEMENTS[1]);
+        assert_eq!(format!("{e:?}"), "\"\"");
+    }
+}
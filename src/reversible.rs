@@ -0,0 +1,98 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::CharDiacriticExt;
+
+/// What [`StrDiacriticExt::strip_diacritics_reversible`](crate::StrDiacriticExt::strip_diacritics_reversible)
+/// changed, enough for [`restore`] to rebuild the original string.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ReverseInfo {
+    edits: Vec<Edit>,
+}
+
+/// A single char replaced by `len` bytes at `offset` in the stripped string,
+/// `len` being 0 for a dropped mark.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edit {
+    pub offset: usize,
+    pub len: usize,
+    pub original: char,
+}
+
+impl ReverseInfo {
+    /// The edits, sorted by offset.
+    #[inline]
+    pub fn edits(&self) -> &[Edit] {
+        &self.edits
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+}
+
+pub(crate) fn strip_reversible(s: &str) -> (String, ReverseInfo) {
+    let mut buf = String::with_capacity(s.len());
+    let mut edits = Vec::new();
+    for c in s.chars() {
+        match c.strip_diacritics() {
+            Some(t) => {
+                edits.push(Edit {
+                    offset: buf.len(),
+                    len: t.len(),
+                    original: c,
+                });
+                buf.push_str(t);
+            }
+            None => buf.push(c),
+        }
+    }
+    (buf, ReverseInfo { edits })
+}
+
+/// Rebuilds the original of a string stripped by
+/// [`StrDiacriticExt::strip_diacritics_reversible`](crate::StrDiacriticExt::strip_diacritics_reversible).
+///
+/// # Panics
+///
+/// Panics if `info` doesn't come from stripping into `stripped`.
+pub fn restore(stripped: &str, info: &ReverseInfo) -> String {
+    let mut buf = String::with_capacity(stripped.len());
+    let mut pos = 0;
+    for edit in &info.edits {
+        buf.push_str(&stripped[pos..edit.offset]);
+        buf.push(edit.original);
+        pos = edit.offset + edit.len;
+    }
+    buf.push_str(&stripped[pos..]);
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let s = "Crème bru\u{302}lée ǆ";
+        let (stripped, info) = strip_reversible(s);
+        assert_eq!(stripped, "Creme brulee dz");
+        assert_eq!(
+            info.edits()[..2],
+            [
+                Edit {
+                    offset: 2,
+                    len: 1,
+                    original: 'è',
+                },
+                Edit {
+                    offset: 9,
+                    len: 0,
+                    original: '\u{302}',
+                },
+            ]
+        );
+        assert_eq!(restore(&stripped, &info), s);
+        assert!(strip_reversible("plain").1.is_empty());
+    }
+}
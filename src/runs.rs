@@ -0,0 +1,111 @@
+use alloc::borrow::Cow;
+use core::iter::FusedIterator;
+
+use crate::CharDiacriticExt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Run<'a> {
+    Unchanged(&'a str),
+    Changed { src: &'a str, out: Cow<'a, str> },
+}
+
+/// Maximal runs of unchanged and changed characters, see
+/// [`StrDiacriticExt::strip_diacritics_runs`](crate::StrDiacriticExt::strip_diacritics_runs).
+#[derive(Debug, Clone)]
+pub struct Runs<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Runs<'a> {
+    #[inline]
+    pub(crate) fn new(s: &'a str) -> Self {
+        Self { rest: s }
+    }
+}
+
+impl<'a> Iterator for Runs<'a> {
+    type Item = Run<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chars = self.rest.char_indices();
+        let first = chars.next()?.1.strip_diacritics();
+
+        let run = match first {
+            None => {
+                let end = chars
+                    .find(|(_, c)| c.strip_diacritics().is_some())
+                    .map_or(self.rest.len(), |(i, _)| i);
+                Run::Unchanged(&self.rest[..end])
+            }
+            Some(out) => {
+                let mut out = Cow::Borrowed(out);
+                let mut end = self.rest.len();
+                for (i, c) in chars {
+                    match c.strip_diacritics() {
+                        Some(t) => out.to_mut().push_str(t),
+                        None => {
+                            end = i;
+                            break;
+                        }
+                    }
+                }
+                Run::Changed {
+                    src: &self.rest[..end],
+                    out,
+                }
+            }
+        };
+
+        self.rest = &self.rest[match &run {
+            Run::Unchanged(src) | Run::Changed { src, .. } => src.len(),
+        }..];
+        Some(run)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::from(!self.rest.is_empty()), Some(self.rest.len()))
+    }
+}
+
+impl<'a> FusedIterator for Runs<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec::Vec};
+
+    use super::*;
+
+    #[test]
+    fn coalesce() {
+        assert_eq!(
+            Runs::new("Crème brûlée").collect::<Vec<_>>(),
+            [
+                Run::Unchanged("Cr"),
+                Run::Changed {
+                    src: "è",
+                    out: Cow::Borrowed("e")
+                },
+                Run::Unchanged("me br"),
+                Run::Changed {
+                    src: "û",
+                    out: Cow::Borrowed("u")
+                },
+                Run::Unchanged("l"),
+                Run::Changed {
+                    src: "é",
+                    out: Cow::Borrowed("e")
+                },
+                Run::Unchanged("e"),
+            ]
+        );
+        assert_eq!(
+            Runs::new("àé").collect::<Vec<_>>(),
+            [Run::Changed {
+                src: "àé",
+                out: Cow::Owned("ae".to_string())
+            }]
+        );
+        assert_eq!(Runs::new("").next(), None);
+    }
+}
@@ -0,0 +1,124 @@
+use core::iter::FusedIterator;
+
+use crate::CharDiacriticExt;
+
+/// Unchanged text followed by the char stripping replaces, see
+/// [`StrDiacriticExt::diacritic_segments`](crate::StrDiacriticExt::diacritic_segments).
+///
+/// The last segment holds the unchanged tail of the string, possibly empty,
+/// with no replaced char.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment<'a> {
+    pub unchanged: &'a str,
+    /// `None` on the tail only.
+    pub replaced_from: Option<char>,
+    /// Empty when the char is dropped, and on the tail.
+    pub replaced_to: &'static str,
+}
+
+#[derive(Debug, Clone)]
+pub struct Segments<'a> {
+    // None once the tail is yielded
+    rest: Option<&'a str>,
+}
+
+impl<'a> Segments<'a> {
+    #[inline]
+    pub(crate) fn new(s: &'a str) -> Self {
+        Self { rest: Some(s) }
+    }
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = Segment<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let s = self.rest?;
+        match crate::next_diacritic_skipping_ascii(s, |c| c.strip_diacritics()) {
+            Some((init, replaced_to, rest)) => {
+                let replaced_from = s[init.len()..].chars().next();
+                self.rest = Some(rest);
+                Some(Segment {
+                    unchanged: init,
+                    replaced_from,
+                    replaced_to,
+                })
+            }
+            None => {
+                self.rest = None;
+                Some(Segment {
+                    unchanged: s,
+                    replaced_from: None,
+                    replaced_to: "",
+                })
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.rest {
+            Some(s) => (1, Some(s.len() + 1)),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<'a> FusedIterator for Segments<'a> {}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::{string::String, vec::Vec};
+
+    use super::*;
+
+    fn segment(
+        unchanged: &'static str,
+        replaced: Option<(char, &'static str)>,
+    ) -> Segment<'static> {
+        Segment {
+            unchanged,
+            replaced_from: replaced.map(|(c, _)| c),
+            replaced_to: replaced.map_or("", |(_, t)| t),
+        }
+    }
+
+    #[test]
+    fn boundaries() {
+        let mut it = Segments::new("TÅRÖÄ");
+        assert_eq!(it.next(), Some(segment("T", Some(('Å', "A")))));
+        assert_eq!(it.next(), Some(segment("R", Some(('Ö', "O")))));
+        assert_eq!(it.next(), Some(segment("", Some(('Ä', "A")))));
+        assert_eq!(it.next(), Some(segment("", None)));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+
+        assert_eq!(
+            Segments::new("e\u{301}t\u{e9}!").collect::<Vec<_>>(),
+            [
+                segment("e", Some(('\u{301}', ""))),
+                segment("t", Some(('é', "e"))),
+                segment("!", None),
+            ]
+        );
+        assert_eq!(Segments::new("").collect::<Vec<_>>(), [segment("", None)]);
+        assert_eq!(
+            Segments::new("plain").collect::<Vec<_>>(),
+            [segment("plain", None)]
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let s = "Crème brûlée, Ærøskøbing";
+        let (mut before, mut after) = (String::new(), String::new());
+        for seg in Segments::new(s) {
+            before.push_str(seg.unchanged);
+            before.extend(seg.replaced_from);
+            after.push_str(seg.unchanged);
+            after.push_str(seg.replaced_to);
+        }
+        assert_eq!(before, s);
+        assert_eq!(after, crate::StrDiacriticExt::strip_diacritics(s));
+    }
+}
@@ -0,0 +1,122 @@
+use std::io::{self, BufRead, ErrorKind, Read, Write};
+
+use crate::StrDiacriticExt;
+
+/// Strips everything read from `r` into `w`, holding at most `buf_size`
+/// bytes of input (plus up to 3 bytes of a char split across reads) at a
+/// time, whatever the length of the stream.
+///
+/// Fails with [`ErrorKind::InvalidData`] if the input isn't valid UTF-8.
+///
+/// # Panics
+///
+/// Panics if `buf_size` is 0.
+pub fn strip_diacritics_bounded<R: Read, W: Write>(
+    mut r: R,
+    mut w: W,
+    buf_size: usize,
+) -> io::Result<()> {
+    assert!(buf_size > 0, "buf_size must be greater than 0");
+
+    let mut buf = vec![0u8; buf_size + 3];
+    let mut pending = 0;
+    loop {
+        let n = match r.read(&mut buf[pending..pending + buf_size]) {
+            Ok(0) if pending == 0 => return w.flush(),
+            Ok(0) => return Err(invalid_utf8()),
+            Ok(n) => n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        let filled = pending + n;
+
+        let valid = match std::str::from_utf8(&buf[..filled]) {
+            Ok(s) => s,
+            Err(e) if e.error_len().is_some() => return Err(invalid_utf8()),
+            // The chunk ends in the middle of a char, keep it for later.
+            Err(e) => unsafe { std::str::from_utf8_unchecked(&buf[..e.valid_up_to()]) },
+        };
+        write_stripped(valid, &mut w)?;
+
+        let consumed = valid.len();
+        buf.copy_within(consumed..filled, 0);
+        pending = filled - consumed;
+    }
+}
+
+/// Strips everything read from `r` into `w` a line at a time, reusing the
+/// same two buffers for every line and its stripped form. Line endings are
+/// written back as read, `\n` or `\r\n`, and a last line without one gets
+/// none.
+///
+/// Fails with [`ErrorKind::InvalidData`] if a line isn't valid UTF-8, after
+/// writing the lines before it.
+pub fn strip_diacritics_lines<R: BufRead, W: Write>(mut r: R, mut w: W) -> io::Result<()> {
+    let mut line = String::new();
+    let mut stripped = String::new();
+    loop {
+        line.clear();
+        if r.read_line(&mut line)? == 0 {
+            return w.flush();
+        }
+        stripped.clear();
+        line.strip_diacritics_into(&mut stripped);
+        w.write_all(stripped.as_bytes())?;
+    }
+}
+
+fn write_stripped<W: Write>(s: &str, w: &mut W) -> io::Result<()> {
+    crate::strip_pieces(s, |piece| w.write_all(piece.as_bytes()))
+}
+
+fn invalid_utf8() -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, "stream did not contain valid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_chars() {
+        let input = "Crème brûlée, Ærøskøbing, Łódź";
+        for buf_size in 1..=8 {
+            let mut out = Vec::new();
+            strip_diacritics_bounded(input.as_bytes(), &mut out, buf_size).unwrap();
+            assert_eq!(
+                out,
+                "Creme brulee, Ærøskøbing, Łodz".as_bytes(),
+                "{buf_size}"
+            );
+        }
+    }
+
+    #[test]
+    fn lines() {
+        let input = "Crème brûlée\n\nÆrøskøbing\r\nŁódź";
+        let mut out = Vec::new();
+        strip_diacritics_lines(io::Cursor::new(input.as_bytes()), &mut out).unwrap();
+        assert_eq!(out, "Creme brulee\n\nÆrøskøbing\r\nŁodz".as_bytes());
+
+        let mut out = Vec::new();
+        strip_diacritics_lines(io::Cursor::new("déjà\n"), &mut out).unwrap();
+        assert_eq!(out, b"deja\n");
+
+        let mut out = Vec::new();
+        strip_diacritics_lines(io::Cursor::new(""), &mut out).unwrap();
+        assert!(out.is_empty());
+
+        let mut out = Vec::new();
+        let err = strip_diacritics_lines(&b"caf\xc3\xa9\ncaf\xc3\n"[..], &mut out).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert_eq!(out, b"cafe\n");
+    }
+
+    #[test]
+    fn rejects_invalid_utf8() {
+        for input in [&b"caf\xc3"[..], b"caf\xff\xc3\xa9"] {
+            let err = strip_diacritics_bounded(input, io::sink(), 2).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidData);
+        }
+    }
+}
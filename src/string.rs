@@ -0,0 +1,187 @@
+use alloc::string::String;
+use core::fmt;
+
+use crate::CharDiacriticExt;
+
+pub trait StringDiacriticExt {
+    /// Strips like [`StrDiacriticExt::strip_diacritics`](crate::StrDiacriticExt::strip_diacritics),
+    /// reusing the string's own buffer. The string isn't touched at all when
+    /// nothing changes.
+    fn strip_diacritics_in_place(&mut self);
+}
+
+impl StringDiacriticExt for String {
+    fn strip_diacritics_in_place(&mut self) {
+        let Some(start) = self.find(|c: char| c.strip_diacritics().is_some()) else {
+            return;
+        };
+
+        // SAFETY: whole chars are copied or replaced by whole strings, and
+        // everything from `read` on is still the original UTF-8, so the
+        // buffer is valid UTF-8 once truncated at `write`.
+        let buf = unsafe { self.as_mut_vec() };
+        let (mut read, mut write) = (start, start);
+        while read < buf.len() {
+            let c = unsafe { core::str::from_utf8_unchecked(&buf[read..]) }
+                .chars()
+                .next()
+                .unwrap();
+            let mut end = read + c.len_utf8();
+            match c.strip_diacritics() {
+                Some(t) => {
+                    // A few CJK compatibility ideographs fold to a longer
+                    // supplementary one: make room by shifting the tail.
+                    if write + t.len() > end {
+                        let gap = write + t.len() - end;
+                        buf.splice(end..end, core::iter::repeat_n(0, gap));
+                        end += gap;
+                    }
+                    buf[write..write + t.len()].copy_from_slice(t.as_bytes());
+                    write += t.len();
+                }
+                None => {
+                    buf.copy_within(read..end, write);
+                    write += end - read;
+                }
+            }
+            read = end;
+        }
+        buf.truncate(write);
+    }
+}
+
+/// A string stripped as it's built, one char at a time: collecting or
+/// extending it with chars pushes the replacement of every char stripping
+/// changes, so that no second pass is needed.
+///
+/// ```
+/// use strip_diacritics::StrippedString;
+///
+/// let s: StrippedString = "cafe\u{301}".chars().collect();
+/// assert_eq!(s.into_string(), "cafe");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StrippedString(String);
+
+impl StrippedString {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(String::new())
+    }
+
+    pub fn push(&mut self, c: char) {
+        match c.strip_diacritics() {
+            Some(t) => self.0.push_str(t),
+            None => self.0.push(c),
+        }
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl FromIterator<char> for StrippedString {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut s = Self::new();
+        s.extend(iter);
+        s
+    }
+}
+
+impl Extend<char> for StrippedString {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        // A lower bound: chars take at least a byte, marks none
+        self.0.reserve(iter.size_hint().0);
+        iter.for_each(|c| self.push(c));
+    }
+}
+
+impl From<StrippedString> for String {
+    #[inline]
+    fn from(s: StrippedString) -> Self {
+        s.0
+    }
+}
+
+impl AsRef<str> for StrippedString {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for StrippedString {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::StrDiacriticExt;
+
+    fn check(s: &str) {
+        let mut owned = String::from(s);
+        owned.strip_diacritics_in_place();
+        assert_eq!(owned, s.strip_diacritics(), "{s:?}");
+    }
+
+    #[test]
+    fn removal() {
+        for s in [
+            "e\u{301}",
+            "\u{300}\u{301}a\u{302}",
+            "Cre\u{300}me bru\u{302}le\u{301}e",
+        ] {
+            check(s);
+        }
+    }
+
+    #[test]
+    fn expansion() {
+        for s in [
+            "æ",
+            "Ærøskøbing ǆ",
+            "\u{fa6c}",
+            "a\u{fa6c}\u{fad7}b",
+            "é\u{fa6c}\u{301}",
+        ] {
+            check(s);
+        }
+    }
+
+    #[test]
+    fn collect() {
+        let s: StrippedString = "cafe\u{301}".chars().collect();
+        assert_eq!(s.into_string(), "cafe");
+
+        let mut s = StrippedString::new();
+        s.extend("Ærø ǆ".chars());
+        s.push('\u{300}');
+        s.extend(['é', '!']);
+        assert_eq!(s.as_str(), "Ærø dze!");
+        assert_eq!(s.as_str(), "Ærø ǆ\u{300}é!".strip_diacritics());
+    }
+
+    #[test]
+    fn unchanged() {
+        let mut s = String::with_capacity(64);
+        s.push_str("plain ascii, ß and Æ");
+        let (ptr, cap) = (s.as_ptr(), s.capacity());
+        s.strip_diacritics_in_place();
+        assert_eq!(s, "plain ascii, ß and Æ");
+        assert_eq!((s.as_ptr(), s.capacity()), (ptr, cap));
+    }
+}
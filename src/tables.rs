@@ -1,1054 +1,15496 @@
-pub const DIACRITICS_MAPPING: crate::phf::CharMap<&'static str> = crate::phf::CharMap {
-    range: '¨'..='￣',
+pub const MAX_REPLACEMENT_CHARS: usize = 18;
+
+pub const MAX_REPLACEMENT_LEN: usize = 33;
+
+// The values of the generated maps, see `crate::Replacement`.
+pub(crate) const REPLACEMENTS: &[&str] = &[
+    "",
+    " ",
+    " \u{3099}",
+    " \u{309a}",
+    "!",
+    "!!",
+    "!?",
+    "\"",
+    "#",
+    "$",
+    "%",
+    "&",
+    "'",
+    "(",
+    "(1)",
+    "(10)",
+    "(11)",
+    "(12)",
+    "(13)",
+    "(14)",
+    "(15)",
+    "(16)",
+    "(17)",
+    "(18)",
+    "(19)",
+    "(2)",
+    "(20)",
+    "(3)",
+    "(4)",
+    "(5)",
+    "(6)",
+    "(7)",
+    "(8)",
+    "(9)",
+    "(A)",
+    "(B)",
+    "(C)",
+    "(D)",
+    "(E)",
+    "(F)",
+    "(G)",
+    "(H)",
+    "(I)",
+    "(J)",
+    "(K)",
+    "(L)",
+    "(M)",
+    "(N)",
+    "(O)",
+    "(P)",
+    "(Q)",
+    "(R)",
+    "(S)",
+    "(T)",
+    "(U)",
+    "(V)",
+    "(W)",
+    "(X)",
+    "(Y)",
+    "(Z)",
+    "(a)",
+    "(b)",
+    "(c)",
+    "(d)",
+    "(e)",
+    "(f)",
+    "(g)",
+    "(h)",
+    "(i)",
+    "(j)",
+    "(k)",
+    "(l)",
+    "(m)",
+    "(n)",
+    "(o)",
+    "(p)",
+    "(q)",
+    "(r)",
+    "(s)",
+    "(t)",
+    "(u)",
+    "(v)",
+    "(w)",
+    "(x)",
+    "(y)",
+    "(z)",
+    "(ᄀ)",
+    "(가)",
+    "(ᄂ)",
+    "(나)",
+    "(ᄃ)",
+    "(다)",
+    "(ᄅ)",
+    "(라)",
+    "(ᄆ)",
+    "(마)",
+    "(ᄇ)",
+    "(바)",
+    "(ᄉ)",
+    "(사)",
+    "(ᄋ)",
+    "(아)",
+    "(오전)",
+    "(오후)",
+    "(ᄌ)",
+    "(자)",
+    "(주)",
+    "(ᄎ)",
+    "(차)",
+    "(ᄏ)",
+    "(카)",
+    "(ᄐ)",
+    "(타)",
+    "(ᄑ)",
+    "(파)",
+    "(ᄒ)",
+    "(하)",
+    "(一)",
+    "(七)",
+    "(三)",
+    "(九)",
+    "(二)",
+    "(五)",
+    "(代)",
+    "(企)",
+    "(休)",
+    "(八)",
+    "(六)",
+    "(労)",
+    "(十)",
+    "(協)",
+    "(名)",
+    "(呼)",
+    "(四)",
+    "(土)",
+    "(学)",
+    "(日)",
+    "(月)",
+    "(有)",
+    "(木)",
+    "(株)",
+    "(水)",
+    "(火)",
+    "(特)",
+    "(監)",
+    "(社)",
+    "(祝)",
+    "(祭)",
+    "(自)",
+    "(至)",
+    "(財)",
+    "(資)",
+    "(金)",
+    ")",
+    "*",
+    "+",
+    ",",
+    "-",
+    ".",
+    "..",
+    "...",
+    "/",
+    "0",
+    "0,",
+    "0.",
+    "0/3",
+    "0⁄3",
+    "0点",
+    "1",
+    "1,",
+    "1.",
+    "1/",
+    "1/10",
+    "1/2",
+    "1/3",
+    "1/4",
+    "1/5",
+    "1/6",
+    "1/7",
+    "1/8",
+    "1/9",
+    "10",
+    "10.",
+    "10日",
+    "10月",
+    "10点",
+    "11",
+    "11.",
+    "11日",
+    "11月",
+    "11点",
+    "12",
+    "12.",
+    "12日",
+    "12月",
+    "12点",
+    "13",
+    "13.",
+    "13日",
+    "13点",
+    "14",
+    "14.",
+    "14日",
+    "14点",
+    "15",
+    "15.",
+    "15日",
+    "15点",
+    "16",
+    "16.",
+    "16日",
+    "16点",
+    "17",
+    "17.",
+    "17日",
+    "17点",
+    "18",
+    "18.",
+    "18日",
+    "18点",
+    "19",
+    "19.",
+    "19日",
+    "19点",
+    "1⁄",
+    "1⁄10",
+    "1⁄2",
+    "1⁄3",
+    "1⁄4",
+    "1⁄5",
+    "1⁄6",
+    "1⁄7",
+    "1⁄8",
+    "1⁄9",
+    "1日",
+    "1月",
+    "1点",
+    "2",
+    "2,",
+    "2.",
+    "2/3",
+    "2/5",
+    "20",
+    "20.",
+    "20日",
+    "20点",
+    "21",
+    "21日",
+    "21点",
+    "22",
+    "22日",
+    "22点",
+    "23",
+    "23日",
+    "23点",
+    "24",
+    "24日",
+    "24点",
+    "25",
+    "25日",
+    "26",
+    "26日",
+    "27",
+    "27日",
+    "28",
+    "28日",
+    "29",
+    "29日",
+    "2⁄3",
+    "2⁄5",
+    "2日",
+    "2月",
+    "2点",
+    "3",
+    "3,",
+    "3.",
+    "3/4",
+    "3/5",
+    "3/8",
+    "30",
+    "30日",
+    "31",
+    "31日",
+    "32",
+    "33",
+    "34",
+    "35",
+    "36",
+    "37",
+    "38",
+    "39",
+    "3⁄4",
+    "3⁄5",
+    "3⁄8",
+    "3日",
+    "3月",
+    "3点",
+    "4",
+    "4,",
+    "4.",
+    "4/5",
+    "40",
+    "41",
+    "42",
+    "43",
+    "44",
+    "45",
+    "46",
+    "47",
+    "48",
+    "49",
+    "4⁄5",
+    "4日",
+    "4月",
+    "4点",
+    "5",
+    "5,",
+    "5.",
+    "5/6",
+    "5/8",
+    "50",
+    "5⁄6",
+    "5⁄8",
+    "5日",
+    "5月",
+    "5点",
+    "6",
+    "6,",
+    "6.",
+    "6日",
+    "6月",
+    "6点",
+    "7",
+    "7,",
+    "7.",
+    "7/8",
+    "7⁄8",
+    "7日",
+    "7月",
+    "7点",
+    "8",
+    "8,",
+    "8.",
+    "8日",
+    "8月",
+    "8点",
+    "9",
+    "9,",
+    "9.",
+    "9日",
+    "9月",
+    "9点",
+    ":",
+    "::=",
+    ";",
+    "<",
+    "=",
+    "==",
+    "===",
+    ">",
+    "?",
+    "?!",
+    "??",
+    "@",
+    "A",
+    "AU",
+    "Aʾ",
+    "A∕m",
+    "B",
+    "Bq",
+    "C",
+    "CD",
+    "Co.",
+    "C∕kg",
+    "D",
+    "DJ",
+    "DZ",
+    "Dz",
+    "E",
+    "F",
+    "FAX",
+    "FF",
+    "FFI",
+    "FFL",
+    "FI",
+    "FL",
+    "G",
+    "GB",
+    "GHz",
+    "GPa",
+    "Gy",
+    "H",
+    "HP",
+    "HV",
+    "Hg",
+    "Hz",
+    "H\u{331}",
+    "I",
+    "II",
+    "III",
+    "IJ",
+    "IU",
+    "IV",
+    "IX",
+    "J",
+    "J\u{30c}",
+    "K",
+    "KB",
+    "KK",
+    "KM",
+    "L",
+    "LJ",
+    "LTD",
+    "Lj",
+    "L·",
+    "M",
+    "MB",
+    "MC",
+    "MD",
+    "MHz",
+    "MPa",
+    "MR",
+    "MV",
+    "MW",
+    "MΩ",
+    "N",
+    "NJ",
+    "Nj",
+    "No",
+    "O",
+    "P",
+    "PH",
+    "PPM",
+    "PPV",
+    "PR",
+    "PTE",
+    "Pa",
+    "Q",
+    "R",
+    "Rs",
+    "S",
+    "SD",
+    "SM",
+    "SS",
+    "ST",
+    "Sv",
+    "T",
+    "TEL",
+    "THz",
+    "TM",
+    "T\u{308}",
+    "U",
+    "V",
+    "VI",
+    "VII",
+    "VIII",
+    "V∕m",
+    "W",
+    "WC",
+    "WZ",
+    "Wb",
+    "W\u{30a}",
+    "X",
+    "XI",
+    "XII",
+    "Y",
+    "Y\u{30a}",
+    "Z",
+    "[",
+    "\\",
+    "]",
+    "^",
+    "_",
+    "`",
+    "a",
+    "a.m.",
+    "a/c",
+    "a/s",
+    "aʾ",
+    "b",
+    "bar",
+    "c",
+    "c/o",
+    "c/u",
+    "cal",
+    "cc",
+    "cd",
+    "cm",
+    "cm2",
+    "cm3",
+    "d",
+    "dB",
+    "da",
+    "dl",
+    "dm",
+    "dm2",
+    "dm3",
+    "dz",
+    "e",
+    "eV",
+    "erg",
+    "f",
+    "ff",
+    "ffi",
+    "ffl",
+    "fi",
+    "fl",
+    "fm",
+    "g",
+    "gal",
+    "h",
+    "hPa",
+    "ha",
+    "h\u{331}",
+    "i",
+    "ii",
+    "iii",
+    "ij",
+    "in",
+    "iv",
+    "ix",
+    "j",
+    "j\u{30c}",
+    "k",
+    "kA",
+    "kHz",
+    "kPa",
+    "kV",
+    "kW",
+    "kcal",
+    "kg",
+    "kl",
+    "km",
+    "km2",
+    "km3",
+    "kt",
+    "kΩ",
+    "l",
+    "lj",
+    "lm",
+    "ln",
+    "log",
+    "lx",
+    "l·",
+    "m",
+    "m2",
+    "m3",
+    "mA",
+    "mV",
+    "mW",
+    "mb",
+    "mg",
+    "mil",
+    "ml",
+    "mm",
+    "mm2",
+    "mm3",
+    "mol",
+    "ms",
+    "m∕s",
+    "m∕s2",
+    "n",
+    "nA",
+    "nF",
+    "nV",
+    "nW",
+    "nj",
+    "nm",
+    "ns",
+    "o",
+    "oV",
+    "p",
+    "p.m.",
+    "pA",
+    "pF",
+    "pV",
+    "pW",
+    "pc",
+    "ps",
+    "q",
+    "r",
+    "rad",
+    "rad∕s",
+    "rad∕s2",
+    "s",
+    "sr",
+    "ss",
+    "st",
+    "t",
+    "t\u{308}",
+    "u",
+    "v",
+    "vi",
+    "vii",
+    "viii",
+    "w",
+    "w\u{30a}",
+    "x",
+    "xi",
+    "xii",
+    "y",
+    "y\u{30a}",
+    "z",
+    "{",
+    "|",
+    "}",
+    "~",
+    "¢",
+    "£",
+    "¥",
+    "¦",
+    "¬",
+    "°C",
+    "°F",
+    "·",
+    "Æ",
+    "Ø",
+    "æ",
+    "ð",
+    "ø",
+    "Ħ",
+    "ħ",
+    "ı",
+    "ŋ",
+    "œ",
+    "Ǝ",
+    "Ɛ",
+    "ƫ",
+    "Ʒ",
+    "ǀ",
+    "ǁ",
+    "ǂ",
+    "Ȣ",
+    "ȷ",
+    "ɐ",
+    "ɑ",
+    "ɒ",
+    "ɓ",
+    "ɔ",
+    "ɕ",
+    "ɖ",
+    "ɗ",
+    "ɘ",
+    "ə",
+    "ɛ",
+    "ɜ",
+    "ɞ",
+    "ɟ",
+    "ɠ",
+    "ɡ",
+    "ɢ",
+    "ɣ",
+    "ɤ",
+    "ɥ",
+    "ɦ",
+    "ɧ",
+    "ɨ",
+    "ɩ",
+    "ɪ",
+    "ɫ",
+    "ɬ",
+    "ɭ",
+    "ɮ",
+    "ɯ",
+    "ɰ",
+    "ɱ",
+    "ɲ",
+    "ɳ",
+    "ɴ",
+    "ɵ",
+    "ɶ",
+    "ɷ",
+    "ɸ",
+    "ɹ",
+    "ɺ",
+    "ɻ",
+    "ɽ",
+    "ɾ",
+    "ʀ",
+    "ʁ",
+    "ʂ",
+    "ʃ",
+    "ʄ",
+    "ʈ",
+    "ʉ",
+    "ʊ",
+    "ʋ",
+    "ʌ",
+    "ʍ",
+    "ʎ",
+    "ʏ",
+    "ʐ",
+    "ʑ",
+    "ʒ",
+    "ʕ",
+    "ʘ",
+    "ʙ",
+    "ʛ",
+    "ʜ",
+    "ʝ",
+    "ʟ",
+    "ʡ",
+    "ʢ",
+    "ʣ",
+    "ʤ",
+    "ʥ",
+    "ʦ",
+    "ʧ",
+    "ʨ",
+    "ʩ",
+    "ʪ",
+    "ʫ",
+    "ʹ",
+    "ʼN",
+    "ʼn",
+    "ː",
+    "ˑ",
+    "ΆΙ",
+    "ΉΙ",
+    "ΏΙ",
+    "Α",
+    "Α\u{342}",
+    "Α\u{342}Ι",
+    "ΑΙ",
+    "Β",
+    "Γ",
+    "Δ",
+    "Ε",
+    "Ζ",
+    "Η",
+    "Η\u{342}",
+    "Η\u{342}Ι",
+    "ΗΙ",
+    "Θ",
+    "Ι",
+    "Ι\u{308}\u{300}",
+    "Ι\u{308}\u{301}",
+    "Ι\u{308}\u{342}",
+    "Ι\u{342}",
+    "Κ",
+    "Λ",
+    "Μ",
+    "Ν",
+    "Ξ",
+    "Ο",
+    "Π",
+    "Ρ",
+    "Ρ\u{313}",
+    "Σ",
+    "Τ",
+    "Υ",
+    "Υ\u{308}\u{300}",
+    "Υ\u{308}\u{301}",
+    "Υ\u{308}\u{342}",
+    "Υ\u{313}",
+    "Υ\u{313}\u{300}",
+    "Υ\u{313}\u{301}",
+    "Υ\u{313}\u{342}",
+    "Υ\u{342}",
+    "Φ",
+    "Χ",
+    "Ψ",
+    "Ω",
+    "Ω\u{342}",
+    "Ω\u{342}Ι",
+    "ΩΙ",
+    "άι",
+    "ήι",
+    "α",
+    "α\u{342}",
+    "α\u{342}ι",
+    "αι",
+    "β",
+    "γ",
+    "δ",
+    "ε",
+    "ζ",
+    "η",
+    "η\u{342}",
+    "η\u{342}ι",
+    "ηι",
+    "θ",
+    "ι",
+    "ι\u{308}\u{300}",
+    "ι\u{308}\u{301}",
+    "ι\u{308}\u{342}",
+    "ι\u{342}",
+    "κ",
+    "λ",
+    "μ",
+    "μA",
+    "μF",
+    "μV",
+    "μW",
+    "μg",
+    "μl",
+    "μm",
+    "μs",
+    "ν",
+    "ξ",
+    "ο",
+    "π",
+    "ρ",
+    "ρ\u{313}",
+    "ς",
+    "σ",
+    "τ",
+    "υ",
+    "υ\u{308}\u{300}",
+    "υ\u{308}\u{301}",
+    "υ\u{308}\u{342}",
+    "υ\u{313}",
+    "υ\u{313}\u{300}",
+    "υ\u{313}\u{301}",
+    "υ\u{313}\u{342}",
+    "υ\u{342}",
+    "φ",
+    "χ",
+    "ψ",
+    "ω",
+    "ω\u{342}",
+    "ω\u{342}ι",
+    "ωι",
+    "ώι",
+    "Ϝ",
+    "ϝ",
+    "І",
+    "А",
+    "Г",
+    "Е",
+    "Ж",
+    "З",
+    "И",
+    "К",
+    "О",
+    "У",
+    "Ч",
+    "Ы",
+    "Э",
+    "а",
+    "б",
+    "в",
+    "г",
+    "д",
+    "е",
+    "ж",
+    "з",
+    "и",
+    "к",
+    "л",
+    "м",
+    "н",
+    "о",
+    "п",
+    "р",
+    "с",
+    "т",
+    "у",
+    "ф",
+    "х",
+    "ц",
+    "ч",
+    "ш",
+    "ъ",
+    "ы",
+    "ь",
+    "э",
+    "ю",
+    "ѕ",
+    "і",
+    "ј",
+    "џ",
+    "Ѵ",
+    "ѵ",
+    "ґ",
+    "ҫ",
+    "ү",
+    "ұ",
+    "ӏ",
+    "Ә",
+    "ә",
+    "Ө",
+    "ө",
+    "ԵՒ",
+    "ՄԵ",
+    "ՄԻ",
+    "ՄԽ",
+    "ՄՆ",
+    "ՎՆ",
+    "եւ",
+    "մե",
+    "մի",
+    "մխ",
+    "մն",
+    "վն",
+    "א",
+    "אל",
+    "ב",
+    "ג",
+    "ד",
+    "ה",
+    "ו",
+    "ז",
+    "ט",
+    "י",
+    "ך",
+    "כ",
+    "ל",
+    "ם",
+    "מ",
+    "נ",
+    "ס",
+    "ע",
+    "ף",
+    "פ",
+    "צ",
+    "ק",
+    "ר",
+    "ש",
+    "ת",
+    "ײ",
+    "ء",
+    "ا",
+    "اكبر",
+    "الله",
+    "اٴ",
+    "ب",
+    "بج",
+    "بح",
+    "بحي",
+    "بخ",
+    "بخي",
+    "بر",
+    "بز",
+    "بم",
+    "بن",
+    "به",
+    "بى",
+    "بي",
+    "ة",
+    "ت",
+    "تج",
+    "تجم",
+    "تجى",
+    "تجي",
+    "تح",
+    "تحج",
+    "تحم",
+    "تخ",
+    "تخم",
+    "تخى",
+    "تخي",
+    "تر",
+    "تز",
+    "تم",
+    "تمج",
+    "تمح",
+    "تمخ",
+    "تمى",
+    "تمي",
+    "تن",
+    "ته",
+    "تى",
+    "تي",
+    "ث",
+    "ثج",
+    "ثر",
+    "ثز",
+    "ثم",
+    "ثن",
+    "ثه",
+    "ثى",
+    "ثي",
+    "ج",
+    "جح",
+    "جحى",
+    "جحي",
+    "جل جلاله",
+    "جم",
+    "جمح",
+    "جمى",
+    "جمي",
+    "جى",
+    "جي",
+    "ح",
+    "حج",
+    "حجي",
+    "حم",
+    "حمى",
+    "حمي",
+    "حى",
+    "حي",
+    "خ",
+    "خج",
+    "خح",
+    "خم",
+    "خى",
+    "خي",
+    "د",
+    "ذ",
+    "ر",
+    "رسول",
+    "ریال",
+    "ز",
+    "س",
+    "سج",
+    "سجح",
+    "سجى",
+    "سح",
+    "سحج",
+    "سخ",
+    "سخى",
+    "سخي",
+    "سر",
+    "سم",
+    "سمج",
+    "سمح",
+    "سمم",
+    "سه",
+    "سى",
+    "سي",
+    "ش",
+    "شج",
+    "شجي",
+    "شح",
+    "شحم",
+    "شحي",
+    "شخ",
+    "شر",
+    "شم",
+    "شمخ",
+    "شمم",
+    "شه",
+    "شى",
+    "شي",
+    "ص",
+    "صح",
+    "صحح",
+    "صحي",
+    "صخ",
+    "صر",
+    "صلعم",
+    "صلى",
+    "صلى الله عليه وسلم",
+    "صلے",
+    "صم",
+    "صمم",
+    "صى",
+    "صي",
+    "ض",
+    "ضج",
+    "ضح",
+    "ضحى",
+    "ضحي",
+    "ضخ",
+    "ضخم",
+    "ضر",
+    "ضم",
+    "ضى",
+    "ضي",
+    "ط",
+    "طح",
+    "طم",
+    "طمح",
+    "طمم",
+    "طمي",
+    "طى",
+    "طي",
+    "ظ",
+    "ظم",
+    "ع",
+    "عج",
+    "عجم",
+    "عليه",
+    "عم",
+    "عمم",
+    "عمى",
+    "عمي",
+    "عى",
+    "عي",
+    "غ",
+    "غج",
+    "غم",
+    "غمم",
+    "غمى",
+    "غمي",
+    "غى",
+    "غي",
+    "ـ",
+    "ف",
+    "فج",
+    "فح",
+    "فخ",
+    "فخم",
+    "فم",
+    "فمي",
+    "فى",
+    "في",
+    "ق",
+    "قح",
+    "قلے",
+    "قم",
+    "قمح",
+    "قمم",
+    "قمي",
+    "قى",
+    "قي",
+    "ك",
+    "كا",
+    "كج",
+    "كح",
+    "كخ",
+    "كل",
+    "كم",
+    "كمم",
+    "كمي",
+    "كى",
+    "كي",
+    "ل",
+    "لا",
+    "لج",
+    "لجج",
+    "لجم",
+    "لجي",
+    "لح",
+    "لحم",
+    "لحى",
+    "لحي",
+    "لخ",
+    "لخم",
+    "لم",
+    "لمح",
+    "لمي",
+    "له",
+    "لى",
+    "لي",
+    "م",
+    "ما",
+    "مج",
+    "مجح",
+    "مجخ",
+    "مجم",
+    "مجي",
+    "مح",
+    "محج",
+    "محم",
+    "محمد",
+    "محي",
+    "مخ",
+    "مخج",
+    "مخم",
+    "مخي",
+    "مم",
+    "ممي",
+    "مى",
+    "مي",
+    "ن",
+    "نج",
+    "نجح",
+    "نجم",
+    "نجى",
+    "نجي",
+    "نح",
+    "نحم",
+    "نحى",
+    "نحي",
+    "نخ",
+    "نر",
+    "نز",
+    "نم",
+    "نمى",
+    "نمي",
+    "نن",
+    "نه",
+    "نى",
+    "ني",
+    "ه",
+    "هج",
+    "هم",
+    "همج",
+    "همم",
+    "هى",
+    "هي",
+    "و",
+    "وسلم",
+    "وٴ",
+    "ى",
+    "ي",
+    "يا",
+    "يج",
+    "يجي",
+    "يح",
+    "يحي",
+    "يخ",
+    "ير",
+    "يز",
+    "يم",
+    "يمم",
+    "يمي",
+    "ين",
+    "يه",
+    "يو",
+    "يى",
+    "يي",
+    "يٴ",
+    "يۆ",
+    "يۇ",
+    "يۈ",
+    "يې",
+    "يە",
+    "ٮ",
+    "ٯ",
+    "ٱ",
+    "ٹ",
+    "ٺ",
+    "ٻ",
+    "پ",
+    "ٿ",
+    "ڀ",
+    "ڃ",
+    "ڄ",
+    "چ",
+    "ڇ",
+    "ڈ",
+    "ڌ",
+    "ڍ",
+    "ڎ",
+    "ڑ",
+    "ژ",
+    "ڡ",
+    "ڤ",
+    "ڦ",
+    "ک",
+    "ڭ",
+    "گ",
+    "ڱ",
+    "ڳ",
+    "ں",
+    "ڻ",
+    "ھ",
+    "ہ",
+    "ۅ",
+    "ۆ",
+    "ۇ",
+    "ۇٴ",
+    "ۈ",
+    "ۉ",
+    "ۋ",
+    "ی",
+    "ې",
+    "ے",
+    "ە",
+    "क",
+    "ख",
+    "ग",
+    "ज",
+    "ड",
+    "ढ",
+    "न",
+    "फ",
+    "य",
+    "र",
+    "ळ",
+    "ড",
+    "ঢ",
+    "য",
+    "ਖ",
+    "ਗ",
+    "ਜ",
+    "ਫ",
+    "ਲ",
+    "ਸ",
+    "ଡ",
+    "ଢ",
+    "େ",
+    "\u{cc2}",
+    "\u{cc2}\u{cd5}",
+    "\u{cd5}",
+    "\u{cd6}",
+    "ෙ",
+    "ෙ\u{dcf}",
+    "า",
+    "ຫນ",
+    "ຫມ",
+    "າ",
+    "་",
+    "ཀ",
+    "ག",
+    "ཌ",
+    "ད",
+    "བ",
+    "ཛ",
+    "ဥ",
+    "ნ",
+    "ᄀ",
+    "가",
+    "ᄁ",
+    "ᄂ",
+    "나",
+    "ᄃ",
+    "다",
+    "ᄄ",
+    "ᄅ",
+    "라",
+    "ᄆ",
+    "마",
+    "ᄇ",
+    "바",
+    "ᄈ",
+    "ᄉ",
+    "사",
+    "ᄊ",
+    "ᄋ",
+    "아",
+    "우",
+    "ᄌ",
+    "자",
+    "주의",
+    "ᄍ",
+    "ᄎ",
+    "차",
+    "참고",
+    "ᄏ",
+    "카",
+    "ᄐ",
+    "타",
+    "ᄑ",
+    "파",
+    "ᄒ",
+    "하",
+    "ᄔ",
+    "ᄕ",
+    "ᄚ",
+    "ᄜ",
+    "ᄝ",
+    "ᄞ",
+    "ᄠ",
+    "ᄡ",
+    "ᄢ",
+    "ᄣ",
+    "ᄧ",
+    "ᄩ",
+    "ᄫ",
+    "ᄬ",
+    "ᄭ",
+    "ᄮ",
+    "ᄯ",
+    "ᄲ",
+    "ᄶ",
+    "ᅀ",
+    "ᅇ",
+    "ᅌ",
+    "ᅗ",
+    "ᅘ",
+    "ᅙ",
+    "ᅠ",
+    "ᅡ",
+    "ᅢ",
+    "ᅣ",
+    "ᅤ",
+    "ᅥ",
+    "ᅦ",
+    "ᅧ",
+    "ᅨ",
+    "ᅩ",
+    "ᅪ",
+    "ᅫ",
+    "ᅬ",
+    "ᅭ",
+    "ᅮ",
+    "ᅯ",
+    "ᅰ",
+    "ᅱ",
+    "ᅲ",
+    "ᅳ",
+    "ᅴ",
+    "ᅵ",
+    "ᆄ",
+    "ᆅ",
+    "ᆈ",
+    "ᆑ",
+    "ᆒ",
+    "ᆔ",
+    "ᆞ",
+    "ᆡ",
+    "ᆪ",
+    "ᆬ",
+    "ᆭ",
+    "ᆰ",
+    "ᆱ",
+    "ᆲ",
+    "ᆳ",
+    "ᆴ",
+    "ᆵ",
+    "ᇇ",
+    "ᇈ",
+    "ᇌ",
+    "ᇎ",
+    "ᇓ",
+    "ᇗ",
+    "ᇙ",
+    "ᇝ",
+    "ᇟ",
+    "ᇱ",
+    "ᇲ",
+    "\u{1b35}",
+    "ᴂ",
+    "ᴖ",
+    "ᴗ",
+    "ᴜ",
+    "ᴝ",
+    "ᴥ",
+    "ᵻ",
+    "ᶅ",
+    "ᶑ",
+    "ἀι",
+    "ἁι",
+    "ἂι",
+    "ἃι",
+    "ἄι",
+    "ἅι",
+    "ἆι",
+    "ἇι",
+    "ἈΙ",
+    "ἉΙ",
+    "ἊΙ",
+    "ἋΙ",
+    "ἌΙ",
+    "ἍΙ",
+    "ἎΙ",
+    "ἏΙ",
+    "ἠι",
+    "ἡι",
+    "ἢι",
+    "ἣι",
+    "ἤι",
+    "ἥι",
+    "ἦι",
+    "ἧι",
+    "ἨΙ",
+    "ἩΙ",
+    "ἪΙ",
+    "ἫΙ",
+    "ἬΙ",
+    "ἭΙ",
+    "ἮΙ",
+    "ἯΙ",
+    "ὠι",
+    "ὡι",
+    "ὢι",
+    "ὣι",
+    "ὤι",
+    "ὥι",
+    "ὦι",
+    "ὧι",
+    "ὨΙ",
+    "ὩΙ",
+    "ὪΙ",
+    "ὫΙ",
+    "ὬΙ",
+    "ὭΙ",
+    "ὮΙ",
+    "ὯΙ",
+    "ὰι",
+    "ὴι",
+    "ὼι",
+    "ᾺΙ",
+    "ῊΙ",
+    "ῺΙ",
+    "‐",
+    "–",
+    "—",
+    "′′",
+    "′′′",
+    "′′′′",
+    "‵‵",
+    "‵‵‵",
+    "₩",
+    "←",
+    "↑",
+    "→",
+    "↓",
+    "↔",
+    "⇐",
+    "⇒",
+    "⇔",
+    "∂",
+    "∃",
+    "∇",
+    "∈",
+    "∋",
+    "∑",
+    "−",
+    "∣",
+    "∥",
+    "∫∫",
+    "∫∫∫",
+    "∫∫∫∫",
+    "∮∮",
+    "∮∮∮",
+    "∼",
+    "≃",
+    "≅",
+    "≈",
+    "≍",
+    "≡",
+    "≤",
+    "≥",
+    "≲",
+    "≳",
+    "≶",
+    "≷",
+    "≺",
+    "≻",
+    "≼",
+    "≽",
+    "⊂",
+    "⊃",
+    "⊆",
+    "⊇",
+    "⊑",
+    "⊒",
+    "⊢",
+    "⊨",
+    "⊩",
+    "⊫",
+    "⊲",
+    "⊳",
+    "⊴",
+    "⊵",
+    "│",
+    "■",
+    "○",
+    "⦅",
+    "⦆",
+    "⫝",
+    "ⱱ",
+    "ⵡ",
+    "、",
+    "。",
+    "〈",
+    "〉",
+    "《",
+    "》",
+    "「",
+    "」",
+    "『",
+    "』",
+    "【",
+    "】",
+    "〒",
+    "〔",
+    "〔S〕",
+    "〔三〕",
+    "〔二〕",
+    "〔勝〕",
+    "〔安〕",
+    "〔打〕",
+    "〔敗〕",
+    "〔本〕",
+    "〔点〕",
+    "〔盗〕",
+    "〕",
+    "〖",
+    "〗",
+    "ほか",
+    "より",
+    "\u{3099}",
+    "\u{309a}",
+    "ァ",
+    "ア",
+    "アハ\u{309a}ート",
+    "アルファ",
+    "アンヘ\u{309a}ア",
+    "アール",
+    "ィ",
+    "イ",
+    "イニンク\u{3099}",
+    "インチ",
+    "ゥ",
+    "ウ",
+    "ウォン",
+    "ェ",
+    "エ",
+    "エスクート\u{3099}",
+    "エーカー",
+    "ォ",
+    "オ",
+    "オンス",
+    "オーム",
+    "カ",
+    "カ\u{3099}ロン",
+    "カ\u{3099}ンマ",
+    "カイリ",
+    "カラット",
+    "カロリー",
+    "キ",
+    "キ\u{3099}カ\u{3099}",
+    "キ\u{3099}ニー",
+    "キ\u{3099}ルタ\u{3099}ー",
+    "キュリー",
+    "キロ",
+    "キロク\u{3099}ラム",
+    "キロメートル",
+    "キロワット",
+    "ク",
+    "ク\u{3099}ラム",
+    "ク\u{3099}ラムトン",
+    "クルセ\u{3099}イロ",
+    "クローネ",
+    "ケ",
+    "ケース",
+    "コ",
+    "ココ",
+    "コト",
+    "コルナ",
+    "コーホ\u{309a}",
+    "サ",
+    "サイクル",
+    "サンチーム",
+    "シ",
+    "シリンク\u{3099}",
+    "ス",
+    "セ",
+    "センチ",
+    "セント",
+    "ソ",
+    "タ",
+    "タ\u{3099}ース",
+    "チ",
+    "ッ",
+    "ツ",
+    "テ",
+    "テ\u{3099}",
+    "テ\u{3099}シ",
+    "ト",
+    "ト\u{3099}ル",
+    "トン",
+    "ナ",
+    "ナノ",
+    "ニ",
+    "ヌ",
+    "ネ",
+    "ノ",
+    "ノット",
+    "ハ",
+    "ハ\u{3099}ーレル",
+    "ハ\u{309a}ーセント",
+    "ハ\u{309a}ーツ",
+    "ハイツ",
+    "ヒ",
+    "ヒ\u{3099}ル",
+    "ヒ\u{309a}アストル",
+    "ヒ\u{309a}クル",
+    "ヒ\u{309a}コ",
+    "フ",
+    "フ\u{3099}ッシェル",
+    "ファラット\u{3099}",
+    "フィート",
+    "フラン",
+    "ヘ",
+    "ヘ\u{3099}ータ",
+    "ヘ\u{309a}ソ",
+    "ヘ\u{309a}ニヒ",
+    "ヘ\u{309a}ンス",
+    "ヘ\u{309a}ーシ\u{3099}",
+    "ヘクタール",
+    "ヘルツ",
+    "ホ",
+    "ホ\u{3099}ルト",
+    "ホ\u{309a}イント",
+    "ホ\u{309a}ント\u{3099}",
+    "ホン",
+    "ホール",
+    "ホーン",
+    "マ",
+    "マイクロ",
+    "マイル",
+    "マッハ",
+    "マルク",
+    "マンション",
+    "ミ",
+    "ミクロン",
+    "ミリ",
+    "ミリハ\u{3099}ール",
+    "ム",
+    "メ",
+    "メカ\u{3099}",
+    "メカ\u{3099}トン",
+    "メートル",
+    "モ",
+    "ャ",
+    "ヤ",
+    "ヤート\u{3099}",
+    "ヤール",
+    "ュ",
+    "ユ",
+    "ユアン",
+    "ョ",
+    "ヨ",
+    "ラ",
+    "リ",
+    "リットル",
+    "リラ",
+    "ル",
+    "ルヒ\u{309a}ー",
+    "ルーフ\u{3099}ル",
+    "レ",
+    "レム",
+    "レントケ\u{3099}ン",
+    "ロ",
+    "ワ",
+    "ワット",
+    "ヰ",
+    "ヱ",
+    "ヲ",
+    "ン",
+    "・",
+    "ー",
+    "㒞",
+    "㒹",
+    "㒻",
+    "㓟",
+    "㔕",
+    "㛮",
+    "㛼",
+    "㞁",
+    "㠯",
+    "㡢",
+    "㡼",
+    "㣇",
+    "㣣",
+    "㤜",
+    "㤺",
+    "㨮",
+    "㩬",
+    "㫤",
+    "㬈",
+    "㬙",
+    "㭉",
+    "㮝",
+    "㰘",
+    "㱎",
+    "㴳",
+    "㶖",
+    "㺬",
+    "㺸",
+    "㼛",
+    "㿼",
+    "䀈",
+    "䀘",
+    "䀹",
+    "䁆",
+    "䂖",
+    "䃣",
+    "䄯",
+    "䈂",
+    "䈧",
+    "䊠",
+    "䌁",
+    "䌴",
+    "䍙",
+    "䏕",
+    "䏙",
+    "䐋",
+    "䑫",
+    "䔫",
+    "䕝",
+    "䕡",
+    "䕫",
+    "䗗",
+    "䗹",
+    "䘵",
+    "䚾",
+    "䛇",
+    "䦕",
+    "䧦",
+    "䩮",
+    "䩶",
+    "䪲",
+    "䬳",
+    "䯎",
+    "䳎",
+    "䳭",
+    "䳸",
+    "䵖",
+    "一",
+    "丁",
+    "七",
+    "三",
+    "上",
+    "下",
+    "不",
+    "丙",
+    "並",
+    "丨",
+    "中",
+    "串",
+    "丶",
+    "丸",
+    "丹",
+    "丽",
+    "丿",
+    "乁",
+    "乙",
+    "九",
+    "亂",
+    "亅",
+    "了",
+    "二",
+    "五",
+    "亠",
+    "交",
+    "亮",
+    "人",
+    "什",
+    "仌",
+    "令",
+    "令和",
+    "企",
+    "休",
+    "你",
+    "侀",
+    "來",
+    "例",
+    "侮",
+    "侻",
+    "便",
+    "倂",
+    "倫",
+    "偺",
+    "備",
+    "像",
+    "僚",
+    "僧",
+    "優",
+    "儿",
+    "兀",
+    "充",
+    "免",
+    "兔",
+    "兤",
+    "入",
+    "內",
+    "全",
+    "兩",
+    "八",
+    "六",
+    "具",
+    "冀",
+    "冂",
+    "再",
+    "冒",
+    "冕",
+    "冖",
+    "冗",
+    "写",
+    "冤",
+    "冫",
+    "冬",
+    "况",
+    "冷",
+    "凉",
+    "凌",
+    "凜",
+    "凞",
+    "几",
+    "凵",
+    "刀",
+    "刃",
+    "切",
+    "列",
+    "初",
+    "利",
+    "刺",
+    "刻",
+    "剆",
+    "前",
+    "割",
+    "剷",
+    "劉",
+    "力",
+    "劣",
+    "劳",
+    "労",
+    "勇",
+    "勉",
+    "勒",
+    "勞",
+    "勤",
+    "勵",
+    "勹",
+    "勺",
+    "包",
+    "匆",
+    "匕",
+    "北",
+    "匚",
+    "匸",
+    "医",
+    "匿",
+    "十",
+    "卄",
+    "卅",
+    "卉",
+    "卑",
+    "協",
+    "博",
+    "卜",
+    "卩",
+    "印",
+    "即",
+    "卵",
+    "卽",
+    "卿",
+    "厂",
+    "厶",
+    "參",
+    "又",
+    "及",
+    "双",
+    "叟",
+    "口",
+    "句",
+    "叫",
+    "可",
+    "叱",
+    "右",
+    "吆",
+    "合",
+    "名",
+    "吏",
+    "吝",
+    "吸",
+    "吹",
+    "呂",
+    "呈",
+    "周",
+    "咞",
+    "咢",
+    "咽",
+    "哶",
+    "唐",
+    "問",
+    "啓",
+    "啕",
+    "啣",
+    "善",
+    "喇",
+    "喙",
+    "喝",
+    "喫",
+    "喳",
+    "営",
+    "嗀",
+    "嗂",
+    "嗢",
+    "嘆",
+    "噑",
+    "器",
+    "噴",
+    "囗",
+    "四",
+    "囹",
+    "圖",
+    "圗",
+    "土",
+    "地",
+    "型",
+    "城",
+    "埴",
+    "堍",
+    "報",
+    "堲",
+    "塀",
+    "塚",
+    "塞",
+    "墨",
+    "墬",
+    "墳",
+    "壘",
+    "壟",
+    "士",
+    "壮",
+    "声",
+    "売",
+    "壷",
+    "夂",
+    "夆",
+    "夊",
+    "夕",
+    "多",
+    "夜",
+    "夢",
+    "大",
+    "大正",
+    "天",
+    "奄",
+    "奈",
+    "契",
+    "奔",
+    "奢",
+    "女",
+    "姘",
+    "姬",
+    "娛",
+    "娧",
+    "婢",
+    "婦",
+    "媵",
+    "嬈",
+    "嬨",
+    "嬾",
+    "子",
+    "字",
+    "学",
+    "宀",
+    "宅",
+    "宗",
+    "寃",
+    "寘",
+    "寧",
+    "寮",
+    "寳",
+    "寸",
+    "寿",
+    "将",
+    "小",
+    "尢",
+    "尸",
+    "尿",
+    "屠",
+    "屢",
+    "層",
+    "履",
+    "屮",
+    "山",
+    "岍",
+    "峀",
+    "崙",
+    "嵃",
+    "嵐",
+    "嵫",
+    "嵮",
+    "嵼",
+    "嶲",
+    "嶺",
+    "巛",
+    "巡",
+    "巢",
+    "工",
+    "左",
+    "己",
+    "巽",
+    "巾",
+    "帨",
+    "帽",
+    "幩",
+    "干",
+    "平成",
+    "年",
+    "幺",
+    "幼",
+    "广",
+    "度",
+    "庰",
+    "庳",
+    "庶",
+    "廉",
+    "廊",
+    "廒",
+    "廓",
+    "廙",
+    "廬",
+    "廴",
+    "廾",
+    "弄",
+    "弋",
+    "弓",
+    "弢",
+    "彐",
+    "当",
+    "彡",
+    "形",
+    "彩",
+    "彫",
+    "彳",
+    "律",
+    "後",
+    "得",
+    "徚",
+    "復",
+    "徭",
+    "心",
+    "忍",
+    "志",
+    "念",
+    "忹",
+    "怒",
+    "怜",
+    "恵",
+    "悁",
+    "悔",
+    "惇",
+    "惘",
+    "惡",
+    "愈",
+    "慄",
+    "慈",
+    "慌",
+    "慎",
+    "慠",
+    "慨",
+    "慺",
+    "憎",
+    "憐",
+    "憤",
+    "憯",
+    "憲",
+    "懞",
+    "懲",
+    "懶",
+    "戀",
+    "戈",
+    "成",
+    "戛",
+    "戮",
+    "戴",
+    "戶",
+    "手",
+    "打",
+    "扝",
+    "投",
+    "抱",
+    "拉",
+    "拏",
+    "拓",
+    "拔",
+    "拼",
+    "拾",
+    "指",
+    "挽",
+    "捐",
+    "捕",
+    "捨",
+    "捻",
+    "掃",
+    "掠",
+    "掩",
+    "揄",
+    "揅",
+    "揤",
+    "搜",
+    "搢",
+    "摒",
+    "摩",
+    "摷",
+    "摾",
+    "撚",
+    "撝",
+    "擄",
+    "支",
+    "攴",
+    "敏",
+    "敖",
+    "敬",
+    "數",
+    "文",
+    "斗",
+    "料",
+    "斤",
+    "新",
+    "方",
+    "旅",
+    "无",
+    "既",
+    "旣",
+    "日",
+    "明治",
+    "易",
+    "映",
+    "昭和",
+    "晉",
+    "晴",
+    "暈",
+    "暑",
+    "暜",
+    "暴",
+    "曆",
+    "曰",
+    "更",
+    "書",
+    "最",
+    "月",
+    "有",
+    "朗",
+    "望",
+    "朡",
+    "木",
+    "李",
+    "杓",
+    "杖",
+    "杞",
+    "杻",
+    "枅",
+    "林",
+    "柳",
+    "柺",
+    "栗",
+    "栟",
+    "株",
+    "株式会社",
+    "桒",
+    "梁",
+    "梅",
+    "梎",
+    "梨",
+    "椔",
+    "楂",
+    "榣",
+    "槪",
+    "樂",
+    "樓",
+    "檨",
+    "櫓",
+    "櫛",
+    "欄",
+    "欠",
+    "次",
+    "歔",
+    "止",
+    "正",
+    "歲",
+    "歷",
+    "歹",
+    "殟",
+    "殮",
+    "殳",
+    "殺",
+    "殻",
+    "毋",
+    "母",
+    "比",
+    "毛",
+    "氏",
+    "气",
+    "水",
+    "汎",
+    "汧",
+    "沈",
+    "沿",
+    "泌",
+    "泍",
+    "泥",
+    "注",
+    "洖",
+    "洛",
+    "洞",
+    "洴",
+    "派",
+    "流",
+    "浩",
+    "浪",
+    "海",
+    "浸",
+    "涅",
+    "淋",
+    "淚",
+    "淪",
+    "淹",
+    "渚",
+    "港",
+    "湮",
+    "満",
+    "溜",
+    "溺",
+    "滇",
+    "滋",
+    "滑",
+    "滛",
+    "漏",
+    "演",
+    "漢",
+    "漣",
+    "潮",
+    "濆",
+    "濫",
+    "濾",
+    "瀛",
+    "瀞",
+    "瀹",
+    "灊",
+    "火",
+    "灰",
+    "灷",
+    "災",
+    "炙",
+    "炭",
+    "烈",
+    "烙",
+    "無",
+    "煅",
+    "煉",
+    "煮",
+    "熜",
+    "燎",
+    "燐",
+    "爐",
+    "爛",
+    "爨",
+    "爪",
+    "爫",
+    "爵",
+    "父",
+    "爻",
+    "爿",
+    "片",
+    "牐",
+    "牙",
+    "牛",
+    "牢",
+    "特",
+    "犀",
+    "犕",
+    "犬",
+    "犯",
+    "狀",
+    "狼",
+    "猪",
+    "獵",
+    "獺",
+    "玄",
+    "率",
+    "玉",
+    "王",
+    "玥",
+    "玲",
+    "珞",
+    "理",
+    "琉",
+    "琢",
+    "瑇",
+    "瑜",
+    "瑩",
+    "瑱",
+    "璅",
+    "璉",
+    "璘",
+    "瓊",
+    "瓜",
+    "瓦",
+    "甆",
+    "甘",
+    "生",
+    "甤",
+    "用",
+    "田",
+    "甲",
+    "申",
+    "男",
+    "画",
+    "甾",
+    "留",
+    "略",
+    "異",
+    "疋",
+    "疒",
+    "痢",
+    "瘐",
+    "瘝",
+    "瘟",
+    "療",
+    "癩",
+    "癶",
+    "白",
+    "皮",
+    "皿",
+    "益",
+    "盛",
+    "監",
+    "盧",
+    "目",
+    "直",
+    "省",
+    "眞",
+    "真",
+    "着",
+    "睊",
+    "瞋",
+    "瞧",
+    "矛",
+    "矢",
+    "石",
+    "硎",
+    "硫",
+    "碌",
+    "碑",
+    "磊",
+    "磌",
+    "磻",
+    "礪",
+    "示",
+    "礼",
+    "社",
+    "祈",
+    "祉",
+    "祐",
+    "祖",
+    "祝",
+    "神",
+    "祥",
+    "祿",
+    "禁",
+    "禍",
+    "禎",
+    "福",
+    "禮",
+    "禸",
+    "禾",
+    "秊",
+    "秘",
+    "秫",
+    "稜",
+    "穀",
+    "穊",
+    "穏",
+    "穴",
+    "空",
+    "突",
+    "窱",
+    "立",
+    "竮",
+    "竹",
+    "笠",
+    "箏",
+    "節",
+    "篆",
+    "築",
+    "簾",
+    "籠",
+    "米",
+    "类",
+    "粒",
+    "精",
+    "糒",
+    "糖",
+    "糣",
+    "糧",
+    "糨",
+    "糸",
+    "紀",
+    "紐",
+    "索",
+    "累",
+    "終",
+    "絛",
+    "絣",
+    "綠",
+    "綾",
+    "緇",
+    "練",
+    "縂",
+    "縉",
+    "縷",
+    "繁",
+    "繅",
+    "缶",
+    "缾",
+    "网",
+    "署",
+    "罹",
+    "罺",
+    "羅",
+    "羊",
+    "羕",
+    "羚",
+    "羽",
+    "翺",
+    "老",
+    "者",
+    "而",
+    "耒",
+    "耳",
+    "聆",
+    "聠",
+    "聯",
+    "聰",
+    "聾",
+    "聿",
+    "肉",
+    "肋",
+    "肭",
+    "育",
+    "脃",
+    "脾",
+    "臘",
+    "臣",
+    "臨",
+    "自",
+    "臭",
+    "至",
+    "臼",
+    "舁",
+    "舄",
+    "舌",
+    "舘",
+    "舛",
+    "舟",
+    "艮",
+    "良",
+    "色",
+    "艸",
+    "艹",
+    "芋",
+    "芑",
+    "芝",
+    "花",
+    "芳",
+    "芽",
+    "若",
+    "苦",
+    "茝",
+    "茣",
+    "茶",
+    "荒",
+    "荓",
+    "荣",
+    "莭",
+    "莽",
+    "菉",
+    "菊",
+    "菌",
+    "菜",
+    "菧",
+    "華",
+    "菱",
+    "落",
+    "葉",
+    "著",
+    "蓮",
+    "蓱",
+    "蓳",
+    "蓼",
+    "蔖",
+    "蕤",
+    "藍",
+    "藺",
+    "蘆",
+    "蘒",
+    "蘭",
+    "蘿",
+    "虍",
+    "虐",
+    "虜",
+    "虧",
+    "虩",
+    "虫",
+    "蚈",
+    "蚩",
+    "蛢",
+    "蜎",
+    "蜨",
+    "蝫",
+    "蝹",
+    "螆",
+    "螺",
+    "蟡",
+    "蠁",
+    "蠟",
+    "血",
+    "行",
+    "衠",
+    "衣",
+    "裂",
+    "裏",
+    "裗",
+    "裞",
+    "裡",
+    "裸",
+    "裺",
+    "褐",
+    "襁",
+    "襤",
+    "襾",
+    "覆",
+    "見",
+    "視",
+    "角",
+    "解",
+    "言",
+    "誠",
+    "說",
+    "調",
+    "請",
+    "諒",
+    "論",
+    "諭",
+    "諸",
+    "諾",
+    "謁",
+    "謹",
+    "識",
+    "讀",
+    "變",
+    "谷",
+    "豆",
+    "豈",
+    "豕",
+    "豸",
+    "貝",
+    "財",
+    "販",
+    "貫",
+    "賁",
+    "賂",
+    "資",
+    "賈",
+    "賓",
+    "贈",
+    "贛",
+    "赤",
+    "走",
+    "起",
+    "足",
+    "趼",
+    "跋",
+    "路",
+    "跰",
+    "身",
+    "車",
+    "軔",
+    "輦",
+    "輪",
+    "輸",
+    "輻",
+    "轢",
+    "辛",
+    "辞",
+    "辰",
+    "辵",
+    "辶",
+    "連",
+    "逸",
+    "遊",
+    "適",
+    "遲",
+    "遼",
+    "邏",
+    "邑",
+    "邔",
+    "郎",
+    "郞",
+    "郱",
+    "都",
+    "鄑",
+    "鄛",
+    "酉",
+    "配",
+    "酪",
+    "醙",
+    "醴",
+    "釆",
+    "里",
+    "量",
+    "金",
+    "鈴",
+    "鈸",
+    "鉶",
+    "鉼",
+    "鋗",
+    "鋘",
+    "錄",
+    "鍊",
+    "鏹",
+    "鐕",
+    "長",
+    "門",
+    "開",
+    "閭",
+    "閷",
+    "阜",
+    "阮",
+    "陋",
+    "降",
+    "陵",
+    "陸",
+    "陼",
+    "隆",
+    "隣",
+    "隶",
+    "隷",
+    "隸",
+    "隹",
+    "雃",
+    "離",
+    "難",
+    "雨",
+    "零",
+    "雷",
+    "霣",
+    "露",
+    "靈",
+    "靑",
+    "靖",
+    "非",
+    "面",
+    "革",
+    "韋",
+    "韛",
+    "韠",
+    "韭",
+    "音",
+    "響",
+    "頁",
+    "項",
+    "頋",
+    "領",
+    "頩",
+    "頻",
+    "類",
+    "風",
+    "飛",
+    "食",
+    "飢",
+    "飯",
+    "飼",
+    "館",
+    "餩",
+    "首",
+    "香",
+    "馧",
+    "馬",
+    "駂",
+    "駱",
+    "駾",
+    "驪",
+    "骨",
+    "高",
+    "髟",
+    "鬒",
+    "鬥",
+    "鬯",
+    "鬲",
+    "鬼",
+    "魚",
+    "魯",
+    "鱀",
+    "鱗",
+    "鳥",
+    "鳽",
+    "鵧",
+    "鶴",
+    "鷺",
+    "鸞",
+    "鹵",
+    "鹿",
+    "麗",
+    "麟",
+    "麥",
+    "麻",
+    "黃",
+    "黍",
+    "黎",
+    "黑",
+    "黹",
+    "黽",
+    "黾",
+    "鼅",
+    "鼎",
+    "鼏",
+    "鼓",
+    "鼖",
+    "鼠",
+    "鼻",
+    "齃",
+    "齊",
+    "齒",
+    "龍",
+    "龎",
+    "龜",
+    "龟",
+    "龠",
+    "ꙑ",
+    "ꚉ",
+    "ꜧ",
+    "ꝯ",
+    "ꞎ",
+    "ꬷ",
+    "ꭒ",
+    "ꭦ",
+    "ꭧ",
+    "𑂙",
+    "𑂛",
+    "𑂥",
+    "𑒹",
+    "𝼄",
+    "𝼅",
+    "𝼆",
+    "𝼈",
+    "𝼊",
+    "𝼞",
+    "𠄢",
+    "𠔜",
+    "𠔥",
+    "𠕋",
+    "𠘺",
+    "𠠄",
+    "𠣞",
+    "𠨬",
+    "𠭣",
+    "𡓤",
+    "𡚨",
+    "𡛪",
+    "𡧈",
+    "𡬘",
+    "𡴋",
+    "𡷤",
+    "𡷦",
+    "𢆃",
+    "𢆟",
+    "𢌱",
+    "𢛔",
+    "𢡄",
+    "𢡊",
+    "𢬌",
+    "𢯱",
+    "𣀊",
+    "𣊸",
+    "𣍟",
+    "𣎓",
+    "𣎜",
+    "𣏃",
+    "𣏕",
+    "𣑭",
+    "𣚣",
+    "𣢧",
+    "𣪍",
+    "𣫺",
+    "𣲼",
+    "𣴞",
+    "𣻑",
+    "𣽞",
+    "𣾎",
+    "𤉣",
+    "𤋮",
+    "𤎫",
+    "𤘈",
+    "𤜵",
+    "𤠔",
+    "𤰶",
+    "𤲒",
+    "𤾡",
+    "𤾸",
+    "𥁄",
+    "𥃲",
+    "𥃳",
+    "𥄙",
+    "𥄳",
+    "𥉉",
+    "𥐝",
+    "𥘦",
+    "𥚚",
+    "𥛅",
+    "𥥼",
+    "𥪧",
+    "𥮫",
+    "𥲀",
+    "𥳐",
+    "𥾆",
+    "𦇚",
+    "𦈨",
+    "𦉇",
+    "𦋙",
+    "𦌾",
+    "𦓚",
+    "𦔣",
+    "𦖨",
+    "𦞧",
+    "𦞵",
+    "𦬼",
+    "𦰶",
+    "𦳕",
+    "𦵫",
+    "𦼬",
+    "𦾱",
+    "𧃒",
+    "𧏊",
+    "𧙧",
+    "𧢮",
+    "𧥦",
+    "𧲨",
+    "𧻓",
+    "𧼯",
+    "𨗒",
+    "𨗭",
+    "𨜮",
+    "𨯺",
+    "𨵷",
+    "𩅅",
+    "𩇟",
+    "𩈚",
+    "𩐊",
+    "𩒖",
+    "𩖶",
+    "𩬰",
+    "𪃎",
+    "𪄅",
+    "𪈎",
+    "𪊑",
+    "𪎒",
+    "𪘀",
+];
+
+/// `match`-based lookup of [`DIACRITICS_MAPPING`] restricted to the
+/// entries in Latin-1 Supplement and Latin Extended-A
+/// (`U+0080..=U+017F`) that fold to a single char. Every other char,
+/// including marks that are dropped and letters that expand to
+/// several chars, returns `None`.
+pub const fn strip_latin1(ch: char) -> Option<char> {
+    match ch {
+        '¨' => Some(' '),
+        '¯' => Some(' '),
+        '´' => Some(' '),
+        '¸' => Some(' '),
+        'À' => Some('A'),
+        'Á' => Some('A'),
+        'Â' => Some('A'),
+        'Ã' => Some('A'),
+        'Ä' => Some('A'),
+        'Å' => Some('A'),
+        'Ç' => Some('C'),
+        'È' => Some('E'),
+        'É' => Some('E'),
+        'Ê' => Some('E'),
+        'Ë' => Some('E'),
+        'Ì' => Some('I'),
+        'Í' => Some('I'),
+        'Î' => Some('I'),
+        'Ï' => Some('I'),
+        'Ñ' => Some('N'),
+        'Ò' => Some('O'),
+        'Ó' => Some('O'),
+        'Ô' => Some('O'),
+        'Õ' => Some('O'),
+        'Ö' => Some('O'),
+        'Ù' => Some('U'),
+        'Ú' => Some('U'),
+        'Û' => Some('U'),
+        'Ü' => Some('U'),
+        'Ý' => Some('Y'),
+        'à' => Some('a'),
+        'á' => Some('a'),
+        'â' => Some('a'),
+        'ã' => Some('a'),
+        'ä' => Some('a'),
+        'å' => Some('a'),
+        'ç' => Some('c'),
+        'è' => Some('e'),
+        'é' => Some('e'),
+        'ê' => Some('e'),
+        'ë' => Some('e'),
+        'ì' => Some('i'),
+        'í' => Some('i'),
+        'î' => Some('i'),
+        'ï' => Some('i'),
+        'ñ' => Some('n'),
+        'ò' => Some('o'),
+        'ó' => Some('o'),
+        'ô' => Some('o'),
+        'õ' => Some('o'),
+        'ö' => Some('o'),
+        'ù' => Some('u'),
+        'ú' => Some('u'),
+        'û' => Some('u'),
+        'ü' => Some('u'),
+        'ý' => Some('y'),
+        'ÿ' => Some('y'),
+        'Ā' => Some('A'),
+        'ā' => Some('a'),
+        'Ă' => Some('A'),
+        'ă' => Some('a'),
+        'Ą' => Some('A'),
+        'ą' => Some('a'),
+        'Ć' => Some('C'),
+        'ć' => Some('c'),
+        'Ĉ' => Some('C'),
+        'ĉ' => Some('c'),
+        'Ċ' => Some('C'),
+        'ċ' => Some('c'),
+        'Č' => Some('C'),
+        'č' => Some('c'),
+        'Ď' => Some('D'),
+        'ď' => Some('d'),
+        'Ē' => Some('E'),
+        'ē' => Some('e'),
+        'Ĕ' => Some('E'),
+        'ĕ' => Some('e'),
+        'Ė' => Some('E'),
+        'ė' => Some('e'),
+        'Ę' => Some('E'),
+        'ę' => Some('e'),
+        'Ě' => Some('E'),
+        'ě' => Some('e'),
+        'Ĝ' => Some('G'),
+        'ĝ' => Some('g'),
+        'Ğ' => Some('G'),
+        'ğ' => Some('g'),
+        'Ġ' => Some('G'),
+        'ġ' => Some('g'),
+        'Ģ' => Some('G'),
+        'ģ' => Some('g'),
+        'Ĥ' => Some('H'),
+        'ĥ' => Some('h'),
+        'Ĩ' => Some('I'),
+        'ĩ' => Some('i'),
+        'Ī' => Some('I'),
+        'ī' => Some('i'),
+        'Ĭ' => Some('I'),
+        'ĭ' => Some('i'),
+        'Į' => Some('I'),
+        'į' => Some('i'),
+        'İ' => Some('I'),
+        'Ĵ' => Some('J'),
+        'ĵ' => Some('j'),
+        'Ķ' => Some('K'),
+        'ķ' => Some('k'),
+        'Ĺ' => Some('L'),
+        'ĺ' => Some('l'),
+        'Ļ' => Some('L'),
+        'ļ' => Some('l'),
+        'Ľ' => Some('L'),
+        'ľ' => Some('l'),
+        'Ń' => Some('N'),
+        'ń' => Some('n'),
+        'Ņ' => Some('N'),
+        'ņ' => Some('n'),
+        'Ň' => Some('N'),
+        'ň' => Some('n'),
+        'Ō' => Some('O'),
+        'ō' => Some('o'),
+        'Ŏ' => Some('O'),
+        'ŏ' => Some('o'),
+        'Ő' => Some('O'),
+        'ő' => Some('o'),
+        'Ŕ' => Some('R'),
+        'ŕ' => Some('r'),
+        'Ŗ' => Some('R'),
+        'ŗ' => Some('r'),
+        'Ř' => Some('R'),
+        'ř' => Some('r'),
+        'Ś' => Some('S'),
+        'ś' => Some('s'),
+        'Ŝ' => Some('S'),
+        'ŝ' => Some('s'),
+        'Ş' => Some('S'),
+        'ş' => Some('s'),
+        'Š' => Some('S'),
+        'š' => Some('s'),
+        'Ţ' => Some('T'),
+        'ţ' => Some('t'),
+        'Ť' => Some('T'),
+        'ť' => Some('t'),
+        'Ũ' => Some('U'),
+        'ũ' => Some('u'),
+        'Ū' => Some('U'),
+        'ū' => Some('u'),
+        'Ŭ' => Some('U'),
+        'ŭ' => Some('u'),
+        'Ů' => Some('U'),
+        'ů' => Some('u'),
+        'Ű' => Some('U'),
+        'ű' => Some('u'),
+        'Ų' => Some('U'),
+        'ų' => Some('u'),
+        'Ŵ' => Some('W'),
+        'ŵ' => Some('w'),
+        'Ŷ' => Some('Y'),
+        'ŷ' => Some('y'),
+        'Ÿ' => Some('Y'),
+        'Ź' => Some('Z'),
+        'ź' => Some('z'),
+        'Ż' => Some('Z'),
+        'ż' => Some('z'),
+        'Ž' => Some('Z'),
+        'ž' => Some('z'),
+        _ => None,
+    }
+}
+
+pub const COMBINING_MARKS: &[core::ops::RangeInclusive<char>] = &[
+    '\u{300}'..='\u{36f}',
+    '\u{483}'..='\u{489}',
+    '\u{591}'..='\u{5bd}',
+    '\u{5bf}'..='\u{5bf}',
+    '\u{5c1}'..='\u{5c2}',
+    '\u{5c4}'..='\u{5c5}',
+    '\u{5c7}'..='\u{5c7}',
+    '\u{610}'..='\u{61a}',
+    '\u{64b}'..='\u{65f}',
+    '\u{670}'..='\u{670}',
+    '\u{6d6}'..='\u{6dc}',
+    '\u{6df}'..='\u{6e4}',
+    '\u{6e7}'..='\u{6e8}',
+    '\u{6ea}'..='\u{6ed}',
+    '\u{711}'..='\u{711}',
+    '\u{730}'..='\u{74a}',
+    '\u{7a6}'..='\u{7b0}',
+    '\u{7eb}'..='\u{7f3}',
+    '\u{7fd}'..='\u{7fd}',
+    '\u{816}'..='\u{819}',
+    '\u{81b}'..='\u{823}',
+    '\u{825}'..='\u{827}',
+    '\u{829}'..='\u{82d}',
+    '\u{859}'..='\u{85b}',
+    '\u{898}'..='\u{89f}',
+    '\u{8ca}'..='\u{8e1}',
+    '\u{8e3}'..='\u{902}',
+    '\u{93a}'..='\u{93a}',
+    '\u{93c}'..='\u{93c}',
+    '\u{941}'..='\u{948}',
+    '\u{94d}'..='\u{94d}',
+    '\u{951}'..='\u{957}',
+    '\u{962}'..='\u{963}',
+    '\u{981}'..='\u{981}',
+    '\u{9bc}'..='\u{9bc}',
+    '\u{9c1}'..='\u{9c4}',
+    '\u{9cd}'..='\u{9cd}',
+    '\u{9e2}'..='\u{9e3}',
+    '\u{9fe}'..='\u{9fe}',
+    '\u{a01}'..='\u{a02}',
+    '\u{a3c}'..='\u{a3c}',
+    '\u{a41}'..='\u{a42}',
+    '\u{a47}'..='\u{a48}',
+    '\u{a4b}'..='\u{a4d}',
+    '\u{a51}'..='\u{a51}',
+    '\u{a70}'..='\u{a71}',
+    '\u{a75}'..='\u{a75}',
+    '\u{a81}'..='\u{a82}',
+    '\u{abc}'..='\u{abc}',
+    '\u{ac1}'..='\u{ac5}',
+    '\u{ac7}'..='\u{ac8}',
+    '\u{acd}'..='\u{acd}',
+    '\u{ae2}'..='\u{ae3}',
+    '\u{afa}'..='\u{aff}',
+    '\u{b01}'..='\u{b01}',
+    '\u{b3c}'..='\u{b3c}',
+    '\u{b3f}'..='\u{b3f}',
+    '\u{b41}'..='\u{b44}',
+    '\u{b4d}'..='\u{b4d}',
+    '\u{b55}'..='\u{b56}',
+    '\u{b62}'..='\u{b63}',
+    '\u{b82}'..='\u{b82}',
+    '\u{bc0}'..='\u{bc0}',
+    '\u{bcd}'..='\u{bcd}',
+    '\u{c00}'..='\u{c00}',
+    '\u{c04}'..='\u{c04}',
+    '\u{c3c}'..='\u{c3c}',
+    '\u{c3e}'..='\u{c40}',
+    '\u{c46}'..='\u{c48}',
+    '\u{c4a}'..='\u{c4d}',
+    '\u{c55}'..='\u{c56}',
+    '\u{c62}'..='\u{c63}',
+    '\u{c81}'..='\u{c81}',
+    '\u{cbc}'..='\u{cbc}',
+    '\u{cbf}'..='\u{cbf}',
+    '\u{cc6}'..='\u{cc6}',
+    '\u{ccc}'..='\u{ccd}',
+    '\u{ce2}'..='\u{ce3}',
+    '\u{d00}'..='\u{d01}',
+    '\u{d3b}'..='\u{d3c}',
+    '\u{d41}'..='\u{d44}',
+    '\u{d4d}'..='\u{d4d}',
+    '\u{d62}'..='\u{d63}',
+    '\u{d81}'..='\u{d81}',
+    '\u{dca}'..='\u{dca}',
+    '\u{dd2}'..='\u{dd4}',
+    '\u{dd6}'..='\u{dd6}',
+    '\u{e31}'..='\u{e31}',
+    '\u{e34}'..='\u{e3a}',
+    '\u{e47}'..='\u{e4e}',
+    '\u{eb1}'..='\u{eb1}',
+    '\u{eb4}'..='\u{ebc}',
+    '\u{ec8}'..='\u{ece}',
+    '\u{f18}'..='\u{f19}',
+    '\u{f35}'..='\u{f35}',
+    '\u{f37}'..='\u{f37}',
+    '\u{f39}'..='\u{f39}',
+    '\u{f71}'..='\u{f7e}',
+    '\u{f80}'..='\u{f84}',
+    '\u{f86}'..='\u{f87}',
+    '\u{f8d}'..='\u{f97}',
+    '\u{f99}'..='\u{fbc}',
+    '\u{fc6}'..='\u{fc6}',
+    '\u{102d}'..='\u{1030}',
+    '\u{1032}'..='\u{1037}',
+    '\u{1039}'..='\u{103a}',
+    '\u{103d}'..='\u{103e}',
+    '\u{1058}'..='\u{1059}',
+    '\u{105e}'..='\u{1060}',
+    '\u{1071}'..='\u{1074}',
+    '\u{1082}'..='\u{1082}',
+    '\u{1085}'..='\u{1086}',
+    '\u{108d}'..='\u{108d}',
+    '\u{109d}'..='\u{109d}',
+    '\u{135d}'..='\u{135f}',
+    '\u{1712}'..='\u{1714}',
+    '\u{1732}'..='\u{1733}',
+    '\u{1752}'..='\u{1753}',
+    '\u{1772}'..='\u{1773}',
+    '\u{17b4}'..='\u{17b5}',
+    '\u{17b7}'..='\u{17bd}',
+    '\u{17c6}'..='\u{17c6}',
+    '\u{17c9}'..='\u{17d3}',
+    '\u{17dd}'..='\u{17dd}',
+    '\u{180b}'..='\u{180d}',
+    '\u{180f}'..='\u{180f}',
+    '\u{1885}'..='\u{1886}',
+    '\u{18a9}'..='\u{18a9}',
+    '\u{1920}'..='\u{1922}',
+    '\u{1927}'..='\u{1928}',
+    '\u{1932}'..='\u{1932}',
+    '\u{1939}'..='\u{193b}',
+    '\u{1a17}'..='\u{1a18}',
+    '\u{1a1b}'..='\u{1a1b}',
+    '\u{1a56}'..='\u{1a56}',
+    '\u{1a58}'..='\u{1a5e}',
+    '\u{1a60}'..='\u{1a60}',
+    '\u{1a62}'..='\u{1a62}',
+    '\u{1a65}'..='\u{1a6c}',
+    '\u{1a73}'..='\u{1a7c}',
+    '\u{1a7f}'..='\u{1a7f}',
+    '\u{1ab0}'..='\u{1ace}',
+    '\u{1b00}'..='\u{1b03}',
+    '\u{1b34}'..='\u{1b34}',
+    '\u{1b36}'..='\u{1b3a}',
+    '\u{1b3c}'..='\u{1b3c}',
+    '\u{1b42}'..='\u{1b42}',
+    '\u{1b6b}'..='\u{1b73}',
+    '\u{1b80}'..='\u{1b81}',
+    '\u{1ba2}'..='\u{1ba5}',
+    '\u{1ba8}'..='\u{1ba9}',
+    '\u{1bab}'..='\u{1bad}',
+    '\u{1be6}'..='\u{1be6}',
+    '\u{1be8}'..='\u{1be9}',
+    '\u{1bed}'..='\u{1bed}',
+    '\u{1bef}'..='\u{1bf1}',
+    '\u{1c2c}'..='\u{1c33}',
+    '\u{1c36}'..='\u{1c37}',
+    '\u{1cd0}'..='\u{1cd2}',
+    '\u{1cd4}'..='\u{1ce0}',
+    '\u{1ce2}'..='\u{1ce8}',
+    '\u{1ced}'..='\u{1ced}',
+    '\u{1cf4}'..='\u{1cf4}',
+    '\u{1cf8}'..='\u{1cf9}',
+    '\u{1dc0}'..='\u{1dff}',
+    '\u{20d0}'..='\u{20f0}',
+    '\u{2cef}'..='\u{2cf1}',
+    '\u{2d7f}'..='\u{2d7f}',
+    '\u{2de0}'..='\u{2dff}',
+    '\u{302a}'..='\u{302d}',
+    '\u{a66f}'..='\u{a672}',
+    '\u{a674}'..='\u{a67d}',
+    '\u{a69e}'..='\u{a69f}',
+    '\u{a6f0}'..='\u{a6f1}',
+    '\u{a802}'..='\u{a802}',
+    '\u{a806}'..='\u{a806}',
+    '\u{a80b}'..='\u{a80b}',
+    '\u{a825}'..='\u{a826}',
+    '\u{a82c}'..='\u{a82c}',
+    '\u{a8c4}'..='\u{a8c5}',
+    '\u{a8e0}'..='\u{a8f1}',
+    '\u{a8ff}'..='\u{a8ff}',
+    '\u{a926}'..='\u{a92d}',
+    '\u{a947}'..='\u{a951}',
+    '\u{a980}'..='\u{a982}',
+    '\u{a9b3}'..='\u{a9b3}',
+    '\u{a9b6}'..='\u{a9b9}',
+    '\u{a9bc}'..='\u{a9bd}',
+    '\u{a9e5}'..='\u{a9e5}',
+    '\u{aa29}'..='\u{aa2e}',
+    '\u{aa31}'..='\u{aa32}',
+    '\u{aa35}'..='\u{aa36}',
+    '\u{aa43}'..='\u{aa43}',
+    '\u{aa4c}'..='\u{aa4c}',
+    '\u{aa7c}'..='\u{aa7c}',
+    '\u{aab0}'..='\u{aab0}',
+    '\u{aab2}'..='\u{aab4}',
+    '\u{aab7}'..='\u{aab8}',
+    '\u{aabe}'..='\u{aabf}',
+    '\u{aac1}'..='\u{aac1}',
+    '\u{aaec}'..='\u{aaed}',
+    '\u{aaf6}'..='\u{aaf6}',
+    '\u{abe5}'..='\u{abe5}',
+    '\u{abe8}'..='\u{abe8}',
+    '\u{abed}'..='\u{abed}',
+    '\u{fb1e}'..='\u{fb1e}',
+    '\u{fe20}'..='\u{fe2f}',
+    '\u{101fd}'..='\u{101fd}',
+    '\u{102e0}'..='\u{102e0}',
+    '\u{10376}'..='\u{1037a}',
+    '\u{10a01}'..='\u{10a03}',
+    '\u{10a05}'..='\u{10a06}',
+    '\u{10a0c}'..='\u{10a0f}',
+    '\u{10a38}'..='\u{10a3a}',
+    '\u{10a3f}'..='\u{10a3f}',
+    '\u{10ae5}'..='\u{10ae6}',
+    '\u{10d24}'..='\u{10d27}',
+    '\u{10eab}'..='\u{10eac}',
+    '\u{10efd}'..='\u{10eff}',
+    '\u{10f46}'..='\u{10f50}',
+    '\u{10f82}'..='\u{10f85}',
+    '\u{11001}'..='\u{11001}',
+    '\u{11038}'..='\u{11046}',
+    '\u{11070}'..='\u{11070}',
+    '\u{11073}'..='\u{11074}',
+    '\u{1107f}'..='\u{11081}',
+    '\u{110b3}'..='\u{110b6}',
+    '\u{110b9}'..='\u{110ba}',
+    '\u{110c2}'..='\u{110c2}',
+    '\u{11100}'..='\u{11102}',
+    '\u{11127}'..='\u{1112b}',
+    '\u{1112d}'..='\u{11134}',
+    '\u{11173}'..='\u{11173}',
+    '\u{11180}'..='\u{11181}',
+    '\u{111b6}'..='\u{111be}',
+    '\u{111c9}'..='\u{111cc}',
+    '\u{111cf}'..='\u{111cf}',
+    '\u{1122f}'..='\u{11231}',
+    '\u{11234}'..='\u{11234}',
+    '\u{11236}'..='\u{11237}',
+    '\u{1123e}'..='\u{1123e}',
+    '\u{11241}'..='\u{11241}',
+    '\u{112df}'..='\u{112df}',
+    '\u{112e3}'..='\u{112ea}',
+    '\u{11300}'..='\u{11301}',
+    '\u{1133b}'..='\u{1133c}',
+    '\u{11340}'..='\u{11340}',
+    '\u{11366}'..='\u{1136c}',
+    '\u{11370}'..='\u{11374}',
+    '\u{11438}'..='\u{1143f}',
+    '\u{11442}'..='\u{11444}',
+    '\u{11446}'..='\u{11446}',
+    '\u{1145e}'..='\u{1145e}',
+    '\u{114b3}'..='\u{114b8}',
+    '\u{114ba}'..='\u{114ba}',
+    '\u{114bf}'..='\u{114c0}',
+    '\u{114c2}'..='\u{114c3}',
+    '\u{115b2}'..='\u{115b5}',
+    '\u{115bc}'..='\u{115bd}',
+    '\u{115bf}'..='\u{115c0}',
+    '\u{115dc}'..='\u{115dd}',
+    '\u{11633}'..='\u{1163a}',
+    '\u{1163d}'..='\u{1163d}',
+    '\u{1163f}'..='\u{11640}',
+    '\u{116ab}'..='\u{116ab}',
+    '\u{116ad}'..='\u{116ad}',
+    '\u{116b0}'..='\u{116b5}',
+    '\u{116b7}'..='\u{116b7}',
+    '\u{1171d}'..='\u{1171f}',
+    '\u{11722}'..='\u{11725}',
+    '\u{11727}'..='\u{1172b}',
+    '\u{1182f}'..='\u{11837}',
+    '\u{11839}'..='\u{1183a}',
+    '\u{1193b}'..='\u{1193c}',
+    '\u{1193e}'..='\u{1193e}',
+    '\u{11943}'..='\u{11943}',
+    '\u{119d4}'..='\u{119d7}',
+    '\u{119da}'..='\u{119db}',
+    '\u{119e0}'..='\u{119e0}',
+    '\u{11a01}'..='\u{11a0a}',
+    '\u{11a33}'..='\u{11a38}',
+    '\u{11a3b}'..='\u{11a3e}',
+    '\u{11a47}'..='\u{11a47}',
+    '\u{11a51}'..='\u{11a56}',
+    '\u{11a59}'..='\u{11a5b}',
+    '\u{11a8a}'..='\u{11a96}',
+    '\u{11a98}'..='\u{11a99}',
+    '\u{11c30}'..='\u{11c36}',
+    '\u{11c38}'..='\u{11c3d}',
+    '\u{11c3f}'..='\u{11c3f}',
+    '\u{11c92}'..='\u{11ca7}',
+    '\u{11caa}'..='\u{11cb0}',
+    '\u{11cb2}'..='\u{11cb3}',
+    '\u{11cb5}'..='\u{11cb6}',
+    '\u{11d31}'..='\u{11d36}',
+    '\u{11d3a}'..='\u{11d3a}',
+    '\u{11d3c}'..='\u{11d3d}',
+    '\u{11d3f}'..='\u{11d45}',
+    '\u{11d47}'..='\u{11d47}',
+    '\u{11d90}'..='\u{11d91}',
+    '\u{11d95}'..='\u{11d95}',
+    '\u{11d97}'..='\u{11d97}',
+    '\u{11ef3}'..='\u{11ef4}',
+    '\u{11f00}'..='\u{11f01}',
+    '\u{11f36}'..='\u{11f3a}',
+    '\u{11f40}'..='\u{11f40}',
+    '\u{11f42}'..='\u{11f42}',
+    '\u{13440}'..='\u{13440}',
+    '\u{13447}'..='\u{13455}',
+    '\u{16af0}'..='\u{16af4}',
+    '\u{16b30}'..='\u{16b36}',
+    '\u{16f4f}'..='\u{16f4f}',
+    '\u{16f8f}'..='\u{16f92}',
+    '\u{16fe4}'..='\u{16fe4}',
+    '\u{1bc9d}'..='\u{1bc9e}',
+    '\u{1cf00}'..='\u{1cf2d}',
+    '\u{1cf30}'..='\u{1cf46}',
+    '\u{1d167}'..='\u{1d169}',
+    '\u{1d17b}'..='\u{1d182}',
+    '\u{1d185}'..='\u{1d18b}',
+    '\u{1d1aa}'..='\u{1d1ad}',
+    '\u{1d242}'..='\u{1d244}',
+    '\u{1da00}'..='\u{1da36}',
+    '\u{1da3b}'..='\u{1da6c}',
+    '\u{1da75}'..='\u{1da75}',
+    '\u{1da84}'..='\u{1da84}',
+    '\u{1da9b}'..='\u{1da9f}',
+    '\u{1daa1}'..='\u{1daaf}',
+    '\u{1e000}'..='\u{1e006}',
+    '\u{1e008}'..='\u{1e018}',
+    '\u{1e01b}'..='\u{1e021}',
+    '\u{1e023}'..='\u{1e024}',
+    '\u{1e026}'..='\u{1e02a}',
+    '\u{1e08f}'..='\u{1e08f}',
+    '\u{1e130}'..='\u{1e136}',
+    '\u{1e2ae}'..='\u{1e2ae}',
+    '\u{1e2ec}'..='\u{1e2ef}',
+    '\u{1e4ec}'..='\u{1e4ef}',
+    '\u{1e8d0}'..='\u{1e8d6}',
+    '\u{1e944}'..='\u{1e94a}',
+];
+
+// See `crate::reorder_combining_marks`.
+#[cfg(feature = "alloc")]
+pub(crate) const COMBINING_CLASSES: &[(char, char, u8)] = &[
+    ('\u{300}', '\u{314}', 230),
+    ('\u{315}', '\u{315}', 232),
+    ('\u{316}', '\u{319}', 220),
+    ('\u{31a}', '\u{31a}', 232),
+    ('\u{31b}', '\u{31b}', 216),
+    ('\u{31c}', '\u{320}', 220),
+    ('\u{321}', '\u{322}', 202),
+    ('\u{323}', '\u{326}', 220),
+    ('\u{327}', '\u{328}', 202),
+    ('\u{329}', '\u{333}', 220),
+    ('\u{334}', '\u{338}', 1),
+    ('\u{339}', '\u{33c}', 220),
+    ('\u{33d}', '\u{344}', 230),
+    ('\u{345}', '\u{345}', 240),
+    ('\u{346}', '\u{346}', 230),
+    ('\u{347}', '\u{349}', 220),
+    ('\u{34a}', '\u{34c}', 230),
+    ('\u{34d}', '\u{34e}', 220),
+    ('\u{350}', '\u{352}', 230),
+    ('\u{353}', '\u{356}', 220),
+    ('\u{357}', '\u{357}', 230),
+    ('\u{358}', '\u{358}', 232),
+    ('\u{359}', '\u{35a}', 220),
+    ('\u{35b}', '\u{35b}', 230),
+    ('\u{35c}', '\u{35c}', 233),
+    ('\u{35d}', '\u{35e}', 234),
+    ('\u{35f}', '\u{35f}', 233),
+    ('\u{360}', '\u{361}', 234),
+    ('\u{362}', '\u{362}', 233),
+    ('\u{363}', '\u{36f}', 230),
+    ('\u{483}', '\u{487}', 230),
+    ('\u{591}', '\u{591}', 220),
+    ('\u{592}', '\u{595}', 230),
+    ('\u{596}', '\u{596}', 220),
+    ('\u{597}', '\u{599}', 230),
+    ('\u{59a}', '\u{59a}', 222),
+    ('\u{59b}', '\u{59b}', 220),
+    ('\u{59c}', '\u{5a1}', 230),
+    ('\u{5a2}', '\u{5a7}', 220),
+    ('\u{5a8}', '\u{5a9}', 230),
+    ('\u{5aa}', '\u{5aa}', 220),
+    ('\u{5ab}', '\u{5ac}', 230),
+    ('\u{5ad}', '\u{5ad}', 222),
+    ('\u{5ae}', '\u{5ae}', 228),
+    ('\u{5af}', '\u{5af}', 230),
+    ('\u{5b0}', '\u{5b0}', 10),
+    ('\u{5b1}', '\u{5b1}', 11),
+    ('\u{5b2}', '\u{5b2}', 12),
+    ('\u{5b3}', '\u{5b3}', 13),
+    ('\u{5b4}', '\u{5b4}', 14),
+    ('\u{5b5}', '\u{5b5}', 15),
+    ('\u{5b6}', '\u{5b6}', 16),
+    ('\u{5b7}', '\u{5b7}', 17),
+    ('\u{5b8}', '\u{5b8}', 18),
+    ('\u{5b9}', '\u{5ba}', 19),
+    ('\u{5bb}', '\u{5bb}', 20),
+    ('\u{5bc}', '\u{5bc}', 21),
+    ('\u{5bd}', '\u{5bd}', 22),
+    ('\u{5bf}', '\u{5bf}', 23),
+    ('\u{5c1}', '\u{5c1}', 24),
+    ('\u{5c2}', '\u{5c2}', 25),
+    ('\u{5c4}', '\u{5c4}', 230),
+    ('\u{5c5}', '\u{5c5}', 220),
+    ('\u{5c7}', '\u{5c7}', 18),
+    ('\u{610}', '\u{617}', 230),
+    ('\u{618}', '\u{618}', 30),
+    ('\u{619}', '\u{619}', 31),
+    ('\u{61a}', '\u{61a}', 32),
+    ('\u{64b}', '\u{64b}', 27),
+    ('\u{64c}', '\u{64c}', 28),
+    ('\u{64d}', '\u{64d}', 29),
+    ('\u{64e}', '\u{64e}', 30),
+    ('\u{64f}', '\u{64f}', 31),
+    ('\u{650}', '\u{650}', 32),
+    ('\u{651}', '\u{651}', 33),
+    ('\u{652}', '\u{652}', 34),
+    ('\u{653}', '\u{654}', 230),
+    ('\u{655}', '\u{656}', 220),
+    ('\u{657}', '\u{65b}', 230),
+    ('\u{65c}', '\u{65c}', 220),
+    ('\u{65d}', '\u{65e}', 230),
+    ('\u{65f}', '\u{65f}', 220),
+    ('\u{670}', '\u{670}', 35),
+    ('\u{6d6}', '\u{6dc}', 230),
+    ('\u{6df}', '\u{6e2}', 230),
+    ('\u{6e3}', '\u{6e3}', 220),
+    ('\u{6e4}', '\u{6e4}', 230),
+    ('\u{6e7}', '\u{6e8}', 230),
+    ('\u{6ea}', '\u{6ea}', 220),
+    ('\u{6eb}', '\u{6ec}', 230),
+    ('\u{6ed}', '\u{6ed}', 220),
+    ('\u{711}', '\u{711}', 36),
+    ('\u{730}', '\u{730}', 230),
+    ('\u{731}', '\u{731}', 220),
+    ('\u{732}', '\u{733}', 230),
+    ('\u{734}', '\u{734}', 220),
+    ('\u{735}', '\u{736}', 230),
+    ('\u{737}', '\u{739}', 220),
+    ('\u{73a}', '\u{73a}', 230),
+    ('\u{73b}', '\u{73c}', 220),
+    ('\u{73d}', '\u{73d}', 230),
+    ('\u{73e}', '\u{73e}', 220),
+    ('\u{73f}', '\u{741}', 230),
+    ('\u{742}', '\u{742}', 220),
+    ('\u{743}', '\u{743}', 230),
+    ('\u{744}', '\u{744}', 220),
+    ('\u{745}', '\u{745}', 230),
+    ('\u{746}', '\u{746}', 220),
+    ('\u{747}', '\u{747}', 230),
+    ('\u{748}', '\u{748}', 220),
+    ('\u{749}', '\u{74a}', 230),
+    ('\u{7eb}', '\u{7f1}', 230),
+    ('\u{7f2}', '\u{7f2}', 220),
+    ('\u{7f3}', '\u{7f3}', 230),
+    ('\u{7fd}', '\u{7fd}', 220),
+    ('\u{816}', '\u{819}', 230),
+    ('\u{81b}', '\u{823}', 230),
+    ('\u{825}', '\u{827}', 230),
+    ('\u{829}', '\u{82d}', 230),
+    ('\u{859}', '\u{85b}', 220),
+    ('\u{898}', '\u{898}', 230),
+    ('\u{899}', '\u{89b}', 220),
+    ('\u{89c}', '\u{89f}', 230),
+    ('\u{8ca}', '\u{8ce}', 230),
+    ('\u{8cf}', '\u{8d3}', 220),
+    ('\u{8d4}', '\u{8e1}', 230),
+    ('\u{8e3}', '\u{8e3}', 220),
+    ('\u{8e4}', '\u{8e5}', 230),
+    ('\u{8e6}', '\u{8e6}', 220),
+    ('\u{8e7}', '\u{8e8}', 230),
+    ('\u{8e9}', '\u{8e9}', 220),
+    ('\u{8ea}', '\u{8ec}', 230),
+    ('\u{8ed}', '\u{8ef}', 220),
+    ('\u{8f0}', '\u{8f0}', 27),
+    ('\u{8f1}', '\u{8f1}', 28),
+    ('\u{8f2}', '\u{8f2}', 29),
+    ('\u{8f3}', '\u{8f5}', 230),
+    ('\u{8f6}', '\u{8f6}', 220),
+    ('\u{8f7}', '\u{8f8}', 230),
+    ('\u{8f9}', '\u{8fa}', 220),
+    ('\u{8fb}', '\u{8ff}', 230),
+    ('\u{93c}', '\u{93c}', 7),
+    ('\u{94d}', '\u{94d}', 9),
+    ('\u{951}', '\u{951}', 230),
+    ('\u{952}', '\u{952}', 220),
+    ('\u{953}', '\u{954}', 230),
+    ('\u{9bc}', '\u{9bc}', 7),
+    ('\u{9cd}', '\u{9cd}', 9),
+    ('\u{9fe}', '\u{9fe}', 230),
+    ('\u{a3c}', '\u{a3c}', 7),
+    ('\u{a4d}', '\u{a4d}', 9),
+    ('\u{abc}', '\u{abc}', 7),
+    ('\u{acd}', '\u{acd}', 9),
+    ('\u{b3c}', '\u{b3c}', 7),
+    ('\u{b4d}', '\u{b4d}', 9),
+    ('\u{bcd}', '\u{bcd}', 9),
+    ('\u{c3c}', '\u{c3c}', 7),
+    ('\u{c4d}', '\u{c4d}', 9),
+    ('\u{c55}', '\u{c55}', 84),
+    ('\u{c56}', '\u{c56}', 91),
+    ('\u{cbc}', '\u{cbc}', 7),
+    ('\u{ccd}', '\u{ccd}', 9),
+    ('\u{d3b}', '\u{d3c}', 9),
+    ('\u{d4d}', '\u{d4d}', 9),
+    ('\u{dca}', '\u{dca}', 9),
+    ('\u{e38}', '\u{e39}', 103),
+    ('\u{e3a}', '\u{e3a}', 9),
+    ('\u{e48}', '\u{e4b}', 107),
+    ('\u{eb8}', '\u{eb9}', 118),
+    ('\u{eba}', '\u{eba}', 9),
+    ('\u{ec8}', '\u{ecb}', 122),
+    ('\u{f18}', '\u{f19}', 220),
+    ('\u{f35}', '\u{f35}', 220),
+    ('\u{f37}', '\u{f37}', 220),
+    ('\u{f39}', '\u{f39}', 216),
+    ('\u{f71}', '\u{f71}', 129),
+    ('\u{f72}', '\u{f72}', 130),
+    ('\u{f74}', '\u{f74}', 132),
+    ('\u{f7a}', '\u{f7d}', 130),
+    ('\u{f80}', '\u{f80}', 130),
+    ('\u{f82}', '\u{f83}', 230),
+    ('\u{f84}', '\u{f84}', 9),
+    ('\u{f86}', '\u{f87}', 230),
+    ('\u{fc6}', '\u{fc6}', 220),
+    ('\u{1037}', '\u{1037}', 7),
+    ('\u{1039}', '\u{103a}', 9),
+    ('\u{108d}', '\u{108d}', 220),
+    ('\u{135d}', '\u{135f}', 230),
+    ('\u{1714}', '\u{1715}', 9),
+    ('\u{1734}', '\u{1734}', 9),
+    ('\u{17d2}', '\u{17d2}', 9),
+    ('\u{17dd}', '\u{17dd}', 230),
+    ('\u{18a9}', '\u{18a9}', 228),
+    ('\u{1939}', '\u{1939}', 222),
+    ('\u{193a}', '\u{193a}', 230),
+    ('\u{193b}', '\u{193b}', 220),
+    ('\u{1a17}', '\u{1a17}', 230),
+    ('\u{1a18}', '\u{1a18}', 220),
+    ('\u{1a60}', '\u{1a60}', 9),
+    ('\u{1a75}', '\u{1a7c}', 230),
+    ('\u{1a7f}', '\u{1a7f}', 220),
+    ('\u{1ab0}', '\u{1ab4}', 230),
+    ('\u{1ab5}', '\u{1aba}', 220),
+    ('\u{1abb}', '\u{1abc}', 230),
+    ('\u{1abd}', '\u{1abd}', 220),
+    ('\u{1abf}', '\u{1ac0}', 220),
+    ('\u{1ac1}', '\u{1ac2}', 230),
+    ('\u{1ac3}', '\u{1ac4}', 220),
+    ('\u{1ac5}', '\u{1ac9}', 230),
+    ('\u{1aca}', '\u{1aca}', 220),
+    ('\u{1acb}', '\u{1ace}', 230),
+    ('\u{1b34}', '\u{1b34}', 7),
+    ('\u{1b44}', '\u{1b44}', 9),
+    ('\u{1b6b}', '\u{1b6b}', 230),
+    ('\u{1b6c}', '\u{1b6c}', 220),
+    ('\u{1b6d}', '\u{1b73}', 230),
+    ('\u{1baa}', '\u{1bab}', 9),
+    ('\u{1be6}', '\u{1be6}', 7),
+    ('\u{1bf2}', '\u{1bf3}', 9),
+    ('\u{1c37}', '\u{1c37}', 7),
+    ('\u{1cd0}', '\u{1cd2}', 230),
+    ('\u{1cd4}', '\u{1cd4}', 1),
+    ('\u{1cd5}', '\u{1cd9}', 220),
+    ('\u{1cda}', '\u{1cdb}', 230),
+    ('\u{1cdc}', '\u{1cdf}', 220),
+    ('\u{1ce0}', '\u{1ce0}', 230),
+    ('\u{1ce2}', '\u{1ce8}', 1),
+    ('\u{1ced}', '\u{1ced}', 220),
+    ('\u{1cf4}', '\u{1cf4}', 230),
+    ('\u{1cf8}', '\u{1cf9}', 230),
+    ('\u{1dc0}', '\u{1dc1}', 230),
+    ('\u{1dc2}', '\u{1dc2}', 220),
+    ('\u{1dc3}', '\u{1dc9}', 230),
+    ('\u{1dca}', '\u{1dca}', 220),
+    ('\u{1dcb}', '\u{1dcc}', 230),
+    ('\u{1dcd}', '\u{1dcd}', 234),
+    ('\u{1dce}', '\u{1dce}', 214),
+    ('\u{1dcf}', '\u{1dcf}', 220),
+    ('\u{1dd0}', '\u{1dd0}', 202),
+    ('\u{1dd1}', '\u{1df5}', 230),
+    ('\u{1df6}', '\u{1df6}', 232),
+    ('\u{1df7}', '\u{1df8}', 228),
+    ('\u{1df9}', '\u{1df9}', 220),
+    ('\u{1dfa}', '\u{1dfa}', 218),
+    ('\u{1dfb}', '\u{1dfb}', 230),
+    ('\u{1dfc}', '\u{1dfc}', 233),
+    ('\u{1dfd}', '\u{1dfd}', 220),
+    ('\u{1dfe}', '\u{1dfe}', 230),
+    ('\u{1dff}', '\u{1dff}', 220),
+    ('\u{20d0}', '\u{20d1}', 230),
+    ('\u{20d2}', '\u{20d3}', 1),
+    ('\u{20d4}', '\u{20d7}', 230),
+    ('\u{20d8}', '\u{20da}', 1),
+    ('\u{20db}', '\u{20dc}', 230),
+    ('\u{20e1}', '\u{20e1}', 230),
+    ('\u{20e5}', '\u{20e6}', 1),
+    ('\u{20e7}', '\u{20e7}', 230),
+    ('\u{20e8}', '\u{20e8}', 220),
+    ('\u{20e9}', '\u{20e9}', 230),
+    ('\u{20ea}', '\u{20eb}', 1),
+    ('\u{20ec}', '\u{20ef}', 220),
+    ('\u{20f0}', '\u{20f0}', 230),
+    ('\u{2cef}', '\u{2cf1}', 230),
+    ('\u{2d7f}', '\u{2d7f}', 9),
+    ('\u{2de0}', '\u{2dff}', 230),
+    ('\u{302a}', '\u{302a}', 218),
+    ('\u{302b}', '\u{302b}', 228),
+    ('\u{302c}', '\u{302c}', 232),
+    ('\u{302d}', '\u{302d}', 222),
+    ('\u{302e}', '\u{302f}', 224),
+    ('\u{3099}', '\u{309a}', 8),
+    ('\u{a66f}', '\u{a66f}', 230),
+    ('\u{a674}', '\u{a67d}', 230),
+    ('\u{a69e}', '\u{a69f}', 230),
+    ('\u{a6f0}', '\u{a6f1}', 230),
+    ('\u{a806}', '\u{a806}', 9),
+    ('\u{a82c}', '\u{a82c}', 9),
+    ('\u{a8c4}', '\u{a8c4}', 9),
+    ('\u{a8e0}', '\u{a8f1}', 230),
+    ('\u{a92b}', '\u{a92d}', 220),
+    ('\u{a953}', '\u{a953}', 9),
+    ('\u{a9b3}', '\u{a9b3}', 7),
+    ('\u{a9c0}', '\u{a9c0}', 9),
+    ('\u{aab0}', '\u{aab0}', 230),
+    ('\u{aab2}', '\u{aab3}', 230),
+    ('\u{aab4}', '\u{aab4}', 220),
+    ('\u{aab7}', '\u{aab8}', 230),
+    ('\u{aabe}', '\u{aabf}', 230),
+    ('\u{aac1}', '\u{aac1}', 230),
+    ('\u{aaf6}', '\u{aaf6}', 9),
+    ('\u{abed}', '\u{abed}', 9),
+    ('\u{fb1e}', '\u{fb1e}', 26),
+    ('\u{fe20}', '\u{fe26}', 230),
+    ('\u{fe27}', '\u{fe2d}', 220),
+    ('\u{fe2e}', '\u{fe2f}', 230),
+    ('\u{101fd}', '\u{101fd}', 220),
+    ('\u{102e0}', '\u{102e0}', 220),
+    ('\u{10376}', '\u{1037a}', 230),
+    ('\u{10a0d}', '\u{10a0d}', 220),
+    ('\u{10a0f}', '\u{10a0f}', 230),
+    ('\u{10a38}', '\u{10a38}', 230),
+    ('\u{10a39}', '\u{10a39}', 1),
+    ('\u{10a3a}', '\u{10a3a}', 220),
+    ('\u{10a3f}', '\u{10a3f}', 9),
+    ('\u{10ae5}', '\u{10ae5}', 230),
+    ('\u{10ae6}', '\u{10ae6}', 220),
+    ('\u{10d24}', '\u{10d27}', 230),
+    ('\u{10eab}', '\u{10eac}', 230),
+    ('\u{10efd}', '\u{10eff}', 220),
+    ('\u{10f46}', '\u{10f47}', 220),
+    ('\u{10f48}', '\u{10f4a}', 230),
+    ('\u{10f4b}', '\u{10f4b}', 220),
+    ('\u{10f4c}', '\u{10f4c}', 230),
+    ('\u{10f4d}', '\u{10f50}', 220),
+    ('\u{10f82}', '\u{10f82}', 230),
+    ('\u{10f83}', '\u{10f83}', 220),
+    ('\u{10f84}', '\u{10f84}', 230),
+    ('\u{10f85}', '\u{10f85}', 220),
+    ('\u{11046}', '\u{11046}', 9),
+    ('\u{11070}', '\u{11070}', 9),
+    ('\u{1107f}', '\u{1107f}', 9),
+    ('\u{110b9}', '\u{110b9}', 9),
+    ('\u{110ba}', '\u{110ba}', 7),
+    ('\u{11100}', '\u{11102}', 230),
+    ('\u{11133}', '\u{11134}', 9),
+    ('\u{11173}', '\u{11173}', 7),
+    ('\u{111c0}', '\u{111c0}', 9),
+    ('\u{111ca}', '\u{111ca}', 7),
+    ('\u{11235}', '\u{11235}', 9),
+    ('\u{11236}', '\u{11236}', 7),
+    ('\u{112e9}', '\u{112e9}', 7),
+    ('\u{112ea}', '\u{112ea}', 9),
+    ('\u{1133b}', '\u{1133c}', 7),
+    ('\u{1134d}', '\u{1134d}', 9),
+    ('\u{11366}', '\u{1136c}', 230),
+    ('\u{11370}', '\u{11374}', 230),
+    ('\u{11442}', '\u{11442}', 9),
+    ('\u{11446}', '\u{11446}', 7),
+    ('\u{1145e}', '\u{1145e}', 230),
+    ('\u{114c2}', '\u{114c2}', 9),
+    ('\u{114c3}', '\u{114c3}', 7),
+    ('\u{115bf}', '\u{115bf}', 9),
+    ('\u{115c0}', '\u{115c0}', 7),
+    ('\u{1163f}', '\u{1163f}', 9),
+    ('\u{116b6}', '\u{116b6}', 9),
+    ('\u{116b7}', '\u{116b7}', 7),
+    ('\u{1172b}', '\u{1172b}', 9),
+    ('\u{11839}', '\u{11839}', 9),
+    ('\u{1183a}', '\u{1183a}', 7),
+    ('\u{1193d}', '\u{1193e}', 9),
+    ('\u{11943}', '\u{11943}', 7),
+    ('\u{119e0}', '\u{119e0}', 9),
+    ('\u{11a34}', '\u{11a34}', 9),
+    ('\u{11a47}', '\u{11a47}', 9),
+    ('\u{11a99}', '\u{11a99}', 9),
+    ('\u{11c3f}', '\u{11c3f}', 9),
+    ('\u{11d42}', '\u{11d42}', 7),
+    ('\u{11d44}', '\u{11d45}', 9),
+    ('\u{11d97}', '\u{11d97}', 9),
+    ('\u{11f41}', '\u{11f42}', 9),
+    ('\u{16af0}', '\u{16af4}', 1),
+    ('\u{16b30}', '\u{16b36}', 230),
+    ('\u{16ff0}', '\u{16ff1}', 6),
+    ('\u{1bc9e}', '\u{1bc9e}', 1),
+    ('\u{1d165}', '\u{1d166}', 216),
+    ('\u{1d167}', '\u{1d169}', 1),
+    ('\u{1d16d}', '\u{1d16d}', 226),
+    ('\u{1d16e}', '\u{1d172}', 216),
+    ('\u{1d17b}', '\u{1d182}', 220),
+    ('\u{1d185}', '\u{1d189}', 230),
+    ('\u{1d18a}', '\u{1d18b}', 220),
+    ('\u{1d1aa}', '\u{1d1ad}', 230),
+    ('\u{1d242}', '\u{1d244}', 230),
+    ('\u{1e000}', '\u{1e006}', 230),
+    ('\u{1e008}', '\u{1e018}', 230),
+    ('\u{1e01b}', '\u{1e021}', 230),
+    ('\u{1e023}', '\u{1e024}', 230),
+    ('\u{1e026}', '\u{1e02a}', 230),
+    ('\u{1e08f}', '\u{1e08f}', 230),
+    ('\u{1e130}', '\u{1e136}', 230),
+    ('\u{1e2ae}', '\u{1e2ae}', 230),
+    ('\u{1e2ec}', '\u{1e2ef}', 230),
+    ('\u{1e4ec}', '\u{1e4ed}', 232),
+    ('\u{1e4ee}', '\u{1e4ee}', 220),
+    ('\u{1e4ef}', '\u{1e4ef}', 230),
+    ('\u{1e8d0}', '\u{1e8d6}', 220),
+    ('\u{1e944}', '\u{1e949}', 230),
+    ('\u{1e94a}', '\u{1e94a}', 7),
+];
+
+#[cfg(feature = "dense-table")]
+static DENSE_INDEX: [u8; 256] = [
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 0, 0, 18, 0, 0, 0, 19, 20, 21, 22,
+    23, 24, 25, 26, 27, 28, 29, 30, 31, 0, 0, 0, 0, 0, 0, 32, 0, 33, 34, 0, 0, 35, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 37, 38, 39, 40, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 41, 42, 43, 44, 45, 46, 47,
+];
+
+#[cfg(feature = "dense-table")]
+static DENSE_BLOCKS: [[u16; 256]; 48] = [
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
+        2, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 365, 365, 365, 365, 365, 365, 0, 371, 379, 379, 379,
+        379, 398, 398, 398, 398, 0, 426, 430, 430, 430, 430, 430, 0, 0, 452, 452, 452, 452, 466, 0,
+        0, 475, 475, 475, 475, 475, 475, 0, 482, 499, 499, 499, 499, 515, 515, 515, 515, 0, 562,
+        570, 570, 570, 570, 570, 0, 0, 591, 591, 591, 591, 601, 0, 601,
+    ],
+    [
+        365, 475, 365, 475, 365, 475, 371, 482, 371, 482, 371, 482, 371, 482, 375, 491, 0, 0, 379,
+        499, 379, 499, 379, 499, 379, 499, 379, 499, 387, 509, 387, 509, 387, 509, 387, 509, 392,
+        511, 0, 0, 398, 515, 398, 515, 398, 515, 398, 515, 398, 0, 0, 0, 405, 522, 407, 524, 0,
+        411, 538, 411, 538, 411, 538, 0, 0, 0, 0, 426, 562, 426, 562, 426, 562, 0, 0, 0, 430, 570,
+        430, 570, 430, 570, 0, 0, 439, 581, 439, 581, 439, 581, 441, 585, 441, 585, 441, 585, 441,
+        585, 447, 589, 447, 589, 0, 0, 452, 591, 452, 591, 452, 591, 452, 591, 452, 591, 452, 591,
+        458, 596, 466, 601, 466, 468, 603, 468, 603, 468, 603, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 430, 570, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 452, 591, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        377, 378, 498, 0, 0, 0, 0, 0, 0, 365, 475, 398, 515, 430, 570, 452, 591, 452, 591, 452,
+        591, 452, 591, 452, 591, 0, 365, 475, 365, 475, 616, 618, 0, 0, 387, 509, 407, 524, 430,
+        570, 430, 570, 629, 694, 522, 0, 0, 0, 387, 509, 0, 0, 426, 562, 365, 475, 616, 618, 617,
+        620,
+    ],
+    [
+        365, 475, 365, 475, 379, 499, 379, 499, 398, 515, 398, 515, 430, 570, 430, 570, 439, 581,
+        439, 581, 452, 591, 452, 591, 441, 585, 447, 589, 0, 0, 392, 511, 0, 0, 0, 0, 0, 0, 365,
+        475, 379, 499, 430, 570, 430, 570, 430, 570, 430, 570, 466, 601, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 2, 2, 2, 2, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 713, 0, 0, 0,
+        0, 0, 2, 0, 0, 0, 355, 0, 0, 0, 0, 0, 2, 2, 721, 615, 728, 730, 735, 0, 745, 0, 751, 763,
+        783, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 735, 751,
+        769, 776, 778, 783, 808, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 783, 808, 801, 808, 820, 0, 0, 0, 0, 751, 751, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0,
+    ],
+    [
+        830, 830, 0, 829, 0, 0, 0, 827, 0, 0, 0, 0, 834, 833, 836, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        833, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 848, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 845, 845, 0,
+        843, 0, 0, 0, 870, 0, 0, 0, 0, 849, 848, 858, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 873, 874, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 831, 846, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 828, 840, 828, 840, 0, 0, 830, 845, 0, 0, 880, 881, 831, 846,
+        832, 847, 0, 0, 833, 848, 833, 848, 835, 853, 0, 0, 882, 883, 839, 867, 836, 858, 836, 858,
+        836, 858, 837, 862, 0, 0, 838, 865, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 0, 1, 1, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0,
+        0, 0, 0, 0, 923, 923, 1184, 923, 1188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1252, 0, 1241, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 1251, 0, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1,
+        0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+        1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    ],
+    [
+        1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1259, 0, 0, 0, 0, 0, 0, 0, 1262, 0, 0, 1263, 0, 0, 0, 0,
+        0, 1, 0, 1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 1, 1, 1, 1, 1,
+        1, 1253, 1254, 1255, 1256, 1257, 1258, 1260, 1261, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0,
+        0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1264, 1265, 0, 1266, 0, 0,
+        1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    ],
+    [
+        0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1271, 0, 0, 1272, 0, 0, 0,
+        0, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0,
+        0, 1267, 1268, 1269, 0, 0, 1270, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1,
+        0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 1, 1, 0, 0, 0,
+        0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1,
+    ],
+    [
+        0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        1, 0, 0, 1, 0, 1, 1, 1, 1, 0, 0, 0, 1275, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0,
+        0, 0, 0, 1273, 1274, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 1278, 0, 0, 0, 0, 0, 1, 1278, 1279, 0, 1276, 1277, 1,
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+        1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+        1, 1, 1, 0, 1, 0, 0, 0, 1280, 0, 0, 1281, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1282, 1, 1, 1, 1, 1, 1, 1,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+        1285, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 1288, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1289, 0, 0, 0, 0, 1290, 0, 0, 0, 0,
+        1291, 0, 0, 0, 0, 1292, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1287, 0, 0, 0, 0, 0, 0, 0, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 1293, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 0, 1, 1,
+        0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+        1, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 1,
+        1, 1, 1, 1, 1, 0, 1, 0, 1, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 1, 1, 1, 1,
+        1406, 1, 1406, 0, 0, 0, 0, 1, 1406, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 1, 1, 0, 1, 1, 1, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0,
+        0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1,
+        1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 1, 0, 0,
+        0, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    ],
+    [
+        365, 475, 369, 480, 369, 480, 369, 480, 371, 482, 375, 491, 375, 491, 375, 491, 375, 491,
+        375, 491, 379, 499, 379, 499, 379, 499, 379, 499, 379, 499, 380, 502, 387, 509, 392, 511,
+        392, 511, 392, 511, 392, 511, 392, 511, 398, 515, 398, 515, 407, 524, 407, 524, 407, 524,
+        411, 538, 411, 538, 411, 538, 411, 538, 416, 545, 416, 545, 416, 545, 426, 562, 426, 562,
+        426, 562, 426, 562, 430, 570, 430, 570, 430, 570, 430, 570, 431, 572, 431, 572, 439, 581,
+        439, 581, 439, 581, 439, 581, 441, 585, 441, 585, 441, 585, 441, 585, 441, 585, 447, 589,
+        447, 589, 447, 589, 447, 589, 452, 591, 452, 591, 452, 591, 452, 591, 452, 591, 453, 592,
+        453, 592, 458, 596, 458, 596, 458, 596, 458, 596, 458, 596, 463, 598, 463, 598, 466, 601,
+        468, 603, 468, 603, 468, 603, 511, 589, 596, 601, 0, 585, 0, 0, 0, 0, 365, 475, 365, 475,
+        365, 475, 365, 475, 365, 475, 365, 475, 365, 475, 365, 475, 365, 475, 365, 475, 365, 475,
+        365, 475, 379, 499, 379, 499, 379, 499, 379, 499, 379, 499, 379, 499, 379, 499, 379, 499,
+        398, 515, 398, 515, 430, 570, 430, 570, 430, 570, 430, 570, 430, 570, 430, 570, 430, 570,
+        430, 570, 430, 570, 430, 570, 430, 570, 430, 570, 452, 591, 452, 591, 452, 591, 452, 591,
+        452, 591, 452, 591, 452, 591, 466, 601, 466, 601, 466, 601, 466, 601, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        769, 769, 769, 769, 769, 769, 769, 769, 721, 721, 721, 721, 721, 721, 721, 721, 776, 776,
+        776, 776, 776, 776, 0, 0, 728, 728, 728, 728, 728, 728, 0, 0, 778, 778, 778, 778, 778, 778,
+        778, 778, 730, 730, 730, 730, 730, 730, 730, 730, 783, 783, 783, 783, 783, 783, 783, 783,
+        735, 735, 735, 735, 735, 735, 735, 735, 801, 801, 801, 801, 801, 801, 0, 0, 745, 745, 745,
+        745, 745, 745, 0, 0, 808, 808, 808, 808, 808, 808, 808, 808, 0, 751, 0, 751, 0, 751, 0,
+        751, 820, 820, 820, 820, 820, 820, 820, 820, 763, 763, 763, 763, 763, 763, 763, 763, 769,
+        769, 776, 776, 778, 778, 783, 783, 801, 801, 808, 808, 820, 820, 0, 0, 769, 769, 769, 769,
+        769, 769, 769, 769, 721, 721, 721, 721, 721, 721, 721, 721, 778, 778, 778, 778, 778, 778,
+        778, 778, 730, 730, 730, 730, 730, 730, 730, 730, 820, 820, 820, 820, 820, 820, 820, 820,
+        763, 763, 763, 763, 763, 763, 763, 763, 769, 769, 769, 769, 769, 0, 769, 769, 721, 721,
+        721, 721, 721, 2, 783, 2, 2, 2, 778, 778, 778, 0, 778, 778, 728, 728, 730, 730, 730, 2, 2,
+        2, 783, 783, 783, 783, 0, 0, 783, 783, 735, 735, 735, 735, 0, 2, 2, 2, 808, 808, 808, 808,
+        803, 803, 808, 808, 751, 751, 751, 751, 747, 2, 2, 474, 0, 0, 820, 820, 820, 0, 820, 820,
+        745, 745, 763, 763, 763, 2, 2, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 763, 0, 0, 0, 407, 365, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 1479, 1481, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1483,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        1484, 1486, 1485, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 1488, 0, 0, 0, 0, 1490, 0, 0, 1491, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 1494, 0, 1495, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1501, 0, 0, 1502, 0, 0, 1503, 0, 1504, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 357, 0, 1506, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        1505, 356, 360, 1507, 1508, 0, 0, 1509, 1510, 0, 0, 1511, 1512, 0, 0, 0, 0, 0, 0, 1513,
+        1514, 0, 0, 1517, 1518, 0, 0, 1519, 1520, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1523, 1524, 1525, 1526, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1515, 1516, 1521, 1522, 0, 0, 0, 0, 0, 0, 1527,
+        1528, 1529, 1530, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1541, 1542, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1536, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+        1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+        0, 0, 1, 1, 1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 1,
+        1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0,
+        0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        2607, 2191, 2630, 2617, 2279, 1798, 1924, 2780, 2780, 2000, 2665, 1949, 1999, 2122, 2373,
+        2473, 2551, 2566, 2579, 2648, 2222, 2257, 2300, 2338, 2537, 2659, 2734, 1807, 1913, 2227,
+        2309, 2550, 2754, 2042, 2287, 2546, 2583, 2135, 2496, 2569, 2070, 2196, 2263, 2328, 2651,
+        1824, 1862, 1889, 2161, 2225, 2308, 2381, 2479, 2548, 2554, 2627, 2701, 2746, 2753, 2396,
+        2412, 2458, 2530, 2672, 2756, 2596, 1982, 2077, 2440, 2488, 2321, 2398, 2615, 2699, 1981,
+        2033, 2223, 2268, 2281, 2454, 2464, 2683, 1888, 2491, 1865, 1864, 2423, 2459, 2536, 2685,
+        2603, 2136, 2222, 2599, 1801, 2022, 2099, 2333, 2365, 1897, 2400, 1828, 2092, 1793, 2252,
+        2167, 2453, 1918, 1977, 2384, 2538, 2592, 2239, 2639, 2250, 2140, 2520, 2148, 2364, 1814,
+        1846, 1863, 2214, 2448, 2510, 2595, 2664, 1891, 1936, 2003, 2074, 2174, 2288, 2401, 2679,
+        2736, 2757, 2763, 1882, 2189, 2234, 2636, 2061, 2116, 2123, 2159, 2284, 2303, 2347, 2420,
+        2461, 2486, 2632, 2540, 2642, 2673, 1872, 1883, 1941, 2299, 2574, 2592, 2069, 2097, 2146,
+        2237, 2439, 2330, 1818, 1964, 2022, 2047, 2100, 2337, 2344, 2476, 2484, 2666, 2698, 2702,
+        2717, 1825, 2417, 2661, 2692, 2106, 1809, 1834, 2023, 2031, 2170, 2222, 2306, 2372, 2543,
+        2647, 2778, 2185, 2682, 1881, 2204, 2207, 2261, 2275, 2340, 2363, 2395, 2452, 2720, 1848,
+        2127, 2686, 1830, 2040, 2269, 2633, 2088, 2108, 2209, 2333, 2688, 1874, 1932, 2035, 2180,
+        2200, 2217, 2254, 2339, 2368, 2471, 2575, 2578, 2663, 2695, 1901, 2276, 1933, 2307, 2348,
+        2547, 2689, 2748, 2758, 2206, 2267, 2498, 2431, 2434, 2443, 2327, 2297, 2602, 1816, 2524,
+        1875,
+    ],
+    [
+        1871, 2065, 2137, 2446, 2018, 2258, 2188, 2635, 2571, 2684, 2586, 2072, 1838, 1955, 0, 0,
+        1976, 0, 2184, 0, 0, 1866, 2329, 2378, 2403, 2410, 2411, 2416, 2704, 2444, 2477, 0, 2549,
+        0, 2598, 0, 0, 2643, 2654, 0, 0, 0, 2725, 2726, 2727, 2752, 2652, 2691, 1826, 1835, 1840,
+        1887, 1890, 1906, 1951, 1958, 1960, 1975, 1978, 2034, 2036, 2103, 2113, 2115, 2121, 2164,
+        2176, 2186, 2215, 2264, 2271, 2283, 2304, 2312, 2341, 2397, 2404, 2406, 2405, 2407, 2408,
+        2409, 2414, 2415, 2424, 2429, 2436, 2461, 2463, 2465, 2470, 2480, 2500, 2513, 2513, 2539,
+        2581, 2587, 2600, 2601, 2618, 2619, 2641, 2643, 2696, 2713, 2719, 2101, 2845, 2506, 0, 0,
+        1795, 1861, 1845, 1823, 1839, 1850, 1886, 1893, 1951, 1946, 1950, 1957, 1976, 1980, 1998,
+        2001, 2008, 2012, 2071, 2073, 2085, 2093, 2105, 2111, 2107, 2115, 2112, 2121, 2128, 2150,
+        2153, 2155, 2165, 2184, 2196, 2197, 2202, 2235, 2239, 2261, 2280, 2278, 2283, 2290, 2304,
+        2390, 2313, 2326, 2329, 2345, 2352, 2361, 2370, 2371, 2378, 2379, 2383, 2388, 2387, 2399,
+        2430, 2436, 2442, 2456, 2461, 2468, 2480, 2525, 2535, 2564, 2582, 2585, 2587, 2593, 2598,
+        2594, 2600, 2599, 2597, 2601, 2604, 2619, 2634, 2646, 2660, 2668, 2687, 2696, 2704, 2709,
+        2713, 2716, 2719, 2740, 2780, 2824, 2823, 2833, 1741, 1751, 1752, 2859, 2868, 2892, 2775,
+        2779, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 905,
+        1, 921, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 919, 919, 919, 919, 896, 896, 896, 898, 899, 900,
+        901, 902, 903, 0, 904, 905, 906, 907, 908, 0, 910, 0, 911, 912, 0, 914, 915, 0, 916, 917,
+        918, 919, 920, 902, 898, 907, 915, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 1252, 1252, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1251, 1251, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1189, 1189, 1210, 1210, 1202,
+        1202, 1207, 1207, 1206, 1206, 1208, 1208, 1209, 1209, 1209, 1203, 1203, 1203, 0, 0, 0, 0,
+    ],
+    [
+        1190, 1192, 1197, 1203, 1204, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 1000, 1001, 1187, 2, 2, 2, 2, 2, 2, 1195, 1196, 1197, 1200, 1203, 1204,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 1187, 0, 0, 0, 0, 0, 0, 1190, 1192, 1194, 1197, 1201, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1177, 0,
+        0, 0, 0, 0, 1197, 1201, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1089, 1089,
+        1089, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        923, 923, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1089, 2, 0, 2, 0, 2,
+        1089, 2, 1089, 2, 1089, 2, 1089, 2, 1089, 0, 923, 923, 923, 923, 1184, 1184, 923, 923,
+        1188, 1188, 1188, 1188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1120, 1120, 1120, 1120,
+        1120, 1120, 0, 0, 0, 0, 0,
+    ],
+    [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+];
+
+/// What [`map_char`](crate::map_char) gives for a BMP char, with two
+/// array lookups instead of a binary search and a hash.
+#[cfg(feature = "dense-table")]
+#[inline]
+pub(crate) fn map_bmp(c: u16) -> Option<&'static str> {
+    match DENSE_BLOCKS[DENSE_INDEX[(c >> 8) as usize] as usize][(c & 0xff) as usize] {
+        0 => None,
+        i => Some(REPLACEMENTS[i as usize - 1]),
+    }
+}
+
+/// The entries of [`DIACRITICS_MAPPING`] sorted by char, for a binary
+/// search instead of a hash: [`map_char`](crate::map_char) looks up
+/// this one with the `binary-search` feature.
+pub const DIACRITICS_SORTED: &[(char, &str)] = &[
+    ('¨', REPLACEMENTS[1]),
+    ('¯', REPLACEMENTS[1]),
+    ('´', REPLACEMENTS[1]),
+    ('¸', REPLACEMENTS[1]),
+    ('À', REPLACEMENTS[364]),
+    ('Á', REPLACEMENTS[364]),
+    ('Â', REPLACEMENTS[364]),
+    ('Ã', REPLACEMENTS[364]),
+    ('Ä', REPLACEMENTS[364]),
+    ('Å', REPLACEMENTS[364]),
+    ('Ç', REPLACEMENTS[370]),
+    ('È', REPLACEMENTS[378]),
+    ('É', REPLACEMENTS[378]),
+    ('Ê', REPLACEMENTS[378]),
+    ('Ë', REPLACEMENTS[378]),
+    ('Ì', REPLACEMENTS[397]),
+    ('Í', REPLACEMENTS[397]),
+    ('Î', REPLACEMENTS[397]),
+    ('Ï', REPLACEMENTS[397]),
+    ('Ñ', REPLACEMENTS[425]),
+    ('Ò', REPLACEMENTS[429]),
+    ('Ó', REPLACEMENTS[429]),
+    ('Ô', REPLACEMENTS[429]),
+    ('Õ', REPLACEMENTS[429]),
+    ('Ö', REPLACEMENTS[429]),
+    ('Ù', REPLACEMENTS[451]),
+    ('Ú', REPLACEMENTS[451]),
+    ('Û', REPLACEMENTS[451]),
+    ('Ü', REPLACEMENTS[451]),
+    ('Ý', REPLACEMENTS[465]),
+    ('à', REPLACEMENTS[474]),
+    ('á', REPLACEMENTS[474]),
+    ('â', REPLACEMENTS[474]),
+    ('ã', REPLACEMENTS[474]),
+    ('ä', REPLACEMENTS[474]),
+    ('å', REPLACEMENTS[474]),
+    ('ç', REPLACEMENTS[481]),
+    ('è', REPLACEMENTS[498]),
+    ('é', REPLACEMENTS[498]),
+    ('ê', REPLACEMENTS[498]),
+    ('ë', REPLACEMENTS[498]),
+    ('ì', REPLACEMENTS[514]),
+    ('í', REPLACEMENTS[514]),
+    ('î', REPLACEMENTS[514]),
+    ('ï', REPLACEMENTS[514]),
+    ('ñ', REPLACEMENTS[561]),
+    ('ò', REPLACEMENTS[569]),
+    ('ó', REPLACEMENTS[569]),
+    ('ô', REPLACEMENTS[569]),
+    ('õ', REPLACEMENTS[569]),
+    ('ö', REPLACEMENTS[569]),
+    ('ù', REPLACEMENTS[590]),
+    ('ú', REPLACEMENTS[590]),
+    ('û', REPLACEMENTS[590]),
+    ('ü', REPLACEMENTS[590]),
+    ('ý', REPLACEMENTS[600]),
+    ('ÿ', REPLACEMENTS[600]),
+    ('Ā', REPLACEMENTS[364]),
+    ('ā', REPLACEMENTS[474]),
+    ('Ă', REPLACEMENTS[364]),
+    ('ă', REPLACEMENTS[474]),
+    ('Ą', REPLACEMENTS[364]),
+    ('ą', REPLACEMENTS[474]),
+    ('Ć', REPLACEMENTS[370]),
+    ('ć', REPLACEMENTS[481]),
+    ('Ĉ', REPLACEMENTS[370]),
+    ('ĉ', REPLACEMENTS[481]),
+    ('Ċ', REPLACEMENTS[370]),
+    ('ċ', REPLACEMENTS[481]),
+    ('Č', REPLACEMENTS[370]),
+    ('č', REPLACEMENTS[481]),
+    ('Ď', REPLACEMENTS[374]),
+    ('ď', REPLACEMENTS[490]),
+    ('Ē', REPLACEMENTS[378]),
+    ('ē', REPLACEMENTS[498]),
+    ('Ĕ', REPLACEMENTS[378]),
+    ('ĕ', REPLACEMENTS[498]),
+    ('Ė', REPLACEMENTS[378]),
+    ('ė', REPLACEMENTS[498]),
+    ('Ę', REPLACEMENTS[378]),
+    ('ę', REPLACEMENTS[498]),
+    ('Ě', REPLACEMENTS[378]),
+    ('ě', REPLACEMENTS[498]),
+    ('Ĝ', REPLACEMENTS[386]),
+    ('ĝ', REPLACEMENTS[508]),
+    ('Ğ', REPLACEMENTS[386]),
+    ('ğ', REPLACEMENTS[508]),
+    ('Ġ', REPLACEMENTS[386]),
+    ('ġ', REPLACEMENTS[508]),
+    ('Ģ', REPLACEMENTS[386]),
+    ('ģ', REPLACEMENTS[508]),
+    ('Ĥ', REPLACEMENTS[391]),
+    ('ĥ', REPLACEMENTS[510]),
+    ('Ĩ', REPLACEMENTS[397]),
+    ('ĩ', REPLACEMENTS[514]),
+    ('Ī', REPLACEMENTS[397]),
+    ('ī', REPLACEMENTS[514]),
+    ('Ĭ', REPLACEMENTS[397]),
+    ('ĭ', REPLACEMENTS[514]),
+    ('Į', REPLACEMENTS[397]),
+    ('į', REPLACEMENTS[514]),
+    ('İ', REPLACEMENTS[397]),
+    ('Ĵ', REPLACEMENTS[404]),
+    ('ĵ', REPLACEMENTS[521]),
+    ('Ķ', REPLACEMENTS[406]),
+    ('ķ', REPLACEMENTS[523]),
+    ('Ĺ', REPLACEMENTS[410]),
+    ('ĺ', REPLACEMENTS[537]),
+    ('Ļ', REPLACEMENTS[410]),
+    ('ļ', REPLACEMENTS[537]),
+    ('Ľ', REPLACEMENTS[410]),
+    ('ľ', REPLACEMENTS[537]),
+    ('Ń', REPLACEMENTS[425]),
+    ('ń', REPLACEMENTS[561]),
+    ('Ņ', REPLACEMENTS[425]),
+    ('ņ', REPLACEMENTS[561]),
+    ('Ň', REPLACEMENTS[425]),
+    ('ň', REPLACEMENTS[561]),
+    ('Ō', REPLACEMENTS[429]),
+    ('ō', REPLACEMENTS[569]),
+    ('Ŏ', REPLACEMENTS[429]),
+    ('ŏ', REPLACEMENTS[569]),
+    ('Ő', REPLACEMENTS[429]),
+    ('ő', REPLACEMENTS[569]),
+    ('Ŕ', REPLACEMENTS[438]),
+    ('ŕ', REPLACEMENTS[580]),
+    ('Ŗ', REPLACEMENTS[438]),
+    ('ŗ', REPLACEMENTS[580]),
+    ('Ř', REPLACEMENTS[438]),
+    ('ř', REPLACEMENTS[580]),
+    ('Ś', REPLACEMENTS[440]),
+    ('ś', REPLACEMENTS[584]),
+    ('Ŝ', REPLACEMENTS[440]),
+    ('ŝ', REPLACEMENTS[584]),
+    ('Ş', REPLACEMENTS[440]),
+    ('ş', REPLACEMENTS[584]),
+    ('Š', REPLACEMENTS[440]),
+    ('š', REPLACEMENTS[584]),
+    ('Ţ', REPLACEMENTS[446]),
+    ('ţ', REPLACEMENTS[588]),
+    ('Ť', REPLACEMENTS[446]),
+    ('ť', REPLACEMENTS[588]),
+    ('Ũ', REPLACEMENTS[451]),
+    ('ũ', REPLACEMENTS[590]),
+    ('Ū', REPLACEMENTS[451]),
+    ('ū', REPLACEMENTS[590]),
+    ('Ŭ', REPLACEMENTS[451]),
+    ('ŭ', REPLACEMENTS[590]),
+    ('Ů', REPLACEMENTS[451]),
+    ('ů', REPLACEMENTS[590]),
+    ('Ű', REPLACEMENTS[451]),
+    ('ű', REPLACEMENTS[590]),
+    ('Ų', REPLACEMENTS[451]),
+    ('ų', REPLACEMENTS[590]),
+    ('Ŵ', REPLACEMENTS[457]),
+    ('ŵ', REPLACEMENTS[595]),
+    ('Ŷ', REPLACEMENTS[465]),
+    ('ŷ', REPLACEMENTS[600]),
+    ('Ÿ', REPLACEMENTS[465]),
+    ('Ź', REPLACEMENTS[467]),
+    ('ź', REPLACEMENTS[602]),
+    ('Ż', REPLACEMENTS[467]),
+    ('ż', REPLACEMENTS[602]),
+    ('Ž', REPLACEMENTS[467]),
+    ('ž', REPLACEMENTS[602]),
+    ('Ơ', REPLACEMENTS[429]),
+    ('ơ', REPLACEMENTS[569]),
+    ('Ư', REPLACEMENTS[451]),
+    ('ư', REPLACEMENTS[590]),
+    ('Ǆ', REPLACEMENTS[376]),
+    ('ǅ', REPLACEMENTS[377]),
+    ('ǆ', REPLACEMENTS[497]),
+    ('Ǎ', REPLACEMENTS[364]),
+    ('ǎ', REPLACEMENTS[474]),
+    ('Ǐ', REPLACEMENTS[397]),
+    ('ǐ', REPLACEMENTS[514]),
+    ('Ǒ', REPLACEMENTS[429]),
+    ('ǒ', REPLACEMENTS[569]),
+    ('Ǔ', REPLACEMENTS[451]),
+    ('ǔ', REPLACEMENTS[590]),
+    ('Ǖ', REPLACEMENTS[451]),
+    ('ǖ', REPLACEMENTS[590]),
+    ('Ǘ', REPLACEMENTS[451]),
+    ('ǘ', REPLACEMENTS[590]),
+    ('Ǚ', REPLACEMENTS[451]),
+    ('ǚ', REPLACEMENTS[590]),
+    ('Ǜ', REPLACEMENTS[451]),
+    ('ǜ', REPLACEMENTS[590]),
+    ('Ǟ', REPLACEMENTS[364]),
+    ('ǟ', REPLACEMENTS[474]),
+    ('Ǡ', REPLACEMENTS[364]),
+    ('ǡ', REPLACEMENTS[474]),
+    ('Ǣ', REPLACEMENTS[615]),
+    ('ǣ', REPLACEMENTS[617]),
+    ('Ǧ', REPLACEMENTS[386]),
+    ('ǧ', REPLACEMENTS[508]),
+    ('Ǩ', REPLACEMENTS[406]),
+    ('ǩ', REPLACEMENTS[523]),
+    ('Ǫ', REPLACEMENTS[429]),
+    ('ǫ', REPLACEMENTS[569]),
+    ('Ǭ', REPLACEMENTS[429]),
+    ('ǭ', REPLACEMENTS[569]),
+    ('Ǯ', REPLACEMENTS[628]),
+    ('ǯ', REPLACEMENTS[693]),
+    ('ǰ', REPLACEMENTS[521]),
+    ('Ǵ', REPLACEMENTS[386]),
+    ('ǵ', REPLACEMENTS[508]),
+    ('Ǹ', REPLACEMENTS[425]),
+    ('ǹ', REPLACEMENTS[561]),
+    ('Ǻ', REPLACEMENTS[364]),
+    ('ǻ', REPLACEMENTS[474]),
+    ('Ǽ', REPLACEMENTS[615]),
+    ('ǽ', REPLACEMENTS[617]),
+    ('Ǿ', REPLACEMENTS[616]),
+    ('ǿ', REPLACEMENTS[619]),
+    ('Ȁ', REPLACEMENTS[364]),
+    ('ȁ', REPLACEMENTS[474]),
+    ('Ȃ', REPLACEMENTS[364]),
+    ('ȃ', REPLACEMENTS[474]),
+    ('Ȅ', REPLACEMENTS[378]),
+    ('ȅ', REPLACEMENTS[498]),
+    ('Ȇ', REPLACEMENTS[378]),
+    ('ȇ', REPLACEMENTS[498]),
+    ('Ȉ', REPLACEMENTS[397]),
+    ('ȉ', REPLACEMENTS[514]),
+    ('Ȋ', REPLACEMENTS[397]),
+    ('ȋ', REPLACEMENTS[514]),
+    ('Ȍ', REPLACEMENTS[429]),
+    ('ȍ', REPLACEMENTS[569]),
+    ('Ȏ', REPLACEMENTS[429]),
+    ('ȏ', REPLACEMENTS[569]),
+    ('Ȑ', REPLACEMENTS[438]),
+    ('ȑ', REPLACEMENTS[580]),
+    ('Ȓ', REPLACEMENTS[438]),
+    ('ȓ', REPLACEMENTS[580]),
+    ('Ȕ', REPLACEMENTS[451]),
+    ('ȕ', REPLACEMENTS[590]),
+    ('Ȗ', REPLACEMENTS[451]),
+    ('ȗ', REPLACEMENTS[590]),
+    ('Ș', REPLACEMENTS[440]),
+    ('ș', REPLACEMENTS[584]),
+    ('Ț', REPLACEMENTS[446]),
+    ('ț', REPLACEMENTS[588]),
+    ('Ȟ', REPLACEMENTS[391]),
+    ('ȟ', REPLACEMENTS[510]),
+    ('Ȧ', REPLACEMENTS[364]),
+    ('ȧ', REPLACEMENTS[474]),
+    ('Ȩ', REPLACEMENTS[378]),
+    ('ȩ', REPLACEMENTS[498]),
+    ('Ȫ', REPLACEMENTS[429]),
+    ('ȫ', REPLACEMENTS[569]),
+    ('Ȭ', REPLACEMENTS[429]),
+    ('ȭ', REPLACEMENTS[569]),
+    ('Ȯ', REPLACEMENTS[429]),
+    ('ȯ', REPLACEMENTS[569]),
+    ('Ȱ', REPLACEMENTS[429]),
+    ('ȱ', REPLACEMENTS[569]),
+    ('Ȳ', REPLACEMENTS[465]),
+    ('ȳ', REPLACEMENTS[600]),
+    ('˘', REPLACEMENTS[1]),
+    ('˙', REPLACEMENTS[1]),
+    ('˚', REPLACEMENTS[1]),
+    ('˛', REPLACEMENTS[1]),
+    ('˜', REPLACEMENTS[1]),
+    ('˝', REPLACEMENTS[1]),
+    ('ʹ', REPLACEMENTS[712]),
+    ('ͺ', REPLACEMENTS[1]),
+    (';', REPLACEMENTS[354]),
+    ('΄', REPLACEMENTS[1]),
+    ('΅', REPLACEMENTS[1]),
+    ('Ά', REPLACEMENTS[720]),
+    ('·', REPLACEMENTS[614]),
+    ('Έ', REPLACEMENTS[727]),
+    ('Ή', REPLACEMENTS[729]),
+    ('Ί', REPLACEMENTS[734]),
+    ('Ό', REPLACEMENTS[744]),
+    ('Ύ', REPLACEMENTS[750]),
+    ('Ώ', REPLACEMENTS[762]),
+    ('ΐ', REPLACEMENTS[782]),
+    ('Ϊ', REPLACEMENTS[734]),
+    ('Ϋ', REPLACEMENTS[750]),
+    ('ά', REPLACEMENTS[768]),
+    ('έ', REPLACEMENTS[775]),
+    ('ή', REPLACEMENTS[777]),
+    ('ί', REPLACEMENTS[782]),
+    ('ΰ', REPLACEMENTS[807]),
+    ('ϊ', REPLACEMENTS[782]),
+    ('ϋ', REPLACEMENTS[807]),
+    ('ό', REPLACEMENTS[800]),
+    ('ύ', REPLACEMENTS[807]),
+    ('ώ', REPLACEMENTS[819]),
+    ('ϓ', REPLACEMENTS[750]),
+    ('ϔ', REPLACEMENTS[750]),
+    ('Ѐ', REPLACEMENTS[829]),
+    ('Ё', REPLACEMENTS[829]),
+    ('Ѓ', REPLACEMENTS[828]),
+    ('Ї', REPLACEMENTS[826]),
+    ('Ќ', REPLACEMENTS[833]),
+    ('Ѝ', REPLACEMENTS[832]),
+    ('Ў', REPLACEMENTS[835]),
+    ('Й', REPLACEMENTS[832]),
+    ('й', REPLACEMENTS[847]),
+    ('ѐ', REPLACEMENTS[844]),
+    ('ё', REPLACEMENTS[844]),
+    ('ѓ', REPLACEMENTS[842]),
+    ('ї', REPLACEMENTS[869]),
+    ('ќ', REPLACEMENTS[848]),
+    ('ѝ', REPLACEMENTS[847]),
+    ('ў', REPLACEMENTS[857]),
+    ('Ѷ', REPLACEMENTS[872]),
+    ('ѷ', REPLACEMENTS[873]),
+    ('Ӂ', REPLACEMENTS[830]),
+    ('ӂ', REPLACEMENTS[845]),
+    ('Ӑ', REPLACEMENTS[827]),
+    ('ӑ', REPLACEMENTS[839]),
+    ('Ӓ', REPLACEMENTS[827]),
+    ('ӓ', REPLACEMENTS[839]),
+    ('Ӗ', REPLACEMENTS[829]),
+    ('ӗ', REPLACEMENTS[844]),
+    ('Ӛ', REPLACEMENTS[879]),
+    ('ӛ', REPLACEMENTS[880]),
+    ('Ӝ', REPLACEMENTS[830]),
+    ('ӝ', REPLACEMENTS[845]),
+    ('Ӟ', REPLACEMENTS[831]),
+    ('ӟ', REPLACEMENTS[846]),
+    ('Ӣ', REPLACEMENTS[832]),
+    ('ӣ', REPLACEMENTS[847]),
+    ('Ӥ', REPLACEMENTS[832]),
+    ('ӥ', REPLACEMENTS[847]),
+    ('Ӧ', REPLACEMENTS[834]),
+    ('ӧ', REPLACEMENTS[852]),
+    ('Ӫ', REPLACEMENTS[881]),
+    ('ӫ', REPLACEMENTS[882]),
+    ('Ӭ', REPLACEMENTS[838]),
+    ('ӭ', REPLACEMENTS[866]),
+    ('Ӯ', REPLACEMENTS[835]),
+    ('ӯ', REPLACEMENTS[857]),
+    ('Ӱ', REPLACEMENTS[835]),
+    ('ӱ', REPLACEMENTS[857]),
+    ('Ӳ', REPLACEMENTS[835]),
+    ('ӳ', REPLACEMENTS[857]),
+    ('Ӵ', REPLACEMENTS[836]),
+    ('ӵ', REPLACEMENTS[861]),
+    ('Ӹ', REPLACEMENTS[837]),
+    ('ӹ', REPLACEMENTS[864]),
+    ('آ', REPLACEMENTS[922]),
+    ('أ', REPLACEMENTS[922]),
+    ('ؤ', REPLACEMENTS[1183]),
+    ('إ', REPLACEMENTS[922]),
+    ('ئ', REPLACEMENTS[1187]),
+    ('ۀ', REPLACEMENTS[1251]),
+    ('ۂ', REPLACEMENTS[1240]),
+    ('ۓ', REPLACEMENTS[1250]),
+    ('ऩ', REPLACEMENTS[1258]),
+    ('ऱ', REPLACEMENTS[1261]),
+    ('ऴ', REPLACEMENTS[1262]),
+    ('क़', REPLACEMENTS[1252]),
+    ('ख़', REPLACEMENTS[1253]),
+    ('ग़', REPLACEMENTS[1254]),
+    ('ज़', REPLACEMENTS[1255]),
+    ('ड़', REPLACEMENTS[1256]),
+    ('ढ़', REPLACEMENTS[1257]),
+    ('फ़', REPLACEMENTS[1259]),
+    ('य़', REPLACEMENTS[1260]),
+    ('ড়', REPLACEMENTS[1263]),
+    ('ঢ়', REPLACEMENTS[1264]),
+    ('য়', REPLACEMENTS[1265]),
+    ('ਲ਼', REPLACEMENTS[1270]),
+    ('ਸ਼', REPLACEMENTS[1271]),
+    ('ਖ਼', REPLACEMENTS[1266]),
+    ('ਗ਼', REPLACEMENTS[1267]),
+    ('ਜ਼', REPLACEMENTS[1268]),
+    ('ਫ਼', REPLACEMENTS[1269]),
+    ('ୈ', REPLACEMENTS[1274]),
+    ('ଡ଼', REPLACEMENTS[1272]),
+    ('ଢ଼', REPLACEMENTS[1273]),
+    ('\u{cc0}', REPLACEMENTS[1277]),
+    ('\u{cc7}', REPLACEMENTS[1277]),
+    ('\u{cc8}', REPLACEMENTS[1278]),
+    ('\u{cca}', REPLACEMENTS[1275]),
+    ('\u{ccb}', REPLACEMENTS[1276]),
+    ('ේ', REPLACEMENTS[1279]),
+    ('ෝ', REPLACEMENTS[1280]),
+    ('ำ', REPLACEMENTS[1281]),
+    ('ຳ', REPLACEMENTS[1284]),
+    ('གྷ', REPLACEMENTS[1287]),
+    ('ཌྷ', REPLACEMENTS[1288]),
+    ('དྷ', REPLACEMENTS[1289]),
+    ('བྷ', REPLACEMENTS[1290]),
+    ('ཛྷ', REPLACEMENTS[1291]),
+    ('ཀྵ', REPLACEMENTS[1286]),
+    ('ဦ', REPLACEMENTS[1292]),
+    ('\u{1b3b}', REPLACEMENTS[1405]),
+    ('\u{1b3d}', REPLACEMENTS[1405]),
+    ('\u{1b43}', REPLACEMENTS[1405]),
+    ('Ḁ', REPLACEMENTS[364]),
+    ('ḁ', REPLACEMENTS[474]),
+    ('Ḃ', REPLACEMENTS[368]),
+    ('ḃ', REPLACEMENTS[479]),
+    ('Ḅ', REPLACEMENTS[368]),
+    ('ḅ', REPLACEMENTS[479]),
+    ('Ḇ', REPLACEMENTS[368]),
+    ('ḇ', REPLACEMENTS[479]),
+    ('Ḉ', REPLACEMENTS[370]),
+    ('ḉ', REPLACEMENTS[481]),
+    ('Ḋ', REPLACEMENTS[374]),
+    ('ḋ', REPLACEMENTS[490]),
+    ('Ḍ', REPLACEMENTS[374]),
+    ('ḍ', REPLACEMENTS[490]),
+    ('Ḏ', REPLACEMENTS[374]),
+    ('ḏ', REPLACEMENTS[490]),
+    ('Ḑ', REPLACEMENTS[374]),
+    ('ḑ', REPLACEMENTS[490]),
+    ('Ḓ', REPLACEMENTS[374]),
+    ('ḓ', REPLACEMENTS[490]),
+    ('Ḕ', REPLACEMENTS[378]),
+    ('ḕ', REPLACEMENTS[498]),
+    ('Ḗ', REPLACEMENTS[378]),
+    ('ḗ', REPLACEMENTS[498]),
+    ('Ḙ', REPLACEMENTS[378]),
+    ('ḙ', REPLACEMENTS[498]),
+    ('Ḛ', REPLACEMENTS[378]),
+    ('ḛ', REPLACEMENTS[498]),
+    ('Ḝ', REPLACEMENTS[378]),
+    ('ḝ', REPLACEMENTS[498]),
+    ('Ḟ', REPLACEMENTS[379]),
+    ('ḟ', REPLACEMENTS[501]),
+    ('Ḡ', REPLACEMENTS[386]),
+    ('ḡ', REPLACEMENTS[508]),
+    ('Ḣ', REPLACEMENTS[391]),
+    ('ḣ', REPLACEMENTS[510]),
+    ('Ḥ', REPLACEMENTS[391]),
+    ('ḥ', REPLACEMENTS[510]),
+    ('Ḧ', REPLACEMENTS[391]),
+    ('ḧ', REPLACEMENTS[510]),
+    ('Ḩ', REPLACEMENTS[391]),
+    ('ḩ', REPLACEMENTS[510]),
+    ('Ḫ', REPLACEMENTS[391]),
+    ('ḫ', REPLACEMENTS[510]),
+    ('Ḭ', REPLACEMENTS[397]),
+    ('ḭ', REPLACEMENTS[514]),
+    ('Ḯ', REPLACEMENTS[397]),
+    ('ḯ', REPLACEMENTS[514]),
+    ('Ḱ', REPLACEMENTS[406]),
+    ('ḱ', REPLACEMENTS[523]),
+    ('Ḳ', REPLACEMENTS[406]),
+    ('ḳ', REPLACEMENTS[523]),
+    ('Ḵ', REPLACEMENTS[406]),
+    ('ḵ', REPLACEMENTS[523]),
+    ('Ḷ', REPLACEMENTS[410]),
+    ('ḷ', REPLACEMENTS[537]),
+    ('Ḹ', REPLACEMENTS[410]),
+    ('ḹ', REPLACEMENTS[537]),
+    ('Ḻ', REPLACEMENTS[410]),
+    ('ḻ', REPLACEMENTS[537]),
+    ('Ḽ', REPLACEMENTS[410]),
+    ('ḽ', REPLACEMENTS[537]),
+    ('Ḿ', REPLACEMENTS[415]),
+    ('ḿ', REPLACEMENTS[544]),
+    ('Ṁ', REPLACEMENTS[415]),
+    ('ṁ', REPLACEMENTS[544]),
+    ('Ṃ', REPLACEMENTS[415]),
+    ('ṃ', REPLACEMENTS[544]),
+    ('Ṅ', REPLACEMENTS[425]),
+    ('ṅ', REPLACEMENTS[561]),
+    ('Ṇ', REPLACEMENTS[425]),
+    ('ṇ', REPLACEMENTS[561]),
+    ('Ṉ', REPLACEMENTS[425]),
+    ('ṉ', REPLACEMENTS[561]),
+    ('Ṋ', REPLACEMENTS[425]),
+    ('ṋ', REPLACEMENTS[561]),
+    ('Ṍ', REPLACEMENTS[429]),
+    ('ṍ', REPLACEMENTS[569]),
+    ('Ṏ', REPLACEMENTS[429]),
+    ('ṏ', REPLACEMENTS[569]),
+    ('Ṑ', REPLACEMENTS[429]),
+    ('ṑ', REPLACEMENTS[569]),
+    ('Ṓ', REPLACEMENTS[429]),
+    ('ṓ', REPLACEMENTS[569]),
+    ('Ṕ', REPLACEMENTS[430]),
+    ('ṕ', REPLACEMENTS[571]),
+    ('Ṗ', REPLACEMENTS[430]),
+    ('ṗ', REPLACEMENTS[571]),
+    ('Ṙ', REPLACEMENTS[438]),
+    ('ṙ', REPLACEMENTS[580]),
+    ('Ṛ', REPLACEMENTS[438]),
+    ('ṛ', REPLACEMENTS[580]),
+    ('Ṝ', REPLACEMENTS[438]),
+    ('ṝ', REPLACEMENTS[580]),
+    ('Ṟ', REPLACEMENTS[438]),
+    ('ṟ', REPLACEMENTS[580]),
+    ('Ṡ', REPLACEMENTS[440]),
+    ('ṡ', REPLACEMENTS[584]),
+    ('Ṣ', REPLACEMENTS[440]),
+    ('ṣ', REPLACEMENTS[584]),
+    ('Ṥ', REPLACEMENTS[440]),
+    ('ṥ', REPLACEMENTS[584]),
+    ('Ṧ', REPLACEMENTS[440]),
+    ('ṧ', REPLACEMENTS[584]),
+    ('Ṩ', REPLACEMENTS[440]),
+    ('ṩ', REPLACEMENTS[584]),
+    ('Ṫ', REPLACEMENTS[446]),
+    ('ṫ', REPLACEMENTS[588]),
+    ('Ṭ', REPLACEMENTS[446]),
+    ('ṭ', REPLACEMENTS[588]),
+    ('Ṯ', REPLACEMENTS[446]),
+    ('ṯ', REPLACEMENTS[588]),
+    ('Ṱ', REPLACEMENTS[446]),
+    ('ṱ', REPLACEMENTS[588]),
+    ('Ṳ', REPLACEMENTS[451]),
+    ('ṳ', REPLACEMENTS[590]),
+    ('Ṵ', REPLACEMENTS[451]),
+    ('ṵ', REPLACEMENTS[590]),
+    ('Ṷ', REPLACEMENTS[451]),
+    ('ṷ', REPLACEMENTS[590]),
+    ('Ṹ', REPLACEMENTS[451]),
+    ('ṹ', REPLACEMENTS[590]),
+    ('Ṻ', REPLACEMENTS[451]),
+    ('ṻ', REPLACEMENTS[590]),
+    ('Ṽ', REPLACEMENTS[452]),
+    ('ṽ', REPLACEMENTS[591]),
+    ('Ṿ', REPLACEMENTS[452]),
+    ('ṿ', REPLACEMENTS[591]),
+    ('Ẁ', REPLACEMENTS[457]),
+    ('ẁ', REPLACEMENTS[595]),
+    ('Ẃ', REPLACEMENTS[457]),
+    ('ẃ', REPLACEMENTS[595]),
+    ('Ẅ', REPLACEMENTS[457]),
+    ('ẅ', REPLACEMENTS[595]),
+    ('Ẇ', REPLACEMENTS[457]),
+    ('ẇ', REPLACEMENTS[595]),
+    ('Ẉ', REPLACEMENTS[457]),
+    ('ẉ', REPLACEMENTS[595]),
+    ('Ẋ', REPLACEMENTS[462]),
+    ('ẋ', REPLACEMENTS[597]),
+    ('Ẍ', REPLACEMENTS[462]),
+    ('ẍ', REPLACEMENTS[597]),
+    ('Ẏ', REPLACEMENTS[465]),
+    ('ẏ', REPLACEMENTS[600]),
+    ('Ẑ', REPLACEMENTS[467]),
+    ('ẑ', REPLACEMENTS[602]),
+    ('Ẓ', REPLACEMENTS[467]),
+    ('ẓ', REPLACEMENTS[602]),
+    ('Ẕ', REPLACEMENTS[467]),
+    ('ẕ', REPLACEMENTS[602]),
+    ('ẖ', REPLACEMENTS[510]),
+    ('ẗ', REPLACEMENTS[588]),
+    ('ẘ', REPLACEMENTS[595]),
+    ('ẙ', REPLACEMENTS[600]),
+    ('ẛ', REPLACEMENTS[584]),
+    ('Ạ', REPLACEMENTS[364]),
+    ('ạ', REPLACEMENTS[474]),
+    ('Ả', REPLACEMENTS[364]),
+    ('ả', REPLACEMENTS[474]),
+    ('Ấ', REPLACEMENTS[364]),
+    ('ấ', REPLACEMENTS[474]),
+    ('Ầ', REPLACEMENTS[364]),
+    ('ầ', REPLACEMENTS[474]),
+    ('Ẩ', REPLACEMENTS[364]),
+    ('ẩ', REPLACEMENTS[474]),
+    ('Ẫ', REPLACEMENTS[364]),
+    ('ẫ', REPLACEMENTS[474]),
+    ('Ậ', REPLACEMENTS[364]),
+    ('ậ', REPLACEMENTS[474]),
+    ('Ắ', REPLACEMENTS[364]),
+    ('ắ', REPLACEMENTS[474]),
+    ('Ằ', REPLACEMENTS[364]),
+    ('ằ', REPLACEMENTS[474]),
+    ('Ẳ', REPLACEMENTS[364]),
+    ('ẳ', REPLACEMENTS[474]),
+    ('Ẵ', REPLACEMENTS[364]),
+    ('ẵ', REPLACEMENTS[474]),
+    ('Ặ', REPLACEMENTS[364]),
+    ('ặ', REPLACEMENTS[474]),
+    ('Ẹ', REPLACEMENTS[378]),
+    ('ẹ', REPLACEMENTS[498]),
+    ('Ẻ', REPLACEMENTS[378]),
+    ('ẻ', REPLACEMENTS[498]),
+    ('Ẽ', REPLACEMENTS[378]),
+    ('ẽ', REPLACEMENTS[498]),
+    ('Ế', REPLACEMENTS[378]),
+    ('ế', REPLACEMENTS[498]),
+    ('Ề', REPLACEMENTS[378]),
+    ('ề', REPLACEMENTS[498]),
+    ('Ể', REPLACEMENTS[378]),
+    ('ể', REPLACEMENTS[498]),
+    ('Ễ', REPLACEMENTS[378]),
+    ('ễ', REPLACEMENTS[498]),
+    ('Ệ', REPLACEMENTS[378]),
+    ('ệ', REPLACEMENTS[498]),
+    ('Ỉ', REPLACEMENTS[397]),
+    ('ỉ', REPLACEMENTS[514]),
+    ('Ị', REPLACEMENTS[397]),
+    ('ị', REPLACEMENTS[514]),
+    ('Ọ', REPLACEMENTS[429]),
+    ('ọ', REPLACEMENTS[569]),
+    ('Ỏ', REPLACEMENTS[429]),
+    ('ỏ', REPLACEMENTS[569]),
+    ('Ố', REPLACEMENTS[429]),
+    ('ố', REPLACEMENTS[569]),
+    ('Ồ', REPLACEMENTS[429]),
+    ('ồ', REPLACEMENTS[569]),
+    ('Ổ', REPLACEMENTS[429]),
+    ('ổ', REPLACEMENTS[569]),
+    ('Ỗ', REPLACEMENTS[429]),
+    ('ỗ', REPLACEMENTS[569]),
+    ('Ộ', REPLACEMENTS[429]),
+    ('ộ', REPLACEMENTS[569]),
+    ('Ớ', REPLACEMENTS[429]),
+    ('ớ', REPLACEMENTS[569]),
+    ('Ờ', REPLACEMENTS[429]),
+    ('ờ', REPLACEMENTS[569]),
+    ('Ở', REPLACEMENTS[429]),
+    ('ở', REPLACEMENTS[569]),
+    ('Ỡ', REPLACEMENTS[429]),
+    ('ỡ', REPLACEMENTS[569]),
+    ('Ợ', REPLACEMENTS[429]),
+    ('ợ', REPLACEMENTS[569]),
+    ('Ụ', REPLACEMENTS[451]),
+    ('ụ', REPLACEMENTS[590]),
+    ('Ủ', REPLACEMENTS[451]),
+    ('ủ', REPLACEMENTS[590]),
+    ('Ứ', REPLACEMENTS[451]),
+    ('ứ', REPLACEMENTS[590]),
+    ('Ừ', REPLACEMENTS[451]),
+    ('ừ', REPLACEMENTS[590]),
+    ('Ử', REPLACEMENTS[451]),
+    ('ử', REPLACEMENTS[590]),
+    ('Ữ', REPLACEMENTS[451]),
+    ('ữ', REPLACEMENTS[590]),
+    ('Ự', REPLACEMENTS[451]),
+    ('ự', REPLACEMENTS[590]),
+    ('Ỳ', REPLACEMENTS[465]),
+    ('ỳ', REPLACEMENTS[600]),
+    ('Ỵ', REPLACEMENTS[465]),
+    ('ỵ', REPLACEMENTS[600]),
+    ('Ỷ', REPLACEMENTS[465]),
+    ('ỷ', REPLACEMENTS[600]),
+    ('Ỹ', REPLACEMENTS[465]),
+    ('ỹ', REPLACEMENTS[600]),
+    ('ἀ', REPLACEMENTS[768]),
+    ('ἁ', REPLACEMENTS[768]),
+    ('ἂ', REPLACEMENTS[768]),
+    ('ἃ', REPLACEMENTS[768]),
+    ('ἄ', REPLACEMENTS[768]),
+    ('ἅ', REPLACEMENTS[768]),
+    ('ἆ', REPLACEMENTS[768]),
+    ('ἇ', REPLACEMENTS[768]),
+    ('Ἀ', REPLACEMENTS[720]),
+    ('Ἁ', REPLACEMENTS[720]),
+    ('Ἂ', REPLACEMENTS[720]),
+    ('Ἃ', REPLACEMENTS[720]),
+    ('Ἄ', REPLACEMENTS[720]),
+    ('Ἅ', REPLACEMENTS[720]),
+    ('Ἆ', REPLACEMENTS[720]),
+    ('Ἇ', REPLACEMENTS[720]),
+    ('ἐ', REPLACEMENTS[775]),
+    ('ἑ', REPLACEMENTS[775]),
+    ('ἒ', REPLACEMENTS[775]),
+    ('ἓ', REPLACEMENTS[775]),
+    ('ἔ', REPLACEMENTS[775]),
+    ('ἕ', REPLACEMENTS[775]),
+    ('Ἐ', REPLACEMENTS[727]),
+    ('Ἑ', REPLACEMENTS[727]),
+    ('Ἒ', REPLACEMENTS[727]),
+    ('Ἓ', REPLACEMENTS[727]),
+    ('Ἔ', REPLACEMENTS[727]),
+    ('Ἕ', REPLACEMENTS[727]),
+    ('ἠ', REPLACEMENTS[777]),
+    ('ἡ', REPLACEMENTS[777]),
+    ('ἢ', REPLACEMENTS[777]),
+    ('ἣ', REPLACEMENTS[777]),
+    ('ἤ', REPLACEMENTS[777]),
+    ('ἥ', REPLACEMENTS[777]),
+    ('ἦ', REPLACEMENTS[777]),
+    ('ἧ', REPLACEMENTS[777]),
+    ('Ἠ', REPLACEMENTS[729]),
+    ('Ἡ', REPLACEMENTS[729]),
+    ('Ἢ', REPLACEMENTS[729]),
+    ('Ἣ', REPLACEMENTS[729]),
+    ('Ἤ', REPLACEMENTS[729]),
+    ('Ἥ', REPLACEMENTS[729]),
+    ('Ἦ', REPLACEMENTS[729]),
+    ('Ἧ', REPLACEMENTS[729]),
+    ('ἰ', REPLACEMENTS[782]),
+    ('ἱ', REPLACEMENTS[782]),
+    ('ἲ', REPLACEMENTS[782]),
+    ('ἳ', REPLACEMENTS[782]),
+    ('ἴ', REPLACEMENTS[782]),
+    ('ἵ', REPLACEMENTS[782]),
+    ('ἶ', REPLACEMENTS[782]),
+    ('ἷ', REPLACEMENTS[782]),
+    ('Ἰ', REPLACEMENTS[734]),
+    ('Ἱ', REPLACEMENTS[734]),
+    ('Ἲ', REPLACEMENTS[734]),
+    ('Ἳ', REPLACEMENTS[734]),
+    ('Ἴ', REPLACEMENTS[734]),
+    ('Ἵ', REPLACEMENTS[734]),
+    ('Ἶ', REPLACEMENTS[734]),
+    ('Ἷ', REPLACEMENTS[734]),
+    ('ὀ', REPLACEMENTS[800]),
+    ('ὁ', REPLACEMENTS[800]),
+    ('ὂ', REPLACEMENTS[800]),
+    ('ὃ', REPLACEMENTS[800]),
+    ('ὄ', REPLACEMENTS[800]),
+    ('ὅ', REPLACEMENTS[800]),
+    ('Ὀ', REPLACEMENTS[744]),
+    ('Ὁ', REPLACEMENTS[744]),
+    ('Ὂ', REPLACEMENTS[744]),
+    ('Ὃ', REPLACEMENTS[744]),
+    ('Ὄ', REPLACEMENTS[744]),
+    ('Ὅ', REPLACEMENTS[744]),
+    ('ὐ', REPLACEMENTS[807]),
+    ('ὑ', REPLACEMENTS[807]),
+    ('ὒ', REPLACEMENTS[807]),
+    ('ὓ', REPLACEMENTS[807]),
+    ('ὔ', REPLACEMENTS[807]),
+    ('ὕ', REPLACEMENTS[807]),
+    ('ὖ', REPLACEMENTS[807]),
+    ('ὗ', REPLACEMENTS[807]),
+    ('Ὑ', REPLACEMENTS[750]),
+    ('Ὓ', REPLACEMENTS[750]),
+    ('Ὕ', REPLACEMENTS[750]),
+    ('Ὗ', REPLACEMENTS[750]),
+    ('ὠ', REPLACEMENTS[819]),
+    ('ὡ', REPLACEMENTS[819]),
+    ('ὢ', REPLACEMENTS[819]),
+    ('ὣ', REPLACEMENTS[819]),
+    ('ὤ', REPLACEMENTS[819]),
+    ('ὥ', REPLACEMENTS[819]),
+    ('ὦ', REPLACEMENTS[819]),
+    ('ὧ', REPLACEMENTS[819]),
+    ('Ὠ', REPLACEMENTS[762]),
+    ('Ὡ', REPLACEMENTS[762]),
+    ('Ὢ', REPLACEMENTS[762]),
+    ('Ὣ', REPLACEMENTS[762]),
+    ('Ὤ', REPLACEMENTS[762]),
+    ('Ὥ', REPLACEMENTS[762]),
+    ('Ὦ', REPLACEMENTS[762]),
+    ('Ὧ', REPLACEMENTS[762]),
+    ('ὰ', REPLACEMENTS[768]),
+    ('ά', REPLACEMENTS[768]),
+    ('ὲ', REPLACEMENTS[775]),
+    ('έ', REPLACEMENTS[775]),
+    ('ὴ', REPLACEMENTS[777]),
+    ('ή', REPLACEMENTS[777]),
+    ('ὶ', REPLACEMENTS[782]),
+    ('ί', REPLACEMENTS[782]),
+    ('ὸ', REPLACEMENTS[800]),
+    ('ό', REPLACEMENTS[800]),
+    ('ὺ', REPLACEMENTS[807]),
+    ('ύ', REPLACEMENTS[807]),
+    ('ὼ', REPLACEMENTS[819]),
+    ('ώ', REPLACEMENTS[819]),
+    ('ᾀ', REPLACEMENTS[768]),
+    ('ᾁ', REPLACEMENTS[768]),
+    ('ᾂ', REPLACEMENTS[768]),
+    ('ᾃ', REPLACEMENTS[768]),
+    ('ᾄ', REPLACEMENTS[768]),
+    ('ᾅ', REPLACEMENTS[768]),
+    ('ᾆ', REPLACEMENTS[768]),
+    ('ᾇ', REPLACEMENTS[768]),
+    ('ᾈ', REPLACEMENTS[720]),
+    ('ᾉ', REPLACEMENTS[720]),
+    ('ᾊ', REPLACEMENTS[720]),
+    ('ᾋ', REPLACEMENTS[720]),
+    ('ᾌ', REPLACEMENTS[720]),
+    ('ᾍ', REPLACEMENTS[720]),
+    ('ᾎ', REPLACEMENTS[720]),
+    ('ᾏ', REPLACEMENTS[720]),
+    ('ᾐ', REPLACEMENTS[777]),
+    ('ᾑ', REPLACEMENTS[777]),
+    ('ᾒ', REPLACEMENTS[777]),
+    ('ᾓ', REPLACEMENTS[777]),
+    ('ᾔ', REPLACEMENTS[777]),
+    ('ᾕ', REPLACEMENTS[777]),
+    ('ᾖ', REPLACEMENTS[777]),
+    ('ᾗ', REPLACEMENTS[777]),
+    ('ᾘ', REPLACEMENTS[729]),
+    ('ᾙ', REPLACEMENTS[729]),
+    ('ᾚ', REPLACEMENTS[729]),
+    ('ᾛ', REPLACEMENTS[729]),
+    ('ᾜ', REPLACEMENTS[729]),
+    ('ᾝ', REPLACEMENTS[729]),
+    ('ᾞ', REPLACEMENTS[729]),
+    ('ᾟ', REPLACEMENTS[729]),
+    ('ᾠ', REPLACEMENTS[819]),
+    ('ᾡ', REPLACEMENTS[819]),
+    ('ᾢ', REPLACEMENTS[819]),
+    ('ᾣ', REPLACEMENTS[819]),
+    ('ᾤ', REPLACEMENTS[819]),
+    ('ᾥ', REPLACEMENTS[819]),
+    ('ᾦ', REPLACEMENTS[819]),
+    ('ᾧ', REPLACEMENTS[819]),
+    ('ᾨ', REPLACEMENTS[762]),
+    ('ᾩ', REPLACEMENTS[762]),
+    ('ᾪ', REPLACEMENTS[762]),
+    ('ᾫ', REPLACEMENTS[762]),
+    ('ᾬ', REPLACEMENTS[762]),
+    ('ᾭ', REPLACEMENTS[762]),
+    ('ᾮ', REPLACEMENTS[762]),
+    ('ᾯ', REPLACEMENTS[762]),
+    ('ᾰ', REPLACEMENTS[768]),
+    ('ᾱ', REPLACEMENTS[768]),
+    ('ᾲ', REPLACEMENTS[768]),
+    ('ᾳ', REPLACEMENTS[768]),
+    ('ᾴ', REPLACEMENTS[768]),
+    ('ᾶ', REPLACEMENTS[768]),
+    ('ᾷ', REPLACEMENTS[768]),
+    ('Ᾰ', REPLACEMENTS[720]),
+    ('Ᾱ', REPLACEMENTS[720]),
+    ('Ὰ', REPLACEMENTS[720]),
+    ('Ά', REPLACEMENTS[720]),
+    ('ᾼ', REPLACEMENTS[720]),
+    ('᾽', REPLACEMENTS[1]),
+    ('ι', REPLACEMENTS[782]),
+    ('᾿', REPLACEMENTS[1]),
+    ('῀', REPLACEMENTS[1]),
+    ('῁', REPLACEMENTS[1]),
+    ('ῂ', REPLACEMENTS[777]),
+    ('ῃ', REPLACEMENTS[777]),
+    ('ῄ', REPLACEMENTS[777]),
+    ('ῆ', REPLACEMENTS[777]),
+    ('ῇ', REPLACEMENTS[777]),
+    ('Ὲ', REPLACEMENTS[727]),
+    ('Έ', REPLACEMENTS[727]),
+    ('Ὴ', REPLACEMENTS[729]),
+    ('Ή', REPLACEMENTS[729]),
+    ('ῌ', REPLACEMENTS[729]),
+    ('῍', REPLACEMENTS[1]),
+    ('῎', REPLACEMENTS[1]),
+    ('῏', REPLACEMENTS[1]),
+    ('ῐ', REPLACEMENTS[782]),
+    ('ῑ', REPLACEMENTS[782]),
+    ('ῒ', REPLACEMENTS[782]),
+    ('ΐ', REPLACEMENTS[782]),
+    ('ῖ', REPLACEMENTS[782]),
+    ('ῗ', REPLACEMENTS[782]),
+    ('Ῐ', REPLACEMENTS[734]),
+    ('Ῑ', REPLACEMENTS[734]),
+    ('Ὶ', REPLACEMENTS[734]),
+    ('Ί', REPLACEMENTS[734]),
+    ('῝', REPLACEMENTS[1]),
+    ('῞', REPLACEMENTS[1]),
+    ('῟', REPLACEMENTS[1]),
+    ('ῠ', REPLACEMENTS[807]),
+    ('ῡ', REPLACEMENTS[807]),
+    ('ῢ', REPLACEMENTS[807]),
+    ('ΰ', REPLACEMENTS[807]),
+    ('ῤ', REPLACEMENTS[802]),
+    ('ῥ', REPLACEMENTS[802]),
+    ('ῦ', REPLACEMENTS[807]),
+    ('ῧ', REPLACEMENTS[807]),
+    ('Ῠ', REPLACEMENTS[750]),
+    ('Ῡ', REPLACEMENTS[750]),
+    ('Ὺ', REPLACEMENTS[750]),
+    ('Ύ', REPLACEMENTS[750]),
+    ('Ῥ', REPLACEMENTS[746]),
+    ('῭', REPLACEMENTS[1]),
+    ('΅', REPLACEMENTS[1]),
+    ('`', REPLACEMENTS[473]),
+    ('ῲ', REPLACEMENTS[819]),
+    ('ῳ', REPLACEMENTS[819]),
+    ('ῴ', REPLACEMENTS[819]),
+    ('ῶ', REPLACEMENTS[819]),
+    ('ῷ', REPLACEMENTS[819]),
+    ('Ὸ', REPLACEMENTS[744]),
+    ('Ό', REPLACEMENTS[744]),
+    ('Ὼ', REPLACEMENTS[762]),
+    ('Ώ', REPLACEMENTS[762]),
+    ('ῼ', REPLACEMENTS[762]),
+    ('´', REPLACEMENTS[1]),
+    ('῾', REPLACEMENTS[1]),
+    ('‗', REPLACEMENTS[1]),
+    ('‾', REPLACEMENTS[1]),
+    ('Ω', REPLACEMENTS[762]),
+    ('K', REPLACEMENTS[406]),
+    ('Å', REPLACEMENTS[364]),
+    ('↚', REPLACEMENTS[1478]),
+    ('↛', REPLACEMENTS[1480]),
+    ('↮', REPLACEMENTS[1482]),
+    ('⇍', REPLACEMENTS[1483]),
+    ('⇎', REPLACEMENTS[1485]),
+    ('⇏', REPLACEMENTS[1484]),
+    ('∄', REPLACEMENTS[1487]),
+    ('∉', REPLACEMENTS[1489]),
+    ('∌', REPLACEMENTS[1490]),
+    ('∤', REPLACEMENTS[1493]),
+    ('∦', REPLACEMENTS[1494]),
+    ('≁', REPLACEMENTS[1500]),
+    ('≄', REPLACEMENTS[1501]),
+    ('≇', REPLACEMENTS[1502]),
+    ('≉', REPLACEMENTS[1503]),
+    ('≠', REPLACEMENTS[356]),
+    ('≢', REPLACEMENTS[1505]),
+    ('≭', REPLACEMENTS[1504]),
+    ('≮', REPLACEMENTS[355]),
+    ('≯', REPLACEMENTS[359]),
+    ('≰', REPLACEMENTS[1506]),
+    ('≱', REPLACEMENTS[1507]),
+    ('≴', REPLACEMENTS[1508]),
+    ('≵', REPLACEMENTS[1509]),
+    ('≸', REPLACEMENTS[1510]),
+    ('≹', REPLACEMENTS[1511]),
+    ('⊀', REPLACEMENTS[1512]),
+    ('⊁', REPLACEMENTS[1513]),
+    ('⊄', REPLACEMENTS[1516]),
+    ('⊅', REPLACEMENTS[1517]),
+    ('⊈', REPLACEMENTS[1518]),
+    ('⊉', REPLACEMENTS[1519]),
+    ('⊬', REPLACEMENTS[1522]),
+    ('⊭', REPLACEMENTS[1523]),
+    ('⊮', REPLACEMENTS[1524]),
+    ('⊯', REPLACEMENTS[1525]),
+    ('⋠', REPLACEMENTS[1514]),
+    ('⋡', REPLACEMENTS[1515]),
+    ('⋢', REPLACEMENTS[1520]),
+    ('⋣', REPLACEMENTS[1521]),
+    ('⋪', REPLACEMENTS[1526]),
+    ('⋫', REPLACEMENTS[1527]),
+    ('⋬', REPLACEMENTS[1528]),
+    ('⋭', REPLACEMENTS[1529]),
+    ('〈', REPLACEMENTS[1540]),
+    ('〉', REPLACEMENTS[1541]),
+    ('⫝̸', REPLACEMENTS[1535]),
+    ('豈', REPLACEMENTS[2606]),
+    ('更', REPLACEMENTS[2190]),
+    ('車', REPLACEMENTS[2629]),
+    ('賈', REPLACEMENTS[2616]),
+    ('滑', REPLACEMENTS[2278]),
+    ('串', REPLACEMENTS[1797]),
+    ('句', REPLACEMENTS[1923]),
+    ('龜', REPLACEMENTS[2779]),
+    ('龜', REPLACEMENTS[2779]),
+    ('契', REPLACEMENTS[1999]),
+    ('金', REPLACEMENTS[2664]),
+    ('喇', REPLACEMENTS[1948]),
+    ('奈', REPLACEMENTS[1998]),
+    ('懶', REPLACEMENTS[2121]),
+    ('癩', REPLACEMENTS[2372]),
+    ('羅', REPLACEMENTS[2472]),
+    ('蘿', REPLACEMENTS[2550]),
+    ('螺', REPLACEMENTS[2565]),
+    ('裸', REPLACEMENTS[2578]),
+    ('邏', REPLACEMENTS[2647]),
+    ('樂', REPLACEMENTS[2221]),
+    ('洛', REPLACEMENTS[2256]),
+    ('烙', REPLACEMENTS[2299]),
+    ('珞', REPLACEMENTS[2337]),
+    ('落', REPLACEMENTS[2536]),
+    ('酪', REPLACEMENTS[2658]),
+    ('駱', REPLACEMENTS[2733]),
+    ('亂', REPLACEMENTS[1806]),
+    ('卵', REPLACEMENTS[1912]),
+    ('欄', REPLACEMENTS[2226]),
+    ('爛', REPLACEMENTS[2308]),
+    ('蘭', REPLACEMENTS[2549]),
+    ('鸞', REPLACEMENTS[2753]),
+    ('嵐', REPLACEMENTS[2041]),
+    ('濫', REPLACEMENTS[2286]),
+    ('藍', REPLACEMENTS[2545]),
+    ('襤', REPLACEMENTS[2582]),
+    ('拉', REPLACEMENTS[2134]),
+    ('臘', REPLACEMENTS[2495]),
+    ('蠟', REPLACEMENTS[2568]),
+    ('廊', REPLACEMENTS[2069]),
+    ('朗', REPLACEMENTS[2195]),
+    ('浪', REPLACEMENTS[2262]),
+    ('狼', REPLACEMENTS[2327]),
+    ('郎', REPLACEMENTS[2650]),
+    ('來', REPLACEMENTS[1823]),
+    ('冷', REPLACEMENTS[1861]),
+    ('勞', REPLACEMENTS[1888]),
+    ('擄', REPLACEMENTS[2160]),
+    ('櫓', REPLACEMENTS[2224]),
+    ('爐', REPLACEMENTS[2307]),
+    ('盧', REPLACEMENTS[2380]),
+    ('老', REPLACEMENTS[2478]),
+    ('蘆', REPLACEMENTS[2547]),
+    ('虜', REPLACEMENTS[2553]),
+    ('路', REPLACEMENTS[2626]),
+    ('露', REPLACEMENTS[2700]),
+    ('魯', REPLACEMENTS[2745]),
+    ('鷺', REPLACEMENTS[2752]),
+    ('碌', REPLACEMENTS[2395]),
+    ('祿', REPLACEMENTS[2411]),
+    ('綠', REPLACEMENTS[2457]),
+    ('菉', REPLACEMENTS[2529]),
+    ('錄', REPLACEMENTS[2671]),
+    ('鹿', REPLACEMENTS[2755]),
+    ('論', REPLACEMENTS[2595]),
+    ('壟', REPLACEMENTS[1981]),
+    ('弄', REPLACEMENTS[2076]),
+    ('籠', REPLACEMENTS[2439]),
+    ('聾', REPLACEMENTS[2487]),
+    ('牢', REPLACEMENTS[2320]),
+    ('磊', REPLACEMENTS[2397]),
+    ('賂', REPLACEMENTS[2614]),
+    ('雷', REPLACEMENTS[2698]),
+    ('壘', REPLACEMENTS[1980]),
+    ('屢', REPLACEMENTS[2032]),
+    ('樓', REPLACEMENTS[2222]),
+    ('淚', REPLACEMENTS[2267]),
+    ('漏', REPLACEMENTS[2280]),
+    ('累', REPLACEMENTS[2453]),
+    ('縷', REPLACEMENTS[2463]),
+    ('陋', REPLACEMENTS[2682]),
+    ('勒', REPLACEMENTS[1887]),
+    ('肋', REPLACEMENTS[2490]),
+    ('凜', REPLACEMENTS[1864]),
+    ('凌', REPLACEMENTS[1863]),
+    ('稜', REPLACEMENTS[2422]),
+    ('綾', REPLACEMENTS[2458]),
+    ('菱', REPLACEMENTS[2535]),
+    ('陵', REPLACEMENTS[2684]),
+    ('讀', REPLACEMENTS[2602]),
+    ('拏', REPLACEMENTS[2135]),
+    ('樂', REPLACEMENTS[2221]),
+    ('諾', REPLACEMENTS[2598]),
+    ('丹', REPLACEMENTS[1800]),
+    ('寧', REPLACEMENTS[2021]),
+    ('怒', REPLACEMENTS[2098]),
+    ('率', REPLACEMENTS[2332]),
+    ('異', REPLACEMENTS[2364]),
+    ('北', REPLACEMENTS[1896]),
+    ('磻', REPLACEMENTS[2399]),
+    ('便', REPLACEMENTS[1827]),
+    ('復', REPLACEMENTS[2091]),
+    ('不', REPLACEMENTS[1792]),
+    ('泌', REPLACEMENTS[2251]),
+    ('數', REPLACEMENTS[2166]),
+    ('索', REPLACEMENTS[2452]),
+    ('參', REPLACEMENTS[1917]),
+    ('塞', REPLACEMENTS[1976]),
+    ('省', REPLACEMENTS[2383]),
+    ('葉', REPLACEMENTS[2537]),
+    ('說', REPLACEMENTS[2591]),
+    ('殺', REPLACEMENTS[2238]),
+    ('辰', REPLACEMENTS[2638]),
+    ('沈', REPLACEMENTS[2249]),
+    ('拾', REPLACEMENTS[2139]),
+    ('若', REPLACEMENTS[2519]),
+    ('掠', REPLACEMENTS[2147]),
+    ('略', REPLACEMENTS[2363]),
+    ('亮', REPLACEMENTS[1813]),
+    ('兩', REPLACEMENTS[1845]),
+    ('凉', REPLACEMENTS[1862]),
+    ('梁', REPLACEMENTS[2213]),
+    ('糧', REPLACEMENTS[2447]),
+    ('良', REPLACEMENTS[2509]),
+    ('諒', REPLACEMENTS[2594]),
+    ('量', REPLACEMENTS[2663]),
+    ('勵', REPLACEMENTS[1890]),
+    ('呂', REPLACEMENTS[1935]),
+    ('女', REPLACEMENTS[2002]),
+    ('廬', REPLACEMENTS[2073]),
+    ('旅', REPLACEMENTS[2173]),
+    ('濾', REPLACEMENTS[2287]),
+    ('礪', REPLACEMENTS[2400]),
+    ('閭', REPLACEMENTS[2678]),
+    ('驪', REPLACEMENTS[2735]),
+    ('麗', REPLACEMENTS[2756]),
+    ('黎', REPLACEMENTS[2762]),
+    ('力', REPLACEMENTS[1881]),
+    ('曆', REPLACEMENTS[2188]),
+    ('歷', REPLACEMENTS[2233]),
+    ('轢', REPLACEMENTS[2635]),
+    ('年', REPLACEMENTS[2060]),
+    ('憐', REPLACEMENTS[2115]),
+    ('戀', REPLACEMENTS[2122]),
+    ('撚', REPLACEMENTS[2158]),
+    ('漣', REPLACEMENTS[2283]),
+    ('煉', REPLACEMENTS[2302]),
+    ('璉', REPLACEMENTS[2346]),
+    ('秊', REPLACEMENTS[2419]),
+    ('練', REPLACEMENTS[2460]),
+    ('聯', REPLACEMENTS[2485]),
+    ('輦', REPLACEMENTS[2631]),
+    ('蓮', REPLACEMENTS[2539]),
+    ('連', REPLACEMENTS[2641]),
+    ('鍊', REPLACEMENTS[2672]),
+    ('列', REPLACEMENTS[1871]),
+    ('劣', REPLACEMENTS[1882]),
+    ('咽', REPLACEMENTS[1940]),
+    ('烈', REPLACEMENTS[2298]),
+    ('裂', REPLACEMENTS[2573]),
+    ('說', REPLACEMENTS[2591]),
+    ('廉', REPLACEMENTS[2068]),
+    ('念', REPLACEMENTS[2096]),
+    ('捻', REPLACEMENTS[2145]),
+    ('殮', REPLACEMENTS[2236]),
+    ('簾', REPLACEMENTS[2438]),
+    ('獵', REPLACEMENTS[2329]),
+    ('令', REPLACEMENTS[1817]),
+    ('囹', REPLACEMENTS[1963]),
+    ('寧', REPLACEMENTS[2021]),
+    ('嶺', REPLACEMENTS[2046]),
+    ('怜', REPLACEMENTS[2099]),
+    ('玲', REPLACEMENTS[2336]),
+    ('瑩', REPLACEMENTS[2343]),
+    ('羚', REPLACEMENTS[2475]),
+    ('聆', REPLACEMENTS[2483]),
+    ('鈴', REPLACEMENTS[2665]),
+    ('零', REPLACEMENTS[2697]),
+    ('靈', REPLACEMENTS[2701]),
+    ('領', REPLACEMENTS[2716]),
+    ('例', REPLACEMENTS[1824]),
+    ('禮', REPLACEMENTS[2416]),
+    ('醴', REPLACEMENTS[2660]),
+    ('隸', REPLACEMENTS[2691]),
+    ('惡', REPLACEMENTS[2105]),
+    ('了', REPLACEMENTS[1808]),
+    ('僚', REPLACEMENTS[1833]),
+    ('寮', REPLACEMENTS[2022]),
+    ('尿', REPLACEMENTS[2030]),
+    ('料', REPLACEMENTS[2169]),
+    ('樂', REPLACEMENTS[2221]),
+    ('燎', REPLACEMENTS[2305]),
+    ('療', REPLACEMENTS[2371]),
+    ('蓼', REPLACEMENTS[2542]),
+    ('遼', REPLACEMENTS[2646]),
+    ('龍', REPLACEMENTS[2777]),
+    ('暈', REPLACEMENTS[2184]),
+    ('阮', REPLACEMENTS[2681]),
+    ('劉', REPLACEMENTS[1880]),
+    ('杻', REPLACEMENTS[2203]),
+    ('柳', REPLACEMENTS[2206]),
+    ('流', REPLACEMENTS[2260]),
+    ('溜', REPLACEMENTS[2274]),
+    ('琉', REPLACEMENTS[2339]),
+    ('留', REPLACEMENTS[2362]),
+    ('硫', REPLACEMENTS[2394]),
+    ('紐', REPLACEMENTS[2451]),
+    ('類', REPLACEMENTS[2719]),
+    ('六', REPLACEMENTS[1847]),
+    ('戮', REPLACEMENTS[2126]),
+    ('陸', REPLACEMENTS[2685]),
+    ('倫', REPLACEMENTS[1829]),
+    ('崙', REPLACEMENTS[2039]),
+    ('淪', REPLACEMENTS[2268]),
+    ('輪', REPLACEMENTS[2632]),
+    ('律', REPLACEMENTS[2087]),
+    ('慄', REPLACEMENTS[2107]),
+    ('栗', REPLACEMENTS[2208]),
+    ('率', REPLACEMENTS[2332]),
+    ('隆', REPLACEMENTS[2687]),
+    ('利', REPLACEMENTS[1873]),
+    ('吏', REPLACEMENTS[1931]),
+    ('履', REPLACEMENTS[2034]),
+    ('易', REPLACEMENTS[2179]),
+    ('李', REPLACEMENTS[2199]),
+    ('梨', REPLACEMENTS[2216]),
+    ('泥', REPLACEMENTS[2253]),
+    ('理', REPLACEMENTS[2338]),
+    ('痢', REPLACEMENTS[2367]),
+    ('罹', REPLACEMENTS[2470]),
+    ('裏', REPLACEMENTS[2574]),
+    ('裡', REPLACEMENTS[2577]),
+    ('里', REPLACEMENTS[2662]),
+    ('離', REPLACEMENTS[2694]),
+    ('匿', REPLACEMENTS[1900]),
+    ('溺', REPLACEMENTS[2275]),
+    ('吝', REPLACEMENTS[1932]),
+    ('燐', REPLACEMENTS[2306]),
+    ('璘', REPLACEMENTS[2347]),
+    ('藺', REPLACEMENTS[2546]),
+    ('隣', REPLACEMENTS[2688]),
+    ('鱗', REPLACEMENTS[2747]),
+    ('麟', REPLACEMENTS[2757]),
+    ('林', REPLACEMENTS[2205]),
+    ('淋', REPLACEMENTS[2266]),
+    ('臨', REPLACEMENTS[2497]),
+    ('立', REPLACEMENTS[2430]),
+    ('笠', REPLACEMENTS[2433]),
+    ('粒', REPLACEMENTS[2442]),
+    ('狀', REPLACEMENTS[2326]),
+    ('炙', REPLACEMENTS[2296]),
+    ('識', REPLACEMENTS[2601]),
+    ('什', REPLACEMENTS[1815]),
+    ('茶', REPLACEMENTS[2523]),
+    ('刺', REPLACEMENTS[1874]),
+    ('切', REPLACEMENTS[1870]),
+    ('度', REPLACEMENTS[2064]),
+    ('拓', REPLACEMENTS[2136]),
+    ('糖', REPLACEMENTS[2445]),
+    ('宅', REPLACEMENTS[2017]),
+    ('洞', REPLACEMENTS[2257]),
+    ('暴', REPLACEMENTS[2187]),
+    ('輻', REPLACEMENTS[2634]),
+    ('行', REPLACEMENTS[2570]),
+    ('降', REPLACEMENTS[2683]),
+    ('見', REPLACEMENTS[2585]),
+    ('廓', REPLACEMENTS[2071]),
+    ('兀', REPLACEMENTS[1837]),
+    ('嗀', REPLACEMENTS[1954]),
+    ('塚', REPLACEMENTS[1975]),
+    ('晴', REPLACEMENTS[2183]),
+    ('凞', REPLACEMENTS[1865]),
+    ('猪', REPLACEMENTS[2328]),
+    ('益', REPLACEMENTS[2377]),
+    ('礼', REPLACEMENTS[2402]),
+    ('神', REPLACEMENTS[2409]),
+    ('祥', REPLACEMENTS[2410]),
+    ('福', REPLACEMENTS[2415]),
+    ('靖', REPLACEMENTS[2703]),
+    ('精', REPLACEMENTS[2443]),
+    ('羽', REPLACEMENTS[2476]),
+    ('蘒', REPLACEMENTS[2548]),
+    ('諸', REPLACEMENTS[2597]),
+    ('逸', REPLACEMENTS[2642]),
+    ('都', REPLACEMENTS[2653]),
+    ('飯', REPLACEMENTS[2724]),
+    ('飼', REPLACEMENTS[2725]),
+    ('館', REPLACEMENTS[2726]),
+    ('鶴', REPLACEMENTS[2751]),
+    ('郞', REPLACEMENTS[2651]),
+    ('隷', REPLACEMENTS[2690]),
+    ('侮', REPLACEMENTS[1825]),
+    ('僧', REPLACEMENTS[1834]),
+    ('免', REPLACEMENTS[1839]),
+    ('勉', REPLACEMENTS[1886]),
+    ('勤', REPLACEMENTS[1889]),
+    ('卑', REPLACEMENTS[1905]),
+    ('喝', REPLACEMENTS[1950]),
+    ('嘆', REPLACEMENTS[1957]),
+    ('器', REPLACEMENTS[1959]),
+    ('塀', REPLACEMENTS[1974]),
+    ('墨', REPLACEMENTS[1977]),
+    ('層', REPLACEMENTS[2033]),
+    ('屮', REPLACEMENTS[2035]),
+    ('悔', REPLACEMENTS[2102]),
+    ('慨', REPLACEMENTS[2112]),
+    ('憎', REPLACEMENTS[2114]),
+    ('懲', REPLACEMENTS[2120]),
+    ('敏', REPLACEMENTS[2163]),
+    ('既', REPLACEMENTS[2175]),
+    ('暑', REPLACEMENTS[2185]),
+    ('梅', REPLACEMENTS[2214]),
+    ('海', REPLACEMENTS[2263]),
+    ('渚', REPLACEMENTS[2270]),
+    ('漢', REPLACEMENTS[2282]),
+    ('煮', REPLACEMENTS[2303]),
+    ('爫', REPLACEMENTS[2311]),
+    ('琢', REPLACEMENTS[2340]),
+    ('碑', REPLACEMENTS[2396]),
+    ('社', REPLACEMENTS[2403]),
+    ('祉', REPLACEMENTS[2405]),
+    ('祈', REPLACEMENTS[2404]),
+    ('祐', REPLACEMENTS[2406]),
+    ('祖', REPLACEMENTS[2407]),
+    ('祝', REPLACEMENTS[2408]),
+    ('禍', REPLACEMENTS[2413]),
+    ('禎', REPLACEMENTS[2414]),
+    ('穀', REPLACEMENTS[2423]),
+    ('突', REPLACEMENTS[2428]),
+    ('節', REPLACEMENTS[2435]),
+    ('練', REPLACEMENTS[2460]),
+    ('縉', REPLACEMENTS[2462]),
+    ('繁', REPLACEMENTS[2464]),
+    ('署', REPLACEMENTS[2469]),
+    ('者', REPLACEMENTS[2479]),
+    ('臭', REPLACEMENTS[2499]),
+    ('艹', REPLACEMENTS[2512]),
+    ('艹', REPLACEMENTS[2512]),
+    ('著', REPLACEMENTS[2538]),
+    ('褐', REPLACEMENTS[2580]),
+    ('視', REPLACEMENTS[2586]),
+    ('謁', REPLACEMENTS[2599]),
+    ('謹', REPLACEMENTS[2600]),
+    ('賓', REPLACEMENTS[2617]),
+    ('贈', REPLACEMENTS[2618]),
+    ('辶', REPLACEMENTS[2640]),
+    ('逸', REPLACEMENTS[2642]),
+    ('難', REPLACEMENTS[2695]),
+    ('響', REPLACEMENTS[2712]),
+    ('頻', REPLACEMENTS[2718]),
+    ('恵', REPLACEMENTS[2100]),
+    ('𤋮', REPLACEMENTS[2844]),
+    ('舘', REPLACEMENTS[2505]),
+    ('並', REPLACEMENTS[1794]),
+    ('况', REPLACEMENTS[1860]),
+    ('全', REPLACEMENTS[1844]),
+    ('侀', REPLACEMENTS[1822]),
+    ('充', REPLACEMENTS[1838]),
+    ('冀', REPLACEMENTS[1849]),
+    ('勇', REPLACEMENTS[1885]),
+    ('勺', REPLACEMENTS[1892]),
+    ('喝', REPLACEMENTS[1950]),
+    ('啕', REPLACEMENTS[1945]),
+    ('喙', REPLACEMENTS[1949]),
+    ('嗢', REPLACEMENTS[1956]),
+    ('塚', REPLACEMENTS[1975]),
+    ('墳', REPLACEMENTS[1979]),
+    ('奄', REPLACEMENTS[1997]),
+    ('奔', REPLACEMENTS[2000]),
+    ('婢', REPLACEMENTS[2007]),
+    ('嬨', REPLACEMENTS[2011]),
+    ('廒', REPLACEMENTS[2070]),
+    ('廙', REPLACEMENTS[2072]),
+    ('彩', REPLACEMENTS[2084]),
+    ('徭', REPLACEMENTS[2092]),
+    ('惘', REPLACEMENTS[2104]),
+    ('慎', REPLACEMENTS[2110]),
+    ('愈', REPLACEMENTS[2106]),
+    ('憎', REPLACEMENTS[2114]),
+    ('慠', REPLACEMENTS[2111]),
+    ('懲', REPLACEMENTS[2120]),
+    ('戴', REPLACEMENTS[2127]),
+    ('揄', REPLACEMENTS[2149]),
+    ('搜', REPLACEMENTS[2152]),
+    ('摒', REPLACEMENTS[2154]),
+    ('敖', REPLACEMENTS[2164]),
+    ('晴', REPLACEMENTS[2183]),
+    ('朗', REPLACEMENTS[2195]),
+    ('望', REPLACEMENTS[2196]),
+    ('杖', REPLACEMENTS[2201]),
+    ('歹', REPLACEMENTS[2234]),
+    ('殺', REPLACEMENTS[2238]),
+    ('流', REPLACEMENTS[2260]),
+    ('滛', REPLACEMENTS[2279]),
+    ('滋', REPLACEMENTS[2277]),
+    ('漢', REPLACEMENTS[2282]),
+    ('瀞', REPLACEMENTS[2289]),
+    ('煮', REPLACEMENTS[2303]),
+    ('瞧', REPLACEMENTS[2389]),
+    ('爵', REPLACEMENTS[2312]),
+    ('犯', REPLACEMENTS[2325]),
+    ('猪', REPLACEMENTS[2328]),
+    ('瑱', REPLACEMENTS[2344]),
+    ('甆', REPLACEMENTS[2351]),
+    ('画', REPLACEMENTS[2360]),
+    ('瘝', REPLACEMENTS[2369]),
+    ('瘟', REPLACEMENTS[2370]),
+    ('益', REPLACEMENTS[2377]),
+    ('盛', REPLACEMENTS[2378]),
+    ('直', REPLACEMENTS[2382]),
+    ('睊', REPLACEMENTS[2387]),
+    ('着', REPLACEMENTS[2386]),
+    ('磌', REPLACEMENTS[2398]),
+    ('窱', REPLACEMENTS[2429]),
+    ('節', REPLACEMENTS[2435]),
+    ('类', REPLACEMENTS[2441]),
+    ('絛', REPLACEMENTS[2455]),
+    ('練', REPLACEMENTS[2460]),
+    ('缾', REPLACEMENTS[2467]),
+    ('者', REPLACEMENTS[2479]),
+    ('荒', REPLACEMENTS[2524]),
+    ('華', REPLACEMENTS[2534]),
+    ('蝹', REPLACEMENTS[2563]),
+    ('襁', REPLACEMENTS[2581]),
+    ('覆', REPLACEMENTS[2584]),
+    ('視', REPLACEMENTS[2586]),
+    ('調', REPLACEMENTS[2592]),
+    ('諸', REPLACEMENTS[2597]),
+    ('請', REPLACEMENTS[2593]),
+    ('謁', REPLACEMENTS[2599]),
+    ('諾', REPLACEMENTS[2598]),
+    ('諭', REPLACEMENTS[2596]),
+    ('謹', REPLACEMENTS[2600]),
+    ('變', REPLACEMENTS[2603]),
+    ('贈', REPLACEMENTS[2618]),
+    ('輸', REPLACEMENTS[2633]),
+    ('遲', REPLACEMENTS[2645]),
+    ('醙', REPLACEMENTS[2659]),
+    ('鉶', REPLACEMENTS[2667]),
+    ('陼', REPLACEMENTS[2686]),
+    ('難', REPLACEMENTS[2695]),
+    ('靖', REPLACEMENTS[2703]),
+    ('韛', REPLACEMENTS[2708]),
+    ('響', REPLACEMENTS[2712]),
+    ('頋', REPLACEMENTS[2715]),
+    ('頻', REPLACEMENTS[2718]),
+    ('鬒', REPLACEMENTS[2739]),
+    ('龜', REPLACEMENTS[2779]),
+    ('𢡊', REPLACEMENTS[2823]),
+    ('𢡄', REPLACEMENTS[2822]),
+    ('𣏕', REPLACEMENTS[2832]),
+    ('㮝', REPLACEMENTS[1740]),
+    ('䀘', REPLACEMENTS[1750]),
+    ('䀹', REPLACEMENTS[1751]),
+    ('𥉉', REPLACEMENTS[2858]),
+    ('𥳐', REPLACEMENTS[2867]),
+    ('𧻓', REPLACEMENTS[2891]),
+    ('齃', REPLACEMENTS[2774]),
+    ('龎', REPLACEMENTS[2778]),
+    ('יִ', REPLACEMENTS[904]),
+    ('ײַ', REPLACEMENTS[920]),
+    ('שׁ', REPLACEMENTS[918]),
+    ('שׂ', REPLACEMENTS[918]),
+    ('שּׁ', REPLACEMENTS[918]),
+    ('שּׂ', REPLACEMENTS[918]),
+    ('אַ', REPLACEMENTS[895]),
+    ('אָ', REPLACEMENTS[895]),
+    ('אּ', REPLACEMENTS[895]),
+    ('בּ', REPLACEMENTS[897]),
+    ('גּ', REPLACEMENTS[898]),
+    ('דּ', REPLACEMENTS[899]),
+    ('הּ', REPLACEMENTS[900]),
+    ('וּ', REPLACEMENTS[901]),
+    ('זּ', REPLACEMENTS[902]),
+    ('טּ', REPLACEMENTS[903]),
+    ('יּ', REPLACEMENTS[904]),
+    ('ךּ', REPLACEMENTS[905]),
+    ('כּ', REPLACEMENTS[906]),
+    ('לּ', REPLACEMENTS[907]),
+    ('מּ', REPLACEMENTS[909]),
+    ('נּ', REPLACEMENTS[910]),
+    ('סּ', REPLACEMENTS[911]),
+    ('ףּ', REPLACEMENTS[913]),
+    ('פּ', REPLACEMENTS[914]),
+    ('צּ', REPLACEMENTS[915]),
+    ('קּ', REPLACEMENTS[916]),
+    ('רּ', REPLACEMENTS[917]),
+    ('שּ', REPLACEMENTS[918]),
+    ('תּ', REPLACEMENTS[919]),
+    ('וֹ', REPLACEMENTS[901]),
+    ('בֿ', REPLACEMENTS[897]),
+    ('כֿ', REPLACEMENTS[906]),
+    ('פֿ', REPLACEMENTS[914]),
+    ('ﮤ', REPLACEMENTS[1251]),
+    ('ﮥ', REPLACEMENTS[1251]),
+    ('ﮰ', REPLACEMENTS[1250]),
+    ('ﮱ', REPLACEMENTS[1250]),
+    ('ﯪ', REPLACEMENTS[1188]),
+    ('ﯫ', REPLACEMENTS[1188]),
+    ('ﯬ', REPLACEMENTS[1209]),
+    ('ﯭ', REPLACEMENTS[1209]),
+    ('ﯮ', REPLACEMENTS[1201]),
+    ('ﯯ', REPLACEMENTS[1201]),
+    ('ﯰ', REPLACEMENTS[1206]),
+    ('ﯱ', REPLACEMENTS[1206]),
+    ('ﯲ', REPLACEMENTS[1205]),
+    ('ﯳ', REPLACEMENTS[1205]),
+    ('ﯴ', REPLACEMENTS[1207]),
+    ('ﯵ', REPLACEMENTS[1207]),
+    ('ﯶ', REPLACEMENTS[1208]),
+    ('ﯷ', REPLACEMENTS[1208]),
+    ('ﯸ', REPLACEMENTS[1208]),
+    ('ﯹ', REPLACEMENTS[1202]),
+    ('ﯺ', REPLACEMENTS[1202]),
+    ('ﯻ', REPLACEMENTS[1202]),
+    ('ﰀ', REPLACEMENTS[1189]),
+    ('ﰁ', REPLACEMENTS[1191]),
+    ('ﰂ', REPLACEMENTS[1196]),
+    ('ﰃ', REPLACEMENTS[1202]),
+    ('ﰄ', REPLACEMENTS[1203]),
+    ('ﱛ', REPLACEMENTS[999]),
+    ('ﱜ', REPLACEMENTS[1000]),
+    ('ﱝ', REPLACEMENTS[1186]),
+    ('ﱞ', REPLACEMENTS[1]),
+    ('ﱟ', REPLACEMENTS[1]),
+    ('ﱠ', REPLACEMENTS[1]),
+    ('ﱡ', REPLACEMENTS[1]),
+    ('ﱢ', REPLACEMENTS[1]),
+    ('ﱣ', REPLACEMENTS[1]),
+    ('ﱤ', REPLACEMENTS[1194]),
+    ('ﱥ', REPLACEMENTS[1195]),
+    ('ﱦ', REPLACEMENTS[1196]),
+    ('ﱧ', REPLACEMENTS[1199]),
+    ('ﱨ', REPLACEMENTS[1202]),
+    ('ﱩ', REPLACEMENTS[1203]),
+    ('ﲐ', REPLACEMENTS[1186]),
+    ('ﲗ', REPLACEMENTS[1189]),
+    ('ﲘ', REPLACEMENTS[1191]),
+    ('ﲙ', REPLACEMENTS[1193]),
+    ('ﲚ', REPLACEMENTS[1196]),
+    ('ﲛ', REPLACEMENTS[1200]),
+    ('ﳙ', REPLACEMENTS[1176]),
+    ('ﳟ', REPLACEMENTS[1196]),
+    ('ﳠ', REPLACEMENTS[1200]),
+    ('ﳲ', REPLACEMENTS[1088]),
+    ('ﳳ', REPLACEMENTS[1088]),
+    ('ﳴ', REPLACEMENTS[1088]),
+    ('ﴼ', REPLACEMENTS[922]),
+    ('ﴽ', REPLACEMENTS[922]),
+    ('﹉', REPLACEMENTS[1]),
+    ('﹊', REPLACEMENTS[1]),
+    ('﹋', REPLACEMENTS[1]),
+    ('﹌', REPLACEMENTS[1]),
+    ('ﹰ', REPLACEMENTS[1]),
+    ('ﹱ', REPLACEMENTS[1088]),
+    ('ﹲ', REPLACEMENTS[1]),
+    ('ﹴ', REPLACEMENTS[1]),
+    ('ﹶ', REPLACEMENTS[1]),
+    ('ﹷ', REPLACEMENTS[1088]),
+    ('ﹸ', REPLACEMENTS[1]),
+    ('ﹹ', REPLACEMENTS[1088]),
+    ('ﹺ', REPLACEMENTS[1]),
+    ('ﹻ', REPLACEMENTS[1088]),
+    ('ﹼ', REPLACEMENTS[1]),
+    ('ﹽ', REPLACEMENTS[1088]),
+    ('ﹾ', REPLACEMENTS[1]),
+    ('ﹿ', REPLACEMENTS[1088]),
+    ('ﺁ', REPLACEMENTS[922]),
+    ('ﺂ', REPLACEMENTS[922]),
+    ('ﺃ', REPLACEMENTS[922]),
+    ('ﺄ', REPLACEMENTS[922]),
+    ('ﺅ', REPLACEMENTS[1183]),
+    ('ﺆ', REPLACEMENTS[1183]),
+    ('ﺇ', REPLACEMENTS[922]),
+    ('ﺈ', REPLACEMENTS[922]),
+    ('ﺉ', REPLACEMENTS[1187]),
+    ('ﺊ', REPLACEMENTS[1187]),
+    ('ﺋ', REPLACEMENTS[1187]),
+    ('ﺌ', REPLACEMENTS[1187]),
+    ('ﻵ', REPLACEMENTS[1119]),
+    ('ﻶ', REPLACEMENTS[1119]),
+    ('ﻷ', REPLACEMENTS[1119]),
+    ('ﻸ', REPLACEMENTS[1119]),
+    ('ﻹ', REPLACEMENTS[1119]),
+    ('ﻺ', REPLACEMENTS[1119]),
+    ('￣', REPLACEMENTS[1]),
+    ('𑂚', REPLACEMENTS[2791]),
+    ('𑂜', REPLACEMENTS[2792]),
+    ('𑂫', REPLACEMENTS[2793]),
+    ('𑒻', REPLACEMENTS[2794]),
+    ('丽', REPLACEMENTS[1801]),
+    ('丸', REPLACEMENTS[1799]),
+    ('乁', REPLACEMENTS[1803]),
+    ('𠄢', REPLACEMENTS[2801]),
+    ('你', REPLACEMENTS[1821]),
+    ('侮', REPLACEMENTS[1825]),
+    ('侻', REPLACEMENTS[1826]),
+    ('倂', REPLACEMENTS[1828]),
+    ('偺', REPLACEMENTS[1830]),
+    ('備', REPLACEMENTS[1831]),
+    ('僧', REPLACEMENTS[1834]),
+    ('像', REPLACEMENTS[1832]),
+    ('㒞', REPLACEMENTS[1719]),
+    ('𠘺', REPLACEMENTS[2805]),
+    ('免', REPLACEMENTS[1839]),
+    ('兔', REPLACEMENTS[1840]),
+    ('兤', REPLACEMENTS[1841]),
+    ('具', REPLACEMENTS[1848]),
+    ('𠔜', REPLACEMENTS[2802]),
+    ('㒹', REPLACEMENTS[1720]),
+    ('內', REPLACEMENTS[1843]),
+    ('再', REPLACEMENTS[1851]),
+    ('𠕋', REPLACEMENTS[2804]),
+    ('冗', REPLACEMENTS[1855]),
+    ('冤', REPLACEMENTS[1857]),
+    ('仌', REPLACEMENTS[1816]),
+    ('冬', REPLACEMENTS[1859]),
+    ('况', REPLACEMENTS[1860]),
+    ('𩇟', REPLACEMENTS[2899]),
+    ('凵', REPLACEMENTS[1867]),
+    ('刃', REPLACEMENTS[1869]),
+    ('㓟', REPLACEMENTS[1722]),
+    ('刻', REPLACEMENTS[1875]),
+    ('剆', REPLACEMENTS[1876]),
+    ('割', REPLACEMENTS[1878]),
+    ('剷', REPLACEMENTS[1879]),
+    ('㔕', REPLACEMENTS[1723]),
+    ('勇', REPLACEMENTS[1885]),
+    ('勉', REPLACEMENTS[1886]),
+    ('勤', REPLACEMENTS[1889]),
+    ('勺', REPLACEMENTS[1892]),
+    ('包', REPLACEMENTS[1893]),
+    ('匆', REPLACEMENTS[1894]),
+    ('北', REPLACEMENTS[1896]),
+    ('卉', REPLACEMENTS[1904]),
+    ('卑', REPLACEMENTS[1905]),
+    ('博', REPLACEMENTS[1907]),
+    ('即', REPLACEMENTS[1911]),
+    ('卽', REPLACEMENTS[1913]),
+    ('卿', REPLACEMENTS[1914]),
+    ('卿', REPLACEMENTS[1914]),
+    ('卿', REPLACEMENTS[1914]),
+    ('𠨬', REPLACEMENTS[2808]),
+    ('灰', REPLACEMENTS[2293]),
+    ('及', REPLACEMENTS[1919]),
+    ('叟', REPLACEMENTS[1921]),
+    ('𠭣', REPLACEMENTS[2809]),
+    ('叫', REPLACEMENTS[1924]),
+    ('叱', REPLACEMENTS[1926]),
+    ('吆', REPLACEMENTS[1928]),
+    ('咞', REPLACEMENTS[1938]),
+    ('吸', REPLACEMENTS[1933]),
+    ('呈', REPLACEMENTS[1936]),
+    ('周', REPLACEMENTS[1937]),
+    ('咢', REPLACEMENTS[1939]),
+    ('哶', REPLACEMENTS[1941]),
+    ('唐', REPLACEMENTS[1942]),
+    ('啓', REPLACEMENTS[1944]),
+    ('啣', REPLACEMENTS[1946]),
+    ('善', REPLACEMENTS[1947]),
+    ('善', REPLACEMENTS[1947]),
+    ('喙', REPLACEMENTS[1949]),
+    ('喫', REPLACEMENTS[1951]),
+    ('喳', REPLACEMENTS[1952]),
+    ('嗂', REPLACEMENTS[1955]),
+    ('圖', REPLACEMENTS[1964]),
+    ('嘆', REPLACEMENTS[1957]),
+    ('圗', REPLACEMENTS[1965]),
+    ('噑', REPLACEMENTS[1958]),
+    ('噴', REPLACEMENTS[1960]),
+    ('切', REPLACEMENTS[1870]),
+    ('壮', REPLACEMENTS[1983]),
+    ('城', REPLACEMENTS[1969]),
+    ('埴', REPLACEMENTS[1970]),
+    ('堍', REPLACEMENTS[1971]),
+    ('型', REPLACEMENTS[1968]),
+    ('堲', REPLACEMENTS[1973]),
+    ('報', REPLACEMENTS[1972]),
+    ('墬', REPLACEMENTS[1978]),
+    ('𡓤', REPLACEMENTS[2810]),
+    ('売', REPLACEMENTS[1985]),
+    ('壷', REPLACEMENTS[1986]),
+    ('夆', REPLACEMENTS[1988]),
+    ('多', REPLACEMENTS[1991]),
+    ('夢', REPLACEMENTS[1993]),
+    ('奢', REPLACEMENTS[2001]),
+    ('𡚨', REPLACEMENTS[2811]),
+    ('𡛪', REPLACEMENTS[2812]),
+    ('姬', REPLACEMENTS[2004]),
+    ('娛', REPLACEMENTS[2005]),
+    ('娧', REPLACEMENTS[2006]),
+    ('姘', REPLACEMENTS[2003]),
+    ('婦', REPLACEMENTS[2008]),
+    ('㛮', REPLACEMENTS[1724]),
+    ('㛼', REPLACEMENTS[1725]),
+    ('嬈', REPLACEMENTS[2010]),
+    ('嬾', REPLACEMENTS[2012]),
+    ('嬾', REPLACEMENTS[2012]),
+    ('𡧈', REPLACEMENTS[2813]),
+    ('寃', REPLACEMENTS[2019]),
+    ('寘', REPLACEMENTS[2020]),
+    ('寧', REPLACEMENTS[2021]),
+    ('寳', REPLACEMENTS[2023]),
+    ('𡬘', REPLACEMENTS[2814]),
+    ('寿', REPLACEMENTS[2025]),
+    ('将', REPLACEMENTS[2026]),
+    ('当', REPLACEMENTS[2081]),
+    ('尢', REPLACEMENTS[2028]),
+    ('㞁', REPLACEMENTS[1726]),
+    ('屠', REPLACEMENTS[2031]),
+    ('屮', REPLACEMENTS[2035]),
+    ('峀', REPLACEMENTS[2038]),
+    ('岍', REPLACEMENTS[2037]),
+    ('𡷤', REPLACEMENTS[2816]),
+    ('嵃', REPLACEMENTS[2040]),
+    ('𡷦', REPLACEMENTS[2817]),
+    ('嵮', REPLACEMENTS[2043]),
+    ('嵫', REPLACEMENTS[2042]),
+    ('嵼', REPLACEMENTS[2044]),
+    ('巡', REPLACEMENTS[2048]),
+    ('巢', REPLACEMENTS[2049]),
+    ('㠯', REPLACEMENTS[1727]),
+    ('巽', REPLACEMENTS[2053]),
+    ('帨', REPLACEMENTS[2055]),
+    ('帽', REPLACEMENTS[2056]),
+    ('幩', REPLACEMENTS[2057]),
+    ('㡢', REPLACEMENTS[1728]),
+    ('𢆃', REPLACEMENTS[2818]),
+    ('㡼', REPLACEMENTS[1729]),
+    ('庰', REPLACEMENTS[2065]),
+    ('庳', REPLACEMENTS[2066]),
+    ('庶', REPLACEMENTS[2067]),
+    ('廊', REPLACEMENTS[2069]),
+    ('𪎒', REPLACEMENTS[2909]),
+    ('廾', REPLACEMENTS[2075]),
+    ('𢌱', REPLACEMENTS[2820]),
+    ('𢌱', REPLACEMENTS[2820]),
+    ('舁', REPLACEMENTS[2502]),
+    ('弢', REPLACEMENTS[2079]),
+    ('弢', REPLACEMENTS[2079]),
+    ('㣇', REPLACEMENTS[1730]),
+    ('𣊸', REPLACEMENTS[2827]),
+    ('𦇚', REPLACEMENTS[2869]),
+    ('形', REPLACEMENTS[2083]),
+    ('彫', REPLACEMENTS[2085]),
+    ('㣣', REPLACEMENTS[1731]),
+    ('徚', REPLACEMENTS[2090]),
+    ('忍', REPLACEMENTS[2094]),
+    ('志', REPLACEMENTS[2095]),
+    ('忹', REPLACEMENTS[2097]),
+    ('悁', REPLACEMENTS[2101]),
+    ('㤺', REPLACEMENTS[1733]),
+    ('㤜', REPLACEMENTS[1732]),
+    ('悔', REPLACEMENTS[2102]),
+    ('𢛔', REPLACEMENTS[2821]),
+    ('惇', REPLACEMENTS[2103]),
+    ('慈', REPLACEMENTS[2108]),
+    ('慌', REPLACEMENTS[2109]),
+    ('慎', REPLACEMENTS[2110]),
+    ('慌', REPLACEMENTS[2109]),
+    ('慺', REPLACEMENTS[2113]),
+    ('憎', REPLACEMENTS[2114]),
+    ('憲', REPLACEMENTS[2118]),
+    ('憤', REPLACEMENTS[2116]),
+    ('憯', REPLACEMENTS[2117]),
+    ('懞', REPLACEMENTS[2119]),
+    ('懲', REPLACEMENTS[2120]),
+    ('懶', REPLACEMENTS[2121]),
+    ('成', REPLACEMENTS[2124]),
+    ('戛', REPLACEMENTS[2125]),
+    ('扝', REPLACEMENTS[2131]),
+    ('抱', REPLACEMENTS[2133]),
+    ('拔', REPLACEMENTS[2137]),
+    ('捐', REPLACEMENTS[2142]),
+    ('𢬌', REPLACEMENTS[2824]),
+    ('挽', REPLACEMENTS[2141]),
+    ('拼', REPLACEMENTS[2138]),
+    ('捨', REPLACEMENTS[2144]),
+    ('掃', REPLACEMENTS[2146]),
+    ('揤', REPLACEMENTS[2151]),
+    ('𢯱', REPLACEMENTS[2825]),
+    ('搢', REPLACEMENTS[2153]),
+    ('揅', REPLACEMENTS[2150]),
+    ('掩', REPLACEMENTS[2148]),
+    ('㨮', REPLACEMENTS[1734]),
+    ('摩', REPLACEMENTS[2155]),
+    ('摾', REPLACEMENTS[2157]),
+    ('撝', REPLACEMENTS[2159]),
+    ('摷', REPLACEMENTS[2156]),
+    ('㩬', REPLACEMENTS[1735]),
+    ('敏', REPLACEMENTS[2163]),
+    ('敬', REPLACEMENTS[2165]),
+    ('𣀊', REPLACEMENTS[2826]),
+    ('旣', REPLACEMENTS[2176]),
+    ('書', REPLACEMENTS[2191]),
+    ('晉', REPLACEMENTS[2182]),
+    ('㬙', REPLACEMENTS[1738]),
+    ('暑', REPLACEMENTS[2185]),
+    ('㬈', REPLACEMENTS[1737]),
+    ('㫤', REPLACEMENTS[1736]),
+    ('冒', REPLACEMENTS[1852]),
+    ('冕', REPLACEMENTS[1853]),
+    ('最', REPLACEMENTS[2192]),
+    ('暜', REPLACEMENTS[2186]),
+    ('肭', REPLACEMENTS[2491]),
+    ('䏙', REPLACEMENTS[1763]),
+    ('朗', REPLACEMENTS[2195]),
+    ('望', REPLACEMENTS[2196]),
+    ('朡', REPLACEMENTS[2197]),
+    ('杞', REPLACEMENTS[2202]),
+    ('杓', REPLACEMENTS[2200]),
+    ('𣏃', REPLACEMENTS[2831]),
+    ('㭉', REPLACEMENTS[1739]),
+    ('柺', REPLACEMENTS[2207]),
+    ('枅', REPLACEMENTS[2204]),
+    ('桒', REPLACEMENTS[2212]),
+    ('梅', REPLACEMENTS[2214]),
+    ('𣑭', REPLACEMENTS[2833]),
+    ('梎', REPLACEMENTS[2215]),
+    ('栟', REPLACEMENTS[2209]),
+    ('椔', REPLACEMENTS[2217]),
+    ('㮝', REPLACEMENTS[1740]),
+    ('楂', REPLACEMENTS[2218]),
+    ('榣', REPLACEMENTS[2219]),
+    ('槪', REPLACEMENTS[2220]),
+    ('檨', REPLACEMENTS[2223]),
+    ('𣚣', REPLACEMENTS[2834]),
+    ('櫛', REPLACEMENTS[2225]),
+    ('㰘', REPLACEMENTS[1741]),
+    ('次', REPLACEMENTS[2228]),
+    ('𣢧', REPLACEMENTS[2835]),
+    ('歔', REPLACEMENTS[2229]),
+    ('㱎', REPLACEMENTS[1742]),
+    ('歲', REPLACEMENTS[2232]),
+    ('殟', REPLACEMENTS[2235]),
+    ('殺', REPLACEMENTS[2238]),
+    ('殻', REPLACEMENTS[2239]),
+    ('𣪍', REPLACEMENTS[2836]),
+    ('𡴋', REPLACEMENTS[2815]),
+    ('𣫺', REPLACEMENTS[2837]),
+    ('汎', REPLACEMENTS[2247]),
+    ('𣲼', REPLACEMENTS[2838]),
+    ('沿', REPLACEMENTS[2250]),
+    ('泍', REPLACEMENTS[2252]),
+    ('汧', REPLACEMENTS[2248]),
+    ('洖', REPLACEMENTS[2255]),
+    ('派', REPLACEMENTS[2259]),
+    ('海', REPLACEMENTS[2263]),
+    ('流', REPLACEMENTS[2260]),
+    ('浩', REPLACEMENTS[2261]),
+    ('浸', REPLACEMENTS[2264]),
+    ('涅', REPLACEMENTS[2265]),
+    ('𣴞', REPLACEMENTS[2839]),
+    ('洴', REPLACEMENTS[2258]),
+    ('港', REPLACEMENTS[2271]),
+    ('湮', REPLACEMENTS[2272]),
+    ('㴳', REPLACEMENTS[1743]),
+    ('滋', REPLACEMENTS[2277]),
+    ('滇', REPLACEMENTS[2276]),
+    ('𣻑', REPLACEMENTS[2840]),
+    ('淹', REPLACEMENTS[2269]),
+    ('潮', REPLACEMENTS[2284]),
+    ('𣽞', REPLACEMENTS[2841]),
+    ('𣾎', REPLACEMENTS[2842]),
+    ('濆', REPLACEMENTS[2285]),
+    ('瀹', REPLACEMENTS[2290]),
+    ('瀞', REPLACEMENTS[2289]),
+    ('瀛', REPLACEMENTS[2288]),
+    ('㶖', REPLACEMENTS[1744]),
+    ('灊', REPLACEMENTS[2291]),
+    ('災', REPLACEMENTS[2295]),
+    ('灷', REPLACEMENTS[2294]),
+    ('炭', REPLACEMENTS[2297]),
+    ('𠔥', REPLACEMENTS[2803]),
+    ('煅', REPLACEMENTS[2301]),
+    ('𤉣', REPLACEMENTS[2843]),
+    ('熜', REPLACEMENTS[2304]),
+    ('𤎫', REPLACEMENTS[2845]),
+    ('爨', REPLACEMENTS[2309]),
+    ('爵', REPLACEMENTS[2312]),
+    ('牐', REPLACEMENTS[2317]),
+    ('𤘈', REPLACEMENTS[2846]),
+    ('犀', REPLACEMENTS[2322]),
+    ('犕', REPLACEMENTS[2323]),
+    ('𤜵', REPLACEMENTS[2847]),
+    ('𤠔', REPLACEMENTS[2848]),
+    ('獺', REPLACEMENTS[2330]),
+    ('王', REPLACEMENTS[2334]),
+    ('㺬', REPLACEMENTS[1745]),
+    ('玥', REPLACEMENTS[2335]),
+    ('㺸', REPLACEMENTS[1746]),
+    ('㺸', REPLACEMENTS[1746]),
+    ('瑇', REPLACEMENTS[2341]),
+    ('瑜', REPLACEMENTS[2342]),
+    ('瑱', REPLACEMENTS[2344]),
+    ('璅', REPLACEMENTS[2345]),
+    ('瓊', REPLACEMENTS[2348]),
+    ('㼛', REPLACEMENTS[1747]),
+    ('甤', REPLACEMENTS[2354]),
+    ('𤰶', REPLACEMENTS[2849]),
+    ('甾', REPLACEMENTS[2361]),
+    ('𤲒', REPLACEMENTS[2850]),
+    ('異', REPLACEMENTS[2364]),
+    ('𢆟', REPLACEMENTS[2819]),
+    ('瘐', REPLACEMENTS[2368]),
+    ('𤾡', REPLACEMENTS[2851]),
+    ('𤾸', REPLACEMENTS[2852]),
+    ('𥁄', REPLACEMENTS[2853]),
+    ('㿼', REPLACEMENTS[1748]),
+    ('䀈', REPLACEMENTS[1749]),
+    ('直', REPLACEMENTS[2382]),
+    ('𥃳', REPLACEMENTS[2855]),
+    ('𥃲', REPLACEMENTS[2854]),
+    ('𥄙', REPLACEMENTS[2856]),
+    ('𥄳', REPLACEMENTS[2857]),
+    ('眞', REPLACEMENTS[2384]),
+    ('真', REPLACEMENTS[2385]),
+    ('真', REPLACEMENTS[2385]),
+    ('睊', REPLACEMENTS[2387]),
+    ('䀹', REPLACEMENTS[1751]),
+    ('瞋', REPLACEMENTS[2388]),
+    ('䁆', REPLACEMENTS[1752]),
+    ('䂖', REPLACEMENTS[1753]),
+    ('𥐝', REPLACEMENTS[2859]),
+    ('硎', REPLACEMENTS[2393]),
+    ('碌', REPLACEMENTS[2395]),
+    ('磌', REPLACEMENTS[2398]),
+    ('䃣', REPLACEMENTS[1754]),
+    ('𥘦', REPLACEMENTS[2860]),
+    ('祖', REPLACEMENTS[2407]),
+    ('𥚚', REPLACEMENTS[2861]),
+    ('𥛅', REPLACEMENTS[2862]),
+    ('福', REPLACEMENTS[2415]),
+    ('秫', REPLACEMENTS[2421]),
+    ('䄯', REPLACEMENTS[1755]),
+    ('穀', REPLACEMENTS[2423]),
+    ('穊', REPLACEMENTS[2424]),
+    ('穏', REPLACEMENTS[2425]),
+    ('𥥼', REPLACEMENTS[2863]),
+    ('𥪧', REPLACEMENTS[2864]),
+    ('𥪧', REPLACEMENTS[2864]),
+    ('竮', REPLACEMENTS[2431]),
+    ('䈂', REPLACEMENTS[1756]),
+    ('𥮫', REPLACEMENTS[2865]),
+    ('篆', REPLACEMENTS[2436]),
+    ('築', REPLACEMENTS[2437]),
+    ('䈧', REPLACEMENTS[1757]),
+    ('𥲀', REPLACEMENTS[2866]),
+    ('糒', REPLACEMENTS[2444]),
+    ('䊠', REPLACEMENTS[1758]),
+    ('糨', REPLACEMENTS[2448]),
+    ('糣', REPLACEMENTS[2446]),
+    ('紀', REPLACEMENTS[2450]),
+    ('𥾆', REPLACEMENTS[2868]),
+    ('絣', REPLACEMENTS[2456]),
+    ('䌁', REPLACEMENTS[1759]),
+    ('緇', REPLACEMENTS[2459]),
+    ('縂', REPLACEMENTS[2461]),
+    ('繅', REPLACEMENTS[2465]),
+    ('䌴', REPLACEMENTS[1760]),
+    ('𦈨', REPLACEMENTS[2870]),
+    ('𦉇', REPLACEMENTS[2871]),
+    ('䍙', REPLACEMENTS[1761]),
+    ('𦋙', REPLACEMENTS[2872]),
+    ('罺', REPLACEMENTS[2471]),
+    ('𦌾', REPLACEMENTS[2873]),
+    ('羕', REPLACEMENTS[2474]),
+    ('翺', REPLACEMENTS[2477]),
+    ('者', REPLACEMENTS[2479]),
+    ('𦓚', REPLACEMENTS[2874]),
+    ('𦔣', REPLACEMENTS[2875]),
+    ('聠', REPLACEMENTS[2484]),
+    ('𦖨', REPLACEMENTS[2876]),
+    ('聰', REPLACEMENTS[2486]),
+    ('𣍟', REPLACEMENTS[2828]),
+    ('䏕', REPLACEMENTS[1762]),
+    ('育', REPLACEMENTS[2492]),
+    ('脃', REPLACEMENTS[2493]),
+    ('䐋', REPLACEMENTS[1764]),
+    ('脾', REPLACEMENTS[2494]),
+    ('媵', REPLACEMENTS[2009]),
+    ('𦞧', REPLACEMENTS[2877]),
+    ('𦞵', REPLACEMENTS[2878]),
+    ('𣎓', REPLACEMENTS[2829]),
+    ('𣎜', REPLACEMENTS[2830]),
+    ('舁', REPLACEMENTS[2502]),
+    ('舄', REPLACEMENTS[2503]),
+    ('辞', REPLACEMENTS[2637]),
+    ('䑫', REPLACEMENTS[1765]),
+    ('芑', REPLACEMENTS[2514]),
+    ('芋', REPLACEMENTS[2513]),
+    ('芝', REPLACEMENTS[2515]),
+    ('劳', REPLACEMENTS[1883]),
+    ('花', REPLACEMENTS[2516]),
+    ('芳', REPLACEMENTS[2517]),
+    ('芽', REPLACEMENTS[2518]),
+    ('苦', REPLACEMENTS[2520]),
+    ('𦬼', REPLACEMENTS[2879]),
+    ('若', REPLACEMENTS[2519]),
+    ('茝', REPLACEMENTS[2521]),
+    ('荣', REPLACEMENTS[2526]),
+    ('莭', REPLACEMENTS[2527]),
+    ('茣', REPLACEMENTS[2522]),
+    ('莽', REPLACEMENTS[2528]),
+    ('菧', REPLACEMENTS[2533]),
+    ('著', REPLACEMENTS[2538]),
+    ('荓', REPLACEMENTS[2525]),
+    ('菊', REPLACEMENTS[2530]),
+    ('菌', REPLACEMENTS[2531]),
+    ('菜', REPLACEMENTS[2532]),
+    ('𦰶', REPLACEMENTS[2880]),
+    ('𦵫', REPLACEMENTS[2882]),
+    ('𦳕', REPLACEMENTS[2881]),
+    ('䔫', REPLACEMENTS[1766]),
+    ('蓱', REPLACEMENTS[2540]),
+    ('蓳', REPLACEMENTS[2541]),
+    ('蔖', REPLACEMENTS[2543]),
+    ('𧏊', REPLACEMENTS[2886]),
+    ('蕤', REPLACEMENTS[2544]),
+    ('𦼬', REPLACEMENTS[2883]),
+    ('䕝', REPLACEMENTS[1767]),
+    ('䕡', REPLACEMENTS[1768]),
+    ('𦾱', REPLACEMENTS[2884]),
+    ('𧃒', REPLACEMENTS[2885]),
+    ('䕫', REPLACEMENTS[1769]),
+    ('虐', REPLACEMENTS[2552]),
+    ('虜', REPLACEMENTS[2553]),
+    ('虧', REPLACEMENTS[2554]),
+    ('虩', REPLACEMENTS[2555]),
+    ('蚩', REPLACEMENTS[2558]),
+    ('蚈', REPLACEMENTS[2557]),
+    ('蜎', REPLACEMENTS[2560]),
+    ('蛢', REPLACEMENTS[2559]),
+    ('蝹', REPLACEMENTS[2563]),
+    ('蜨', REPLACEMENTS[2561]),
+    ('蝫', REPLACEMENTS[2562]),
+    ('螆', REPLACEMENTS[2564]),
+    ('䗗', REPLACEMENTS[1770]),
+    ('蟡', REPLACEMENTS[2566]),
+    ('蠁', REPLACEMENTS[2567]),
+    ('䗹', REPLACEMENTS[1771]),
+    ('衠', REPLACEMENTS[2571]),
+    ('衣', REPLACEMENTS[2572]),
+    ('𧙧', REPLACEMENTS[2887]),
+    ('裗', REPLACEMENTS[2575]),
+    ('裞', REPLACEMENTS[2576]),
+    ('䘵', REPLACEMENTS[1772]),
+    ('裺', REPLACEMENTS[2579]),
+    ('㒻', REPLACEMENTS[1721]),
+    ('𧢮', REPLACEMENTS[2888]),
+    ('𧥦', REPLACEMENTS[2889]),
+    ('䚾', REPLACEMENTS[1773]),
+    ('䛇', REPLACEMENTS[1774]),
+    ('誠', REPLACEMENTS[2590]),
+    ('諭', REPLACEMENTS[2596]),
+    ('變', REPLACEMENTS[2603]),
+    ('豕', REPLACEMENTS[2607]),
+    ('𧲨', REPLACEMENTS[2890]),
+    ('貫', REPLACEMENTS[2612]),
+    ('賁', REPLACEMENTS[2613]),
+    ('贛', REPLACEMENTS[2619]),
+    ('起', REPLACEMENTS[2622]),
+    ('𧼯', REPLACEMENTS[2892]),
+    ('𠠄', REPLACEMENTS[2806]),
+    ('跋', REPLACEMENTS[2625]),
+    ('趼', REPLACEMENTS[2624]),
+    ('跰', REPLACEMENTS[2627]),
+    ('𠣞', REPLACEMENTS[2807]),
+    ('軔', REPLACEMENTS[2630]),
+    ('輸', REPLACEMENTS[2633]),
+    ('𨗒', REPLACEMENTS[2893]),
+    ('𨗭', REPLACEMENTS[2894]),
+    ('邔', REPLACEMENTS[2649]),
+    ('郱', REPLACEMENTS[2652]),
+    ('鄑', REPLACEMENTS[2654]),
+    ('𨜮', REPLACEMENTS[2895]),
+    ('鄛', REPLACEMENTS[2655]),
+    ('鈸', REPLACEMENTS[2666]),
+    ('鋗', REPLACEMENTS[2669]),
+    ('鋘', REPLACEMENTS[2670]),
+    ('鉼', REPLACEMENTS[2668]),
+    ('鏹', REPLACEMENTS[2673]),
+    ('鐕', REPLACEMENTS[2674]),
+    ('𨯺', REPLACEMENTS[2896]),
+    ('開', REPLACEMENTS[2677]),
+    ('䦕', REPLACEMENTS[1775]),
+    ('閷', REPLACEMENTS[2679]),
+    ('𨵷', REPLACEMENTS[2897]),
+    ('䧦', REPLACEMENTS[1776]),
+    ('雃', REPLACEMENTS[2693]),
+    ('嶲', REPLACEMENTS[2045]),
+    ('霣', REPLACEMENTS[2699]),
+    ('𩅅', REPLACEMENTS[2898]),
+    ('𩈚', REPLACEMENTS[2900]),
+    ('䩮', REPLACEMENTS[1777]),
+    ('䩶', REPLACEMENTS[1778]),
+    ('韠', REPLACEMENTS[2709]),
+    ('𩐊', REPLACEMENTS[2901]),
+    ('䪲', REPLACEMENTS[1779]),
+    ('𩒖', REPLACEMENTS[2902]),
+    ('頋', REPLACEMENTS[2715]),
+    ('頋', REPLACEMENTS[2715]),
+    ('頩', REPLACEMENTS[2717]),
+    ('𩖶', REPLACEMENTS[2903]),
+    ('飢', REPLACEMENTS[2723]),
+    ('䬳', REPLACEMENTS[1780]),
+    ('餩', REPLACEMENTS[2727]),
+    ('馧', REPLACEMENTS[2730]),
+    ('駂', REPLACEMENTS[2732]),
+    ('駾', REPLACEMENTS[2734]),
+    ('䯎', REPLACEMENTS[1781]),
+    ('𩬰', REPLACEMENTS[2904]),
+    ('鬒', REPLACEMENTS[2739]),
+    ('鱀', REPLACEMENTS[2746]),
+    ('鳽', REPLACEMENTS[2749]),
+    ('䳎', REPLACEMENTS[1782]),
+    ('䳭', REPLACEMENTS[1783]),
+    ('鵧', REPLACEMENTS[2750]),
+    ('𪃎', REPLACEMENTS[2905]),
+    ('䳸', REPLACEMENTS[1784]),
+    ('𪄅', REPLACEMENTS[2906]),
+    ('𪈎', REPLACEMENTS[2907]),
+    ('𪊑', REPLACEMENTS[2908]),
+    ('麻', REPLACEMENTS[2759]),
+    ('䵖', REPLACEMENTS[1785]),
+    ('黹', REPLACEMENTS[2764]),
+    ('黾', REPLACEMENTS[2766]),
+    ('鼅', REPLACEMENTS[2767]),
+    ('鼏', REPLACEMENTS[2769]),
+    ('鼖', REPLACEMENTS[2771]),
+    ('鼻', REPLACEMENTS[2773]),
+    ('𪘀', REPLACEMENTS[2910]),
+];
+
+pub const DIACRITICS_MAPPING: crate::phf::CharMap<crate::Replacement> = crate::phf::CharMap {
+    range: '¨'..='𪘀',
     key: 12913932095322966823,
     disps: &[
-        (0, 150),
-        (0, 2),
         (0, 12),
-        (0, 340),
-        (1, 259),
-        (0, 0),
-        (0, 65),
-        (0, 7),
+        (0, 1),
+        (0, 1075),
+        (0, 3),
+        (0, 103),
         (0, 0),
-        (0, 274),
-        (0, 14),
-        (0, 77),
-        (0, 80),
+        (0, 534),
+        (0, 30),
+        (0, 5),
+        (0, 4),
+        (0, 236),
+        (0, 214),
+        (0, 324),
         (0, 0),
+        (0, 168),
+        (0, 51),
+        (0, 258),
+        (0, 36),
+        (0, 604),
         (0, 0),
-        (0, 13),
-        (0, 6),
-        (0, 7),
-        (0, 3),
-        (0, 199),
         (0, 1),
-        (1, 0),
-        (0, 109),
-        (0, 9),
-        (0, 39),
         (0, 17),
-        (0, 35),
-        (0, 2),
-        (0, 17),
-        (0, 290),
+        (0, 730),
+        (0, 461),
+        (0, 22),
+        (0, 1),
+        (0, 109),
+        (0, 30),
         (0, 0),
-        (0, 100),
+        (0, 94),
+        (0, 3),
+        (0, 485),
+        (0, 10),
+        (0, 1607),
+        (0, 113),
+        (0, 140),
+        (0, 1235),
+        (0, 0),
+        (0, 72),
+        (0, 766),
         (0, 188),
+        (0, 18),
+        (0, 6),
+        (0, 1),
+        (0, 37),
+        (0, 16),
+        (0, 0),
         (0, 2),
-        (0, 51),
+        (0, 189),
         (0, 39),
-        (0, 756),
-        (0, 24),
-        (0, 227),
-        (0, 161),
-        (0, 712),
-        (0, 35),
-        (0, 547),
-        (0, 135),
-        (0, 36),
-        (0, 15),
+        (0, 150),
+        (0, 5),
+        (0, 140),
+        (0, 4),
+        (0, 67),
+        (0, 69),
+        (0, 0),
+        (0, 51),
+        (0, 1288),
+        (0, 132),
         (0, 18),
-        (0, 239),
+        (0, 26),
+        (0, 61),
+        (0, 178),
+        (0, 1),
+        (0, 46),
+        (0, 17),
+        (0, 49),
+        (0, 730),
+        (0, 0),
+        (0, 0),
+        (0, 20),
+        (0, 182),
         (0, 4),
-        (0, 181),
-        (0, 16),
-        (0, 249),
-        (0, 112),
+        (0, 382),
         (0, 35),
-        (0, 14),
-        (0, 403),
-        (0, 798),
-        (0, 96),
-        (0, 412),
-        (0, 329),
-        (0, 541),
-        (0, 85),
-        (0, 72),
-        (0, 9),
-        (0, 77),
-        (0, 210),
+        (0, 92),
+        (0, 7),
+        (0, 341),
+        (0, 20),
+        (0, 8),
+        (0, 20),
+        (0, 7),
+        (0, 1366),
+        (0, 1588),
+        (1, 45),
+        (0, 76),
+        (0, 280),
+        (0, 414),
+        (0, 5),
         (0, 2),
+        (0, 445),
         (0, 7),
-        (0, 268),
-        (0, 155),
-        (0, 31),
-        (0, 157),
-        (0, 17),
-        (0, 456),
-        (1, 7),
-        (0, 151),
-        (0, 21),
-        (0, 70),
-        (0, 257),
+        (0, 838),
+        (0, 111),
+        (0, 6),
+        (0, 7),
+        (0, 10),
+        (0, 84),
+        (0, 110),
+        (0, 766),
+        (0, 10),
+        (0, 4),
+        (0, 24),
+        (0, 1194),
+        (0, 47),
+        (0, 329),
         (0, 1),
-        (0, 375),
-        (0, 5),
-        (0, 106),
-        (0, 48),
-        (0, 698),
+        (0, 87),
+        (0, 57),
+        (0, 60),
+        (0, 0),
+        (0, 221),
+        (0, 7),
+        (0, 43),
+        (0, 25),
+        (0, 280),
+        (0, 99),
+        (0, 37),
+        (0, 1239),
+        (0, 227),
+        (0, 599),
+        (0, 438),
+        (0, 122),
+        (0, 18),
+        (0, 4),
+        (0, 483),
+        (0, 242),
+        (0, 787),
         (0, 5),
-        (1, 39),
-        (0, 507),
+        (0, 18),
+        (0, 165),
+        (0, 4),
+        (0, 3),
+        (0, 3),
+        (0, 23),
+        (1, 721),
+        (0, 10),
+        (0, 982),
         (0, 9),
-        (1, 8),
-        (0, 267),
+        (0, 35),
+        (0, 157),
+        (0, 1868),
+        (0, 7),
+        (0, 766),
+        (0, 140),
+        (0, 60),
+        (0, 2),
+        (0, 2),
+        (0, 7),
+        (0, 1010),
+        (0, 146),
         (0, 28),
-        (0, 3),
-        (0, 200),
-        (0, 467),
+        (0, 19),
+        (0, 866),
+        (0, 876),
+        (0, 172),
+        (0, 1546),
+        (0, 6),
+        (0, 0),
+        (0, 5),
+        (0, 80),
+        (0, 190),
+        (0, 1),
+        (1, 1487),
+        (0, 112),
+        (0, 338),
+        (0, 108),
+        (0, 20),
+        (0, 0),
+        (0, 1),
+        (0, 0),
+        (0, 0),
+        (0, 109),
+        (0, 1172),
+        (0, 124),
+        (0, 664),
+        (0, 12),
+        (0, 1),
         (0, 0),
-        (1, 321),
+        (0, 157),
+        (0, 1554),
+        (0, 18),
+        (0, 34),
         (0, 486),
-        (0, 27),
-        (0, 8),
+        (0, 194),
         (0, 30),
-        (2, 543),
-        (0, 42),
-        (0, 176),
-        (3, 532),
-        (0, 87),
-        (0, 33),
-        (1, 139),
-        (0, 146),
-        (7, 8),
-        (1, 33),
-        (5, 851),
-        (0, 463),
-        (0, 11),
-        (0, 860),
-        (5, 671),
-        (0, 648),
-        (0, 12),
-        (2, 336),
+        (0, 3),
+        (0, 821),
+        (0, 905),
+        (0, 107),
+        (0, 26),
+        (0, 52),
+        (0, 1156),
+        (0, 943),
+        (0, 43),
+        (0, 114),
+        (0, 62),
+        (0, 126),
+        (0, 261),
         (0, 1),
-        (1, 194),
-        (0, 68),
-        (0, 250),
-        (0, 96),
-        (0, 38),
+        (0, 57),
+        (0, 19),
+        (0, 684),
+        (0, 4),
+        (0, 1),
+        (0, 830),
+        (0, 110),
+        (0, 303),
+        (0, 26),
+        (1, 568),
+        (0, 809),
+        (0, 126),
+        (0, 2),
+        (0, 220),
+        (0, 67),
+        (0, 947),
+        (0, 303),
+        (0, 1332),
+        (0, 2),
+        (0, 5),
+        (0, 4),
+        (1, 673),
+        (0, 18),
+        (1, 101),
+        (0, 1026),
+        (0, 485),
+        (0, 15),
+        (0, 0),
+        (1, 856),
+        (0, 1427),
         (0, 6),
-        (8, 336),
-        (0, 136),
-        (0, 350),
-        (0, 365),
-        (5, 301),
+        (0, 26),
+        (0, 47),
+        (0, 1554),
+        (1, 682),
+        (0, 469),
+        (1, 1988),
+        (0, 109),
+        (0, 1755),
+        (0, 0),
+        (0, 223),
+        (0, 52),
+        (0, 161),
+        (0, 86),
+        (0, 70),
         (0, 10),
-        (0, 73),
-        (0, 14),
-        (0, 257),
-        (0, 293),
-        (0, 842),
+        (0, 2041),
+        (0, 107),
+        (0, 83),
         (0, 6),
+        (0, 322),
+        (0, 1),
+        (0, 52),
+        (1, 1941),
+        (0, 55),
+        (0, 40),
+        (0, 1),
+        (0, 24),
+        (0, 134),
+        (0, 62),
+        (1, 35),
+        (0, 1161),
+        (0, 191),
+        (0, 882),
+        (1, 98),
+        (0, 2),
+        (0, 15),
+        (1, 1354),
+        (0, 109),
+        (0, 243),
+        (1, 1501),
+        (0, 622),
+        (0, 81),
+        (0, 204),
+        (0, 13),
+        (0, 577),
+        (0, 18),
+        (0, 0),
+        (0, 1825),
+        (0, 743),
+        (0, 32),
+        (1, 704),
+        (0, 807),
+        (0, 546),
+        (0, 11),
+        (0, 1394),
+        (0, 482),
+        (0, 526),
         (0, 9),
-        (1, 649),
-        (5, 33),
+        (0, 837),
+        (0, 778),
+        (0, 13),
         (0, 2),
         (0, 0),
-        (0, 186),
-        (0, 350),
-        (0, 163),
-        (0, 16),
-        (0, 34),
+        (0, 1795),
+        (0, 149),
+        (0, 58),
+        (0, 126),
+        (0, 633),
+        (0, 320),
+        (5, 1296),
+        (0, 13),
         (0, 0),
-        (4, 390),
-        (2, 575),
-        (0, 336),
-        (0, 401),
-        (0, 620),
-        (12, 742),
-        (4, 287),
+        (0, 2),
+        (0, 17),
+        (0, 7),
+        (0, 8),
+        (0, 0),
+        (1, 443),
+        (0, 0),
+        (0, 0),
+        (0, 62),
+        (0, 27),
+        (0, 552),
+        (0, 2),
+        (0, 79),
+        (0, 46),
+        (0, 1),
+        (0, 463),
+        (0, 190),
+        (0, 500),
+        (0, 244),
+        (1, 901),
+        (2, 691),
+        (0, 134),
+        (0, 2),
+        (0, 1225),
+        (0, 41),
         (0, 5),
-        (2, 175),
-        (1, 244),
-        (0, 4),
-        (1, 657),
-        (0, 3),
-        (0, 135),
-        (0, 858),
-        (0, 157),
-        (0, 694),
-        (0, 476),
-        (2, 103),
-        (0, 52),
-        (1, 570),
+        (0, 458),
+        (2, 1425),
+        (0, 423),
+        (0, 344),
+        (0, 1164),
+        (3, 1088),
+        (0, 0),
+        (0, 371),
+        (0, 205),
+        (0, 327),
+        (10, 947),
+        (0, 20),
+        (0, 49),
+        (0, 116),
+        (0, 289),
+        (0, 243),
+        (0, 18),
+        (0, 6),
+        (0, 264),
+        (2, 1328),
+        (0, 780),
+        (2, 976),
+        (0, 35),
+        (1, 1436),
         (0, 123),
+        (7, 994),
+        (0, 536),
+        (8, 436),
+        (0, 157),
+        (0, 686),
+        (0, 29),
+        (0, 282),
+        (0, 789),
+        (0, 180),
+        (0, 1782),
+        (0, 0),
+        (0, 60),
+        (10, 194),
         (0, 3),
-        (0, 59),
-        (5, 300),
-        (0, 367),
+        (0, 535),
+        (0, 1334),
+        (0, 286),
+        (0, 78),
+        (0, 928),
+        (0, 102),
+        (0, 305),
+        (0, 42),
+        (0, 1),
+        (0, 1311),
+        (0, 75),
+        (3, 978),
+        (0, 100),
+        (6, 1830),
+        (0, 169),
+        (0, 147),
+        (2, 85),
+        (3, 1797),
+        (0, 895),
+        (0, 1262),
+        (1, 252),
+        (0, 121),
+        (0, 706),
+        (0, 2),
+        (2, 588),
+        (7, 1704),
+        (0, 403),
+        (11, 620),
+        (0, 0),
+        (0, 108),
+        (0, 1487),
+        (0, 593),
+        (3, 1134),
+        (0, 46),
+        (1, 341),
+        (0, 0),
+        (0, 381),
+        (0, 347),
+        (0, 236),
+        (2, 73),
+        (0, 1),
+        (0, 1503),
+        (5, 420),
+    ],
+    entries: &[
+        ('庶', crate::Replacement(2067)),
+        ('賈', crate::Replacement(2616)),
+        ('請', crate::Replacement(2593)),
+        ('舁', crate::Replacement(2502)),
+        ('Ṝ', crate::Replacement(438)),
+        ('ḫ', crate::Replacement(510)),
+        ('Ἐ', crate::Replacement(727)),
+        ('ὴ', crate::Replacement(777)),
+        ('率', crate::Replacement(2332)),
+        ('ķ', crate::Replacement(523)),
+        ('汧', crate::Replacement(2248)),
+        ('⇍', crate::Replacement(1483)),
+        ('滑', crate::Replacement(2278)),
+        ('芑', crate::Replacement(2514)),
+        ('Ȧ', crate::Replacement(364)),
+        ('ä', crate::Replacement(474)),
+        ('Ḟ', crate::Replacement(379)),
+        ('礼', crate::Replacement(2402)),
+        ('ﺃ', crate::Replacement(922)),
+        ('婢', crate::Replacement(2007)),
+        ('ᾪ', crate::Replacement(762)),
+        ('ḹ', crate::Replacement(537)),
+        ('備', crate::Replacement(1831)),
+        ('ṫ', crate::Replacement(588)),
+        ('Ẇ', crate::Replacement(457)),
+        ('Ǻ', crate::Replacement(364)),
+        ('ῼ', crate::Replacement(762)),
+        ('ó', crate::Replacement(569)),
+        ('𠔜', crate::Replacement(2802)),
+        ('災', crate::Replacement(2295)),
+        ('Ṍ', crate::Replacement(429)),
+        ('ὦ', crate::Replacement(819)),
+        ('海', crate::Replacement(2263)),
+        ('ọ', crate::Replacement(569)),
+        ('ș', crate::Replacement(584)),
+        ('ᾗ', crate::Replacement(777)),
+        ('難', crate::Replacement(2695)),
+        ('≭', crate::Replacement(1504)),
+        ('䗗', crate::Replacement(1770)),
+        ('絛', crate::Replacement(2455)),
+        ('ۂ', crate::Replacement(1240)),
+        ('Ŕ', crate::Replacement(438)),
+        ('¨', crate::Replacement(1)),
+        ('Ñ', crate::Replacement(425)),
+        ('櫓', crate::Replacement(2224)),
+        ('軔', crate::Replacement(2630)),
+        ('Έ', crate::Replacement(727)),
+        ('尢', crate::Replacement(2028)),
+        ('ΐ', crate::Replacement(782)),
+        ('ŗ', crate::Replacement(580)),
+        ('Ś', crate::Replacement(440)),
+        ('糖', crate::Replacement(2445)),
+        ('兀', crate::Replacement(1837)),
+        ('ō', crate::Replacement(569)),
+        ('祐', crate::Replacement(2406)),
+        ('שׁ', crate::Replacement(918)),
+        ('ṡ', crate::Replacement(584)),
+        ('ử', crate::Replacement(590)),
+        ('בֿ', crate::Replacement(897)),
+        ('נּ', crate::Replacement(910)),
+        ('𣍟', crate::Replacement(2828)),
+        ('𦉇', crate::Replacement(2871)),
+        ('Ὗ', crate::Replacement(750)),
+        ('咢', crate::Replacement(1939)),
+        ('變', crate::Replacement(2603)),
+        ('落', crate::Replacement(2536)),
+        ('㒻', crate::Replacement(1721)),
+        ('ǭ', crate::Replacement(569)),
+        ('靖', crate::Replacement(2703)),
+        ('Ȕ', crate::Replacement(451)),
+        ('ἳ', crate::Replacement(782)),
+        ('ȃ', crate::Replacement(474)),
+        ('𥥼', crate::Replacement(2863)),
+        ('飯', crate::Replacement(2724)),
+        ('歔', crate::Replacement(2229)),
+        ('ﹺ', crate::Replacement(1)),
+        ('ﹱ', crate::Replacement(1088)),
+        ('琉', crate::Replacement(2339)),
+        ('𥄳', crate::Replacement(2857)),
+        ('𣑭', crate::Replacement(2833)),
+        ('奈', crate::Replacement(1998)),
+        ('𡧈', crate::Replacement(2813)),
+        ('Ö', crate::Replacement(429)),
+        ('塚', crate::Replacement(1975)),
+        ('ḱ', crate::Replacement(523)),
+        ('Ë', crate::Replacement(378)),
+        ('Ἠ', crate::Replacement(729)),
+        ('é', crate::Replacement(498)),
+        ('῎', crate::Replacement(1)),
+        ('ᾘ', crate::Replacement(729)),
+        ('識', crate::Replacement(2601)),
+        ('ﴼ', crate::Replacement(922)),
+        ('㶖', crate::Replacement(1744)),
+        ('洴', crate::Replacement(2258)),
+        ('⊮', crate::Replacement(1524)),
+        ('屮', crate::Replacement(2035)),
+        ('珞', crate::Replacement(2337)),
+        ('望', crate::Replacement(2196)),
+        ('陵', crate::Replacement(2684)),
+        ('ǩ', crate::Replacement(523)),
+        ('割', crate::Replacement(1878)),
+        ('謹', crate::Replacement(2600)),
+        ('￣', crate::Replacement(1)),
+        ('Ȱ', crate::Replacement(429)),
+        ('丹', crate::Replacement(1800)),
+        ('蜨', crate::Replacement(2561)),
+        ('諸', crate::Replacement(2597)),
+        ('\u{1b3b}', crate::Replacement(1405)),
+        ('聠', crate::Replacement(2484)),
+        ('弄', crate::Replacement(2076)),
+        ('Ὤ', crate::Replacement(762)),
+        ('Ω', crate::Replacement(762)),
+        ('≱', crate::Replacement(1507)),
+        ('ù', crate::Replacement(590)),
+        ('Å', crate::Replacement(364)),
+        ('峀', crate::Replacement(2038)),
+        ('ȋ', crate::Replacement(514)),
+        ('鷺', crate::Replacement(2752)),
+        ('辞', crate::Replacement(2637)),
+        ('¸', crate::Replacement(1)),
+        ('Ϊ', crate::Replacement(734)),
+        ('𣎜', crate::Replacement(2830)),
+        ('Ï', crate::Replacement(397)),
+        ('霣', crate::Replacement(2699)),
+        ('裂', crate::Replacement(2573)),
+        ('摒', crate::Replacement(2154)),
+        ('娛', crate::Replacement(2005)),
+        ('龍', crate::Replacement(2777)),
+        ('變', crate::Replacement(2603)),
+        ('ᾢ', crate::Replacement(819)),
+        ('郱', crate::Replacement(2652)),
+        ('Ἁ', crate::Replacement(720)),
+        ('ạ', crate::Replacement(474)),
+        ('Ỗ', crate::Replacement(429)),
+        ('㰘', crate::Replacement(1741)),
+        ('ำ', crate::Replacement(1281)),
+        ('ề', crate::Replacement(498)),
+        ('祈', crate::Replacement(2404)),
+        ('柺', crate::Replacement(2207)),
+        ('ώ', crate::Replacement(819)),
+        ('Ṡ', crate::Replacement(440)),
+        ('Ẻ', crate::Replacement(378)),
+        ('零', crate::Replacement(2697)),
+        ('鱀', crate::Replacement(2746)),
+        ('堲', crate::Replacement(1973)),
+        ('蜎', crate::Replacement(2560)),
+        ('ﱢ', crate::Replacement(1)),
+        ('῍', crate::Replacement(1)),
+        ('喙', crate::Replacement(1949)),
+        ('Ş', crate::Replacement(440)),
+        ('瘝', crate::Replacement(2369)),
+        ('𤎫', crate::Replacement(2845)),
+        ('郎', crate::Replacement(2650)),
+        ('Ĕ', crate::Replacement(378)),
+        ('Ὥ', crate::Replacement(762)),
+        ('遲', crate::Replacement(2645)),
+        ('洞', crate::Replacement(2257)),
+        ('堍', crate::Replacement(1971)),
+        ('裞', crate::Replacement(2576)),
+        ('烙', crate::Replacement(2299)),
+        ('磻', crate::Replacement(2399)),
+        ('隣', crate::Replacement(2688)),
+        ('䊠', crate::Replacement(1758)),
+        ('刻', crate::Replacement(1875)),
+        ('Ș', crate::Replacement(440)),
+        ('廙', crate::Replacement(2072)),
+        ('Ẳ', crate::Replacement(364)),
+        ('頋', crate::Replacement(2715)),
+        ('Ĺ', crate::Replacement(410)),
+        ('≯', crate::Replacement(359)),
+        ('ĵ', crate::Replacement(521)),
+        ('≸', crate::Replacement(1510)),
+        ('惇', crate::Replacement(2103)),
+        ('ỵ', crate::Replacement(600)),
+        ('Ự', crate::Replacement(451)),
+        ('廬', crate::Replacement(2073)),
+        ('椔', crate::Replacement(2217)),
+        ('𦌾', crate::Replacement(2873)),
+        ('ǽ', crate::Replacement(617)),
+        ('拏', crate::Replacement(2135)),
+        ('㞁', crate::Replacement(1726)),
+        ('ǅ', crate::Replacement(377)),
+        ('摩', crate::Replacement(2155)),
+        ('ᾅ', crate::Replacement(768)),
+        ('著', crate::Replacement(2538)),
+        ('Ǟ', crate::Replacement(364)),
+        ('ἆ', crate::Replacement(768)),
+        ('㮝', crate::Replacement(1740)),
+        ('当', crate::Replacement(2081)),
+        ('荣', crate::Replacement(2526)),
+        ('ỏ', crate::Replacement(569)),
+        ('ű', crate::Replacement(590)),
+        ('ئ', crate::Replacement(1187)),
+        ('ﺂ', crate::Replacement(922)),
+        ('∄', crate::Replacement(1487)),
+        ('壮', crate::Replacement(1983)),
+        ('獺', crate::Replacement(2330)),
+        ('鸞', crate::Replacement(2753)),
+        ('ﯲ', crate::Replacement(1205)),
+        ('羕', crate::Replacement(2474)),
+        ('ü', crate::Replacement(590)),
+        ('Ỷ', crate::Replacement(465)),
+        ('ἔ', crate::Replacement(775)),
+        ('ů', crate::Replacement(590)),
+        ('ḯ', crate::Replacement(514)),
+        ('ё', crate::Replacement(844)),
+        ('\u{cc7}', crate::Replacement(1277)),
+        ('犯', crate::Replacement(2325)),
+        ('菱', crate::Replacement(2535)),
+        ('ᾶ', crate::Replacement(768)),
+        ('節', crate::Replacement(2435)),
+        ('車', crate::Replacement(2629)),
+        ('ṏ', crate::Replacement(569)),
+        ('ἠ', crate::Replacement(777)),
+        ('葉', crate::Replacement(2537)),
+        ('復', crate::Replacement(2091)),
+        ('䳭', crate::Replacement(1783)),
+        ('ả', crate::Replacement(474)),
+        ('籠', crate::Replacement(2439)),
+        ('Ň', crate::Replacement(425)),
+        ('Ÿ', crate::Replacement(465)),
+        ('Ǎ', crate::Replacement(364)),
+        ('浪', crate::Replacement(2262)),
+        ('㩬', crate::Replacement(1735)),
+        ('脾', crate::Replacement(2494)),
+        ('ě', crate::Replacement(498)),
+        ('𧻓', crate::Replacement(2891)),
+        ('ѝ', crate::Replacement(847)),
+        ('ﯹ', crate::Replacement(1202)),
+        ('ẏ', crate::Replacement(600)),
+        ('ṓ', crate::Replacement(569)),
+        ('靖', crate::Replacement(2703)),
+        ('פֿ', crate::Replacement(914)),
+        ('גּ', crate::Replacement(898)),
+        ('ﺉ', crate::Replacement(1187)),
+        ('Ŝ', crate::Replacement(440)),
+        ('䑫', crate::Replacement(1765)),
+        ('Ӂ', crate::Replacement(830)),
+        ('Ḙ', crate::Replacement(378)),
+        ('Ἄ', crate::Replacement(720)),
+        ('𥉉', crate::Replacement(2858)),
+        ('ǿ', crate::Replacement(619)),
+        ('形', crate::Replacement(2083)),
+        ('王', crate::Replacement(2334)),
+        ('哶', crate::Replacement(1941)),
+        ('蘒', crate::Replacement(2548)),
+        ('ṋ', crate::Replacement(561)),
+        ('喇', crate::Replacement(1948)),
+        ('ĝ', crate::Replacement(508)),
+        ('驪', crate::Replacement(2735)),
+        ('翺', crate::Replacement(2477)),
+        ('Ὼ', crate::Replacement(762)),
+        ('K', crate::Replacement(406)),
+        ('Ḥ', crate::Replacement(391)),
+        ('帨', crate::Replacement(2055)),
+        ('Ὑ', crate::Replacement(750)),
+        ('ắ', crate::Replacement(474)),
+        ('敏', crate::Replacement(2163)),
+        ('ą', crate::Replacement(474)),
+        ('成', crate::Replacement(2124)),
+        ('省', crate::Replacement(2383)),
+        ('ӣ', crate::Replacement(847)),
+        ('免', crate::Replacement(1839)),
+        ('煮', crate::Replacement(2303)),
+        ('Ọ', crate::Replacement(429)),
+        ('懶', crate::Replacement(2121)),
+        ('Ŗ', crate::Replacement(438)),
+        ('ǣ', crate::Replacement(617)),
+        ('ᾥ', crate::Replacement(819)),
+        ('蘭', crate::Replacement(2549)),
+        ('𤋮', crate::Replacement(2844)),
+        ('楂', crate::Replacement(2218)),
+        ('䳸', crate::Replacement(1784)),
+        ('ᾰ', crate::Replacement(768)),
+        ('ﱠ', crate::Replacement(1)),
+        ('䀘', crate::Replacement(1750)),
+        ('Ě', crate::Replacement(378)),
+        ('粒', crate::Replacement(2442)),
+        ('﹋', crate::Replacement(1)),
+        ('書', crate::Replacement(2191)),
+        ('फ़', crate::Replacement(1259)),
+        ('紐', crate::Replacement(2451)),
+        ('蔖', crate::Replacement(2543)),
+        ('黾', crate::Replacement(2766)),
+        ('紀', crate::Replacement(2450)),
+        ('真', crate::Replacement(2385)),
+        ('檨', crate::Replacement(2223)),
+        ('Ţ', crate::Replacement(446)),
+        ('稜', crate::Replacement(2422)),
+        ('Ĭ', crate::Replacement(397)),
+        ('क़', crate::Replacement(1252)),
+        ('ﴽ', crate::Replacement(922)),
+        ('拔', crate::Replacement(2137)),
+        ('缾', crate::Replacement(2467)),
+        ('Ẹ', crate::Replacement(378)),
+        ('ế', crate::Replacement(498)),
+        ('ﹸ', crate::Replacement(1)),
+        ('真', crate::Replacement(2385)),
+        ('Ŭ', crate::Replacement(451)),
+        ('ợ', crate::Replacement(569)),
+        ('㛮', crate::Replacement(1724)),
+        ('奔', crate::Replacement(2000)),
+        ('ῠ', crate::Replacement(807)),
+        ('↮', crate::Replacement(1482)),
+        ('ḅ', crate::Replacement(479)),
+        ('ǆ', crate::Replacement(497)),
+        ('穀', crate::Replacement(2423)),
+        ('ﯫ', crate::Replacement(1188)),
+        ('巢', crate::Replacement(2049)),
+        ('Ὺ', crate::Replacement(750)),
+        ('𠄢', crate::Replacement(2801)),
+        ('ė', crate::Replacement(498)),
+        ('ᾲ', crate::Replacement(768)),
+        ('Ḻ', crate::Replacement(410)),
+        ('𦰶', crate::Replacement(2880)),
+        ('ᾑ', crate::Replacement(777)),
+        ('ῲ', crate::Replacement(819)),
+        ('ȇ', crate::Replacement(498)),
+        ('刺', crate::Replacement(1874)),
+        ('蟡', crate::Replacement(2566)),
+        ('嵼', crate::Replacement(2044)),
+        ('Ṻ', crate::Replacement(451)),
+        ('揤', crate::Replacement(2151)),
+        ('尿', crate::Replacement(2030)),
+        ('ḻ', crate::Replacement(537)),
+        ('來', crate::Replacement(1823)),
+        ('ẋ', crate::Replacement(597)),
+        ('直', crate::Replacement(2382)),
+        ('ὕ', crate::Replacement(807)),
+        ('Ễ', crate::Replacement(378)),
+        ('脃', crate::Replacement(2493)),
+        ('ẫ', crate::Replacement(474)),
+        ('轢', crate::Replacement(2635)),
+        ('蕤', crate::Replacement(2544)),
+        ('練', crate::Replacement(2460)),
+        ('ᾖ', crate::Replacement(777)),
+        ('ȩ', crate::Replacement(498)),
+        ('菧', crate::Replacement(2533)),
+        ('悔', crate::Replacement(2102)),
+        ('Ę', crate::Replacement(378)),
+        ('â', crate::Replacement(474)),
+        ('ὼ', crate::Replacement(819)),
+        ('\u{1b43}', crate::Replacement(1405)),
+        ('杻', crate::Replacement(2203)),
+        ('噑', crate::Replacement(1958)),
+        ('į', crate::Replacement(514)),
+        ('﹉', crate::Replacement(1)),
+        ('ἰ', crate::Replacement(782)),
+        ('次', crate::Replacement(2228)),
+        ('灰', crate::Replacement(2293)),
+        ('Ṵ', crate::Replacement(451)),
+        ('Ṹ', crate::Replacement(451)),
+        ('善', crate::Replacement(1947)),
+        ('Ğ', crate::Replacement(386)),
+        ('`', crate::Replacement(473)),
+        ('ἐ', crate::Replacement(775)),
+        ('豈', crate::Replacement(2606)),
+        ('志', crate::Replacement(2095)),
+        ('節', crate::Replacement(2435)),
+        ('𥳐', crate::Replacement(2867)),
+        ('蓳', crate::Replacement(2541)),
+        ('韛', crate::Replacement(2708)),
+        ('𩒖', crate::Replacement(2902)),
+        ('諾', crate::Replacement(2598)),
+        ('拾', crate::Replacement(2139)),
+        ('立', crate::Replacement(2430)),
+        ('ὃ', crate::Replacement(800)),
+        ('Ū', crate::Replacement(451)),
+        ('巽', crate::Replacement(2053)),
+        ('莭', crate::Replacement(2527)),
+        ('淪', crate::Replacement(2268)),
+        ('ﯱ', crate::Replacement(1206)),
+        ('𢯱', crate::Replacement(2825)),
+        ('й', crate::Replacement(847)),
+        ('暜', crate::Replacement(2186)),
+        ('Ḳ', crate::Replacement(406)),
+        ('𥐝', crate::Replacement(2859)),
+        ('Ȍ', crate::Replacement(429)),
+        ('Ậ', crate::Replacement(364)),
+        ('ĭ', crate::Replacement(514)),
+        ('ῄ', crate::Replacement(777)),
+        ('率', crate::Replacement(2332)),
+        ('ἒ', crate::Replacement(775)),
+        ('揅', crate::Replacement(2150)),
+        ('ὂ', crate::Replacement(800)),
+        ('ӥ', crate::Replacement(847)),
+        ('ἡ', crate::Replacement(777)),
+        ('響', crate::Replacement(2712)),
+        ('拉', crate::Replacement(2134)),
+        ('舁', crate::Replacement(2502)),
+        ('絣', crate::Replacement(2456)),
+        ('鳽', crate::Replacement(2749)),
+        ('שּׁ', crate::Replacement(918)),
+        ('調', crate::Replacement(2592)),
+        ('ē', crate::Replacement(498)),
+        ('既', crate::Replacement(2175)),
+        ('裗', crate::Replacement(2575)),
+        ('串', crate::Replacement(1797)),
+        ('夆', crate::Replacement(1988)),
+        ('姘', crate::Replacement(2003)),
+        ('ñ', crate::Replacement(561)),
+        ('ḟ', crate::Replacement(501)),
+        ('ᾴ', crate::Replacement(768)),
+        ('冒', crate::Replacement(1852)),
+        ('Ȭ', crate::Replacement(429)),
+        ('海', crate::Replacement(2263)),
+        ('者', crate::Replacement(2479)),
+        ('ᾇ', crate::Replacement(768)),
+        ('勞', crate::Replacement(1888)),
+        ('瑇', crate::Replacement(2341)),
+        ('䵖', crate::Replacement(1785)),
+        ('𤉣', crate::Replacement(2843)),
+        ('䘵', crate::Replacement(1772)),
+        ('鼅', crate::Replacement(2767)),
+        ('陸', crate::Replacement(2685)),
+        ('ӳ', crate::Replacement(857)),
+        ('Ẋ', crate::Replacement(462)),
+        ('禎', crate::Replacement(2414)),
+        ('奢', crate::Replacement(2001)),
+        ('滛', crate::Replacement(2279)),
+        ('ﻶ', crate::Replacement(1119)),
+        ('睊', crate::Replacement(2387)),
+        ('ẇ', crate::Replacement(595)),
+        ('隷', crate::Replacement(2690)),
+        ('Ḣ', crate::Replacement(391)),
+        ('ί', crate::Replacement(782)),
+        ('令', crate::Replacement(1817)),
+        ('䏕', crate::Replacement(1762)),
+        ('ṍ', crate::Replacement(569)),
+        ('⋭', crate::Replacement(1529)),
+        ('Ώ', crate::Replacement(762)),
+        ('羚', crate::Replacement(2475)),
+        ('剆', crate::Replacement(1876)),
+        ('肋', crate::Replacement(2490)),
+        ('朗', crate::Replacement(2195)),
+        ('ड़', crate::Replacement(1256)),
+        ('㬙', crate::Replacement(1738)),
+        ('ঢ়', crate::Replacement(1264)),
+        ('淚', crate::Replacement(2267)),
+        ('育', crate::Replacement(2492)),
+        ('ਖ਼', crate::Replacement(1266)),
+        ('Ǵ', crate::Replacement(386)),
+        ('𣴞', crate::Replacement(2839)),
+        ('ﲐ', crate::Replacement(1186)),
+        ('𤰶', crate::Replacement(2849)),
+        ('藺', crate::Replacement(2546)),
+        ('ﺄ', crate::Replacement(922)),
+        ('É', crate::Replacement(378)),
+        ('聯', crate::Replacement(2485)),
+        ('Ὸ', crate::Replacement(744)),
+        ('Ḃ', crate::Replacement(368)),
+        ('戀', crate::Replacement(2122)),
+        ('\u{cca}', crate::Replacement(1275)),
+        ('𦖨', crate::Replacement(2876)),
+        ('ﲘ', crate::Replacement(1191)),
+        ('䍙', crate::Replacement(1761)),
+        ('㭉', crate::Replacement(1739)),
+        ('ȗ', crate::Replacement(590)),
+        ('汎', crate::Replacement(2247)),
+        ('Ǜ', crate::Replacement(451)),
+        ('捨', crate::Replacement(2144)),
+        ('裏', crate::Replacement(2574)),
+        ('𠕋', crate::Replacement(2804)),
+        ('Ớ', crate::Replacement(429)),
+        ('∦', crate::Replacement(1494)),
+        ('蝹', crate::Replacement(2563)),
+        ('ἂ', crate::Replacement(768)),
+        ('ȑ', crate::Replacement(580)),
+        ('Å', crate::Replacement(364)),
+        ('餩', crate::Replacement(2727)),
+        ('菌', crate::Replacement(2531)),
+        ('ﱟ', crate::Replacement(1)),
+        ('寿', crate::Replacement(2025)),
+        ('𩖶', crate::Replacement(2903)),
+        ('ᾐ', crate::Replacement(777)),
+        ('ỷ', crate::Replacement(600)),
+        ('ὥ', crate::Replacement(819)),
+        ('漢', crate::Replacement(2282)),
+        ('˝', crate::Replacement(1)),
+        ('צּ', crate::Replacement(915)),
+        ('𢛔', crate::Replacement(2821)),
+        ('簾', crate::Replacement(2438)),
+        ('劳', crate::Replacement(1883)),
+        ('ç', crate::Replacement(481)),
+        ('ẍ', crate::Replacement(597)),
+        ('ť', crate::Replacement(588)),
+        ('喝', crate::Replacement(1950)),
+        ('鹿', crate::Replacement(2755)),
+        ('涅', crate::Replacement(2265)),
+        ('悁', crate::Replacement(2101)),
+        ('隸', crate::Replacement(2691)),
+        ('敏', crate::Replacement(2163)),
+        ('ဦ', crate::Replacement(1292)),
+        ('Ἰ', crate::Replacement(734)),
+        ('惡', crate::Replacement(2105)),
+        ('𥃳', crate::Replacement(2855)),
+        ('ẛ', crate::Replacement(584)),
+        ('福', crate::Replacement(2415)),
+        ('ǯ', crate::Replacement(693)),
+        ('飼', crate::Replacement(2725)),
+        ('繅', crate::Replacement(2465)),
+        ('𤲒', crate::Replacement(2850)),
+        ('ụ', crate::Replacement(590)),
+        ('𦋙', crate::Replacement(2872)),
+        ('媵', crate::Replacement(2009)),
+        ('ﱦ', crate::Replacement(1196)),
+        ('ȯ', crate::Replacement(569)),
+        ('Ἧ', crate::Replacement(729)),
+        ('你', crate::Replacement(1821)),
+        ('突', crate::Replacement(2428)),
+        ('行', crate::Replacement(2570)),
+        ('癩', crate::Replacement(2372)),
+        ('ᾨ', crate::Replacement(762)),
+        ('虩', crate::Replacement(2555)),
+        ('ﹽ', crate::Replacement(1088)),
+        ('ḓ', crate::Replacement(490)),
+        ('ᾭ', crate::Replacement(762)),
+        ('輦', crate::Replacement(2631)),
+        ('וּ', crate::Replacement(901)),
+        ('奄', crate::Replacement(1997)),
+        ('㓟', crate::Replacement(1722)),
+        ('ḛ', crate::Replacement(498)),
+        ('樂', crate::Replacement(2221)),
+        ('梅', crate::Replacement(2214)),
+        ('圗', crate::Replacement(1965)),
+        ('ễ', crate::Replacement(498)),
+        ('ﱧ', crate::Replacement(1199)),
+        ('憯', crate::Replacement(2117)),
+        ('殻', crate::Replacement(2239)),
+        ('ẓ', crate::Replacement(602)),
+        ('璉', crate::Replacement(2346)),
+        ('ὗ', crate::Replacement(807)),
+        ('ᾛ', crate::Replacement(729)),
+        ('秊', crate::Replacement(2419)),
+        ('Ἤ', crate::Replacement(729)),
+        ('ẹ', crate::Replacement(498)),
+        ('拼', crate::Replacement(2138)),
+        ('ś', crate::Replacement(584)),
+        ('廒', crate::Replacement(2070)),
+        ('Ṥ', crate::Replacement(440)),
+        ('Ể', crate::Replacement(378)),
+        ('女', crate::Replacement(2002)),
+        ('ӓ', crate::Replacement(839)),
+        ('䕡', crate::Replacement(1768)),
+        ('勉', crate::Replacement(1886)),
+        ('倂', crate::Replacement(1828)),
+        ('律', crate::Replacement(2087)),
+        ('ϋ', crate::Replacement(807)),
+        ('Ắ', crate::Replacement(364)),
+        ('忹', crate::Replacement(2097)),
+        ('ﰃ', crate::Replacement(1202)),
+        ('荒', crate::Replacement(2524)),
+        ('䃣', crate::Replacement(1754)),
+        ('䗹', crate::Replacement(1771)),
+        ('拓', crate::Replacement(2136)),
+        ('ӫ', crate::Replacement(882)),
+        ('Ṣ', crate::Replacement(440)),
+        ('Ὂ', crate::Replacement(744)),
+        ('Ř', crate::Replacement(438)),
+        ('ǘ', crate::Replacement(590)),
+        ('臘', crate::Replacement(2495)),
+        ('祥', crate::Replacement(2410)),
+        ('兤', crate::Replacement(1841)),
+        ('囹', crate::Replacement(1963)),
+        ('ή', crate::Replacement(777)),
+        ('𥘦', crate::Replacement(2860)),
+        ('Ȃ', crate::Replacement(364)),
+        ('㡢', crate::Replacement(1728)),
+        ('梁', crate::Replacement(2213)),
+        ('最', crate::Replacement(2192)),
+        ('ά', crate::Replacement(768)),
+        ('΄', crate::Replacement(1)),
+        ('À', crate::Replacement(364)),
+        ('啓', crate::Replacement(1944)),
+        ('Ȑ', crate::Replacement(438)),
+        ('ᾕ', crate::Replacement(777)),
+        ('Ί', crate::Replacement(734)),
+        ('領', crate::Replacement(2716)),
+        ('㡼', crate::Replacement(1729)),
+        ('惘', crate::Replacement(2104)),
+        ('ï', crate::Replacement(514)),
+        ('噴', crate::Replacement(1960)),
+        ('𡷤', crate::Replacement(2816)),
+        ('劣', crate::Replacement(1882)),
+        ('ṅ', crate::Replacement(561)),
+        ('Ὁ', crate::Replacement(744)),
+        ('Ἅ', crate::Replacement(720)),
+        ('ᾩ', crate::Replacement(762)),
+        ('𨯺', crate::Replacement(2896)),
+        ('𢆃', crate::Replacement(2818)),
+        ('Ῐ', crate::Replacement(734)),
+        ('Ѷ', crate::Replacement(872)),
+        ('淹', crate::Replacement(2269)),
+        ('嶲', crate::Replacement(2045)),
+        ('ﻹ', crate::Replacement(1119)),
+        ('䀹', crate::Replacement(1751)),
+        ('ῥ', crate::Replacement(802)),
+        ('ǫ', crate::Replacement(569)),
+        ('Ź', crate::Replacement(467)),
+        ('ᾼ', crate::Replacement(720)),
+        ('䩶', crate::Replacement(1778)),
+        ('ὅ', crate::Replacement(800)),
+        ('憎', crate::Replacement(2114)),
+        ('𣻑', crate::Replacement(2840)),
+        ('ﳲ', crate::Replacement(1088)),
+        ('῾', crate::Replacement(1)),
+        ('黎', crate::Replacement(2762)),
+        ('ἤ', crate::Replacement(777)),
+        ('濾', crate::Replacement(2287)),
+        ('᾿', crate::Replacement(1)),
+        ('䕫', crate::Replacement(1769)),
+        ('㱎', crate::Replacement(1742)),
+        ('𧙧', crate::Replacement(2887)),
+        ('慎', crate::Replacement(2110)),
+        ('靈', crate::Replacement(2701)),
+        ('Ẫ', crate::Replacement(364)),
+        ('𥪧', crate::Replacement(2864)),
+        ('鈴', crate::Replacement(2665)),
+        ('Ẵ', crate::Replacement(364)),
+        ('⋢', crate::Replacement(1520)),
+        ('ﺅ', crate::Replacement(1183)),
+        ('שׂ', crate::Replacement(918)),
+        ('禮', crate::Replacement(2416)),
+        ('å', crate::Replacement(474)),
+        ('冗', crate::Replacement(1855)),
+        ('愈', crate::Replacement(2106)),
+        ('ĕ', crate::Replacement(498)),
+        ('Ộ', crate::Replacement(429)),
+        ('虐', crate::Replacement(2552)),
+        ('Ṋ', crate::Replacement(425)),
+        ('ṉ', crate::Replacement(561)),
+        ('Ǔ', crate::Replacement(451)),
+        ('寘', crate::Replacement(2020)),
+        ('ὰ', crate::Replacement(768)),
+        ('Ữ', crate::Replacement(451)),
+        ('ặ', crate::Replacement(474)),
+        ('𨗒', crate::Replacement(2893)),
+        ('索', crate::Replacement(2452)),
+        ('Ϋ', crate::Replacement(750)),
+        ('ᾦ', crate::Replacement(819)),
+        ('Ǆ', crate::Replacement(376)),
+        ('埴', crate::Replacement(1970)),
+        ('Ľ', crate::Replacement(410)),
+        ('殺', crate::Replacement(2238)),
+        ('á', crate::Replacement(474)),
+        ('≵', crate::Replacement(1509)),
+        ('Ơ', crate::Replacement(429)),
+        ('邏', crate::Replacement(2647)),
+        ('易', crate::Replacement(2179)),
+        ('麻', crate::Replacement(2759)),
+        ('≠', crate::Replacement(356)),
+        ('Ả', crate::Replacement(364)),
+        ('廾', crate::Replacement(2075)),
+        ('肭', crate::Replacement(2491)),
+        ('杓', crate::Replacement(2200)),
+        ('≄', crate::Replacement(1501)),
+        ('包', crate::Replacement(1893)),
+        ('塞', crate::Replacement(1976)),
+        ('ӂ', crate::Replacement(845)),
+        ('韠', crate::Replacement(2709)),
+        ('茶', crate::Replacement(2523)),
+        ('ᾌ', crate::Replacement(720)),
+        ('Ӑ', crate::Replacement(827)),
+        ('Ỏ', crate::Replacement(429)),
+        ('ŭ', crate::Replacement(590)),
+        ('Ὃ', crate::Replacement(744)),
+        ('Ć', crate::Replacement(370)),
+        ('桒', crate::Replacement(2212)),
+        ('匿', crate::Replacement(1900)),
+        ('露', crate::Replacement(2700)),
+        ('揄', crate::Replacement(2149)),
+        ('瑜', crate::Replacement(2342)),
+        ('ḳ', crate::Replacement(523)),
+        ('έ', crate::Replacement(775)),
+        ('慌', crate::Replacement(2109)),
+        ('㣇', crate::Replacement(1730)),
+        ('ῦ', crate::Replacement(807)),
+        ('ᾜ', crate::Replacement(729)),
+        ('Ẅ', crate::Replacement(457)),
+        ('ﰀ', crate::Replacement(1189)),
+        ('Ḱ', crate::Replacement(406)),
+        ('穊', crate::Replacement(2424)),
+        ('ὸ', crate::Replacement(800)),
+        ('滇', crate::Replacement(2276)),
+        ('遼', crate::Replacement(2646)),
+        ('𣎓', crate::Replacement(2829)),
+        ('ﺆ', crate::Replacement(1183)),
+        ('⇎', crate::Replacement(1485)),
+        ('聾', crate::Replacement(2487)),
+        ('嘆', crate::Replacement(1957)),
+        ('𥲀', crate::Replacement(2866)),
+        ('Ç', crate::Replacement(370)),
+        ('ź', crate::Replacement(602)),
+        ('憤', crate::Replacement(2116)),
+        ('ẑ', crate::Replacement(602)),
+        ('ǧ', crate::Replacement(508)),
+        ('擄', crate::Replacement(2160)),
+        ('ũ', crate::Replacement(590)),
+        ('Ổ', crate::Replacement(429)),
+        ('祉', crate::Replacement(2405)),
+        ('朗', crate::Replacement(2195)),
+        ('ǎ', crate::Replacement(474)),
+        ('ﺈ', crate::Replacement(922)),
+        ('窱', crate::Replacement(2429)),
+        ('忍', crate::Replacement(2094)),
+        ('ὓ', crate::Replacement(807)),
+        ('מּ', crate::Replacement(909)),
+        ('Ạ', crate::Replacement(364)),
+        ('Ỉ', crate::Replacement(397)),
+        ('ऴ', crate::Replacement(1262)),
+        ('晉', crate::Replacement(2182)),
+        ('ἷ', crate::Replacement(782)),
+        ('\u{1b3d}', crate::Replacement(1405)),
+        ('Ṳ', crate::Replacement(451)),
+        ('š', crate::Replacement(584)),
+        ('流', crate::Replacement(2260)),
+        ('ﯴ', crate::Replacement(1207)),
+        ('祖', crate::Replacement(2407)),
+        ('أ', crate::Replacement(922)),
+        ('裸', crate::Replacement(2578)),
+        ('周', crate::Replacement(1937)),
+        ('開', crate::Replacement(2677)),
+        ('䀈', crate::Replacement(1749)),
+        ('ᾮ', crate::Replacement(762)),
+        ('΅', crate::Replacement(1)),
+        ('派', crate::Replacement(2259)),
+        ('歹', crate::Replacement(2234)),
+        ('ѷ', crate::Replacement(873)),
+        ('槪', crate::Replacement(2220)),
+        ('殟', crate::Replacement(2235)),
+        ('ἃ', crate::Replacement(768)),
+        ('ό', crate::Replacement(800)),
+        ('Ȯ', crate::Replacement(429)),
+        ('וֹ', crate::Replacement(901)),
+        ('廊', crate::Replacement(2069)),
+        ('逸', crate::Replacement(2642)),
+        ('輸', crate::Replacement(2633)),
+        ('Ṧ', crate::Replacement(440)),
+        ('ﳙ', crate::Replacement(1176)),
+        ('ӹ', crate::Replacement(864)),
+        ('˙', crate::Replacement(1)),
+        ('ċ', crate::Replacement(481)),
+        ('型', crate::Replacement(1968)),
+        ('𥾆', crate::Replacement(2868)),
+        ('喫', crate::Replacement(1951)),
+        ('燐', crate::Replacement(2306)),
+        ('贈', crate::Replacement(2618)),
+        ('ӯ', crate::Replacement(857)),
+        ('ṭ', crate::Replacement(588)),
+        ('Ό', crate::Replacement(744)),
+        ('華', crate::Replacement(2534)),
+        ('ΐ', crate::Replacement(782)),
+        ('𦞵', crate::Replacement(2878)),
+        ('ȁ', crate::Replacement(474)),
+        ('ﱣ', crate::Replacement(1)),
+        ('ﺁ', crate::Replacement(922)),
+        ('梨', crate::Replacement(2216)),
+        ('磊', crate::Replacement(2397)),
+        ('刃', crate::Replacement(1869)),
+        ('Ὣ', crate::Replacement(762)),
+        ('Ḋ', crate::Replacement(374)),
+        ('戴', crate::Replacement(2127)),
+        ('ṩ', crate::Replacement(584)),
+        ('⊭', crate::Replacement(1523)),
+        ('Ė', crate::Replacement(378)),
+        ('嬾', crate::Replacement(2012)),
+        ('Ӛ', crate::Replacement(879)),
+        ('ầ', crate::Replacement(474)),
+        ('Ṷ', crate::Replacement(451)),
+        ('侀', crate::Replacement(1822)),
+        ('淋', crate::Replacement(2266)),
+        ('﹌', crate::Replacement(1)),
+        ('Ở', crate::Replacement(429)),
+        ('Ἡ', crate::Replacement(729)),
+        ('欄', crate::Replacement(2226)),
+        ('Ņ', crate::Replacement(425)),
+        ('輪', crate::Replacement(2632)),
+        ('縷', crate::Replacement(2463)),
+        ('𪘀', crate::Replacement(2910)),
+        ('ì', crate::Replacement(514)),
+        ('叟', crate::Replacement(1921)),
+        ('亮', crate::Replacement(1813)),
+        ('陼', crate::Replacement(2686)),
+        ('參', crate::Replacement(1917)),
+        ('ὒ', crate::Replacement(807)),
+        ('ୈ', crate::Replacement(1274)),
+        ('嗢', crate::Replacement(1956)),
+        ('ǔ', crate::Replacement(590)),
+        ('瀞', crate::Replacement(2289)),
+        ('ẳ', crate::Replacement(474)),
+        ('Í', crate::Replacement(397)),
+        ('ẃ', crate::Replacement(595)),
+        ('贛', crate::Replacement(2619)),
+        ('Ǡ', crate::Replacement(364)),
+        ('諒', crate::Replacement(2594)),
+        ('᾽', crate::Replacement(1)),
+        ('充', crate::Replacement(1838)),
+        ('辶', crate::Replacement(2640)),
+        ('隆', crate::Replacement(2687)),
+        ('ﳠ', crate::Replacement(1200)),
+        ('ŝ', crate::Replacement(584)),
+        ('Ǚ', crate::Replacement(451)),
+        ('Ḑ', crate::Replacement(374)),
+        ('ὢ', crate::Replacement(819)),
+        ('Ӓ', crate::Replacement(827)),
+        ('雃', crate::Replacement(2693)),
+        ('Â', crate::Replacement(364)),
+        ('ﲛ', crate::Replacement(1200)),
+        ('Ị', crate::Replacement(397)),
+        ('Ử', crate::Replacement(451)),
+        ('犀', crate::Replacement(2322)),
+        ('謁', crate::Replacement(2599)),
+        ('ଢ଼', crate::Replacement(1273)),
+        ('漏', crate::Replacement(2280)),
+        ('Ḫ', crate::Replacement(391)),
+        ('Ž', crate::Replacement(467)),
+        ('Ṇ', crate::Replacement(425)),
+        ('Ǘ', crate::Replacement(451)),
+        ('⊀', crate::Replacement(1512)),
+        ('ứ', crate::Replacement(590)),
+        ('Ἥ', crate::Replacement(729)),
+        ('穀', crate::Replacement(2423)),
+        ('ᾊ', crate::Replacement(720)),
+        ('Ḯ', crate::Replacement(397)),
+        ('鬒', crate::Replacement(2739)),
+        ('Į', crate::Replacement(397)),
+        ('練', crate::Replacement(2460)),
+        ('蓮', crate::Replacement(2539)),
+        ('ấ', crate::Replacement(474)),
+        ('𣚣', crate::Replacement(2834)),
+        ('Ἃ', crate::Replacement(720)),
+        ('ǻ', crate::Replacement(474)),
+        ('ᾄ', crate::Replacement(768)),
+        ('漢', crate::Replacement(2282)),
+        ('ẽ', crate::Replacement(498)),
+        ('𠠄', crate::Replacement(2806)),
+        ('侮', crate::Replacement(1825)),
+        ('勒', crate::Replacement(1887)),
+        ('㺬', crate::Replacement(1745)),
+        ('嵫', crate::Replacement(2042)),
+        ('Ὲ', crate::Replacement(727)),
+        ('Ἆ', crate::Replacement(720)),
+        ('寧', crate::Replacement(2021)),
+        ('蘆', crate::Replacement(2547)),
+        ('泍', crate::Replacement(2252)),
+        ('懲', crate::Replacement(2120)),
+        ('≁', crate::Replacement(1500)),
+        ('巡', crate::Replacement(2048)),
+        ('ग़', crate::Replacement(1254)),
+        ('ί', crate::Replacement(782)),
+        ('ģ', crate::Replacement(508)),
+        ('鉼', crate::Replacement(2668)),
+        ('ẻ', crate::Replacement(498)),
+        ('Ӳ', crate::Replacement(835)),
+        ('多', crate::Replacement(1991)),
+        ('ý', crate::Replacement(600)),
+        ('Ē', crate::Replacement(378)),
+        ('羅', crate::Replacement(2472)),
+        ('ř', crate::Replacement(580)),
+        ('ὤ', crate::Replacement(819)),
+        ('Ä', crate::Replacement(364)),
+        ('ӝ', crate::Replacement(845)),
+        ('˚', crate::Replacement(1)),
+        ('勉', crate::Replacement(1886)),
+        ('⊅', crate::Replacement(1517)),
+        ('Ṿ', crate::Replacement(452)),
+        ('Ǣ', crate::Replacement(615)),
+        ('Ȇ', crate::Replacement(378)),
+        ('滋', crate::Replacement(2277)),
+        ('全', crate::Replacement(1844)),
+        ('利', crate::Replacement(1873)),
+        ('ज़', crate::Replacement(1255)),
+        ('𩅅', crate::Replacement(2898)),
+        ('ﯯ', crate::Replacement(1201)),
+        ('´', crate::Replacement(1)),
+        ('΅', crate::Replacement(1)),
+        ('卿', crate::Replacement(1914)),
+        ('ḿ', crate::Replacement(544)),
+        ('弢', crate::Replacement(2079)),
+        ('Ὦ', crate::Replacement(762)),
+        ('ὁ', crate::Replacement(800)),
+        ('ﹹ', crate::Replacement(1088)),
+        ('吝', crate::Replacement(1932)),
+        ('Ṏ', crate::Replacement(429)),
+        ('邔', crate::Replacement(2649)),
+        ('ῡ', crate::Replacement(807)),
+        ('Ŵ', crate::Replacement(457)),
+        ('ḁ', crate::Replacement(474)),
+        ('ῷ', crate::Replacement(819)),
+        ('Ṃ', crate::Replacement(415)),
+        ('Ġ', crate::Replacement(386)),
+        ('ẁ', crate::Replacement(595)),
+        ('杖', crate::Replacement(2201)),
+        ('ﺊ', crate::Replacement(1187)),
+        ('Ú', crate::Replacement(451)),
+        ('雷', crate::Replacement(2698)),
+        ('菉', crate::Replacement(2529)),
+        ('ῧ', crate::Replacement(807)),
+        ('徚', crate::Replacement(2090)),
+        ('ﰁ', crate::Replacement(1191)),
+        ('亂', crate::Replacement(1806)),
+        ('Ċ', crate::Replacement(370)),
+        ('芝', crate::Replacement(2515)),
+        ('יִ', crate::Replacement(904)),
+        ('í', crate::Replacement(514)),
+        ('朗', crate::Replacement(2195)),
+        ('ἁ', crate::Replacement(768)),
+        ('怜', crate::Replacement(2099)),
+        ('內', crate::Replacement(1843)),
+        ('ﲚ', crate::Replacement(1196)),
+        ('ᾀ', crate::Replacement(768)),
+        ('齃', crate::Replacement(2774)),
+        ('𧼯', crate::Replacement(2892)),
+        ('栗', crate::Replacement(2208)),
+        ('Ḭ', crate::Replacement(397)),
+        ('ŵ', crate::Replacement(595)),
+        ('ﺋ', crate::Replacement(1187)),
+        ('兩', crate::Replacement(1845)),
+        ('䁆', crate::Replacement(1752)),
+        ('Û', crate::Replacement(451)),
+        ('Ồ', crate::Replacement(429)),
+        ('憎', crate::Replacement(2114)),
+        ('Ḍ', crate::Replacement(374)),
+        ('糧', crate::Replacement(2447)),
+        ('硎', crate::Replacement(2393)),
+        ('Ά', crate::Replacement(720)),
+        ('免', crate::Replacement(1839)),
+        ('Ӫ', crate::Replacement(881)),
+        ('ᾠ', crate::Replacement(819)),
+        ('𣾎', crate::Replacement(2842)),
+        ('ά', crate::Replacement(768)),
+        ('Ȩ', crate::Replacement(378)),
+        ('ẵ', crate::Replacement(474)),
+        ('醴', crate::Replacement(2660)),
+        ('輸', crate::Replacement(2633)),
+        ('龜', crate::Replacement(2779)),
+        ('鱗', crate::Replacement(2747)),
+        ('𣪍', crate::Replacement(2836)),
+        ('ἴ', crate::Replacement(782)),
+        ('ṛ', crate::Replacement(580)),
+        ('ਜ਼', crate::Replacement(1268)),
+        ('炙', crate::Replacement(2296)),
+        ('ă', crate::Replacement(474)),
+        ('㤜', crate::Replacement(1732)),
+        ('僚', crate::Replacement(1833)),
+        ('ῂ', crate::Replacement(777)),
+        ('ﯵ', crate::Replacement(1207)),
+        ('ṙ', crate::Replacement(580)),
+        ('ἕ', crate::Replacement(775)),
+        ('虜', crate::Replacement(2553)),
+        ('𪃎', crate::Replacement(2905)),
+        ('Ӵ', crate::Replacement(836)),
+        ('甾', crate::Replacement(2361)),
+        ('ǐ', crate::Replacement(514)),
+        ('六', crate::Replacement(1847)),
+        ('撚', crate::Replacement(2158)),
+        ('Ó', crate::Replacement(429)),
+        ('卉', crate::Replacement(1904)),
+        ('ᾳ', crate::Replacement(768)),
+        ('𪊑', crate::Replacement(2908)),
+        ('ὄ', crate::Replacement(800)),
+        ('Ŷ', crate::Replacement(465)),
+        ('視', crate::Replacement(2586)),
+        ('ţ', crate::Replacement(588)),
+        ('切', crate::Replacement(1870)),
+        ('ώ', crate::Replacement(819)),
+        ('趼', crate::Replacement(2624)),
+        ('ﰄ', crate::Replacement(1203)),
+        ('Ἶ', crate::Replacement(734)),
+        ('花', crate::Replacement(2516)),
+        ('憲', crate::Replacement(2118)),
+        ('ῒ', crate::Replacement(782)),
+        ('Ḓ', crate::Replacement(374)),
+        ('ṇ', crate::Replacement(561)),
+        ('魯', crate::Replacement(2745)),
+        ('ǵ', crate::Replacement(508)),
+        ('˜', crate::Replacement(1)),
+        ('Ṉ', crate::Replacement(425)),
+        ('樂', crate::Replacement(2221)),
+        ('列', crate::Replacement(1871)),
+        ('Ǯ', crate::Replacement(628)),
+        ('猪', crate::Replacement(2328)),
+        ('瑩', crate::Replacement(2343)),
+        ('ẉ', crate::Replacement(595)),
+        ('ẖ', crate::Replacement(510)),
+        ('祝', crate::Replacement(2408)),
+        ('旅', crate::Replacement(2173)),
+        ('ᾱ', crate::Replacement(768)),
+        ('掩', crate::Replacement(2148)),
+        ('Ĥ', crate::Replacement(391)),
+        ('דּ', crate::Replacement(899)),
+        ('שּ', crate::Replacement(918)),
+        ('煅', crate::Replacement(2301)),
+        ('痢', crate::Replacement(2367)),
+        ('Ἦ', crate::Replacement(729)),
+        ('𦼬', crate::Replacement(2883)),
+        ('𣀊', crate::Replacement(2826)),
+        ('Ἂ', crate::Replacement(720)),
+        ('Ῥ', crate::Replacement(746)),
+        ('Ṑ', crate::Replacement(429)),
+        ('𦞧', crate::Replacement(2877)),
+        ('ϊ', crate::Replacement(782)),
+        ('ṗ', crate::Replacement(571)),
+        ('ὧ', crate::Replacement(819)),
+        ('圖', crate::Replacement(1964)),
+        ('῏', crate::Replacement(1)),
+        ('῟', crate::Replacement(1)),
+        ('ﱡ', crate::Replacement(1)),
+        ('湮', crate::Replacement(2272)),
+        ('浸', crate::Replacement(2264)),
+        ('זּ', crate::Replacement(902)),
+        ('Ǩ', crate::Replacement(406)),
+        ('䛇', crate::Replacement(1774)),
+        ('嗀', crate::Replacement(1954)),
+        ('Ḕ', crate::Replacement(378)),
+        ('Ẑ', crate::Replacement(467)),
+        ('ײַ', crate::Replacement(920)),
+        ('瞧', crate::Replacement(2389)),
+        ('灊', crate::Replacement(2291)),
+        ('Ṽ', crate::Replacement(452)),
+        ('留', crate::Replacement(2362)),
+        ('Ā', crate::Replacement(364)),
+        ('念', crate::Replacement(2096)),
+        ('ﯪ', crate::Replacement(1188)),
+        ('篆', crate::Replacement(2436)),
+        ('ż', crate::Replacement(602)),
+        ('ӟ', crate::Replacement(846)),
+        ('ṽ', crate::Replacement(591)),
+        ('ή', crate::Replacement(777)),
+        ('嵃', crate::Replacement(2040)),
+        ('ἦ', crate::Replacement(777)),
+        ('㿼', crate::Replacement(1748)),
+        ('駂', crate::Replacement(2732)),
+        ('ᾯ', crate::Replacement(762)),
+        ('䯎', crate::Replacement(1781)),
+        ('ख़', crate::Replacement(1253)),
+        ('ۀ', crate::Replacement(1251)),
+        ('ᾆ', crate::Replacement(768)),
+        ('ĉ', crate::Replacement(481)),
+        ('ǖ', crate::Replacement(590)),
+        ('ӱ', crate::Replacement(857)),
+        ('築', crate::Replacement(2437)),
+        ('Ȗ', crate::Replacement(451)),
+        ('ǡ', crate::Replacement(474)),
+        ('Ü', crate::Replacement(451)),
+        ('ṷ', crate::Replacement(590)),
+        ('懲', crate::Replacement(2120)),
+        ('ǒ', crate::Replacement(569)),
+        ('῀', crate::Replacement(1)),
+        ('玥', crate::Replacement(2335)),
+        ('ổ', crate::Replacement(569)),
+        ('直', crate::Replacement(2382)),
+        ('褐', crate::Replacement(2580)),
+        ('閭', crate::Replacement(2678)),
+        ('Ἱ', crate::Replacement(734)),
+        ('ū', crate::Replacement(590)),
+        ('य़', crate::Replacement(1260)),
+        ('卑', crate::Replacement(1905)),
+        ('烈', crate::Replacement(2298)),
+        ('ﹾ', crate::Replacement(1)),
+        ('誠', crate::Replacement(2590)),
+        ('吆', crate::Replacement(1928)),
+        ('ṁ', crate::Replacement(544)),
+        ('精', crate::Replacement(2443)),
+        ('凌', crate::Replacement(1863)),
+        ('Ṅ', crate::Replacement(425)),
+        ('浩', crate::Replacement(2261)),
+        ('∌', crate::Replacement(1490)),
+        ('器', crate::Replacement(1959)),
+        ('樂', crate::Replacement(2221)),
+        ('䏙', crate::Replacement(1763)),
+        ('ї', crate::Replacement(869)),
+        ('藍', crate::Replacement(2545)),
+        ('梎', crate::Replacement(2215)),
+        ('聰', crate::Replacement(2486)),
+        ('Ṱ', crate::Replacement(446)),
+        ('栟', crate::Replacement(2209)),
+        ('蚩', crate::Replacement(2558)),
+        ('Ȉ', crate::Replacement(397)),
+        ('爨', crate::Replacement(2309)),
+        ('鋗', crate::Replacement(2669)),
+        ('ᾝ', crate::Replacement(729)),
+        ('Ề', crate::Replacement(378)),
+        ('ę', crate::Replacement(498)),
+        ('ᾟ', crate::Replacement(729)),
+        ('䕝', crate::Replacement(1767)),
+        ('𥛅', crate::Replacement(2862)),
+        ('乁', crate::Replacement(1803)),
+        ('略', crate::Replacement(2363)),
+        ('≴', crate::Replacement(1508)),
+        ('流', crate::Replacement(2260)),
+        ('ﻸ', crate::Replacement(1119)),
+        ('濫', crate::Replacement(2286)),
+        ('ῇ', crate::Replacement(777)),
+        ('賂', crate::Replacement(2614)),
+        ('ĩ', crate::Replacement(514)),
+        ('དྷ', crate::Replacement(1289)),
+        ('數', crate::Replacement(2166)),
+        ('贈', crate::Replacement(2618)),
+        ('芋', crate::Replacement(2513)),
+        ('歷', crate::Replacement(2233)),
+        ('བྷ', crate::Replacement(1290)),
+        ('望', crate::Replacement(2196)),
+        ('年', crate::Replacement(2060)),
+        ('茣', crate::Replacement(2522)),
+        ('弢', crate::Replacement(2079)),
+        ('ẗ', crate::Replacement(588)),
+        ('ﯮ', crate::Replacement(1201)),
+        ('Ỵ', crate::Replacement(465)),
+        ('福', crate::Replacement(2415)),
+        ('牐', crate::Replacement(2317)),
+        ('Ῡ', crate::Replacement(750)),
+        ('冕', crate::Replacement(1853)),
+        ('蛢', crate::Replacement(2559)),
+        ('⊉', crate::Replacement(1519)),
+        ('糨', crate::Replacement(2448)),
+        ('嗂', crate::Replacement(1955)),
+        ('䌁', crate::Replacement(1759)),
+        ('𑂫', crate::Replacement(2793)),
+        ('פּ', crate::Replacement(914)),
+        ('𪎒', crate::Replacement(2909)),
+        ('㔕', crate::Replacement(1723)),
+        ('ὖ', crate::Replacement(807)),
+        ('善', crate::Replacement(1947)),
+        ('者', crate::Replacement(2479)),
+        ('⋬', crate::Replacement(1528)),
+        ('ń', crate::Replacement(561)),
+        ('糒', crate::Replacement(2444)),
+        ('ë', crate::Replacement(498)),
+        ('ὺ', crate::Replacement(807)),
+        ('ḷ', crate::Replacement(537)),
+        ('馧', crate::Replacement(2730)),
+        ('סּ', crate::Replacement(911)),
+        ('梅', crate::Replacement(2214)),
+        ('Ụ', crate::Replacement(451)),
+        ('着', crate::Replacement(2386)),
+        ('ġ', crate::Replacement(508)),
+        ('画', crate::Replacement(2360)),
+        ('ῗ', crate::Replacement(782)),
+        ('ḵ', crate::Replacement(523)),
+        ('碌', crate::Replacement(2395)),
+        ('ừ', crate::Replacement(590)),
+        ('ᾒ', crate::Replacement(777)),
+        ('殺', crate::Replacement(2238)),
+        ('冤', crate::Replacement(1857)),
+        ('凞', crate::Replacement(1865)),
+        ('Ὕ', crate::Replacement(750)),
+        ('Č', crate::Replacement(370)),
+        ('樓', crate::Replacement(2222)),
+        ('菜', crate::Replacement(2532)),
+        ('摷', crate::Replacement(2156)),
+        ('降', crate::Replacement(2683)),
+        ('黹', crate::Replacement(2764)),
+        ('ộ', crate::Replacement(569)),
+        ('阮', crate::Replacement(2681)),
+        ('ਸ਼', crate::Replacement(1271)),
+        ('ỗ', crate::Replacement(569)),
+        ('ᾫ', crate::Replacement(762)),
+        ('呈', crate::Replacement(1936)),
+        ('ﱥ', crate::Replacement(1195)),
+        ('ṟ', crate::Replacement(580)),
+        ('ὡ', crate::Replacement(819)),
+        ('卿', crate::Replacement(1914)),
+        ('Ȳ', crate::Replacement(465)),
+        ('鄛', crate::Replacement(2655)),
+        ('אּ', crate::Replacement(895)),
+        ('罺', crate::Replacement(2471)),
+        ('﹊', crate::Replacement(1)),
+        ('ṯ', crate::Replacement(588)),
+        ('⊯', crate::Replacement(1525)),
+        ('Ḵ', crate::Replacement(406)),
+        ('蝫', crate::Replacement(2562)),
+        ('老', crate::Replacement(2478)),
+        ('ὲ', crate::Replacement(775)),
+        ('䐋', crate::Replacement(1764)),
+        ('Ὄ', crate::Replacement(744)),
+        ('Ἒ', crate::Replacement(727)),
+        ('賓', crate::Replacement(2617)),
+        ('𣏕', crate::Replacement(2832)),
+        ('ﯷ', crate::Replacement(1208)),
+        ('艹', crate::Replacement(2512)),
+        ('社', crate::Replacement(2403)),
+        ('Ў', crate::Replacement(835)),
+        ('ὔ', crate::Replacement(807)),
+        ('ӗ', crate::Replacement(844)),
+        ('𑒻', crate::Replacement(2794)),
+        ('倫', crate::Replacement(1829)),
+        ('ﹲ', crate::Replacement(1)),
+        ('慺', crate::Replacement(2113)),
+        ('暑', crate::Replacement(2185)),
+        ('𦔣', crate::Replacement(2875)),
+        ('Ẉ', crate::Replacement(457)),
+        ('碑', crate::Replacement(2396)),
+        ('Ἀ', crate::Replacement(720)),
+        ('Ἳ', crate::Replacement(734)),
+        ('Ȟ', crate::Replacement(391)),
+        ('ﹰ', crate::Replacement(1)),
+        ('Ȅ', crate::Replacement(378)),
+        ('䳎', crate::Replacement(1782)),
+        ('磌', crate::Replacement(2398)),
+        ('瀹', crate::Replacement(2290)),
+        ('ﱩ', crate::Replacement(1203)),
+        ('ᾈ', crate::Replacement(720)),
+        ('Ț', crate::Replacement(446)),
+        ('↚', crate::Replacement(1478)),
+        ('Ỡ', crate::Replacement(429)),
+        ('戛', crate::Replacement(2125)),
+        ('憐', crate::Replacement(2115)),
+        ('Ἑ', crate::Replacement(727)),
+        ('Ů', crate::Replacement(451)),
+        ('及', crate::Replacement(1919)),
+        ('ﺌ', crate::Replacement(1187)),
+        ('玲', crate::Replacement(2336)),
+        ('壷', crate::Replacement(1986)),
+        ('咞', crate::Replacement(1938)),
+        ('¯', crate::Replacement(1)),
+        ('勺', crate::Replacement(1892)),
+        ('鉶', crate::Replacement(2667)),
+        ('撝', crate::Replacement(2159)),
+        ('⊄', crate::Replacement(1516)),
+        ('沿', crate::Replacement(2250)),
+        ('𢡊', crate::Replacement(2823)),
+        ('Î', crate::Replacement(397)),
+        ('Ӥ', crate::Replacement(832)),
+        ('῞', crate::Replacement(1)),
+        ('Έ', crate::Replacement(727)),
+        ('讀', crate::Replacement(2602)),
+        ('理', crate::Replacement(2338)),
+        ('壘', crate::Replacement(1980)),
+        ('≇', crate::Replacement(1502)),
+        ('鼻', crate::Replacement(2773)),
+        ('寮', crate::Replacement(2022)),
+        ('õ', crate::Replacement(569)),
+        ('ự', crate::Replacement(590)),
+        ('庳', crate::Replacement(2066)),
+        ('ຳ', crate::Replacement(1284)),
+        ('𑂚', crate::Replacement(2791)),
+        ('Ǖ', crate::Replacement(451)),
+        ('崙', crate::Replacement(2039)),
+        ('嬾', crate::Replacement(2012)),
+        ('ʹ', crate::Replacement(712)),
+        ('Ģ', crate::Replacement(386)),
+        ('ὑ', crate::Replacement(807)),
+        ('ḡ', crate::Replacement(508)),
+        ('盧', crate::Replacement(2380)),
+        ('Ӭ', crate::Replacement(838)),
+        ('㨮', crate::Replacement(1734)),
+        ('洖', crate::Replacement(2255)),
+        ('ϔ', crate::Replacement(750)),
+        ('潮', crate::Replacement(2284)),
+        ('ȱ', crate::Replacement(569)),
+        ('Ὶ', crate::Replacement(734)),
+        ('况', crate::Replacement(1860)),
+        ('ἱ', crate::Replacement(782)),
+        ('ň', crate::Replacement(561)),
+        ('冀', crate::Replacement(1849)),
+        ('泌', crate::Replacement(2251)),
+        ('墬', crate::Replacement(1978)),
+        ('謁', crate::Replacement(2599)),
+        ('Ẕ', crate::Replacement(467)),
+        ('辰', crate::Replacement(2638)),
+        ('ѓ', crate::Replacement(842)),
+        ('ÿ', crate::Replacement(600)),
+        ('ᾎ', crate::Replacement(720)),
+        ('杞', crate::Replacement(2202)),
+        ('劉', crate::Replacement(1880)),
+        ('㒞', crate::Replacement(1719)),
+        ('舘', crate::Replacement(2505)),
+        ('ῶ', crate::Replacement(819)),
+        ('𥄙', crate::Replacement(2856)),
+        ('ḕ', crate::Replacement(498)),
+        ('視', crate::Replacement(2586)),
+        ('喝', crate::Replacement(1950)),
+        ('ὣ', crate::Replacement(819)),
+        ('喙', crate::Replacement(1949)),
+        ('ऩ', crate::Replacement(1258)),
+        ('Ḝ', crate::Replacement(378)),
+        ('Ứ', crate::Replacement(451)),
+        ('Ĩ', crate::Replacement(397)),
+        ('煉', crate::Replacement(2302)),
+        (';', crate::Replacement(354)),
+        ('ṕ', crate::Replacement(571)),
+        ('ﻷ', crate::Replacement(1119)),
+        ('㠯', crate::Replacement(1727)),
+        ('懶', crate::Replacement(2121)),
+        ('Ờ', crate::Replacement(429)),
+        ('芽', crate::Replacement(2518)),
+        ('Ᾰ', crate::Replacement(720)),
+        ('晴', crate::Replacement(2183)),
+        ('嵐', crate::Replacement(2041)),
+        ('\u{cc8}', crate::Replacement(1278)),
+        ('Ṭ', crate::Replacement(446)),
+        ('Ὧ', crate::Replacement(762)),
+        ('ŕ', crate::Replacement(580)),
+        ('說', crate::Replacement(2591)),
+        ('曆', crate::Replacement(2188)),
+        ('ῖ', crate::Replacement(782)),
+        ('ų', crate::Replacement(590)),
+        ('Ѐ', crate::Replacement(829)),
+        ('良', crate::Replacement(2509)),
+        ('瀛', crate::Replacement(2288)),
+        ('嘆', crate::Replacement(1957)),
+        ('姬', crate::Replacement(2004)),
+        ('ẙ', crate::Replacement(600)),
+        ('慄', crate::Replacement(2107)),
+        ('ṥ', crate::Replacement(584)),
+        ('ằ', crate::Replacement(474)),
+        ('Ż', crate::Replacement(467)),
+        ('侻', crate::Replacement(1826)),
+        ('鍊', crate::Replacement(2672)),
+        ('鬒', crate::Replacement(2739)),
+        ('喳', crate::Replacement(1952)),
+        ('逸', crate::Replacement(2642)),
+        ('Ἔ', crate::Replacement(727)),
+        ('蝹', crate::Replacement(2563)),
+        ('ậ', crate::Replacement(474)),
+        ('Ệ', crate::Replacement(378)),
+        ('ऱ', crate::Replacement(1261)),
+        ('像', crate::Replacement(1832)),
+        ('兔', crate::Replacement(1840)),
+        ('牢', crate::Replacement(2320)),
+        ('Ῠ', crate::Replacement(750)),
+        ('駾', crate::Replacement(2734)),
+        ('凜', crate::Replacement(1864)),
+        ('寃', crate::Replacement(2019)),
+        ('炭', crate::Replacement(2297)),
+        ('Ṟ', crate::Replacement(438)),
+        ('Ӹ', crate::Replacement(837)),
+        ('𤾡', crate::Replacement(2851)),
+        ('吏', crate::Replacement(1931)),
+        ('䌴', crate::Replacement(1760)),
+        ('挽', crate::Replacement(2141)),
+        ('蠟', crate::Replacement(2568)),
+        ('Ă', crate::Replacement(364)),
+        ('療', crate::Replacement(2371)),
+        ('î', crate::Replacement(514)),
+        ('آ', crate::Replacement(922)),
+        ('≮', crate::Replacement(355)),
+        ('לּ', crate::Replacement(907)),
+        ('帽', crate::Replacement(2056)),
+        ('ṃ', crate::Replacement(544)),
+        ('Ẃ', crate::Replacement(457)),
+        ('䄯', crate::Replacement(1755)),
+        ('㣣', crate::Replacement(1731)),
+        ('Ӝ', crate::Replacement(830)),
+        ('∤', crate::Replacement(1493)),
+        ('གྷ', crate::Replacement(1287)),
+        ('嶺', crate::Replacement(2046)),
+        ('犕', crate::Replacement(2323)),
+        ('朡', crate::Replacement(2197)),
+        ('ἣ', crate::Replacement(777)),
+        ('ﻺ', crate::Replacement(1119)),
+        ('契', crate::Replacement(1999)),
+        ('ﮥ', crate::Replacement(1251)),
+        ('况', crate::Replacement(1860)),
+        ('悔', crate::Replacement(2102)),
+        ('凵', crate::Replacement(1867)),
+        ('ú', crate::Replacement(590)),
+        ('龜', crate::Replacement(2779)),
+        ('䈧', crate::Replacement(1757)),
+        ('爵', crate::Replacement(2312)),
+        ('𡴋', crate::Replacement(2815)),
+        ('ﯬ', crate::Replacement(1209)),
+        ('ᾡ', crate::Replacement(819)),
+        ('蓱', crate::Replacement(2540)),
+        ('Ư', crate::Replacement(451)),
+        ('ἅ', crate::Replacement(768)),
+        ('具', crate::Replacement(1848)),
+        ('凉', crate::Replacement(1862)),
+        ('ṵ', crate::Replacement(590)),
+        ('Ã', crate::Replacement(364)),
+        ('屢', crate::Replacement(2032)),
+        ('Ṛ', crate::Replacement(438)),
+        ('蘿', crate::Replacement(2550)),
+        ('Ṁ', crate::Replacement(415)),
+        ('𣊸', crate::Replacement(2827)),
+        ('ȏ', crate::Replacement(569)),
+        ('ѐ', crate::Replacement(844)),
+        ('仌', crate::Replacement(1816)),
+        ('≉', crate::Replacement(1503)),
+        ('ὐ', crate::Replacement(807)),
+        ('ỳ', crate::Replacement(600)),
+        ('Ȋ', crate::Replacement(397)),
+        ('勺', crate::Replacement(1892)),
+        ('港', crate::Replacement(2271)),
+        ('㫤', crate::Replacement(1736)),
+        ('Ế', crate::Replacement(378)),
+        ('Ṓ', crate::Replacement(429)),
+        ('便', crate::Replacement(1827)),
+        ('鐕', crate::Replacement(2674)),
+        ('麟', crate::Replacement(2757)),
+        ('Ũ', crate::Replacement(451)),
+        ('ড়', crate::Replacement(1263)),
+        ('ȕ', crate::Replacement(590)),
+        ('Ḛ', crate::Replacement(378)),
+        ('ἧ', crate::Replacement(777)),
+        ('Ṕ', crate::Replacement(430)),
+        ('Ẩ', crate::Replacement(364)),
+        ('Ὢ', crate::Replacement(762)),
+        ('Ḅ', crate::Replacement(368)),
+        ('ύ', crate::Replacement(807)),
+        ('ļ', crate::Replacement(537)),
+        ('夢', crate::Replacement(1993)),
+        ('῁', crate::Replacement(1)),
+        ('都', crate::Replacement(2653)),
+        ('È', crate::Replacement(378)),
+        ('אַ', crate::Replacement(895)),
+        ('ﯸ', crate::Replacement(1208)),
+        ('ᾣ', crate::Replacement(819)),
+        ('娧', crate::Replacement(2006)),
+        ('Й', crate::Replacement(832)),
+        ('ᾋ', crate::Replacement(720)),
+        ('ӑ', crate::Replacement(839)),
+        ('嵮', crate::Replacement(2043)),
+        ('𩈚', crate::Replacement(2900)),
+        ('ư', crate::Replacement(590)),
+        ('櫛', crate::Replacement(2225)),
+        ('Ǐ', crate::Replacement(397)),
+        ('Ἣ', crate::Replacement(729)),
+        ('丸', crate::Replacement(1799)),
+        ('Ȁ', crate::Replacement(364)),
+        ('䀹', crate::Replacement(1751)),
+        ('ȫ', crate::Replacement(569)),
+        ('輻', crate::Replacement(2634)),
+        ('跋', crate::Replacement(2625)),
+        ('Ẓ', crate::Replacement(467)),
+        ('穏', crate::Replacement(2425)),
+        ('Ṗ', crate::Replacement(430)),
+        ('ǚ', crate::Replacement(590)),
+        ('Ì', crate::Replacement(397)),
+        ('ἄ', crate::Replacement(768)),
+        ('𧃒', crate::Replacement(2885)),
+        ('≰', crate::Replacement(1506)),
+        ('諾', crate::Replacement(2598)),
+        ('祖', crate::Replacement(2407)),
+        ('ṑ', crate::Replacement(569)),
+        ('䚾', crate::Replacement(1773)),
+        ('瞋', crate::Replacement(2388)),
+        ('ô', crate::Replacement(569)),
+        ('報', crate::Replacement(1972)),
+        ('Ἲ', crate::Replacement(734)),
+        ('ć', crate::Replacement(481)),
+        ('洛', crate::Replacement(2256)),
+        ('ᾃ', crate::Replacement(768)),
+        ('衣', crate::Replacement(2572)),
+        ('頻', crate::Replacement(2718)),
+        ('אָ', crate::Replacement(895)),
+        ('屠', crate::Replacement(2031)),
+        ('懞', crate::Replacement(2119)),
+        ('ȓ', crate::Replacement(580)),
+        ('Ặ', crate::Replacement(364)),
+        ('甤', crate::Replacement(2354)),
+        ('Ừ', crate::Replacement(451)),
+        ('ﺇ', crate::Replacement(922)),
+        ('諭', crate::Replacement(2596)),
+        ('ȟ', crate::Replacement(510)),
+        ('ᾍ', crate::Replacement(720)),
+        ('綠', crate::Replacement(2457)),
+        ('歲', crate::Replacement(2232)),
+        ('Ἢ', crate::Replacement(729)),
+        ('ﻵ', crate::Replacement(1119)),
+        ('ņ', crate::Replacement(561)),
+        ('宅', crate::Replacement(2017)),
+        ('⋪', crate::Replacement(1526)),
+        ('衠', crate::Replacement(2571)),
+        ('懲', crate::Replacement(2120)),
+        ('ö', crate::Replacement(569)),
+        ('û', crate::Replacement(590)),
+        ('Ἓ', crate::Replacement(727)),
+        ('聆', crate::Replacement(2483)),
+        ('𦬼', crate::Replacement(2879)),
+        ('Ḩ', crate::Replacement(391)),
+        ('שּׂ', crate::Replacement(918)),
+        ('Ų', crate::Replacement(451)),
+        ('ğ', crate::Replacement(508)),
+        ('ṳ', crate::Replacement(590)),
+        ('𪈎', crate::Replacement(2907)),
+        ('磌', crate::Replacement(2398)),
+        ('䂖', crate::Replacement(1753)),
+        ('Ἴ', crate::Replacement(734)),
+        ('異', crate::Replacement(2364)),
+        ('𪄅', crate::Replacement(2906)),
+        ('תּ', crate::Replacement(919)),
+        ('錄', crate::Replacement(2671)),
+        ('慈', crate::Replacement(2108)),
+        ('Ὀ', crate::Replacement(744)),
+        ('≹', crate::Replacement(1511)),
+        ('即', crate::Replacement(1911)),
+        ('יּ', crate::Replacement(904)),
+        ('ӵ', crate::Replacement(861)),
+        ('㺸', crate::Replacement(1746)),
+        ('獵', crate::Replacement(2329)),
+        ('艹', crate::Replacement(2512)),
+        ('Ή', crate::Replacement(729)),
+        ('∉', crate::Replacement(1489)),
+        ('麗', crate::Replacement(2756)),
+        ('北', crate::Replacement(1896)),
+        ('Ӯ', crate::Replacement(835)),
+        ('龜', crate::Replacement(2779)),
+        ('爐', crate::Replacement(2307)),
+        ('ι', crate::Replacement(782)),
+        ('ཌྷ', crate::Replacement(1288)),
+        ('ӧ', crate::Replacement(852)),
+        ('ṿ', crate::Replacement(591)),
+        ('酪', crate::Replacement(2658)),
+        ('Ō', crate::Replacement(429)),
+        ('㮝', crate::Replacement(1740)),
+        ('ṝ', crate::Replacement(580)),
+        ('神', crate::Replacement(2409)),
+        ('離', crate::Replacement(2694)),
+        ('ž', crate::Replacement(602)),
+        ('苦', crate::Replacement(2520)),
+        ('𠨬', crate::Replacement(2808)),
+        ('בּ', crate::Replacement(897)),
+        ('Ĉ', crate::Replacement(370)),
+        ('狼', crate::Replacement(2327)),
+        ('Ὰ', crate::Replacement(720)),
+        ('ď', crate::Replacement(490)),
+        ('館', crate::Replacement(2726)),
+        ('Ő', crate::Replacement(429)),
+        ('ā', crate::Replacement(474)),
+        ('丽', crate::Replacement(1801)),
+        ('署', crate::Replacement(2469)),
+        ('Ầ', crate::Replacement(364)),
+        ('芳', crate::Replacement(2517)),
+        ('ṣ', crate::Replacement(584)),
+        ('·', crate::Replacement(614)),
+        ('ﯰ', crate::Replacement(1206)),
+        ('ệ', crate::Replacement(498)),
+        ('怒', crate::Replacement(2098)),
+        ('猪', crate::Replacement(2328)),
+        ('ᾬ', crate::Replacement(762)),
+        ('彩', crate::Replacement(2084)),
+        ('䪲', crate::Replacement(1779)),
+        ('Ǹ', crate::Replacement(425)),
+        ('Ӗ', crate::Replacement(829)),
+        ('Ύ', crate::Replacement(750)),
+        ('寧', crate::Replacement(2021)),
+        ('醙', crate::Replacement(2659)),
+        ('Ȫ', crate::Replacement(429)),
+        ('漣', crate::Replacement(2283)),
+        ('糣', crate::Replacement(2446)),
+        ('爛', crate::Replacement(2308)),
+        ('叫', crate::Replacement(1924)),
+        ('𧏊', crate::Replacement(2886)),
+        ('鵧', crate::Replacement(2750)),
+        ('𡬘', crate::Replacement(2814)),
+        ('⊬', crate::Replacement(1522)),
+        ('è', crate::Replacement(498)),
+        ('𡷦', crate::Replacement(2817)),
+        ('捐', crate::Replacement(2142)),
+        ('Ἵ', crate::Replacement(734)),
+        ('李', crate::Replacement(2199)),
+        ('旣', crate::Replacement(2176)),
+        ('了', crate::Replacement(1808)),
+        ('έ', crate::Replacement(775)),
+        ('Ỳ', crate::Replacement(465)),
+        ('ḉ', crate::Replacement(481)),
+        ('𣽞', crate::Replacement(2841)),
+        ('見', crate::Replacement(2585)),
+        ('螺', crate::Replacement(2565)),
+        ('Ẏ', crate::Replacement(465)),
+        ('כּ', crate::Replacement(906)),
+        ('嬈', crate::Replacement(2010)),
+        ('⋠', crate::Replacement(1514)),
+        ('Ά', crate::Replacement(720)),
+        ('蓼', crate::Replacement(2542)),
+        ('ḏ', crate::Replacement(490)),
+        ('ﹷ', crate::Replacement(1088)),
+        ('𠣞', crate::Replacement(2807)),
+        ('ẩ', crate::Replacement(474)),
+        ('Ò', crate::Replacement(429)),
+        ('扝', crate::Replacement(2131)),
+        ('ḃ', crate::Replacement(479)),
+        ('\u{ccb}', crate::Replacement(1276)),
+        ('𨗭', crate::Replacement(2894)),
+        ('ṹ', crate::Replacement(590)),
+        ('著', crate::Replacement(2538)),
+        ('ﲙ', crate::Replacement(1193)),
+        ('陋', crate::Replacement(2682)),
+        ('流', crate::Replacement(2260)),
+        ('Ű', crate::Replacement(451)),
+        ('什', crate::Replacement(1815)),
+        ('林', crate::Replacement(2205)),
+        ('Ἇ', crate::Replacement(720)),
+        ('Ί', crate::Replacement(734)),
+        ('ᾔ', crate::Replacement(777)),
+        ('Ợ', crate::Replacement(429)),
+        ('㤺', crate::Replacement(1733)),
+        ('益', crate::Replacement(2377)),
+        ('ὶ', crate::Replacement(782)),
+        ('Ḏ', crate::Replacement(374)),
+        ('Ὴ', crate::Replacement(729)),
+        ('Ô', crate::Replacement(429)),
+        ('䔫', crate::Replacement(1766)),
+        ('Ą', crate::Replacement(364)),
+        ('𧲨', crate::Replacement(2890)),
+        ('熜', crate::Replacement(2304)),
+        ('ő', crate::Replacement(569)),
+        ('𢌱', crate::Replacement(2820)),
+        ('諭', crate::Replacement(2596)),
+        ('ἇ', crate::Replacement(768)),
+        ('ḭ', crate::Replacement(514)),
+        ('ﹼ', crate::Replacement(1)),
+        ('裺', crate::Replacement(2579)),
+        ('ḋ', crate::Replacement(490)),
+        ('ἓ', crate::Replacement(775)),
+        ('祿', crate::Replacement(2411)),
+        ('Ý', crate::Replacement(465)),
+        ('𢌱', crate::Replacement(2820)),
+        ('綾', crate::Replacement(2458)),
+        ('Ǿ', crate::Replacement(616)),
+        ('ȅ', crate::Replacement(498)),
+        ('茝', crate::Replacement(2521)),
+        ('𥪧', crate::Replacement(2864)),
+        ('ﱤ', crate::Replacement(1194)),
+        ('ῴ', crate::Replacement(819)),
+        ('ῤ', crate::Replacement(802)),
+        ('𦳕', crate::Replacement(2881)),
+        ('ᾧ', crate::Replacement(819)),
+        ('Ḧ', crate::Replacement(391)),
+        ('恵', crate::Replacement(2100)),
+        ('ύ', crate::Replacement(807)),
+        ('渚', crate::Replacement(2270)),
+        ('ףּ', crate::Replacement(913)),
+        ('ḍ', crate::Replacement(490)),
+        ('慠', crate::Replacement(2111)),
+        ('溜', crate::Replacement(2274)),
+        ('掠', crate::Replacement(2147)),
+        ('盛', crate::Replacement(2378)),
+        ('起', crate::Replacement(2622)),
+        ('῭', crate::Replacement(1)),
+        ('𠘺', crate::Replacement(2805)),
+        ('ờ', crate::Replacement(569)),
+        ('卑', crate::Replacement(1905)),
+        ('城', crate::Replacement(1969)),
+        ('練', crate::Replacement(2460)),
+        ('ῑ', crate::Replacement(782)),
+        ('Ḗ', crate::Replacement(378)),
+        ('敬', crate::Replacement(2165)),
+        ('Ǽ', crate::Replacement(615)),
+        ('ẘ', crate::Replacement(595)),
+        ('Ḿ', crate::Replacement(415)),
+        ('Ѓ', crate::Replacement(828)),
+        ('Ḹ', crate::Replacement(410)),
+        ('敖', crate::Replacement(2164)),
+        ('蚈', crate::Replacement(2557)),
+        ('廊', crate::Replacement(2069)),
+        ('寧', crate::Replacement(2021)),
+        ('Ό', crate::Replacement(744)),
+        ('ữ', crate::Replacement(590)),
+        ('Ń', crate::Replacement(425)),
+        ('幩', crate::Replacement(2057)),
+        ('臨', crate::Replacement(2497)),
+        ('↛', crate::Replacement(1480)),
+        ('飢', crate::Replacement(2723)),
+        ('㬈', crate::Replacement(1737)),
+        ('ΰ', crate::Replacement(807)),
+        ('瑱', crate::Replacement(2344)),
+        ('Ê', crate::Replacement(378)),
+        ('𢬌', crate::Replacement(2824)),
+        ('金', crate::Replacement(2664)),
+        ('Ὠ', crate::Replacement(762)),
+        ('勵', crate::Replacement(1890)),
+        ('鏹', crate::Replacement(2673)),
+        ('瑱', crate::Replacement(2344)),
+        ('覆', crate::Replacement(2584)),
+        ('Ӱ', crate::Replacement(835)),
+        ('類', crate::Replacement(2719)),
+        ('𡛪', crate::Replacement(2812)),
+        ('礪', crate::Replacement(2400)),
+        ('𤘈', crate::Replacement(2846)),
+        ('ơ', crate::Replacement(569)),
+        ('切', crate::Replacement(1870)),
+        ('荓', crate::Replacement(2525)),
+        ('𡚨', crate::Replacement(2811)),
+        ('甆', crate::Replacement(2351)),
+        ('博', crate::Replacement(1907)),
+        ('裡', crate::Replacement(2577)),
+        ('ᾏ', crate::Replacement(720)),
+        ('暴', crate::Replacement(2187)),
+        ('Ļ', crate::Replacement(410)),
+        ('ş', crate::Replacement(584)),
+        ('ḽ', crate::Replacement(537)),
+        ('⇏', crate::Replacement(1484)),
+        ('Ố', crate::Replacement(429)),
+        ('連', crate::Replacement(2641)),
+        ('襤', crate::Replacement(2582)),
+        ('Ḷ', crate::Replacement(410)),
+        ('ǹ', crate::Replacement(561)),
+        ('ȳ', crate::Replacement(600)),
+        ('䈂', crate::Replacement(1756)),
+        ('搜', crate::Replacement(2152)),
+        ('ŏ', crate::Replacement(569)),
+        ('˘', crate::Replacement(1)),
+        ('ὀ', crate::Replacement(800)),
+        ('勤', crate::Replacement(1889)),
+        ('罹', crate::Replacement(2470)),
+        ('ò', crate::Replacement(569)),
+        ('璅', crate::Replacement(2345)),
+        ('墳', crate::Replacement(1979)),
+        ('ḗ', crate::Replacement(498)),
+        ('濆', crate::Replacement(2285)),
+        ('טּ', crate::Replacement(903)),
+        ('襁', crate::Replacement(2581)),
+        ('跰', crate::Replacement(2627)),
+        ('ᾞ', crate::Replacement(729)),
+        ('ͺ', crate::Replacement(1)),
+        ('ﮰ', crate::Replacement(1250)),
+        ('力', crate::Replacement(1881)),
+        ('ῳ', crate::Replacement(819)),
+        ('䬳', crate::Replacement(1780)),
+        ('𠭣', crate::Replacement(2809)),
+        ('Ἕ', crate::Replacement(727)),
+        ('ỹ', crate::Replacement(600)),
+        ('ﯶ', crate::Replacement(1208)),
+        ('度', crate::Replacement(2064)),
+        ('鼖', crate::Replacement(2771)),
+        ('冷', crate::Replacement(1861)),
+        ('⋡', crate::Replacement(1515)),
+        ('ἑ', crate::Replacement(775)),
+        ('Ṫ', crate::Replacement(446)),
+        ('𨜮', crate::Replacement(2895)),
+        ('蠁', crate::Replacement(2567)),
+        ('句', crate::Replacement(1923)),
+        ('Ή', crate::Replacement(729)),
+        ('ό', crate::Replacement(800)),
+        ('𩇟', crate::Replacement(2899)),
+        ('à', crate::Replacement(474)),
+        ('禍', crate::Replacement(2413)),
+        ('鋘', crate::Replacement(2670)),
+        ('竮', crate::Replacement(2431)),
+        ('Ᾱ', crate::Replacement(720)),
+        ('ཛྷ', crate::Replacement(1291)),
+        ('´', crate::Replacement(1)),
+        ('Ḡ', crate::Replacement(386)),
+        ('慌', crate::Replacement(2109)),
+        ('ǰ', crate::Replacement(521)),
+        ('类', crate::Replacement(2441)),
+        ('Ǭ', crate::Replacement(429)),
+        ('再', crate::Replacement(1851)),
+        ('Ủ', crate::Replacement(451)),
+        ('ෝ', crate::Replacement(1280)),
+        ('ĺ', crate::Replacement(537)),
+        ('ḙ', crate::Replacement(498)),
+        ('ﱝ', crate::Replacement(1186)),
+        ('侮', crate::Replacement(1825)),
+        ('ӭ', crate::Replacement(866)),
+        ('㼛', crate::Replacement(1747)),
+        ('ῢ', crate::Replacement(807)),
+        ('ῌ', crate::Replacement(729)),
+        ('Ὓ', crate::Replacement(750)),
+        ('異', crate::Replacement(2364)),
+        ('𩬰', crate::Replacement(2904)),
+        ('ớ', crate::Replacement(569)),
+        ('縉', crate::Replacement(2462)),
+        ('Ṙ', crate::Replacement(438)),
+        ('ᾉ', crate::Replacement(720)),
+        ('並', crate::Replacement(1794)),
+        ('燎', crate::Replacement(2305)),
+        ('繁', crate::Replacement(2464)),
+        ('累', crate::Replacement(2453)),
+        ('ﰂ', crate::Replacement(1196)),
+        ('Ὅ', crate::Replacement(744)),
+        ('ᾓ', crate::Replacement(777)),
+        ('Ť', crate::Replacement(446)),
+        ('⊁', crate::Replacement(1513)),
+        ('卿', crate::Replacement(1914)),
+        ('ﯻ', crate::Replacement(1202)),
+        ('眞', crate::Replacement(2384)),
+        ('ẅ', crate::Replacement(595)),
+        ('吸', crate::Replacement(1933)),
+        ('Ḽ', crate::Replacement(410)),
+        ('郞', crate::Replacement(2651)),
+        ('Ī', crate::Replacement(397)),
+        ('卵', crate::Replacement(1912)),
+        ('כֿ', crate::Replacement(906)),
+        ('ț', crate::Replacement(588)),
+        ('〈', crate::Replacement(1540)),
+        ('論', crate::Replacement(2595)),
+        ('ﱞ', crate::Replacement(1)),
+        ('ќ', crate::Replacement(848)),
+        ('ἢ', crate::Replacement(777)),
+        ('ﮤ', crate::Replacement(1251)),
+        ('瀞', crate::Replacement(2289)),
+        ('Ḇ', crate::Replacement(368)),
+        ('嬨', crate::Replacement(2011)),
+        ('Ё', crate::Replacement(829)),
+        ('ﱛ', crate::Replacement(999)),
+        ('Ӧ', crate::Replacement(834)),
+        ('𠔥', crate::Replacement(2803)),
+        ('Ї', crate::Replacement(826)),
+        ('慎', crate::Replacement(2110)),
+        ('履', crate::Replacement(2034)),
+        ('莽', crate::Replacement(2528)),
+        ('ۓ', crate::Replacement(1250)),
+        ('硫', crate::Replacement(2394)),
+        ('ǜ', crate::Replacement(590)),
+        ('ϓ', crate::Replacement(750)),
+        ('ﹻ', crate::Replacement(1088)),
+        ('璘', crate::Replacement(2347)),
+        ('Ќ', crate::Replacement(833)),
+        ('寳', crate::Replacement(2023)),
+        ('Ẍ', crate::Replacement(462)),
+        ('ﹴ', crate::Replacement(1)),
+        ('ﱜ', crate::Replacement(1000)),
+        ('ἀ', crate::Replacement(768)),
+        ('Ŏ', crate::Replacement(429)),
+        ('ὠ', crate::Replacement(819)),
+        ('勇', crate::Replacement(1885)),
+        ('𦓚', crate::Replacement(2874)),
+        ('Ù', crate::Replacement(451)),
+        ('瘟', crate::Replacement(2370)),
+        ('呂', crate::Replacement(1935)),
+        ('秫', crate::Replacement(2421)),
+        ('𦵫', crate::Replacement(2882)),
+        ('路', crate::Replacement(2626)),
+        ('僧', crate::Replacement(1834)),
+        ('Š', crate::Replacement(440)),
+        ('ΰ', crate::Replacement(807)),
+        ('ố', crate::Replacement(569)),
+        ('頻', crate::Replacement(2718)),
+        ('鈸', crate::Replacement(2666)),
+        ('≢', crate::Replacement(1505)),
+        ('ḇ', crate::Replacement(479)),
+        ('捻', crate::Replacement(2145)),
+        ('‗', crate::Replacement(1)),
+        ('瘐', crate::Replacement(2368)),
+        ('爫', crate::Replacement(2311)),
+        ('Ῑ', crate::Replacement(734)),
+        ('Ṯ', crate::Replacement(446)),
+        ('䩮', crate::Replacement(1777)),
+        ('Ǫ', crate::Replacement(429)),
+        ('ĥ', crate::Replacement(510)),
+        ('剷', crate::Replacement(1879)),
+        ('泥', crate::Replacement(2253)),
+        ('ᾷ', crate::Replacement(768)),
+        ('𤠔', crate::Replacement(2848)),
+        ('溺', crate::Replacement(2275)),
+        ('ਗ਼', crate::Replacement(1267)),
+        ('搢', crate::Replacement(2153)),
+        ('臭', crate::Replacement(2499)),
+        ('𢡄', crate::Replacement(2822)),
+        ('Ḉ', crate::Replacement(370)),
+        ('Ὡ', crate::Replacement(762)),
+        ('𨵷', crate::Replacement(2897)),
+        ('北', crate::Replacement(1896)),
+        ('𣏃', crate::Replacement(2831)),
+        ('殮', crate::Replacement(2236)),
+        ('縂', crate::Replacement(2461)),
+        ('Ằ', crate::Replacement(364)),
+        ('睊', crate::Replacement(2387)),
+        ('說', crate::Replacement(2591)),
+        ('Ḁ', crate::Replacement(364)),
+        ('卽', crate::Replacement(1913)),
+        ('ȧ', crate::Replacement(474)),
+        ('賁', crate::Replacement(2613)),
+        ('ể', crate::Replacement(498)),
+        ('掃', crate::Replacement(2146)),
+        ('⋫', crate::Replacement(1527)),
+        ('Ỹ', crate::Replacement(465)),
+        ('ỡ', crate::Replacement(569)),
+        ('ؤ', crate::Replacement(1183)),
+        ('Ώ', crate::Replacement(762)),
+        ('虧', crate::Replacement(2554)),
+        ('῝', crate::Replacement(1)),
+        ('灷', crate::Replacement(2294)),
+        ('塀', crate::Replacement(1974)),
+        ('𡓤', crate::Replacement(2810)),
+        ('𦾱', crate::Replacement(2884)),
+        ('ȉ', crate::Replacement(514)),
+        ('頋', crate::Replacement(2715)),
+        ('例', crate::Replacement(1824)),
+        ('碌', crate::Replacement(2395)),
+        ('য়', crate::Replacement(1265)),
+        ('勇', crate::Replacement(1885)),
+        ('ﯳ', crate::Replacement(1205)),
+        ('ḩ', crate::Replacement(510)),
+        ('ị', crate::Replacement(514)),
+        ('勤', crate::Replacement(1889)),
+        ('叱', crate::Replacement(1926)),
+        ('ﯺ', crate::Replacement(1202)),
+        ('枅', crate::Replacement(2204)),
+        ('ở', crate::Replacement(569)),
+        ('ǟ', crate::Replacement(474)),
+        ('咽', crate::Replacement(1940)),
+        ('屮', crate::Replacement(2035)),
+        ('𧢮', crate::Replacement(2888)),
+        ('ã', crate::Replacement(474)),
+        ('Ấ', crate::Replacement(364)),
+        ('ṻ', crate::Replacement(590)),
+        ('ᾁ', crate::Replacement(768)),
+        ('暈', crate::Replacement(2184)),
+        ('啕', crate::Replacement(1945)),
+        ('ī', crate::Replacement(514)),
+        ('𥁄', crate::Replacement(2853)),
+        ('㴳', crate::Replacement(1743)),
+        ('ਲ਼', crate::Replacement(1270)),
+        ('鄑', crate::Replacement(2654)),
+        ('Õ', crate::Replacement(429)),
+        ('匆', crate::Replacement(1894)),
+        ('層', crate::Replacement(2033)),
+        ('𣢧', crate::Replacement(2835)),
+        ('諸', crate::Replacement(2597)),
+        ('ἥ', crate::Replacement(777)),
+        ('ේ', crate::Replacement(1279)),
+        ('ȭ', crate::Replacement(569)),
+        ('頋', crate::Replacement(2715)),
+        ('ἶ', crate::Replacement(782)),
+        ('Ӟ', crate::Replacement(831)),
+        ('Ӣ', crate::Replacement(832)),
+        ('𩐊', crate::Replacement(2901)),
+        ('ỉ', crate::Replacement(514)),
+        ('𢆟', crate::Replacement(2819)),
+        ('徭', crate::Replacement(2092)),
+        ('䦕', crate::Replacement(1775)),
+        ('将', crate::Replacement(2026)),
+        ('廓', crate::Replacement(2071)),
+        ('啣', crate::Replacement(1946)),
+        ('駱', crate::Replacement(2733)),
+        ('鶴', crate::Replacement(2751)),
+        ('إ', crate::Replacement(922)),
+        ('ṱ', crate::Replacement(588)),
+        ('‾', crate::Replacement(1)),
+        ('ľ', crate::Replacement(537)),
+        ('č', crate::Replacement(481)),
+        ('Ĵ', crate::Replacement(404)),
+        ('ἲ', crate::Replacement(782)),
+        ('ढ़', crate::Replacement(1257)),
+        ('𧥦', crate::Replacement(2889)),
+        ('ﹶ', crate::Replacement(1)),
+        ('⋣', crate::Replacement(1521)),
+        ('𥃲', crate::Replacement(2854)),
+        ('庰', crate::Replacement(2065)),
+        ('ᾚ', crate::Replacement(729)),
+        ('冬', crate::Replacement(1859)),
+        ('Ύ', crate::Replacement(750)),
+        ('ﳴ', crate::Replacement(1088)),
+        ('墨', crate::Replacement(1977)),
+        ('柳', crate::Replacement(2206)),
+        ('彫', crate::Replacement(2085)),
+        ('ῃ', crate::Replacement(777)),
+        ('暑', crate::Replacement(2185)),
+        ('舄', crate::Replacement(2503)),
+        ('Ď', crate::Replacement(374)),
+        ('緇', crate::Replacement(2459)),
+        ('ଡ଼', crate::Replacement(1272)),
+        ('ḝ', crate::Replacement(498)),
+        ('ḣ', crate::Replacement(510)),
+        ('〉', crate::Replacement(1541)),
+        ('抱', crate::Replacement(2133)),
+        ('𦈨', crate::Replacement(2870)),
+        ('𥚚', crate::Replacement(2861)),
+        ('𣲼', crate::Replacement(2838)),
+        ('売', crate::Replacement(1985)),
+        ('ךּ', crate::Replacement(905)),
+        ('料', crate::Replacement(2169)),
+        ('קּ', crate::Replacement(916)),
+        ('婦', crate::Replacement(2008)),
+        ('𑂜', crate::Replacement(2792)),
+        ('豕', crate::Replacement(2607)),
+        ('難', crate::Replacement(2695)),
+        ('Ǒ', crate::Replacement(429)),
+        ('ồ', crate::Replacement(569)),
+        ('量', crate::Replacement(2663)),
+        ('ḑ', crate::Replacement(490)),
+        ('İ', crate::Replacement(397)),
+        ('˛', crate::Replacement(1)),
+        ('廉', crate::Replacement(2068)),
+        ('⫝̸', crate::Replacement(1535)),
+        ('ﮱ', crate::Replacement(1250)),
+        ('ủ', crate::Replacement(590)),
+        ('רּ', crate::Replacement(917)),
+        ('者', crate::Replacement(2479)),
+        ('ﲗ', crate::Replacement(1189)),
+        ('頩', crate::Replacement(2717)),
+        ('岍', crate::Replacement(2037)),
+        ('ᾤ', crate::Replacement(819)),
+        ('菊', crate::Replacement(2530)),
+        ('慨', crate::Replacement(2112)),
+        ('偺', crate::Replacement(1830)),
+        ('榣', crate::Replacement(2219)),
+        ('⊈', crate::Replacement(1518)),
+        ('瓊', crate::Replacement(2348)),
+        ('爵', crate::Replacement(2312)),
+        ('Ķ', crate::Replacement(406)),
+        ('Ĝ', crate::Replacement(386)),
+        ('憎', crate::Replacement(2114)),
+        ('ཀྵ', crate::Replacement(1286)),
+        ('Ṩ', crate::Replacement(440)),
+        ('ﳟ', crate::Replacement(1196)),
+        ('ŷ', crate::Replacement(600)),
+        ('𤾸', crate::Replacement(2852)),
+        ('僧', crate::Replacement(1834)),
+        ('若', crate::Replacement(2519)),
+        ('Ẽ', crate::Replacement(378)),
+        ('ῐ', crate::Replacement(782)),
+        ('晴', crate::Replacement(2183)),
+        ('ᾙ', crate::Replacement(729)),
+        ('הּ', crate::Replacement(900)),
+        ('貫', crate::Replacement(2612)),
+        ('益', crate::Replacement(2377)),
+        ('𦇚', crate::Replacement(2869)),
+        ('Á', crate::Replacement(364)),
+        ('若', crate::Replacement(2519)),
+        ('ӛ', crate::Replacement(880)),
+        ('𤜵', crate::Replacement(2847)),
+        ('䧦', crate::Replacement(1776)),
+        ('琢', crate::Replacement(2340)),
+        ('謹', crate::Replacement(2600)),
+        ('\u{cc0}', crate::Replacement(1277)),
+        ('𣫺', crate::Replacement(2837)),
+        ('ḧ', crate::Replacement(510)),
+        ('螆', crate::Replacement(2564)),
+        ('摾', crate::Replacement(2157)),
+        ('㺸', crate::Replacement(1746)),
+        ('ᾂ', crate::Replacement(768)),
+        ('唐', crate::Replacement(1942)),
+        ('ȍ', crate::Replacement(569)),
+        ('Ȏ', crate::Replacement(429)),
+        ('ﯭ', crate::Replacement(1209)),
+        ('ਫ਼', crate::Replacement(1269)),
+        ('響', crate::Replacement(2712)),
+        ('Ǧ', crate::Replacement(386)),
+        ('㛼', crate::Replacement(1725)),
+        ('更', crate::Replacement(2190)),
+        ('狀', crate::Replacement(2326)),
+        ('Ἷ', crate::Replacement(734)),
+        ('不', crate::Replacement(1792)),
+        ('ḥ', crate::Replacement(510)),
+        ('戮', crate::Replacement(2126)),
+        ('塚', crate::Replacement(1975)),
+        ('ﹿ', crate::Replacement(1088)),
+        ('ẕ', crate::Replacement(602)),
+        ('笠', crate::Replacement(2433)),
+        ('Ȓ', crate::Replacement(438)),
+        ('鼏', crate::Replacement(2769)),
+        ('壟', crate::Replacement(1981)),
+        ('ﱨ', crate::Replacement(1202)),
+        ('Ѝ', crate::Replacement(832)),
+        ('ў', crate::Replacement(857)),
+        ('Ẁ', crate::Replacement(457)),
+        ('滋', crate::Replacement(2277)),
+        ('沈', crate::Replacement(2249)),
+        ('𥮫', crate::Replacement(2865)),
+        ('㒹', crate::Replacement(1720)),
+        ('煮', crate::Replacement(2303)),
+        ('ῆ', crate::Replacement(777)),
+        ('龎', crate::Replacement(2778)),
+        ('虜', crate::Replacement(2553)),
+        ('ἵ', crate::Replacement(782)),
+        ('里', crate::Replacement(2662)),
+        ('ṧ', crate::Replacement(584)),
+        ('ﳳ', crate::Replacement(1088)),
+        ('殺', crate::Replacement(2238)),
+        ('羽', crate::Replacement(2476)),
+        ('ê', crate::Replacement(498)),
+        ('閷', crate::Replacement(2679)),
+    ],
+};
+
+/// The chars that [`DIACRITICS_MAPPING`] folds to exactly `target`,
+/// lazily found by a linear scan. They come in table order, which
+/// follows no particular order of chars.
+pub fn reverse_lookup(target: &str) -> impl Iterator<Item = char> + '_ {
+    DIACRITICS_MAPPING
+        .entries()
+        .filter(move |(_, v)| v.as_str() == target)
+        .map(|(k, _)| k)
+}
+
+pub const COMPATIBILITY_MAPPING: crate::phf::CharMap<crate::Replacement> = crate::phf::CharMap {
+    range: '\u{a0}'..='🯹',
+    key: 12913932095322966823,
+    disps: &[
+        (0, 35),
+        (0, 219),
+        (0, 10),
+        (0, 0),
+        (0, 67),
+        (0, 276),
+        (0, 22),
+        (0, 49),
+        (0, 4),
+        (0, 31),
+        (0, 4),
+        (0, 5),
+        (0, 9),
+        (0, 94),
+        (0, 1),
+        (0, 35),
+        (0, 104),
+        (0, 36),
+        (0, 23),
+        (0, 2),
+        (0, 610),
+        (0, 124),
+        (0, 6),
+        (0, 422),
+        (0, 536),
+        (0, 0),
+        (0, 0),
+        (0, 1),
+        (0, 28),
+        (0, 290),
+        (0, 2015),
+        (0, 9),
+        (0, 47),
+        (0, 352),
+        (0, 3),
+        (0, 0),
+        (0, 7),
+        (0, 6),
+        (0, 24),
+        (0, 315),
+        (0, 1),
+        (0, 683),
+        (0, 4),
+        (0, 96),
+        (0, 25),
+        (0, 228),
+        (0, 628),
+        (0, 3),
+        (0, 84),
+        (0, 0),
+        (0, 66),
+        (0, 44),
+        (0, 552),
+        (0, 2),
+        (0, 22),
+        (0, 109),
+        (0, 12),
+        (0, 239),
+        (0, 25),
+        (0, 1),
+        (0, 0),
+        (0, 1538),
+        (0, 385),
+        (0, 10),
+        (0, 464),
+        (0, 36),
+        (0, 1),
+        (0, 85),
+        (0, 62),
+        (0, 0),
+        (0, 1),
+        (0, 116),
+        (0, 0),
+        (0, 24),
+        (0, 12),
+        (0, 49),
+        (0, 119),
+        (0, 18),
+        (0, 32),
+        (0, 143),
+        (0, 20),
+        (0, 3),
+        (0, 4),
+        (0, 48),
+        (0, 199),
+        (0, 1171),
+        (0, 397),
+        (0, 110),
+        (0, 144),
+        (0, 1001),
+        (0, 214),
+        (0, 399),
+        (0, 32),
+        (0, 544),
+        (0, 15),
+        (0, 0),
+        (0, 587),
+        (0, 180),
+        (0, 1),
+        (0, 0),
+        (0, 0),
+        (0, 219),
+        (0, 1018),
+        (0, 83),
+        (0, 2),
+        (0, 1118),
+        (0, 5),
+        (0, 30),
+        (0, 141),
+        (0, 106),
+        (0, 161),
+        (0, 0),
+        (0, 162),
+        (0, 10),
+        (0, 5),
+        (0, 204),
+        (0, 0),
+        (0, 16),
+        (0, 10),
+        (0, 257),
+        (0, 161),
+        (0, 23),
+        (0, 5),
+        (0, 76),
+        (0, 2),
+        (0, 5),
+        (0, 150),
+        (0, 733),
+        (0, 28),
+        (0, 223),
+        (0, 80),
+        (0, 445),
+        (0, 160),
+        (0, 0),
+        (0, 290),
+        (0, 338),
+        (0, 1),
+        (0, 7),
+        (0, 120),
+        (0, 25),
+        (0, 0),
+        (0, 452),
+        (0, 401),
+        (0, 295),
+        (0, 0),
+        (0, 12),
+        (0, 46),
+        (0, 784),
+        (0, 1090),
+        (0, 277),
+        (0, 103),
+        (0, 157),
+        (0, 58),
+        (0, 2076),
+        (0, 63),
+        (0, 1111),
+        (0, 50),
+        (0, 1042),
+        (0, 12),
+        (0, 486),
+        (0, 3228),
+        (0, 407),
+        (0, 9),
+        (0, 167),
+        (0, 1),
+        (0, 88),
+        (0, 19),
+        (0, 31),
+        (0, 2222),
+        (0, 12),
+        (0, 2635),
+        (0, 6),
+        (0, 35),
+        (0, 24),
+        (0, 1),
+        (0, 103),
+        (0, 9),
+        (0, 309),
+        (0, 0),
+        (0, 557),
+        (0, 1133),
+        (0, 0),
+        (0, 709),
+        (0, 1111),
+        (0, 257),
+        (0, 7),
+        (0, 663),
+        (0, 3),
+        (0, 0),
+        (0, 7),
+        (0, 75),
+        (0, 687),
+        (0, 190),
+        (0, 2),
+        (0, 1727),
+        (0, 0),
+        (0, 3),
+        (0, 1),
+        (0, 410),
+        (0, 24),
+        (0, 496),
+        (0, 13),
+        (0, 6),
+        (0, 215),
+        (0, 167),
+        (0, 401),
+        (0, 0),
+        (0, 86),
+        (0, 87),
+        (0, 15),
+        (0, 4),
+        (0, 1735),
+        (0, 35),
+        (0, 102),
+        (0, 23),
+        (0, 0),
+        (0, 53),
+        (0, 172),
+        (0, 8),
+        (0, 29),
+        (0, 311),
+        (0, 155),
+        (0, 443),
+        (0, 202),
+        (0, 281),
+        (0, 0),
+        (0, 14),
+        (0, 475),
+        (0, 21),
+        (0, 953),
+        (0, 136),
+        (0, 17),
+        (0, 6),
+        (0, 17),
+        (0, 109),
+        (0, 163),
+        (0, 0),
+        (0, 2),
+        (0, 502),
+        (0, 273),
+        (0, 150),
+        (0, 65),
+        (0, 1220),
+        (0, 632),
+        (0, 230),
+        (0, 24),
+        (0, 1646),
+        (0, 3),
+        (0, 51),
+        (0, 0),
+        (0, 29),
+        (0, 1025),
+        (0, 941),
+        (0, 809),
+        (0, 137),
+        (0, 73),
+        (0, 164),
+        (0, 0),
+        (0, 141),
+        (0, 3),
+        (0, 2),
+        (0, 88),
+        (0, 6),
+        (0, 4),
+        (0, 55),
+        (0, 2015),
+        (0, 184),
+        (0, 227),
+        (0, 0),
+        (0, 160),
+        (0, 1405),
+        (0, 0),
+        (0, 294),
+        (0, 9),
+        (0, 39),
+        (0, 11),
+        (0, 10),
+        (0, 37),
+        (0, 192),
+        (0, 1),
+        (0, 119),
+        (0, 9),
+        (0, 704),
+        (0, 0),
+        (0, 649),
+        (0, 19),
+        (0, 1192),
+        (0, 0),
+        (0, 1),
+        (0, 60),
+        (0, 34),
+        (0, 0),
+        (0, 21),
+        (0, 205),
+        (0, 18),
+        (0, 68),
+        (0, 112),
+        (0, 121),
+        (0, 0),
+        (0, 388),
+        (0, 157),
+        (0, 2961),
+        (0, 29),
+        (0, 743),
+        (0, 1525),
+        (0, 3),
+        (0, 726),
+        (0, 15),
+        (0, 8),
+        (0, 3),
+        (0, 46),
+        (0, 365),
+        (0, 447),
+        (0, 590),
+        (0, 1268),
+        (0, 623),
+        (0, 3331),
+        (0, 204),
+        (0, 46),
+        (0, 2732),
+        (0, 1),
+        (0, 1155),
+        (0, 37),
+        (0, 126),
+        (0, 21),
+        (0, 0),
+        (0, 673),
+        (0, 2),
+        (0, 803),
+        (0, 2569),
+        (0, 1096),
+        (0, 189),
+        (0, 206),
+        (0, 1405),
+        (0, 10),
+        (0, 3476),
+        (0, 1),
+        (0, 15),
+        (0, 530),
+        (0, 79),
+        (0, 417),
+        (1, 99),
+        (0, 6),
+        (0, 372),
+        (0, 841),
+        (0, 13),
+        (0, 86),
+        (0, 710),
+        (0, 70),
+        (0, 0),
+        (0, 89),
+        (0, 51),
+        (0, 1808),
+        (0, 260),
+        (0, 32),
+        (0, 590),
+        (0, 1385),
+        (0, 1408),
+        (0, 28),
+        (0, 536),
+        (0, 1),
+        (0, 79),
+        (0, 203),
+        (0, 30),
+        (0, 2268),
+        (0, 16),
+        (0, 0),
+        (0, 10),
+        (0, 1541),
+        (0, 84),
+        (0, 3269),
+        (0, 1894),
+        (0, 118),
+        (0, 58),
+        (0, 0),
+        (0, 58),
+        (0, 1760),
+        (0, 61),
+        (0, 447),
+        (0, 21),
+        (0, 3259),
+        (0, 10),
+        (0, 804),
+        (0, 566),
+        (0, 0),
+        (0, 7),
+        (0, 269),
+        (0, 201),
+        (0, 1261),
+        (0, 1281),
+        (0, 167),
+        (0, 54),
+        (0, 22),
+        (0, 18),
+        (0, 12),
+        (0, 55),
+        (0, 71),
+        (0, 638),
+        (0, 8),
+        (0, 1520),
+        (0, 520),
+        (0, 112),
+        (0, 1074),
+        (0, 43),
+        (0, 3),
+        (0, 278),
+        (0, 1484),
+        (0, 0),
+        (0, 384),
+        (0, 796),
+        (0, 40),
+        (0, 40),
+        (0, 66),
+        (0, 82),
+        (0, 420),
+        (0, 20),
+        (0, 2463),
+        (0, 1795),
+        (0, 23),
+        (0, 20),
+        (0, 610),
+        (0, 32),
+        (0, 56),
+        (0, 340),
+        (0, 10),
+        (0, 4),
+        (0, 7),
+        (0, 256),
+        (0, 98),
+        (0, 4),
+        (0, 68),
+        (0, 421),
+        (0, 2743),
+        (1, 1204),
+        (0, 2),
+        (0, 179),
+        (0, 428),
+        (0, 14),
+        (0, 1725),
+        (0, 522),
+        (0, 14),
+        (0, 6),
+        (0, 9),
+        (0, 2712),
+        (0, 22),
+        (0, 165),
+        (0, 184),
+        (0, 1996),
+        (0, 2567),
+        (0, 251),
+        (0, 3),
+        (0, 0),
+        (0, 2362),
+        (0, 27),
+        (2, 595),
+        (0, 87),
+        (0, 399),
+        (0, 911),
+        (0, 3357),
+        (0, 12),
+        (0, 51),
+        (0, 228),
+        (0, 208),
+        (0, 5),
+        (0, 301),
+        (0, 28),
+        (0, 53),
+        (0, 241),
+        (0, 33),
+        (0, 801),
+        (0, 108),
+        (0, 123),
+        (0, 444),
+        (0, 18),
+        (1, 717),
+        (0, 670),
+        (0, 6),
+        (0, 3128),
+        (0, 29),
+        (0, 189),
+        (0, 1905),
+        (0, 22),
+        (0, 2),
+        (0, 389),
+        (0, 29),
+        (0, 2775),
+        (0, 2248),
+        (0, 3),
+        (0, 427),
+        (0, 180),
+        (0, 99),
+        (0, 8),
+        (0, 44),
+        (0, 560),
+        (0, 61),
+        (0, 36),
+        (0, 62),
+        (0, 5),
+        (0, 472),
+        (0, 23),
+        (0, 2267),
+        (0, 251),
+        (0, 182),
+        (2, 1960),
+        (0, 17),
+        (0, 397),
+        (0, 930),
+        (0, 0),
+        (0, 138),
+        (0, 8),
+        (0, 33),
+        (0, 44),
+        (0, 1697),
+        (0, 107),
+        (0, 29),
+        (0, 241),
+        (0, 182),
+        (0, 9),
+        (0, 52),
+        (0, 4),
+        (0, 1),
+        (1, 771),
+        (0, 8),
+        (0, 507),
+        (0, 49),
+        (0, 8),
+        (0, 5),
+        (1, 416),
+        (0, 0),
+        (0, 785),
+        (0, 3547),
+        (0, 48),
+        (1, 2195),
+        (0, 21),
+        (0, 33),
+        (0, 376),
+        (0, 578),
+        (0, 933),
+        (0, 0),
+        (0, 1),
+        (0, 1644),
+        (0, 30),
+        (0, 2515),
+        (0, 217),
+        (0, 142),
+        (0, 124),
+        (0, 32),
+        (0, 220),
+        (0, 63),
+        (0, 201),
+        (1, 3177),
+        (0, 1),
+        (0, 174),
+        (0, 1),
+        (0, 0),
+        (0, 13),
+        (0, 8),
+        (0, 385),
+        (0, 3131),
+        (0, 0),
+        (0, 1462),
+        (0, 275),
+        (0, 2053),
+        (0, 172),
+        (0, 318),
+        (0, 7),
+        (0, 2),
+        (0, 53),
+        (0, 7),
+        (0, 0),
+        (0, 3391),
+        (0, 551),
+        (0, 28),
+        (0, 2370),
+        (0, 2549),
+        (0, 69),
+        (0, 177),
+        (0, 13),
+        (0, 3),
+        (0, 2910),
+        (0, 259),
+        (0, 1072),
+        (0, 60),
+        (0, 35),
+        (0, 444),
+        (0, 3),
+        (0, 29),
+        (0, 155),
+        (0, 11),
+        (3, 2143),
+        (0, 2413),
+        (0, 374),
+        (0, 88),
+        (0, 962),
+        (0, 185),
+        (0, 1084),
+        (0, 799),
+        (0, 545),
+        (0, 366),
+        (0, 1928),
+        (0, 14),
+        (0, 624),
+        (0, 5),
+        (0, 3),
+        (1, 7),
+        (1, 2236),
+        (0, 54),
+        (0, 157),
+        (0, 362),
+        (0, 534),
+        (4, 1621),
+        (0, 1559),
+        (0, 0),
+        (1, 629),
+        (0, 110),
+        (0, 2929),
+        (0, 559),
+        (0, 2823),
+        (0, 66),
+        (0, 0),
+        (2, 957),
+        (0, 92),
+        (0, 4),
+        (0, 162),
+        (0, 105),
+        (0, 685),
+        (0, 2142),
+        (0, 1968),
+        (0, 1354),
+        (0, 3),
+        (0, 7),
+        (0, 742),
+        (0, 314),
+        (0, 278),
+        (0, 20),
+        (0, 945),
+        (0, 352),
+        (3, 535),
+        (1, 1616),
+        (0, 0),
+        (0, 261),
+        (0, 85),
+        (0, 2287),
+        (0, 176),
+        (0, 0),
+        (0, 351),
+        (0, 737),
+        (1, 798),
+        (0, 66),
+        (0, 381),
+        (0, 228),
+        (1, 1628),
+        (0, 343),
+        (0, 121),
+        (0, 8),
+        (0, 745),
+        (0, 0),
+        (0, 2627),
+        (0, 415),
+        (0, 627),
+        (0, 23),
+        (0, 811),
+        (0, 1),
+        (0, 3),
+        (0, 12),
+        (0, 156),
+        (0, 498),
+        (0, 53),
+        (0, 15),
+        (1, 833),
+        (2, 197),
+        (2, 2122),
+        (0, 2023),
+        (0, 12),
+        (0, 24),
+        (0, 2674),
+        (0, 1994),
+        (1, 3249),
+        (0, 11),
+        (0, 326),
+        (0, 50),
+        (0, 267),
+        (0, 6),
+        (2, 1996),
+        (6, 3172),
+        (0, 1253),
+        (0, 643),
+        (5, 3209),
+        (0, 36),
+        (0, 2),
+        (0, 333),
+        (0, 6),
+        (0, 3103),
+        (0, 1),
+        (0, 144),
+        (0, 1183),
+        (0, 1645),
+        (4, 1846),
+        (0, 18),
+        (0, 3600),
+        (0, 2),
+        (0, 1134),
+        (4, 947),
+        (0, 3152),
+        (0, 410),
+        (0, 2336),
+        (0, 2047),
+        (3, 3320),
+        (0, 8),
+        (0, 785),
+        (11, 969),
+        (0, 1),
+        (0, 2021),
+        (0, 2218),
+        (0, 2421),
+        (0, 94),
+        (1, 996),
+        (0, 2531),
+        (1, 1900),
+        (0, 96),
+        (0, 1),
+        (0, 85),
+        (0, 235),
+        (0, 1212),
+        (0, 2),
+        (0, 537),
+        (0, 3019),
+        (0, 121),
+        (0, 308),
+        (0, 73),
+        (0, 0),
+        (0, 539),
+        (0, 962),
+        (2, 998),
+        (0, 9),
+        (0, 0),
+    ],
+    entries: &[
+        ('⒡', crate::Replacement(65)),
+        ('⽎', crate::Replacement(2237)),
+        ('𝘦', crate::Replacement(498)),
+        ('㋾', crate::Replacement(1715)),
+        ('𞀴', crate::Replacement(843)),
+        ('⿄', crate::Replacement(2754)),
+        ('ﻛ', crate::Replacement(1107)),
+        ('𝘖', crate::Replacement(429)),
+        ('𝗭', crate::Replacement(467)),
+        ('𝕤', crate::Replacement(584)),
+        ('ᵂ', crate::Replacement(457)),
+        ('㉗', crate::Replacement(262)),
+        ('𝙿', crate::Replacement(430)),
+        ('𝔡', crate::Replacement(490)),
+        ('⒜', crate::Replacement(60)),
+        ('ㅽ', crate::Replacement(1347)),
+        ('ﭤ', crate::Replacement(1217)),
+        ('𞺻', crate::Replacement(1080)),
+        ('⾌', crate::Replacement(2551)),
+        ('㍃', crate::Replacement(1676)),
+        ('ꭩ', crate::Replacement(688)),
+        ('⾖', crate::Replacement(2605)),
+        ('ﮌ', crate::Replacement(1227)),
+        ('𝖝', crate::Replacement(597)),
+        ('㋥', crate::Replacement(1640)),
+        ('㌷', crate::Replacement(1662)),
+        ('⽐', crate::Replacement(2242)),
+        ('！', crate::Replacement(4)),
+        ('ⅺ', crate::Replacement(598)),
+        ('ᴲ', crate::Replacement(625)),
+        ('𞹺', crate::Replacement(1068)),
+        ('ﯜ', crate::Replacement(1245)),
+        ('ﵘ', crate::Replacement(979)),
+        ('𝝸', crate::Replacement(782)),
+        ('𝝰', crate::Replacement(768)),
+        ('ﶡ', crate::Replacement(951)),
+        ('⒦', crate::Replacement(70)),
+        ('Ⓥ', crate::Replacement(452)),
+        ('㎷', crate::Replacement(548)),
+        ('ﷺ', crate::Replacement(1043)),
+        ('𝙠', crate::Replacement(523)),
+        ('⼶', crate::Replacement(2075)),
+        ('ﮝ', crate::Replacement(1235)),
+        ('𝘿', crate::Replacement(374)),
+        ('ㅊ', crate::Replacement(1319)),
+        ('𞸋', crate::Replacement(1118)),
+        ('ￚ', crate::Replacement(1374)),
+        ('ﾄ', crate::Replacement(1635)),
+        ('ℏ', crate::Replacement(621)),
+        ('ﳶ', crate::Replacement(1067)),
+        ('ﳹ', crate::Replacement(1086)),
+        ('𞹾', crate::Replacement(1229)),
+        ('⒙', crate::Replacement(217)),
+        ('𝕶', crate::Replacement(406)),
+        ('ⓝ', crate::Replacement(561)),
+        ('ﬔ', crate::Replacement(890)),
+        ('𝘚', crate::Replacement(440)),
+        ('𝑓', crate::Replacement(501)),
+        ('𝟀', crate::Replacement(817)),
+        ('㎙', crate::Replacement(507)),
+        ('ᵩ', crate::Replacement(816)),
+        ('㈅', crate::Replacement(96)),
+        ('ﻑ', crate::Replacement(1089)),
+        ('𝟘', crate::Replacement(162)),
+        ('🈦', crate::Replacement(2281)),
+        ('⒤', crate::Replacement(68)),
+        ('𝗘', crate::Replacement(378)),
+        ('𝘓', crate::Replacement(410)),
+        ('⿌', crate::Replacement(2765)),
+        ('ꚜ', crate::Replacement(863)),
+        ('𝘁', crate::Replacement(588)),
+        ('ﻁ', crate::Replacement(1060)),
+        ('𝝥', crate::Replacement(745)),
+        ('𝚮', crate::Replacement(729)),
+        ('𝘎', crate::Replacement(386)),
+        ('ﻪ', crate::Replacement(1176)),
+        ('ｃ', crate::Replacement(481)),
+        ('𝕬', crate::Replacement(364)),
+        ('⺟', crate::Replacement(2241)),
+        ('𝖒', crate::Replacement(544)),
+        ('ㅱ', crate::Replacement(1334)),
+        ('㍺', crate::Replacement(401)),
+        ('ﮆ', crate::Replacement(1226)),
+        ('ﶰ', crate::Replacement(1198)),
+        ('ﱊ', crate::Replacement(1155)),
+        ('Ⓙ', crate::Replacement(404)),
+        ('𝒔', crate::Replacement(584)),
+        ('ﻐ', crate::Replacement(1080)),
+        ('🄬', crate::Replacement(438)),
+        ('𝔍', crate::Replacement(404)),
+        ('ﲖ', crate::Replacement(1203)),
+        ('𝓵', crate::Replacement(537)),
+        ('ﲴ', crate::Replacement(1050)),
+        ('🈤', crate::Replacement(1984)),
+        ('𝑟', crate::Replacement(580)),
+        ('ﲾ', crate::Replacement(1090)),
+        ('ﰕ', crate::Replacement(974)),
+        ('🄈', crate::Replacement(333)),
+        ('ｧ', crate::Replacement(1569)),
+        ('ﲏ', crate::Replacement(1175)),
+        ('𝝶', crate::Replacement(777)),
+        ('㆜', crate::Replacement(1787)),
+        ('𝖾', crate::Replacement(498)),
+        ('ﭞ', crate::Replacement(1214)),
+        ('𞹟', crate::Replacement(1211)),
+        ('𝗇', crate::Replacement(561)),
+        ('⽋', crate::Replacement(2227)),
+        ('㉔', crate::Replacement(255)),
+        ('④', crate::Replacement(297)),
+        ('🈸', crate::Replacement(2358)),
+        ('︕', crate::Replacement(4)),
+        ('㏊', crate::Replacement(512)),
+        ('𐞔', crate::Replacement(697)),
+        ('ﬥ', crate::Replacement(907)),
+        ('㈬', crate::Replacement(141)),
+        ('㈵', crate::Replacement(143)),
+        ('㉶', crate::Replacement(1316)),
+        ('㊭', crate::Replacement(1819)),
+        ('𝖲', crate::Replacement(440)),
+        ('㌥', crate::Replacement(1634)),
+        ('ₑ', crate::Replacement(498)),
+        ('⽞', crate::Replacement(2331)),
+        ('ﲍ', crate::Replacement(1172)),
+        ('⼇', crate::Replacement(1811)),
+        ('𝖟', crate::Replacement(602)),
+        ('𝘪', crate::Replacement(514)),
+        ('𝕫', crate::Replacement(602)),
+        ('𝘒', crate::Replacement(406)),
+        ('𞁏', crate::Replacement(876)),
+        ('𝗻', crate::Replacement(561)),
+        ('㊆', crate::Replacement(1788)),
+        ('𝗝', crate::Replacement(404)),
+        ('︲', crate::Replacement(1470)),
+        ('㉛', crate::Replacement(281)),
+        ('𝜋', crate::Replacement(801)),
+        ('ㅩ', crate::Replacement(1396)),
+        ('ᵛ', crate::Replacement(591)),
+        ('𝝁', crate::Replacement(789)),
+        ('㏄', crate::Replacement(485)),
+        ('︖', crate::Replacement(360)),
+        ('⽅', crate::Replacement(2172)),
+        ('㎃', crate::Replacement(547)),
+        ('𞹋', crate::Replacement(1118)),
+        ('𝝀', crate::Replacement(788)),
+        ('𝓙', crate::Replacement(404)),
+        ('𝑯', crate::Replacement(391)),
+        ('㎔', crate::Replacement(448)),
+        ('ℹ', crate::Replacement(514)),
+        ('⾒', crate::Replacement(2585)),
+        ('﹦', crate::Replacement(356)),
+        ('ₖ', crate::Replacement(523)),
+        ('ㅹ', crate::Replacement(1343)),
+        ('ﬀ', crate::Replacement(502)),
+        ('ﻦ', crate::Replacement(1156)),
+        ('ﺪ', crate::Replacement(998)),
+        ('𝚄', crate::Replacement(451)),
+        ('𝚍', crate::Replacement(490)),
+        ('㊙', crate::Replacement(2420)),
+        ('𞺂', crate::Replacement(973)),
+        ('𝞙', crate::Replacement(739)),
+        ('⒃', crate::Replacement(21)),
+        ('𝒏', crate::Replacement(561)),
+        ('ⓦ', crate::Replacement(595)),
+        ('ﮃ', crate::Replacement(1225)),
+        ('｢', crate::Replacement(1544)),
+        ('ﳵ', crate::Replacement(1066)),
+        ('ﱐ', crate::Replacement(1175)),
+        ('⒈', crate::Replacement(170)),
+        ('ﲬ', crate::Replacement(995)),
+        ('＝', crate::Replacement(356)),
+        ('ﮞ', crate::Replacement(1237)),
+        ('ﳷ', crate::Replacement(1078)),
+        ('𝖇', crate::Replacement(479)),
+        ('㏼', crate::Replacement(267)),
+        ('㎌', crate::Replacement(791)),
+        ('⽫', crate::Replacement(2376)),
+        ('ｊ', crate::Replacement(521)),
+        ('ﴷ', crate::Replacement(1022)),
+        ('🄦', crate::Replacement(56)),
+        ('￨', crate::Replacement(1530)),
+        ('ﾷ', crate::Replacement(1312)),
+        ('ﻂ', crate::Replacement(1060)),
+        ('⒣', crate::Replacement(67)),
+        ('ᴭ', crate::Replacement(615)),
+        ('⼑', crate::Replacement(1868)),
+        ('𐞯', crate::Replacement(683)),
+        ('ﺐ', crate::Replacement(926)),
+        ('𐞃', crate::Replacement(617)),
+        ('⒂', crate::Replacement(20)),
+        ('𝘆', crate::Replacement(600)),
+        ('𞹱', crate::Replacement(1035)),
+        ('𝕩', crate::Replacement(597)),
+        ('ㆀ', crate::Replacement(1350)),
+        ('𝜇', crate::Replacement(789)),
+        ('ǲ', crate::Replacement(377)),
+        ('𞀰', crate::Replacement(839)),
+        ('㋬', crate::Replacement(1660)),
+        ('𝞳', crate::Replacement(787)),
+        ('🄸', crate::Replacement(397)),
+        ('ᶰ', crate::Replacement(668)),
+        ('𝖉', crate::Replacement(490)),
+        ('︺', crate::Replacement(1562)),
+        ('ﲊ', crate::Replacement(1167)),
+        ('㍱', crate::Replacement(511)),
+        ('🄣', crate::Replacement(53)),
+        ('𞹑', crate::Replacement(1035)),
+        ('𝒵', crate::Replacement(467)),
+        ('㈥', crate::Replacement(127)),
+        ('🅉', crate::Replacement(467)),
+        ('ﭔ', crate::Replacement(1215)),
+        ('𝟇', crate::Replacement(816)),
+        ('ⓤ', crate::Replacement(590)),
+        ('𝟺', crate::Replacement(297)),
+        ('𝞂', crate::Replacement(805)),
+        ('𝕃', crate::Replacement(410)),
+        ('ℿ', crate::Replacement(745)),
+        ('𝗬', crate::Replacement(465)),
+        ('ﾅ', crate::Replacement(1638)),
+        ('⁗', crate::Replacement(1474)),
+        ('𝒜', crate::Replacement(364)),
+        ('ﻇ', crate::Replacement(1068)),
+        ('ﮪ', crate::Replacement(1239)),
+        ('🅊', crate::Replacement(393)),
+        ('Ｊ', crate::Replacement(404)),
+        ('︸', crate::Replacement(605)),
+        ('ｾ', crate::Replacement(1623)),
+        ('｡', crate::Replacement(1539)),
+        ('㊒', crate::Replacement(2194)),
+        ('㍋', crate::Replacement(1687)),
+        ('㏓', crate::Replacement(542)),
+        ('⑪', crate::Replacement(186)),
+        ('𝑗', crate::Replacement(521)),
+        ('𝑜', crate::Replacement(569)),
+        ('ﶕ', crate::Replacement(1163)),
+        ('⼧', crate::Replacement(2016)),
+        ('№', crate::Replacement(428)),
+        ('𞸛', crate::Replacement(1080)),
+        ('𝙚', crate::Replacement(498)),
+        ('㉷', crate::Replacement(1320)),
+        ('ᵣ', crate::Replacement(580)),
+        ('⾟', crate::Replacement(2636)),
+        ('𞁧', crate::Replacement(874)),
+        ('𝖧', crate::Replacement(391)),
+        ('ﳊ', crate::Replacement(1124)),
+        ('𝑰', crate::Replacement(397)),
+        ('⽑', crate::Replacement(2243)),
+        ('ㅏ', crate::Replacement(1356)),
+        ('𐞕', crate::Replacement(621)),
+        ('ₜ', crate::Replacement(588)),
+        ('ﮯ', crate::Replacement(1250)),
+        ('㈉', crate::Replacement(107)),
+        ('ﰧ', crate::Replacement(1062)),
+        ('𞸪', crate::Replacement(1107)),
+        ('ﶵ', crate::Replacement(1125)),
+        ('𝟢', crate::Replacement(162)),
+        ('ㆂ', crate::Replacement(1403)),
+        ('⑿', crate::Replacement(17)),
+        ('ﺻ', crate::Replacement(1035)),
+        ('ﲣ', crate::Replacement(948)),
+        ('㉅', crate::Replacement(2062)),
+        ('｜', crate::Replacement(604)),
+        ('𞸐', crate::Replacement(1089)),
+        ('🄮', crate::Replacement(459)),
+        ('⅕', crate::Replacement(229)),
+        ('𝓻', crate::Replacement(580)),
+        ('𝖍', crate::Replacement(510)),
+        ('⼣', crate::Replacement(1990)),
+        ('ﯙ', crate::Replacement(1242)),
+        ('𝔑', crate::Replacement(425)),
+        ('⑲', crate::Replacement(220)),
+        ('ﶛ', crate::Replacement(1170)),
+        ('𞺣', crate::Replacement(998)),
+        ('𝜛', crate::Replacement(801)),
+        ('ﭧ', crate::Replacement(1213)),
+        ('🄧', crate::Replacement(57)),
+        ('𝜆', crate::Replacement(788)),
+        ('ﳥ', crate::Replacement(968)),
+        ('𝗫', crate::Replacement(462)),
+        ('㌍', crate::Replacement(1595)),
+        ('𝙇', crate::Replacement(410)),
+        ('🅍', crate::Replacement(443)),
+        ('🄹', crate::Replacement(404)),
+        ('𐞌', crate::Replacement(641)),
+        ('﹎', crate::Replacement(472)),
+        ('ﱫ', crate::Replacement(933)),
+        ('㉝', crate::Replacement(284)),
+        ('𞁟', crate::Replacement(857)),
+        ('𝐙', crate::Replacement(467)),
+        ('𞺱', crate::Replacement(1035)),
+        ('𐞐', crate::Replacement(709)),
+        ('𝟳', crate::Replacement(332)),
+        ('🅅', crate::Replacement(452)),
+        ('ﱘ', crate::Replacement(1196)),
+        ('𝑂', crate::Replacement(429)),
+        ('𝖱', crate::Replacement(438)),
+        ('𝕆', crate::Replacement(429)),
+        ('ﴨ', crate::Replacement(1029)),
+        ('㆟', crate::Replacement(1814)),
+        ('ﮁ', crate::Replacement(1222)),
+        ('𐞴', crate::Replacement(702)),
+        ('𝐁', crate::Replacement(368)),
+        ('㊄', crate::Replacement(1810)),
+        ('🈻', crate::Replacement(2657)),
+        ('㋿', crate::Replacement(1818)),
+        ('㊫', crate::Replacement(2015)),
+        ('㏠', crate::Replacement(234)),
+        ('𝘭', crate::Replacement(537)),
+        ('𝒎', crate::Replacement(544)),
+        ('ᴼ', crate::Replacement(429)),
+        ('🄗', crate::Replacement(41)),
+        ('Ⓧ', crate::Replacement(462)),
+        ('𝔨', crate::Replacement(523)),
+        ('𝟬', crate::Replacement(162)),
+        ('ᵄ', crate::Replacement(634)),
+        ('𝑶', crate::Replacement(429)),
+        ('㍫', crate::Replacement(223)),
+        ('𝚥', crate::Replacement(633)),
+        ('𝑑', crate::Replacement(490)),
+        ('𝒮', crate::Replacement(440)),
+        ('７', crate::Replacement(332)),
+        ('𝟶', crate::Replacement(162)),
+        ('𝚇', crate::Replacement(462)),
+        ('ﴤ', crate::Replacement(1059)),
+        ('🆐', crate::Replacement(375)),
+        ('ㄶ', crate::Replacement(1387)),
+        ('𝜒', crate::Replacement(817)),
+        ('㉨', crate::Replacement(1315)),
+        ('𝙹', crate::Replacement(404)),
+        ('ꭜ', crate::Replacement(2784)),
+        ('ᶳ', crate::Replacement(680)),
+        ('𝜶', crate::Replacement(768)),
+        ('︶', crate::Replacement(153)),
+        ('㏑', crate::Replacement(540)),
+        ('𐞷', crate::Replacement(630)),
+        ('ﴓ', crate::Replacement(1078)),
+        ('㍊', crate::Replacement(1684)),
+        ('ﰨ', crate::Replacement(1069)),
+        ('︴', crate::Replacement(472)),
+        ('ﶈ', crate::Replacement(1131)),
+        ('ℚ', crate::Replacement(437)),
+        ('ﳩ', crate::Replacement(1029)),
+        ('㎉', crate::Replacement(529)),
+        ('⼔', crate::Replacement(1895)),
+        ('㉫', crate::Replacement(1324)),
+        ('㎅', crate::Replacement(407)),
+        ('ﵗ', crate::Replacement(957)),
+        ('ㅡ', crate::Replacement(1374)),
+        ('ﺛ', crate::Replacement(964)),
+        ('ﱼ', crate::Replacement(1096)),
+        ('ﾑ', crate::Replacement(1685)),
+        ('🉇', crate::Replacement(1555)),
+        ('ﲩ', crate::Replacement(985)),
+        ('𝗛', crate::Replacement(391)),
+        ('𞺩', crate::Replacement(1187)),
+        ('ⓞ', crate::Replacement(569)),
+        ('⿎', crate::Replacement(2770)),
+        ('㏨', crate::Replacement(349)),
+        ('ᴰ', crate::Replacement(374)),
+        ('㈦', crate::Replacement(118)),
+        ('𐞝', crate::Replacement(2786)),
+        ('ﲑ', crate::Replacement(1194)),
+        ('⒖', crate::Replacement(205)),
+        ('ﶤ', crate::Replacement(958)),
+        ('ﾶ', crate::Replacement(1311)),
+        ('﹣', crate::Replacement(157)),
+        ('𝐣', crate::Replacement(521)),
+        ('｛', crate::Replacement(603)),
+        ('𝗸', crate::Replacement(523)),
+        ('⻳', crate::Replacement(2780)),
+        ('ﺕ', crate::Replacement(940)),
+        ('ﻮ', crate::Replacement(1183)),
+        ('ﰬ', crate::Replacement(1082)),
+        ('⾬', crate::Replacement(2696)),
+        ('Ｓ', crate::Replacement(440)),
+        ('ﳗ', crate::Replacement(1177)),
+        ('𝞖', crate::Replacement(729)),
+        ('↉', crate::Replacement(166)),
+        ('₎', crate::Replacement(153)),
+        ('𝝊', crate::Replacement(807)),
+        ('𝟡', crate::Replacement(346)),
+        ('🅄', crate::Replacement(451)),
+        ('Ⓓ', crate::Replacement(374)),
+        ('𝜘', crate::Replacement(787)),
+        ('𝙜', crate::Replacement(508)),
+        ('ₛ', crate::Replacement(584)),
+        ('㍅', crate::Replacement(1678)),
+        ('ￔ', crate::Replacement(1370)),
+        ('￪', crate::Replacement(1479)),
+        ('ﱁ', crate::Replacement(1128)),
+        ('Ⅷ', crate::Replacement(455)),
+        ('\u{2000}', crate::Replacement(1)),
+        ('㎋', crate::Replacement(563)),
+        ('ﮉ', crate::Replacement(1223)),
+        ('ﬂ', crate::Replacement(506)),
+        ('𝕡', crate::Replacement(571)),
+        ('𝗾', crate::Replacement(579)),
+        ('𝒃', crate::Replacement(479)),
+        ('㌧', crate::Replacement(1637)),
+        ('ᶵ', crate::Replacement(627)),
+        ('𝑆', crate::Replacement(440)),
+        ('𐞄', crate::Replacement(696)),
+        ('㌘', crate::Replacement(1606)),
+        ('㊖', crate::Replacement(2610)),
+        ('㊚', crate::Replacement(2359)),
+        ('ﬕ', crate::Replacement(891)),
+        ('𝚜', crate::Replacement(584)),
+        ('ﰊ', crate::Replacement(938)),
+        ('𝚯', crate::Replacement(733)),
+        ('㌈', crate::Replacement(1585)),
+        ('𝗴', crate::Replacement(508)),
+        ('ﶍ', crate::Replacement(1141)),
+        ('𝔟', crate::Replacement(479)),
+        ('ﶺ', crate::Replacement(1122)),
+        ('㍟', crate::Replacement(339)),
+        ('㈻', crate::Replacement(135)),
+        ('⾼', crate::Replacement(2737)),
+        ('🄴', crate::Replacement(378)),
+        ('⿅', crate::Replacement(2755)),
+        ('㈮', crate::Replacement(152)),
+        ('ｸ', crate::Replacement(1605)),
+        ('🈓', crate::Replacement(1633)),
+        ('㉠', crate::Replacement(1294)),
+        ('］', crate::Replacement(470)),
+        ('𝓸', crate::Replacement(569)),
+        ('⾅', crate::Replacement(2501)),
+        ('ﴎ', crate::Replacement(1013)),
+        ('ﺸ', crate::Replacement(1021)),
+        ('𝖢', crate::Replacement(370)),
+        ('𝘸', crate::Replacement(595)),
+        ('ﰈ', crate::Replacement(934)),
+        ('ㅲ', crate::Replacement(1335)),
+        ('𝑤', crate::Replacement(595)),
+        ('＿', crate::Replacement(472)),
+        ('⅑', crate::Replacement(233)),
+        ('𝝐', crate::Replacement(775)),
+        ('㍍', crate::Replacement(1689)),
+        ('𝝧', crate::Replacement(733)),
+        ('𝕁', crate::Replacement(404)),
+        ('𝒯', crate::Replacement(446)),
+        ('𝐓', crate::Replacement(446)),
+        ('𞹷', crate::Replacement(992)),
+        ('ϲ', crate::Replacement(804)),
+        ('㏏', crate::Replacement(535)),
+        ('ﻳ', crate::Replacement(1187)),
+        ('＊', crate::Replacement(154)),
+        ('ᵟ', crate::Replacement(774)),
+        ('𝕼', crate::Replacement(437)),
+        ('ﮅ', crate::Replacement(1224)),
+        ('\u{3000}', crate::Replacement(1)),
+        ('㉼', crate::Replacement(1321)),
+        ('⒌', crate::Replacement(317)),
+        ('ￂ', crate::Replacement(1356)),
+        ('～', crate::Replacement(606)),
+        ('𝝒', crate::Replacement(787)),
+        ('🄪', crate::Replacement(1552)),
+        ('㏺', crate::Replacement(263)),
+        ('ﳏ', crate::Replacement(1143)),
+        ('㏶', crate::Replacement(253)),
+        ('🈳', crate::Replacement(2427)),
+        ('𝒁', crate::Replacement(467)),
+        ('ﴲ', crate::Replacement(1032)),
+        ('ﺷ', crate::Replacement(1021)),
+        ('㊝', crate::Replacement(1835)),
+        ('ﵒ', crate::Replacement(946)),
+        ('㊣', crate::Replacement(2231)),
+        ('𝜄', crate::Replacement(782)),
+        ('（', crate::Replacement(13)),
+        ('㌻', crate::Replacement(1665)),
+        ('𝕢', crate::Replacement(579)),
+        ('ᵗ', crate::Replacement(588)),
+        ('℆', crate::Replacement(483)),
+        ('ｏ', crate::Replacement(569)),
+        ('ﱻ', crate::Replacement(972)),
+        ('㊷', crate::Replacement(303)),
+        ('🄅', crate::Replacement(298)),
+        ('𝓜', crate::Replacement(415)),
+        ('𝘫', crate::Replacement(521)),
+        ('𝟏', crate::Replacement(168)),
+        ('［', crate::Replacement(468)),
+        ('㍼', crate::Replacement(2181)),
+        ('㊁', crate::Replacement(1809)),
+        ('⽥', crate::Replacement(2356)),
+        ('㌉', crate::Replacement(1588)),
+        ('㍵', crate::Replacement(570)),
+        ('ໝ', crate::Replacement(1283)),
+        ('ﵡ', crate::Replacement(1015)),
+        ('𝟕', crate::Replacement(332)),
+        ('⿋', crate::Replacement(2764)),
+        ('𝞆', crate::Replacement(817)),
+        ('⅟', crate::Replacement(224)),
+        ('㍘', crate::Replacement(167)),
+        ('⿈', crate::Replacement(2760)),
+        ('㍷', crate::Replacement(494)),
+        ('𞸙', crate::Replacement(1049)),
+        ('⽜', crate::Replacement(2319)),
+        ('𐞛', crate::Replacement(660)),
+        ('⾋', crate::Replacement(2511)),
+        ('：', crate::Replacement(352)),
+        ('⒠', crate::Replacement(64)),
+        ('ﷶ', crate::Replacement(1001)),
+        ('𝓍', crate::Replacement(597)),
+        ('🈣', crate::Replacement(2611)),
+        ('ﶘ', crate::Replacement(1159)),
+        ('𝝛', crate::Replacement(728)),
+        ('ﲽ', crate::Replacement(1082)),
+        ('㉹', crate::Replacement(1325)),
+        ('𝐊', crate::Replacement(406)),
+        ('𐞲', crate::Replacement(690)),
+        ('ﳱ', crate::Replacement(1200)),
+        ('𞺇', crate::Replacement(984)),
+        ('🈴', crate::Replacement(1929)),
+        ('㋸', crate::Replacement(1704)),
+        ('𝗍', crate::Replacement(588)),
+        ('µ', crate::Replacement(789)),
+        ('ﻲ', crate::Replacement(1187)),
+        ('ﻀ', crate::Replacement(1049)),
+        ('＂', crate::Replacement(7)),
+        ('𝕲', crate::Replacement(386)),
+        ('𞺢', crate::Replacement(973)),
+        ('𝗺', crate::Replacement(544)),
+        ('ⓜ', crate::Replacement(544)),
+        ('゜', crate::Replacement(3)),
+        ('ﾬ', crate::Replacement(1390)),
+        ('㏎', crate::Replacement(409)),
+        ('ﶻ', crate::Replacement(1114)),
+        ('𝝞', crate::Replacement(734)),
+        ('㈭', crate::Replacement(139)),
+        ('ﵾ', crate::Replacement(1102)),
+        ('𞁘', crate::Replacement(846)),
+        ('‷', crate::Replacement(1476)),
+        ('𝑞', crate::Replacement(579)),
+        ('ﳨ', crate::Replacement(1018)),
+        ('ﶙ', crate::Replacement(1160)),
+        ('ˠ', crate::Replacement(651)),
+        ('㏜', crate::Replacement(445)),
+        ('⾁', crate::Replacement(2489)),
+        ('⽇', crate::Replacement(2177)),
+        ('ￒ', crate::Replacement(1368)),
+        ('㊳', crate::Replacement(289)),
+        ('𝟝', crate::Replacement(315)),
+        ('‶', crate::Replacement(1475)),
+        ('⾸', crate::Replacement(2728)),
+        ('𐞡', crate::Replacement(2797)),
+        ('𝕖', crate::Replacement(498)),
+        ('㎹', crate::Replacement(422)),
+        ('﹛', crate::Replacement(603)),
+        ('㎢', crate::Replacement(533)),
+        ('㊉', crate::Replacement(1901)),
+        ('𝐩', crate::Replacement(571)),
+        ('𝖵', crate::Replacement(452)),
+        ('ﭥ', crate::Replacement(1217)),
+        ('ᵑ', crate::Replacement(623)),
+        ('ﳂ', crate::Replacement(1099)),
+        ('𝓤', crate::Replacement(451)),
+        ('㆝', crate::Replacement(1996)),
+        ('ￆ', crate::Replacement(1360)),
+        ('㏒', crate::Replacement(541)),
+        ('﹔', crate::Replacement(354)),
+        ('㍽', crate::Replacement(1995)),
+        ('𝙝', crate::Replacement(510)),
+        ('𝜷', crate::Replacement(772)),
+        ('＇', crate::Replacement(12)),
+        ('㋼', crate::Replacement(1713)),
+        ('ㄲ', crate::Replacement(1296)),
+        ('𝝡', crate::Replacement(741)),
+        ('ﳐ', crate::Replacement(1148)),
+        ('ﳍ', crate::Replacement(1133)),
+        ('𝖮', crate::Replacement(429)),
+        ('ǌ', crate::Replacement(566)),
+        ('𝓉', crate::Replacement(588)),
+        ('ﳧ', crate::Replacement(1014)),
+        ('ﶦ', crate::Replacement(975)),
+        ('ﶜ', crate::Replacement(1197)),
+        ('ℵ', crate::Replacement(895)),
+        ('𝞗', crate::Replacement(733)),
+        ('Ｍ', crate::Replacement(415)),
+        ('ℐ', crate::Replacement(397)),
+        ('⾗', crate::Replacement(2607)),
+        ('𝐜', crate::Replacement(481)),
+        ('ﰲ', crate::Replacement(1097)),
+        ('🅪', crate::Replacement(417)),
+        ('🈱', crate::Replacement(2130)),
+        ('𞁖', crate::Replacement(844)),
+        ('🈫', crate::Replacement(2643)),
+        ('⽆', crate::Replacement(2174)),
+        ('⽂', crate::Replacement(2167)),
+        ('⁴', crate::Replacement(297)),
+        ('ﶟ', crate::Replacement(944)),
+        ('⒫', crate::Replacement(75)),
+        ('🈲', crate::Replacement(2412)),
+        ('⿆', crate::Replacement(2758)),
+        ('ⓧ', crate::Replacement(597)),
+        ('㉞', crate::Replacement(285)),
+        ('𞁣', crate::Replacement(861)),
+        ('ℝ', crate::Replacement(438)),
+        ('ﻡ', crate::Replacement(1136)),
+        ('\u{ff9e}', crate::Replacement(1567)),
+        ('ᵏ', crate::Replacement(523)),
+        ('ﯥ', crate::Replacement(1249)),
+        ('𝞑', crate::Replacement(724)),
+        ('⼨', crate::Replacement(2024)),
+        ('𝟣', crate::Replacement(168)),
+        ('𝒓', crate::Replacement(580)),
+        ('；', crate::Replacement(354)),
+        ('𝙗', crate::Replacement(479)),
+        ('𝘼', crate::Replacement(364)),
+        ('𞺬', crate::Replacement(1136)),
+        ('₁', crate::Replacement(168)),
+        ('𝞿', crate::Replacement(816)),
+        ('㉮', crate::Replacement(1295)),
+        ('ㅒ', crate::Replacement(1359)),
+        ('𝔚', crate::Replacement(457)),
+        ('ᵦ', crate::Replacement(772)),
+        ('㏍', crate::Replacement(408)),
+        ('𝜼', crate::Replacement(777)),
+        ('𝘌', crate::Replacement(378)),
+        ('㎥', crate::Replacement(546)),
+        ('ﯘ', crate::Replacement(1243)),
+        ('ￍ', crate::Replacement(1365)),
+        ('𝘘', crate::Replacement(437)),
+        ('㎇', crate::Replacement(387)),
+        ('ᵒ', crate::Replacement(569)),
+        ('㍞', crate::Replacement(331)),
+        ('㈨', crate::Replacement(120)),
+        ('ゟ', crate::Replacement(1566)),
+        ('𞀺', crate::Replacement(849)),
+        ('ｭ', crate::Replacement(1695)),
+        ('ﾔ', crate::Replacement(1692)),
+        ('𞸶', crate::Replacement(964)),
+        ('Ｔ', crate::Replacement(446)),
+        ('𝗢', crate::Replacement(429)),
+        ('₌', crate::Replacement(356)),
+        ('ﺩ', crate::Replacement(998)),
+        ('𞁥', crate::Replacement(863)),
+        ('º', crate::Replacement(569)),
+        ('𝙃', crate::Replacement(391)),
+        ('Ⓞ', crate::Replacement(429)),
+        ('ㆊ', crate::Replacement(1380)),
+        ('🈶', crate::Replacement(2194)),
+        ('㎘', crate::Replacement(531)),
+        ('ｨ', crate::Replacement(1575)),
+        ('ﲧ', crate::Replacement(974)),
+        ('㈞', crate::Replacement(103)),
+        ('𝝴', crate::Replacement(775)),
+        ('𝛿', crate::Replacement(774)),
+        ('⽤', crate::Replacement(2355)),
+        ('ﱒ', crate::Replacement(1178)),
+        ('﹁', crate::Replacement(1544)),
+        ('ⅴ', crate::Replacement(591)),
+        ('𝔽', crate::Replacement(379)),
+        ('⽃', crate::Replacement(2168)),
+        ('և', crate::Replacement(889)),
+        ('︻', crate::Replacement(1548)),
+        ('𝛇', crate::Replacement(776)),
+        ('ﾊ', crate::Replacement(1645)),
+        ('㏀', crate::Replacement(536)),
+        ('㊘', crate::Replacement(1884)),
+        ('㌯', crate::Replacement(1653)),
+        ('⼎', crate::Replacement(1858)),
+        ('㋴', crate::Replacement(1696)),
+        ('𝒄', crate::Replacement(481)),
+        ('⒁', crate::Replacement(19)),
+        ('﹚', crate::Replacement(153)),
+        ('ﭑ', crate::Replacement(1212)),
+        ('㉜', crate::Replacement(283)),
+        ('ﲟ', crate::Replacement(934)),
+        ('ﲿ', crate::Replacement(1091)),
+        ('ﻯ', crate::Replacement(1186)),
+        ('🄡', crate::Replacement(51)),
+        ('𝕽', crate::Replacement(438)),
+        ('ﭺ', crate::Replacement(1221)),
+        ('𝝖', crate::Replacement(720)),
+        ('ﱌ', crate::Replacement(1162)),
+        ('５', crate::Replacement(315)),
+        ('𝗔', crate::Replacement(364)),
+        ('𝝓', crate::Replacement(816)),
+        ('ℂ', crate::Replacement(370)),
+        ('𞁞', crate::Replacement(855)),
+        ('𐞣', crate::Replacement(670)),
+        ('𝚏', crate::Replacement(501)),
+        ('\u{2001}', crate::Replacement(1)),
+        ('𝑒', crate::Replacement(498)),
+        ('𝜮', crate::Replacement(748)),
+        ('Ⅰ', crate::Replacement(397)),
+        ('𐞋', crate::Replacement(640)),
+        ('𝚕', crate::Replacement(537)),
+        ('㊀', crate::Replacement(1786)),
+        ('ʴ', crate::Replacement(673)),
+        ('𝝉', crate::Replacement(806)),
+        ('⽌', crate::Replacement(2230)),
+        ('𝝈', crate::Replacement(805)),
+        ('ⓨ', crate::Replacement(600)),
+        ('⽍', crate::Replacement(2234)),
+        ('ｔ', crate::Replacement(588)),
+        ('ￄ', crate::Replacement(1358)),
+        ('𝖭', crate::Replacement(425)),
+        ('℠', crate::Replacement(442)),
+        ('ﻍ', crate::Replacement(1080)),
+        ('㏵', crate::Replacement(250)),
+        ('￮', crate::Replacement(1532)),
+        ('ﺰ', crate::Replacement(1003)),
+        ('ﬣ', crate::Replacement(900)),
+        ('⽡', crate::Replacement(2350)),
+        ('ㅁ', crate::Replacement(1304)),
+        ('㈾', crate::Replacement(151)),
+        ('㉯', crate::Replacement(1298)),
+        ('𝒕', crate::Replacement(588)),
+        ('︒', crate::Replacement(1539)),
+        ('⼳', crate::Replacement(2061)),
+        ('🉃', crate::Replacement(1556)),
+        ('ﮇ', crate::Replacement(1226)),
+        ('㎞', crate::Replacement(532)),
+        ('㋮', crate::Replacement(1675)),
+        ('𝐑', crate::Replacement(438)),
+        ('𝗂', crate::Replacement(514)),
+        ('𝗧', crate::Replacement(446)),
+        ('𝛯', crate::Replacement(743)),
+        ('㉐', crate::Replacement(435)),
+        ('ㅑ', crate::Replacement(1358)),
+        ('⅜', crate::Replacement(293)),
+        ('㈧', crate::Replacement(126)),
+        ('𝞻', crate::Replacement(804)),
+        ('𝒞', crate::Replacement(370)),
+        ('𝑭', crate::Replacement(379)),
+        ('⼌', crate::Replacement(1850)),
+        ('㈢', crate::Replacement(119)),
+        ('𝓊', crate::Replacement(590)),
+        ('㋄', crate::Replacement(324)),
+        ('𝓡', crate::Replacement(438)),
+        ('𝟤', crate::Replacement(237)),
+        ('︷', crate::Replacement(603)),
+        ('𝟦', crate::Replacement(297)),
+        ('𝔞', crate::Replacement(474)),
+        ('𝟷', crate::Replacement(168)),
+        ('㎻', crate::Replacement(565)),
+        ('￬', crate::Replacement(1481)),
+        ('ﴔ', crate::Replacement(1079)),
+        ('ㆈ', crate::Replacement(1378)),
+        ('ﭒ', crate::Replacement(1215)),
+        ('⼛', crate::Replacement(1916)),
+        ('㉸', crate::Replacement(1323)),
+        ('㈼', crate::Replacement(144)),
+        ('⒩', crate::Replacement(73)),
+        ('ŀ', crate::Replacement(543)),
+        ('ﭛ', crate::Replacement(1218)),
+        ('𐞫', crate::Replacement(708)),
+        ('ﾡ', crate::Replacement(1294)),
+        ('𝕍', crate::Replacement(452)),
+        ('𝜠', crate::Replacement(727)),
+        ('𝖘', crate::Replacement(584)),
+        ('⁸', crate::Replacement(340)),
+        ('🯲', crate::Replacement(237)),
+        ('㈡', crate::Replacement(121)),
+        ('ⅆ', crate::Replacement(490)),
+        ('𝔒', crate::Replacement(429)),
+        ('𞹇', crate::Replacement(984)),
+        ('㎮', crate::Replacement(582)),
+        ('ﰻ', crate::Replacement(1112)),
+        ('ﯞ', crate::Replacement(1247)),
+        ('ﵳ', crate::Replacement(1064)),
+        ('Ｉ', crate::Replacement(397)),
+        ('ﬢ', crate::Replacement(899)),
+        ('𝑅', crate::Replacement(438)),
+        ('𝝮', crate::Replacement(762)),
+        ('𝝢', crate::Replacement(742)),
+        ('ᴿ', crate::Replacement(438)),
+        ('ﴡ', crate::Replacement(1047)),
+        ('￢', crate::Replacement(611)),
+        ('𝗼', crate::Replacement(569)),
+        ('𞀵', crate::Replacement(844)),
+        ('⒚', crate::Replacement(221)),
+        ('ﺒ', crate::Replacement(926)),
+        ('𝑵', crate::Replacement(425)),
+        ('㊞', crate::Replacement(1910)),
+        ('㌐', crate::Replacement(1597)),
+        ('⾫', crate::Replacement(2692)),
+        ('㍭', crate::Replacement(248)),
+        ('⾯', crate::Replacement(2705)),
+        ('ㅕ', crate::Replacement(1362)),
+        ('𞺸', crate::Replacement(999)),
+        ('㊟', crate::Replacement(2254)),
+        ('ﰰ', crate::Replacement(1094)),
+        ('ﱋ', crate::Replacement(1157)),
+        ('𝒆', crate::Replacement(498)),
+        ('ￜ', crate::Replacement(1376)),
+        ('ﯧ', crate::Replacement(1249)),
+        ('ᵀ', crate::Replacement(446)),
+        ('ﵚ', crate::Replacement(989)),
+        ('ϕ', crate::Replacement(816)),
+        ('𝘠', crate::Replacement(465)),
+        ('𝞋', crate::Replacement(781)),
+        ('ﵨ', crate::Replacement(1025)),
+        ('ﰟ', crate::Replacement(1014)),
+        ('𝒾', crate::Replacement(514)),
+        ('ⱼ', crate::Replacement(521)),
+        ('ﴴ', crate::Replacement(1005)),
+        ('㉒', crate::Replacement(249)),
+        ('𝘱', crate::Replacement(571)),
+        ('⑧', crate::Replacement(340)),
+        ('Ⅳ', crate::Replacement(402)),
+        ('𝐖', crate::Replacement(457)),
+        ('ﴒ', crate::Replacement(1067)),
+        ('ﵥ', crate::Replacement(1037)),
+        ('𝒲', crate::Replacement(457)),
+        ('ⅼ', crate::Replacement(537)),
+        ('ﻈ', crate::Replacement(1068)),
+        ('𞸈', crate::Replacement(1060)),
+        ('ﳅ', crate::Replacement(1110)),
+        ('６', crate::Replacement(326)),
+        ('𞺵', crate::Replacement(940)),
+        ('🅫', crate::Replacement(418)),
+        ('𝘛', crate::Replacement(446)),
+        ('𝐴', crate::Replacement(364)),
+        ('𝖃', crate::Replacement(462)),
+        ('⒳', crate::Replacement(83)),
+        ('ﴵ', crate::Replacement(1008)),
+        ('𝞷', crate::Replacement(799)),
+        ('㌸', crate::Replacement(1663)),
+        ('𝟙', crate::Replacement(168)),
+        ('，', crate::Replacement(156)),
+        ('㍇', crate::Replacement(1680)),
+        ('⾈', crate::Replacement(2507)),
+        ('⑼', crate::Replacement(33)),
+        ('🈵', crate::Replacement(2273)),
+        ('㋪', crate::Replacement(1650)),
+        ('𞺒', crate::Replacement(1098)),
+        ('ﭝ', crate::Replacement(1218)),
+        ('ﴮ', crate::Replacement(1024)),
+        ('ﶥ', crate::Replacement(981)),
+        ('𝔛', crate::Replacement(462)),
+        ('ﶹ', crate::Replacement(1151)),
+        ('𝞬', crate::Replacement(773)),
+        ('ᵧ', crate::Replacement(773)),
+        ('𝚎', crate::Replacement(498)),
+        ('ﵯ', crate::Replacement(1055)),
+        ('𝛜', crate::Replacement(775)),
+        ('𝜞', crate::Replacement(725)),
+        ('㎧', crate::Replacement(559)),
+        ('ᶟ', crate::Replacement(645)),
+        ('𝕘', crate::Replacement(508)),
+        ('ⓐ', crate::Replacement(474)),
+        ('ᵅ', crate::Replacement(635)),
+        ('㈓', crate::Replacement(97)),
+        ('㌆', crate::Replacement(1581)),
+        ('㊋', crate::Replacement(2292)),
+        ('𝛖', crate::Replacement(807)),
+        ('⾂', crate::Replacement(2496)),
+        ('ℊ', crate::Replacement(508)),
+        ('𝒊', crate::Replacement(514)),
+        ('𝜀', crate::Replacement(775)),
+        ('ℎ', crate::Replacement(510)),
+        ('ﴹ', crate::Replacement(1027)),
+        ('ﺭ', crate::Replacement(1000)),
+        ('⽘', crate::Replacement(2314)),
+        ('ᶧ', crate::Replacement(1412)),
+        ('𝙧', crate::Replacement(580)),
+        ('𝝱', crate::Replacement(772)),
+        ('𞀲', crate::Replacement(841)),
+        ('𝖀', crate::Replacement(451)),
+        ('🈮', crate::Replacement(1927)),
+        ('𝘐', crate::Replacement(397)),
+        ('￤', crate::Replacement(610)),
+        ('𞺧', crate::Replacement(984)),
+        ('𝟪', crate::Replacement(340)),
+        ('𝜢', crate::Replacement(729)),
+        ('⒭', crate::Replacement(77)),
+        ('⒕', crate::Replacement(201)),
+        ('𝚒', crate::Replacement(514)),
+        ('𞁍', crate::Replacement(870)),
+        ('ﾒ', crate::Replacement(1686)),
+        ('㌚', crate::Replacement(1608)),
+        ('𝛹', crate::Replacement(761)),
+        ('𞺈', crate::Replacement(1060)),
+        ('ﭫ', crate::Replacement(1230)),
+        ('⿒', crate::Replacement(2776)),
+        ('ﯢ', crate::Replacement(1246)),
+        ('𝔊', crate::Replacement(386)),
+        ('㊽', crate::Replacement(309)),
+        ('ﳣ', crate::Replacement(954)),
+        ('𝜔', crate::Replacement(819)),
+        ('㊨', crate::Replacement(1927)),
+        ('ￕ', crate::Replacement(1371)),
+        ('ﵻ', crate::Replacement(1084)),
+        ('⁷', crate::Replacement(332)),
+        ('𝘢', crate::Replacement(474)),
+        ('ﮎ', crate::Replacement(1232)),
+        ('Ⓩ', crate::Replacement(467)),
+        ('ﷁ', crate::Replacement(1095)),
+        ('𝕿', crate::Replacement(446)),
+        ('⽖', crate::Replacement(2310)),
+        ('𞁩', crate::Replacement(868)),
+        ('ﮊ', crate::Replacement(1228)),
+        ('㉽', crate::Replacement(1317)),
+        ('ﵓ', crate::Replacement(947)),
+        ('⿔', crate::Replacement(2779)),
+        ('ﾹ', crate::Replacement(1318)),
+        ('𐞜', crate::Replacement(2795)),
+        ('ᶛ', crate::Replacement(636)),
+        ('𞸲', crate::Replacement(1098)),
+        ('ℑ', crate::Replacement(397)),
+        ('𝛓', crate::Replacement(804)),
+        ('𞁚', crate::Replacement(848)),
+        ('𞸫', crate::Replacement(1118)),
+        ('ﶴ', crate::Replacement(1102)),
+        ('𝗕', crate::Replacement(368)),
+        ('𝖁', crate::Replacement(452)),
+        ('＄', crate::Replacement(9)),
+        ('⿀', crate::Replacement(2742)),
+        ('ﺼ', crate::Replacement(1035)),
+        ('𝚿', crate::Replacement(761)),
+        ('ㄸ', crate::Replacement(1301)),
+        ('ﺿ', crate::Replacement(1049)),
+        ('ﭲ', crate::Replacement(1220)),
+        ('𝜗', crate::Replacement(781)),
+        ('︔', crate::Replacement(354)),
+        ('𝖞', crate::Replacement(600)),
+        ('𝚅', crate::Replacement(452)),
+        ('ℶ', crate::Replacement(897)),
+        ('ﱕ', crate::Replacement(1189)),
+        ('ｱ', crate::Replacement(1570)),
+        ('ﮂ', crate::Replacement(1225)),
+        ('ﱗ', crate::Replacement(1193)),
+        ('ᵃ', crate::Replacement(474)),
+        ('⾹', crate::Replacement(2729)),
+        ('ﱚ', crate::Replacement(1203)),
+        ('ㅜ', crate::Replacement(1369)),
+        ('㎶', crate::Replacement(792)),
+        ('𝐳', crate::Replacement(602)),
+        ('ⅷ', crate::Replacement(594)),
+        ('ﻟ', crate::Replacement(1118)),
+        ('︱', crate::Replacement(1471)),
+        ('𝞺', crate::Replacement(802)),
+        ('ᵁ', crate::Replacement(451)),
+        ('⁽', crate::Replacement(13)),
+        ('㊧', crate::Replacement(2051)),
+        ('𝐻', crate::Replacement(391)),
+        ('🄵', crate::Replacement(379)),
+        ('ｫ', crate::Replacement(1586)),
+        ('ￇ', crate::Replacement(1361)),
+        ('𞸻', crate::Replacement(1080)),
+        ('𝚷', crate::Replacement(745)),
+        ('…', crate::Replacement(160)),
+        ('🈡', crate::Replacement(2454)),
+        ('𝘄', crate::Replacement(595)),
+        ('⒵', crate::Replacement(85)),
+        ('ﺎ', crate::Replacement(922)),
+        ('ﯓ', crate::Replacement(1233)),
+        ('ʲ', crate::Replacement(521)),
+        ('㍔', crate::Replacement(1706)),
+        ('ㅓ', crate::Replacement(1360)),
+        ('𝐺', crate::Replacement(386)),
+        ('ﲯ', crate::Replacement(1010)),
+        ('𝕴', crate::Replacement(397)),
+        ('ₚ', crate::Replacement(571)),
+        ('𝖠', crate::Replacement(364)),
+        ('ⵯ', crate::Replacement(1537)),
+        ('𝛺', crate::Replacement(762)),
+        ('𝘺', crate::Replacement(600)),
+        ('ﾴ', crate::Replacement(1337)),
+        ('ﰑ', crate::Replacement(965)),
+        ('ﴳ', crate::Replacement(1062)),
+        ('㋋', crate::Replacement(194)),
+        ('ﮔ', crate::Replacement(1234)),
+        ('㊐', crate::Replacement(2177)),
+        ('㍨', crate::Replacement(211)),
+        ('㏿', crate::Replacement(509)),
+        ('㌱', crate::Replacement(1651)),
+        ('𝜨', crate::Replacement(742)),
+        ('ﻜ', crate::Replacement(1107)),
+        ('ﳽ', crate::Replacement(1033)),
+        ('ﳕ', crate::Replacement(1169)),
+        ('¹', crate::Replacement(168)),
+        ('𝜻', crate::Replacement(776)),
+        ('ﵝ', crate::Replacement(1006)),
+        ('ﭣ', crate::Replacement(1217)),
+        ('ﾻ', crate::Replacement(1322)),
+        ('ﺖ', crate::Replacement(940)),
+        ('ﰎ', crate::Replacement(954)),
+        ('㍡', crate::Replacement(351)),
+        ('ﾋ', crate::Replacement(1650)),
+        ('𝟓', crate::Replacement(315)),
+        ('ϰ', crate::Replacement(787)),
+        ('𝞵', crate::Replacement(789)),
+        ('𞀹', crate::Replacement(848)),
+        ('𞀳', crate::Replacement(842)),
+        ('⁶', crate::Replacement(326)),
+        ('𝒻', crate::Replacement(501)),
+        ('𝒐', crate::Replacement(569)),
+        ('𝑥', crate::Replacement(597)),
+        ('㈆', crate::Replacement(98)),
+        ('ﳚ', crate::Replacement(1189)),
+        ('𝑷', crate::Replacement(430)),
+        ('Ⓨ', crate::Replacement(465)),
+        ('ﱆ', crate::Replacement(1143)),
+        ('⾶', crate::Replacement(2721)),
+        ('⼝', crate::Replacement(1922)),
+        ('㌔', crate::Replacement(1601)),
+        ('㌋', crate::Replacement(1593)),
+        ('𝚨', crate::Replacement(720)),
+        ('𝐽', crate::Replacement(404)),
+        ('ﵐ', crate::Replacement(942)),
+        ('⁻', crate::Replacement(1492)),
+        ('𝒛', crate::Replacement(602)),
+        ('𝙷', crate::Replacement(391)),
+        ('𝙣', crate::Replacement(561)),
+        ('ﭰ', crate::Replacement(1231)),
+        ('ﱹ', crate::Replacement(969)),
+        ('𝗱', crate::Replacement(490)),
+        ('𝓨', crate::Replacement(465)),
+        ('\u{2003}', crate::Replacement(1)),
+        ('𝘶', crate::Replacement(590)),
+        ('ﲶ', crate::Replacement(1054)),
+        ('𞸒', crate::Replacement(1098)),
+        ('ㆎ', crate::Replacement(1384)),
+        ('ﲄ', crate::Replacement(1117)),
+        ('ᵥ', crate::Replacement(591)),
+        ('㈿', crate::Replacement(130)),
+        ('🈪', crate::Replacement(1789)),
+        ('㈹', crate::Replacement(123)),
+        ('ｶ', crate::Replacement(1590)),
+        ('𝝣', crate::Replacement(743)),
+        ('𞁡', crate::Replacement(859)),
+        ('𝚂', crate::Replacement(440)),
+        ('Ⓝ', crate::Replacement(425)),
+        ('ﳁ', crate::Replacement(1094)),
+        ('𝝩', crate::Replacement(749)),
+        ('㋘', crate::Replacement(1610)),
+        ('𝝭', crate::Replacement(761)),
+        ('ㅢ', crate::Replacement(1375)),
+        ('㏝', crate::Replacement(460)),
+        ('Ⓘ', crate::Replacement(397)),
+        ('⒒', crate::Replacement(187)),
+        ('𝛃', crate::Replacement(772)),
+        ('𝛠', crate::Replacement(802)),
+        ('𞁐', crate::Replacement(878)),
+        ('ﬆ', crate::Replacement(587)),
+        ('𐞵', crate::Replacement(695)),
+        ('𞹉', crate::Replacement(1187)),
+        ('𝞩', crate::Replacement(1488)),
+        ('㏋', crate::Replacement(392)),
+        ('𝖿', crate::Replacement(501)),
+        ('⑴', crate::Replacement(14)),
+        ('⼅', crate::Replacement(1807)),
+        ('𝐬', crate::Replacement(584)),
+        ('ﰣ', crate::Replacement(1051)),
+        ('ⓣ', crate::Replacement(588)),
+        ('𝖹', crate::Replacement(467)),
+        ('ﰸ', crate::Replacement(1109)),
+        ('ﳜ', crate::Replacement(1193)),
+        ('🈩', crate::Replacement(1786)),
+        ('𞺔', crate::Replacement(1021)),
+        ('𞁜', crate::Replacement(852)),
+        ('㊥', crate::Replacement(1796)),
+        ('𐞧', crate::Replacement(2798)),
+        ('🉄', crate::Replacement(1560)),
+        ('ﶔ', crate::Replacement(1180)),
+        ('ﮣ', crate::Replacement(1238)),
+        ('Ⅹ', crate::Replacement(462)),
+        ('𝐃', crate::Replacement(374)),
+        ('ﲋ', crate::Replacement(1168)),
+        ('Ｆ', crate::Replacement(379)),
+        ('ᵕ', crate::Replacement(1408)),
+        ('＋', crate::Replacement(155)),
+        ('㏣', crate::Replacement(312)),
+        ('𞺛', crate::Replacement(1080)),
+        ('𞁫', crate::Replacement(875)),
+        ('㊼', crate::Replacement(308)),
+        ('ﴍ', crate::Replacement(1028)),
+        ('𝕊', crate::Replacement(440)),
+        ('ﻚ', crate::Replacement(1107)),
+        ('𝐹', crate::Replacement(379)),
+        ('ﶖ', crate::Replacement(1164)),
+        ('𝓁', crate::Replacement(537)),
+        ('ﴏ', crate::Replacement(1040)),
+        ('ﵠ', crate::Replacement(1016)),
+        ('ㅦ', crate::Replacement(1331)),
+        ('🄼', crate::Replacement(415)),
+        ('㋇', crate::Replacement(344)),
+        ('𝝳', crate::Replacement(774)),
+        ('𝛟', crate::Replacement(816)),
+        ('Ｄ', crate::Replacement(374)),
+        ('ℨ', crate::Replacement(467)),
+        ('𝕂', crate::Replacement(406)),
+        ('𝕋', crate::Replacement(446)),
+        ('ㅻ', crate::Replacement(1345)),
+        ('⼊', crate::Replacement(1842)),
+        ('ㅤ', crate::Replacement(1355)),
+        ('🈠', crate::Replacement(1872)),
+        ('ﳫ', crate::Replacement(1112)),
+        ('⽺', crate::Replacement(2473)),
+        ('𝒅', crate::Replacement(490)),
+        ('㋂', crate::Replacement(295)),
+        ('ⓟ', crate::Replacement(571)),
+        ('ᵤ', crate::Replacement(590)),
+        ('㏫', crate::Replacement(193)),
+        ('ﳋ', crate::Replacement(1128)),
+        ('𝔴', crate::Replacement(595)),
+        ('𝓾', crate::Replacement(590)),
+        ('ﺑ', crate::Replacement(926)),
+        ('𝜾', crate::Replacement(782)),
+        ('ﰏ', crate::Replacement(962)),
+        ('㉦', crate::Replacement(1309)),
+        ('𞸗', crate::Replacement(992)),
+        ('ﳌ', crate::Replacement(1130)),
+        ('⒝', crate::Replacement(61)),
+        ('𝓘', crate::Replacement(397)),
+        ('𝖔', crate::Replacement(569)),
+        ('𝚹', crate::Replacement(733)),
+        ('㎨', crate::Replacement(560)),
+        ('𝛴', crate::Replacement(748)),
+        ('ﰇ', crate::Replacement(930)),
+        ('ﭜ', crate::Replacement(1218)),
+        ('𝓈', crate::Replacement(584)),
+        ('⿇', crate::Replacement(2759)),
+        ('㈘', crate::Replacement(110)),
+        ('\u{a0}', crate::Replacement(1)),
+        ('𞁢', crate::Replacement(860)),
+        ('ㄽ', crate::Replacement(1391)),
+        ('ﾂ', crate::Replacement(1631)),
+        ('🄞', crate::Replacement(48)),
+        ('ﶣ', crate::Replacement(959)),
+        ('ﺯ', crate::Replacement(1003)),
+        ('⼵', crate::Replacement(2074)),
+        ('㏤', crate::Replacement(323)),
+        ('𝗣', crate::Replacement(430)),
+        ('𞺹', crate::Replacement(1049)),
+        ('𝚝', crate::Replacement(588)),
+        ('𝒑', crate::Replacement(571)),
+        ('㎑', crate::Replacement(525)),
+        ('㌬', crate::Replacement(1648)),
+        ('𐞙', crate::Replacement(710)),
+        ('𝒱', crate::Replacement(452)),
+        ('Ⅶ', crate::Replacement(454)),
+        ('ﶏ', crate::Replacement(1150)),
+        ('𝘧', crate::Replacement(501)),
+        ('ﱬ', crate::Replacement(934)),
+        ('𐞠', crate::Replacement(689)),
+        ('⒱', crate::Replacement(81)),
+        ('ﱃ', crate::Replacement(1134)),
+        ('－', crate::Replacement(157)),
+        ('𝜰', crate::Replacement(750)),
+        ('𝓕', crate::Replacement(379)),
+        ('🈺', crate::Replacement(1953)),
+        ('𝓳', crate::Replacement(521)),
+        ('𝟁', crate::Replacement(818)),
+        ('㏢', crate::Replacement(294)),
+        ('𝟹', crate::Replacement(273)),
+        ('ໜ', crate::Replacement(1282)),
+        ('ﷵ', crate::Replacement(1041)),
+        ('ᵙ', crate::Replacement(1410)),
+        ('𞺷', crate::Replacement(992)),
+        ('ㅣ', crate::Replacement(1376)),
+        ('ﳤ', crate::Replacement(961)),
+        ('𝚗', crate::Replacement(561)),
+        ('㍗', crate::Replacement(1712)),
+        ('𝘻', crate::Replacement(602)),
+        ('𝓰', crate::Replacement(508)),
+        ('ﺝ', crate::Replacement(973)),
+        ('𝐘', crate::Replacement(465)),
+        ('𝖯', crate::Replacement(430)),
+        ('𝕧', crate::Replacement(591)),
+        ('𝜏', crate::Replacement(806)),
+        ('ﵸ', crate::Replacement(1076)),
+        ('⾩', crate::Replacement(2680)),
+        ('ｒ', crate::Replacement(580)),
+        ('𞁑', crate::Replacement(839)),
+        ('ℤ', crate::Replacement(467)),
+        ('㎛', crate::Replacement(796)),
+        ('①', crate::Replacement(168)),
+        ('ⓖ', crate::Replacement(508)),
+        ('🄖', crate::Replacement(40)),
+        ('ﯛ', crate::Replacement(1245)),
+        ('㈲', crate::Replacement(138)),
+        ('🄊', crate::Replacement(347)),
+        ('𝛲', crate::Replacement(746)),
+        ('𞹔', crate::Replacement(1021)),
+        ('𝘽', crate::Replacement(368)),
+        ('﹀', crate::Replacement(1541)),
+        ('ꟲ', crate::Replacement(370)),
+        ('ﭩ', crate::Replacement(1213)),
+        ('㍀', crate::Replacement(1671)),
+        ('𞁔', crate::Replacement(842)),
+        ('ﲓ', crate::Replacement(1196)),
+        ('⾷', crate::Replacement(2722)),
+        ('Ⓗ', crate::Replacement(391)),
+        ('ᵓ', crate::Replacement(638)),
+        ('㍮', crate::Replacement(251)),
+        ('ﱶ', crate::Replacement(966)),
+        ('ﲻ', crate::Replacement(1074)),
+        ('ĳ', crate::Replacement(517)),
+        ('⁹', crate::Replacement(346)),
+        ('𝕐', crate::Replacement(465)),
+        ('𝘵', crate::Replacement(588)),
+        ('𝞏', crate::Replacement(801)),
+        ('⅐', crate::Replacement(231)),
+        ('ﳀ', crate::Replacement(1092)),
+        ('ﰤ', crate::Replacement(1054)),
+        ('𝒸', crate::Replacement(481)),
+        ('ㅎ', crate::Replacement(1328)),
+        ('ﲜ', crate::Replacement(927)),
+        ('ﴇ', crate::Replacement(1058)),
+        ('𝓀', crate::Replacement(523)),
+        ('𐞬', crate::Replacement(706)),
+        ('㎓', crate::Replacement(388)),
+        ('𝗎', crate::Replacement(590)),
+        ('𝘏', crate::Replacement(391)),
+        ('𝘀', crate::Replacement(584)),
+        ('𝓣', crate::Replacement(446)),
+        ('Ｎ', crate::Replacement(425)),
+        ('𝜱', crate::Replacement(759)),
+        ('ﺶ', crate::Replacement(1021)),
+        ('ℽ', crate::Replacement(773)),
+        ('𝞶', crate::Replacement(798)),
+        ('⅙', crate::Replacement(230)),
+        ('ﺾ', crate::Replacement(1049)),
+        ('⾃', crate::Replacement(2498)),
+        ('𝜝', crate::Replacement(724)),
+        ('⼯', crate::Replacement(2050)),
+        ('ﴶ', crate::Replacement(1010)),
+        ('𝐇', crate::Replacement(391)),
+        ('𝝔', crate::Replacement(802)),
+        ('ᶫ', crate::Replacement(700)),
+        ('𝚻', crate::Replacement(749)),
+        ('𝓛', crate::Replacement(410)),
+        ('ㅯ', crate::Replacement(1401)),
+        ('︐', crate::Replacement(156)),
+        ('₨', crate::Replacement(439)),
+        ('𝙂', crate::Replacement(386)),
+        ('ﻥ', crate::Replacement(1156)),
+        ('㎯', crate::Replacement(583)),
+        ('㈎', crate::Replacement(87)),
+        ('𞸕', crate::Replacement(940)),
+        ('ﳖ', crate::Replacement(1173)),
+        ('ﭽ', crate::Replacement(1221)),
+        ('㈒', crate::Replacement(95)),
+        ('㎜', crate::Replacement(554)),
+        ('ₗ', crate::Replacement(537)),
+        ('𝟯', crate::Replacement(273)),
+        ('𞸀', crate::Replacement(922)),
+        ('𝟃', crate::Replacement(1486)),
+        ('⾾', crate::Replacement(2740)),
+        ('ﰅ', crate::Replacement(927)),
+        ('𝛉', crate::Replacement(781)),
+        ('𞺉', crate::Replacement(1187)),
+        ('㏖', crate::Replacement(557)),
+        ('𝟥', crate::Replacement(273)),
+        ('ﴁ', crate::Replacement(982)),
+        ('㌌', crate::Replacement(1594)),
+        ('ﺬ', crate::Replacement(999)),
+        ('ﻕ', crate::Replacement(1098)),
+        ('𝝘', crate::Replacement(725)),
+        ('ﮭ', crate::Replacement(1239)),
+        ('ﵑ', crate::Replacement(946)),
+        ('𝞞', crate::Replacement(744)),
+        ('\u{2005}', crate::Replacement(1)),
+        ('𝖗', crate::Replacement(580)),
+        ('ﱺ', crate::Replacement(971)),
+        ('℃', crate::Replacement(612)),
+        ('𝗟', crate::Replacement(410)),
+        ('\u{2009}', crate::Replacement(1)),
+        ('𝝍', crate::Replacement(818)),
+        ('𝜡', crate::Replacement(728)),
+        ('🈧', crate::Replacement(2132)),
+        ('ﺵ', crate::Replacement(1021)),
+        ('ﾭ', crate::Replacement(1391)),
+        ('𝛀', crate::Replacement(762)),
+        ('⁺', crate::Replacement(155)),
+        ('㉢', crate::Replacement(1299)),
+        ('⓪', crate::Replacement(162)),
+        ('⼪', crate::Replacement(2028)),
+        ('㍩', crate::Replacement(215)),
+        ('ﲼ', crate::Replacement(1081)),
+        ('､', crate::Replacement(1538)),
+        ('ﬧ', crate::Replacement(917)),
+        ('ﮜ', crate::Replacement(1235)),
+        ('㏙', crate::Replacement(432)),
+        ('ᴳ', crate::Replacement(386)),
+        ('🄄', crate::Replacement(274)),
+        ('🈟', crate::Replacement(2171)),
+        ('１', crate::Replacement(168)),
+        ('＾', crate::Replacement(471)),
+        ('ￖ', crate::Replacement(1372)),
+        ('𝟲', crate::Replacement(326)),
+        ('𞸊', crate::Replacement(1107)),
+        ('㍴', crate::Replacement(480)),
+        ('🈞', crate::Replacement(1851)),
+        ('ﰢ', crate::Replacement(1050)),
+        ('𝛧', crate::Replacement(728)),
+        ('⁵', crate::Replacement(315)),
+        ('ﲒ', crate::Replacement(1195)),
+        ('𝐰', crate::Replacement(595)),
+        ('ﳛ', crate::Replacement(1191)),
+        ('﹤', crate::Replacement(355)),
+        ('﹏', crate::Replacement(472)),
+        ('𞸘', crate::Replacement(999)),
+        ('⿃', crate::Replacement(2748)),
+        ('㉡', crate::Replacement(1297)),
+        ('⽚', crate::Replacement(2316)),
+        ('ｻ', crate::Replacement(1617)),
+        ('ㆋ', crate::Replacement(1381)),
+        ('㊬', crate::Replacement(2379)),
+        ('ﲂ', crate::Replacement(1113)),
+        ('𝓔', crate::Replacement(378)),
+        ('₊', crate::Replacement(155)),
+        ('Ǉ', crate::Replacement(411)),
+        ('ﶄ', crate::Replacement(1121)),
+        ('𝖚', crate::Replacement(590)),
+        ('𝔎', crate::Replacement(406)),
+        ('⽷', crate::Replacement(2449)),
+        ('𝕸', crate::Replacement(415)),
+        ('𝙘', crate::Replacement(481)),
+        ('𝔘', crate::Replacement(451)),
+        ('ᶱ', crate::Replacement(669)),
+        ('ŉ', crate::Replacement(714)),
+        ('ﭴ', crate::Replacement(1220)),
+        ('㌫', crate::Replacement(1647)),
+        ('𝚫', crate::Replacement(726)),
+        ('𝗷', crate::Replacement(521)),
+        ('⾭', crate::Replacement(2702)),
+        ('②', crate::Replacement(237)),
+        ('𞸁', crate::Replacement(926)),
+        ('ﮙ', crate::Replacement(1236)),
+        ('ꟹ', crate::Replacement(624)),
+        ('￥', crate::Replacement(609)),
+        ('㊴', crate::Replacement(290)),
+        ('ﭨ', crate::Replacement(1213)),
+        ('ʷ', crate::Replacement(595)),
+        ('㍥', crate::Replacement(199)),
+        ('𝘗', crate::Replacement(430)),
+        ('𝚭', crate::Replacement(728)),
+        ('Ｃ', crate::Replacement(370)),
+        ('㈃', crate::Replacement(92)),
+        ('𝕄', crate::Replacement(415)),
+        ('𞹤', crate::Replacement(1176)),
+        ('ﲰ', crate::Replacement(1014)),
+        ('ﷀ', crate::Replacement(1142)),
+        ('🈹', crate::Replacement(1878)),
+        ('℻', crate::Replacement(380)),
+        ('⾢', crate::Replacement(2648)),
+        ('ㆄ', crate::Replacement(1352)),
+        ('ﮡ', crate::Replacement(1238)),
+        ('㌭', crate::Replacement(1646)),
+        ('㋉', crate::Replacement(184)),
+        ('ﷴ', crate::Replacement(1146)),
+        ('ᴽ', crate::Replacement(632)),
+        ('𝖏', crate::Replacement(521)),
+        ('₇', crate::Replacement(332)),
+        ('⽶', crate::Replacement(2440)),
+        ('𝐋', crate::Replacement(410)),
+        ('ㅀ', crate::Replacement(1332)),
+        ('⼿', crate::Replacement(2129)),
+        ('㏸', crate::Replacement(259)),
+        ('𝚔', crate::Replacement(523)),
+        ('″', crate::Replacement(1472)),
+        ('⾎', crate::Replacement(2569)),
+        ('ﾌ', crate::Replacement(1655)),
+        ('⿁', crate::Replacement(2743)),
+        ('𝛦', crate::Replacement(727)),
+        ('𝟔', crate::Replacement(326)),
+        ('ⓙ', crate::Replacement(521)),
+        ('㏴', crate::Replacement(247)),
+        ('𝜲', crate::Replacement(760)),
+        ('㋹', crate::Replacement(1707)),
+        ('⿍', crate::Replacement(2768)),
+        ('⼦', crate::Replacement(2013)),
+        ('𞹡', crate::Replacement(926)),
+        ('ﻬ', crate::Replacement(1176)),
+        ('ﶋ', crate::Replacement(1147)),
+        ('🈗', crate::Replacement(1996)),
+        ('𞹛', crate::Replacement(1080)),
+        ('㈈', crate::Replacement(104)),
+        ('ﲠ', crate::Replacement(936)),
+        ('𝓓', crate::Replacement(374)),
+        ('ﲅ', crate::Replacement(1130)),
+        ('＆', crate::Replacement(11)),
+        ('ⓥ', crate::Replacement(591)),
+        ('𞸹', crate::Replacement(1049)),
+        ('𝟟', crate::Replacement(332)),
+        ('＞', crate::Replacement(359)),
+        ('︽', crate::Replacement(1542)),
+        ('ﻨ', crate::Replacement(1156)),
+        ('𝚩', crate::Replacement(724)),
+        ('ﰝ', crate::Replacement(1008)),
+        ('𐞨', crate::Replacement(676)),
+        ('🅀', crate::Replacement(437)),
+        ('𞁬', crate::Replacement(2782)),
+        ('ﰐ', crate::Replacement(963)),
+        ('㉾', crate::Replacement(1314)),
+        ('㈕', crate::Replacement(101)),
+        ('ﻣ', crate::Replacement(1136)),
+        ('ℍ', crate::Replacement(391)),
+        ('㏔', crate::Replacement(550)),
+        ('𞹹', crate::Replacement(1049)),
+        ('ﱪ', crate::Replacement(932)),
+        ('ﺽ', crate::Replacement(1049)),
+        ('𝝇', crate::Replacement(804)),
+        ('ꭟ', crate::Replacement(2788)),
+        ('𝙅', crate::Replacement(404)),
+        ('𞹎', crate::Replacement(1004)),
+        ('ﳼ', crate::Replacement(1020)),
+        ('ﱎ', crate::Replacement(1169)),
+        ('⽔', crate::Replacement(2246)),
+        ('𝙸', crate::Replacement(397)),
+        ('𝘨', crate::Replacement(508)),
+        ('𝐞', crate::Replacement(498)),
+        ('⩵', crate::Replacement(357)),
+        ('㉭', crate::Replacement(1328)),
+        ('ſ', crate::Replacement(584)),
+        ('㏘', crate::Replacement(572)),
+        ('︗', crate::Replacement(1563)),
+        ('ﴗ', crate::Replacement(1019)),
+        ('ﵰ', crate::Replacement(1055)),
+        ('𝐧', crate::Replacement(561)),
+        ('㈍', crate::Replacement(115)),
+        ('︓', crate::Replacement(352)),
+        ('ﲇ', crate::Replacement(1135)),
+        ('ﾉ', crate::Replacement(1643)),
+        ('ﵪ', crate::Replacement(1030)),
+        ('𝛶', crate::Replacement(750)),
+        ('𐞪', crate::Replacement(678)),
+        ('ﺴ', crate::Replacement(1004)),
+        ('ﻏ', crate::Replacement(1080)),
+        ('𝚪', crate::Replacement(725)),
+        ('𝓩', crate::Replacement(467)),
+        ('𝒌', crate::Replacement(523)),
+        ('𐞘', crate::Replacement(682)),
+        ('⽰', crate::Replacement(2401)),
+        ('𝟱', crate::Replacement(315)),
+        ('⾛', crate::Replacement(2621)),
+        ('￡', crate::Replacement(608)),
+        ('㋡', crate::Replacement(1631)),
+        ('㊦', crate::Replacement(1791)),
+        ('𝙤', crate::Replacement(569)),
+        ('🈘', crate::Replacement(1812)),
+        ('ᶴ', crate::Replacement(681)),
+        ('𝕭', crate::Replacement(368)),
+        ('ﯣ', crate::Replacement(1246)),
+        ('㆛', crate::Replacement(1793)),
+        ('ﭸ', crate::Replacement(1219)),
+        ('ﭠ', crate::Replacement(1214)),
+        ('㉴', crate::Replacement(1310)),
+        ('ⱽ', crate::Replacement(452)),
+        ('ﶨ', crate::Replacement(1011)),
+        ('ℳ', crate::Replacement(415)),
+        ('㆗', crate::Replacement(1796)),
+        ('ﾩ', crate::Replacement(1302)),
+        ('ｺ', crate::Replacement(1612)),
+        ('ﴖ', crate::Replacement(1087)),
+        ('𝕚', crate::Replacement(514)),
+        ('㋧', crate::Replacement(1642)),
+        ('𝚛', crate::Replacement(580)),
+        ('ﾼ', crate::Replacement(1324)),
+        ('₉', crate::Replacement(346)),
+        ('⑩', crate::Replacement(181)),
+        ('𞸭', crate::Replacement(1156)),
+        ('㎡', crate::Replacement(545)),
+        ('㎗', crate::Replacement(493)),
+        ('⒬', crate::Replacement(76)),
+        ('𝗶', crate::Replacement(514)),
+        ('𐞦', crate::Replacement(674)),
+        ('ᶺ', crate::Replacement(687)),
+        ('ﴕ', crate::Replacement(1086)),
+        ('𞁗', crate::Replacement(845)),
+        ('𞁠', crate::Replacement(858)),
+        ('ﰼ', crate::Replacement(1113)),
+        ('⽒', crate::Replacement(2244)),
+        ('𝑛', crate::Replacement(561)),
+        ('ﲳ', crate::Replacement(1045)),
+        ('⼃', crate::Replacement(1802)),
+        ('㋫', crate::Replacement(1655)),
+        ('𝛐', crate::Replacement(800)),
+        ('／', crate::Replacement(161)),
+        ('ᶲ', crate::Replacement(672)),
+        ('𞸝', crate::Replacement(1237)),
+        ('𝒪', crate::Replacement(429)),
+        ('ﺹ', crate::Replacement(1035)),
+        ('ㆁ', crate::Replacement(1351)),
+        ('ᶹ', crate::Replacement(686)),
+        ('𝝨', crate::Replacement(748)),
+        ('ㄻ', crate::Replacement(1389)),
+        ('ﶞ', crate::Replacement(931)),
+        ('𞹮', crate::Replacement(1004)),
+        ('㌎', crate::Replacement(1591)),
+        ('𝖊', crate::Replacement(498)),
+        ('․', crate::Replacement(158)),
+        ('ﴚ', crate::Replacement(1034)),
+        ('𝔩', crate::Replacement(537)),
+        ('㎵', crate::Replacement(564)),
+        ('𝛌', crate::Replacement(788)),
+        ('㎖', crate::Replacement(553)),
+        ('𝗜', crate::Replacement(397)),
+        ('ﻞ', crate::Replacement(1118)),
+        ('𝝗', crate::Replacement(724)),
+        ('𞺐', crate::Replacement(1089)),
+        ('㎈', crate::Replacement(484)),
+        ('‼', crate::Replacement(5)),
+        ('Ⅺ', crate::Replacement(463)),
+        ('Ｇ', crate::Replacement(386)),
+        ('ﴻ', crate::Replacement(1069)),
+        ('㋛', crate::Replacement(1620)),
+        ('⑽', crate::Replacement(15)),
+        ('𝖎', crate::Replacement(514)),
+        ('🄐', crate::Replacement(34)),
+        ('℅', crate::Replacement(482)),
+        ('ﭬ', crate::Replacement(1230)),
+        ('ﲫ', crate::Replacement(993)),
+        ('𝘤', crate::Replacement(481)),
+        ('ᴵ', crate::Replacement(397)),
+        ('⼕', crate::Replacement(1897)),
+        ('𝞕', crate::Replacement(728)),
+        ('￦', crate::Replacement(1477)),
+        ('𝓗', crate::Replacement(391)),
+        ('ﵱ', crate::Replacement(1063)),
+        ('㍾', crate::Replacement(2178)),
+        ('⒗', crate::Replacement(209)),
+        ('𝙡', crate::Replacement(537)),
+        ('𝞰', crate::Replacement(777)),
+        ('ℕ', crate::Replacement(425)),
+        ('𝙄', crate::Replacement(397)),
+        ('𝝦', crate::Replacement(746)),
+        ('㏞', crate::Replacement(456)),
+        ('ﾁ', crate::Replacement(1629)),
+        ('ㅂ', crate::Replacement(1306)),
+        ('🈖', crate::Replacement(2588)),
+        ('㊑', crate::Replacement(2210)),
+        ('𝗐', crate::Replacement(595)),
+        ('𝛕', crate::Replacement(806)),
+        ('ﰌ', crate::Replacement(945)),
+        ('🅈', crate::Replacement(465)),
+        ('𞁤', crate::Replacement(862)),
+        ('𝑦', crate::Replacement(600)),
+        ('ﱭ', crate::Replacement(935)),
+        ('㏥', crate::Replacement(329)),
+        ('𝙺', crate::Replacement(406)),
+        ('ⓘ', crate::Replacement(514)),
+        ('⑮', crate::Replacement(204)),
+        ('𝔔', crate::Replacement(437)),
+        ('𝝏', crate::Replacement(1486)),
+        ('ﱷ', crate::Replacement(967)),
+        ('⾣', crate::Replacement(2656)),
+        ('𝟾', crate::Replacement(340)),
+        ('ﺙ', crate::Replacement(964)),
+        ('⼴', crate::Replacement(2063)),
+        ('⒀', crate::Replacement(18)),
+        ('🯸', crate::Replacement(340)),
+        ('🄨', crate::Replacement(58)),
+        ('ʶ', crate::Replacement(679)),
+        ('ﻩ', crate::Replacement(1176)),
+        ('𐞢', crate::Replacement(619)),
+        ('𝞫', crate::Replacement(772)),
+        ('𝔼', crate::Replacement(378)),
+        ('ﰒ', crate::Replacement(968)),
+        ('⒍', crate::Replacement(328)),
+        ('ﮄ', crate::Replacement(1224)),
+        ('㌨', crate::Replacement(1639)),
+        ('𝜵', crate::Replacement(1488)),
+        ('⼷', crate::Replacement(2077)),
+        ('𝛘', crate::Replacement(817)),
+        ('𝕜', crate::Replacement(523)),
+        ('𝞸', crate::Replacement(800)),
+        ('ᵔ', crate::Replacement(1407)),
+        ('𝗲', crate::Replacement(498)),
+        ('𝘇', crate::Replacement(602)),
+        ('㏛', crate::Replacement(585)),
+        ('𝔄', crate::Replacement(364)),
+        ('ﴫ', crate::Replacement(1040)),
+        ('㍆', crate::Replacement(1679)),
+        ('𝚳', crate::Replacement(741)),
+        ('⅖', crate::Replacement(269)),
+        ('‥', crate::Replacement(159)),
+        ('𞸧', crate::Replacement(984)),
+        ('ｩ', crate::Replacement(1579)),
+        ('𝕯', crate::Replacement(374)),
+        ('ᶨ', crate::Replacement(699)),
+        ('𝗒', crate::Replacement(600)),
+        ('ﾈ', crate::Replacement(1642)),
+        ('⽼', crate::Replacement(2478)),
+        ('﹢', crate::Replacement(155)),
+        ('ﰙ', crate::Replacement(993)),
+        ('ﮮ', crate::Replacement(1250)),
+        ('ᶡ', crate::Replacement(647)),
+        ('🯷', crate::Replacement(332)),
+        ('ﵮ', crate::Replacement(1052)),
+        ('㉂', crate::Replacement(148)),
+        ('㊃', crate::Replacement(1962)),
+        ('ﾰ', crate::Replacement(1332)),
+        ('ﵖ', crate::Replacement(956)),
+        ('🄚', crate::Replacement(44)),
+        ('㎩', crate::Replacement(436)),
+        ('𝔷', crate::Replacement(602)),
+        ('ﵶ', crate::Replacement(1075)),
+        ('𝖨', crate::Replacement(397)),
+        ('⼐', crate::Replacement(1867)),
+        ('𝟈', crate::Replacement(802)),
+        ('㈠', crate::Replacement(117)),
+        ('ﳉ', crate::Replacement(1120)),
+        ('ﵟ', crate::Replacement(1016)),
+        ('𝔭', crate::Replacement(571)),
+        ('ㅴ', crate::Replacement(1338)),
+        ('🈂', crate::Replacement(1617)),
+        ('𝐔', crate::Replacement(451)),
+        ('ˤ', crate::Replacement(694)),
+        ('𝖸', crate::Replacement(465)),
+        ('𝐸', crate::Replacement(378)),
+        ('𞸯', crate::Replacement(1070)),
+        ('𝞨', crate::Replacement(762)),
+        ('㏦', crate::Replacement(337)),
+        ('㎣', crate::Replacement(556)),
+        ('㊜', crate::Replacement(2644)),
+        ('⾱', crate::Replacement(2707)),
+        ('ｴ', crate::Replacement(1583)),
+        ('ㅠ', crate::Replacement(1373)),
+        ('㈝', crate::Replacement(102)),
+        ('ⅳ', crate::Replacement(519)),
+        ('ℋ', crate::Replacement(391)),
+        ('\u{2002}', crate::Replacement(1)),
+        ('⼚', crate::Replacement(1915)),
+        ('ﶆ', crate::Replacement(1129)),
+        ('ﱍ', crate::Replacement(1166)),
+        ('㏉', crate::Replacement(390)),
+        ('𝓐', crate::Replacement(364)),
+        ('〸', crate::Replacement(1901)),
+        ('𝘟', crate::Replacement(462)),
+        ('𝗮', crate::Replacement(474)),
+        ('ﮗ', crate::Replacement(1236)),
+        ('🅂', crate::Replacement(440)),
+        ('ⓔ', crate::Replacement(498)),
+        ('ﻱ', crate::Replacement(1187)),
+        ('⾔', crate::Replacement(2589)),
+        ('｣', crate::Replacement(1545)),
+        ('𝜽', crate::Replacement(781)),
+        ('𐞅', crate::Replacement(637)),
+        ('𝔫', crate::Replacement(561)),
+        ('𝞤', crate::Replacement(750)),
+        ('𞸑', crate::Replacement(1035)),
+        ('𝕛', crate::Replacement(521)),
+        ('𝙀', crate::Replacement(378)),
+        ('ﾦ', crate::Replacement(1387)),
+        ('⑳', crate::Replacement(242)),
+        ('｀', crate::Replacement(473)),
+        ('ㅼ', crate::Replacement(1346)),
+        ('㉚', crate::Replacement(279)),
+        ('𝘮', crate::Replacement(544)),
+        ('㋐', crate::Replacement(1570)),
+        ('ﲦ', crate::Replacement(968)),
+        ('Ⓤ', crate::Replacement(451)),
+        ('㎄', crate::Replacement(524)),
+        ('𞸏', crate::Replacement(1070)),
+        ('⑹', crate::Replacement(30)),
+        ('𝛄', crate::Replacement(773)),
+        ('⒢', crate::Replacement(66)),
+        ('𝔖', crate::Replacement(440)),
+        ('𞹨', crate::Replacement(1060)),
+        ('ⓒ', crate::Replacement(481)),
+        ('ﺟ', crate::Replacement(973)),
+        ('𝞀', crate::Replacement(802)),
+        ('㎝', crate::Replacement(487)),
+        ('ㅟ', crate::Replacement(1372)),
+        ('㈱', crate::Replacement(140)),
+        ('〹', crate::Replacement(1902)),
+        ('ﶼ', crate::Replacement(1122)),
+        ('𞺀', crate::Replacement(922)),
+        ('𝖬', crate::Replacement(415)),
+        ('ﴐ', crate::Replacement(1056)),
+        ('𝙪', crate::Replacement(590)),
+        ('𐞏', crate::Replacement(646)),
+        ('ﱾ', crate::Replacement(1105)),
+        ('ʵ', crate::Replacement(675)),
+        ('ﭚ', crate::Replacement(1218)),
+        ('㊕', crate::Replacement(2321)),
+        ('⾙', crate::Replacement(2609)),
+        ('𝒳', crate::Replacement(462)),
+        ('𝐌', crate::Replacement(415)),
+        ('⿏', crate::Replacement(2772)),
+        ('㍶', crate::Replacement(577)),
+        ('ﴢ', crate::Replacement(1048)),
+        ('𝓺', crate::Replacement(579)),
+        ('𝗌', crate::Replacement(584)),
+        ('ｵ', crate::Replacement(1587)),
+        ('｝', crate::Replacement(605)),
+        ('𝘹', crate::Replacement(597)),
+        ('Ｌ', crate::Replacement(410)),
+        ('ﰚ', crate::Replacement(994)),
+        ('ﰦ', crate::Replacement(1061)),
+        ('﹠', crate::Replacement(11)),
+        ('ｯ', crate::Replacement(1630)),
+        ('𞸡', crate::Replacement(926)),
+        ('𝝫', crate::Replacement(759)),
+        ('ﻘ', crate::Replacement(1098)),
+        ('㆘', crate::Replacement(1791)),
+        ('𝕝', crate::Replacement(537)),
+        ('⾏', crate::Replacement(2570)),
+        ('ﾛ', crate::Replacement(1710)),
+        ('𝖪', crate::Replacement(406)),
+        ('㉃', crate::Replacement(149)),
+        ('ꭝ', crate::Replacement(2787)),
+        ('⾲', crate::Replacement(2710)),
+        ('𝛩', crate::Replacement(733)),
+        ('🅃', crate::Replacement(446)),
+        ('ﲸ', crate::Replacement(1061)),
+        ('ﲉ', crate::Replacement(1152)),
+        ('㍲', crate::Replacement(492)),
+        ('ﻃ', crate::Replacement(1060)),
+        ('𝜃', crate::Replacement(781)),
+        ('𝗿', crate::Replacement(580)),
+        ('ǳ', crate::Replacement(497)),
+        ('𞺙', crate::Replacement(1049)),
+        ('㋱', crate::Replacement(1686)),
+        ('Ⓕ', crate::Replacement(379)),
+        ('︾', crate::Replacement(1543)),
+        ('ﴦ', crate::Replacement(1024)),
+        ('𐞒', crate::Replacement(650)),
+        ('㍌', crate::Replacement(1688)),
+        ('･', crate::Replacement(1717)),
+        ('🉐', crate::Replacement(2089)),
+        ('𞹢', crate::Replacement(973)),
+        ('𝚌', crate::Replacement(481)),
+        ('⽊', crate::Replacement(2198)),
+        ('𞹲', crate::Replacement(1098)),
+        ('㋽', crate::Replacement(1714)),
+        ('⼋', crate::Replacement(1846)),
+        ('𝖻', crate::Replacement(479)),
+        ('𝓴', crate::Replacement(523)),
+        ('𝒗', crate::Replacement(591)),
+        ('𝕾', crate::Replacement(440)),
+        ('⒇', crate::Replacement(26)),
+        ('ﮏ', crate::Replacement(1232)),
+        ('𝛱', crate::Replacement(745)),
+        ('⼘', crate::Replacement(1908)),
+        ('㌩', crate::Replacement(1644)),
+        ('ﶳ', crate::Replacement(1165)),
+        ('𝜬', crate::Replacement(746)),
+        ('ﷸ', crate::Replacement(1184)),
+        ('ㅐ', crate::Replacement(1357)),
+        ('𐞓', crate::Replacement(648)),
+        ('ㅨ', crate::Replacement(1395)),
+        ('ℭ', crate::Replacement(370)),
+        ('ﴠ', crate::Replacement(997)),
+        ('㎭', crate::Replacement(581)),
+        ('\u{200a}', crate::Replacement(1)),
+        ('𝝎', crate::Replacement(819)),
+        ('𝔰', crate::Replacement(584)),
+        ('🯳', crate::Replacement(273)),
+        ('𝟋', crate::Replacement(825)),
+        ('𝛽', crate::Replacement(772)),
+        ('ﯦ', crate::Replacement(1249)),
+        ('𝚊', crate::Replacement(474)),
+        ('㊍', crate::Replacement(2198)),
+        ('𞁅', crate::Replacement(861)),
+        ('ℛ', crate::Replacement(438)),
+        ('㍏', crate::Replacement(1694)),
+        ('⼸', crate::Replacement(2078)),
+        ('𝑢', crate::Replacement(590)),
+        ('𝘉', crate::Replacement(368)),
+        ('㌾', crate::Replacement(1669)),
+        ('⾮', crate::Replacement(2704)),
+        ('ﴊ', crate::Replacement(1024)),
+        ('ᵆ', crate::Replacement(1406)),
+        ('𝖆', crate::Replacement(474)),
+        ('𝝷', crate::Replacement(781)),
+        ('㉤', crate::Replacement(1304)),
+        ('８', crate::Replacement(340)),
+        ('𝛑', crate::Replacement(801)),
+        ('ﶓ', crate::Replacement(1179)),
+        ('𐞟', crate::Replacement(2796)),
+        ('𝞉', crate::Replacement(1486)),
+        ('⾊', crate::Replacement(2510)),
+        ('🯰', crate::Replacement(162)),
+        ('⽱', crate::Replacement(2417)),
+        ('⒊', crate::Replacement(275)),
+        ('𝑲', crate::Replacement(406)),
+        ('𝛂', crate::Replacement(768)),
+        ('𝖅', crate::Replacement(467)),
+        ('𝞾', crate::Replacement(807)),
+        ('ㅸ', crate::Replacement(1342)),
+        ('㌁', crate::Replacement(1572)),
+        ('ﭭ', crate::Replacement(1230)),
+        ('𝟊', crate::Replacement(824)),
+        ('⽠', crate::Replacement(2349)),
+        ('ⓩ', crate::Replacement(602)),
+        ('⿊', crate::Replacement(2763)),
+        ('Ⓛ', crate::Replacement(410)),
+        ('㉧', crate::Replacement(1312)),
+        ('ᶠ', crate::Replacement(501)),
+        ('𝚾', crate::Replacement(760)),
+        ('🄷', crate::Replacement(391)),
+        ('𞺦', crate::Replacement(1003)),
+        ('⾥', crate::Replacement(2662)),
+        ('𝗞', crate::Replacement(406)),
+        ('𝙉', crate::Replacement(425)),
+        ('𝞪', crate::Replacement(768)),
+        ('ﴀ', crate::Replacement(991)),
+        ('ﰋ', crate::Replacement(941)),
+        ('𝑡', crate::Replacement(588)),
+        ('𝐷', crate::Replacement(374)),
+        ('₄', crate::Replacement(297)),
+        ('㈙', crate::Replacement(112)),
+        ('ﳈ', crate::Replacement(1113)),
+        ('𝝅', crate::Replacement(801)),
+        ('㋩', crate::Replacement(1645)),
+        ('㊊', crate::Replacement(2193)),
+        ('𞺓', crate::Replacement(1000)),
+        ('⾄', crate::Replacement(2500)),
+        ('ｘ', crate::Replacement(597)),
+        ('𝞚', crate::Replacement(740)),
+        ('﹞', crate::Replacement(1562)),
+        ('𝓹', crate::Replacement(571)),
+        ('ﳇ', crate::Replacement(1112)),
+        ('㌂', crate::Replacement(1573)),
+        ('㌵', crate::Replacement(1659)),
+        ('㏰', crate::Replacement(214)),
+        ('㍯', crate::Replacement(254)),
+        ('２', crate::Replacement(237)),
+        ('𝙱', crate::Replacement(368)),
+        ('㈌', crate::Replacement(113)),
+        ('ￏ', crate::Replacement(1367)),
+        ('ﻅ', crate::Replacement(1068)),
+        ('Ⅼ', crate::Replacement(410)),
+        ('⽢', crate::Replacement(2352)),
+        ('⒅', crate::Replacement(23)),
+        ('𝞴', crate::Replacement(788)),
+        ('ﾜ', crate::Replacement(1711)),
+        ('𝑳', crate::Replacement(410)),
+        ('ʱ', crate::Replacement(654)),
+        ('ｇ', crate::Replacement(508)),
+        ('ﮑ', crate::Replacement(1232)),
+        ('⽻', crate::Replacement(2476)),
+        ('ﻙ', crate::Replacement(1107)),
+        ('🉁', crate::Replacement(1553)),
+        ('ﭼ', crate::Replacement(1221)),
+        ('🄀', crate::Replacement(164)),
+        ('ᴶ', crate::Replacement(404)),
+        ('⼼', crate::Replacement(2093)),
+        ('⒥', crate::Replacement(69)),
+        ('ǈ', crate::Replacement(413)),
+        ('𝟎', crate::Replacement(162)),
+        ('ᵠ', crate::Replacement(816)),
+        ('𝙦', crate::Replacement(579)),
+        ('㌕', crate::Replacement(1602)),
+        ('㋰', crate::Replacement(1685)),
+        ('ﾆ', crate::Replacement(1640)),
+        ('ﵽ', crate::Replacement(1093)),
+        ('𝜳', crate::Replacement(761)),
+        ('₂', crate::Replacement(237)),
+        ('⽭', crate::Replacement(2390)),
+        ('ﻔ', crate::Replacement(1089)),
+        ('🈝', crate::Replacement(2088)),
+        ('🄾', crate::Replacement(429)),
+        ('ﬓ', crate::Replacement(893)),
+        ('⁇', crate::Replacement(362)),
+        ('ﱿ', crate::Replacement(1106)),
+        ('㎪', crate::Replacement(526)),
+        ('𝞟', crate::Replacement(745)),
+        ('ﵭ', crate::Replacement(1031)),
+        ('𝗰', crate::Replacement(481)),
+        ('℉', crate::Replacement(613)),
+        ('ￛ', crate::Replacement(1375)),
+        ('𝓥', crate::Replacement(452)),
+        ('ﴈ', crate::Replacement(1059)),
+        ('𝜺', crate::Replacement(775)),
+        ('㍂', crate::Replacement(1674)),
+        ('㌇', crate::Replacement(1584)),
+        ('㏳', crate::Replacement(244)),
+        ('𝖛', crate::Replacement(591)),
+        ('ﰴ', crate::Replacement(1101)),
+        ('㋯', crate::Replacement(1681)),
+        ('￭', crate::Replacement(1531)),
+        ('𝜥', crate::Replacement(739)),
+        ('㋙', crate::Replacement(1612)),
+        ('𝟠', crate::Replacement(340)),
+        ('￠', crate::Replacement(607)),
+        ('ﮬ', crate::Replacement(1239)),
+        ('ﲷ', crate::Replacement(1057)),
+        ('ﶊ', crate::Replacement(1145)),
+        ('ⅵ', crate::Replacement(592)),
+        ('ﻻ', crate::Replacement(1119)),
+        ('ﵩ', crate::Replacement(1023)),
+        ('𝓼', crate::Replacement(584)),
+        ('㋚', crate::Replacement(1617)),
+        ('㈪', crate::Replacement(137)),
+        ('𝕵', crate::Replacement(404)),
+        ('ℴ', crate::Replacement(569)),
+        ('㌊', crate::Replacement(1589)),
+        ('㍳', crate::Replacement(365)),
+        ('ﲞ', crate::Replacement(930)),
+        ('㈶', crate::Replacement(150)),
+        ('﹃', crate::Replacement(1546)),
+        ('𝓮', crate::Replacement(498)),
+        ('𝛨', crate::Replacement(729)),
+        ('🄛', crate::Replacement(45)),
+        ('ﻧ', crate::Replacement(1156)),
+        ('𝖑', crate::Replacement(537)),
+        ('𝐈', crate::Replacement(397)),
+        ('ﳻ', crate::Replacement(1019)),
+        ('𞹂', crate::Replacement(973)),
+        ('⿓', crate::Replacement(2777)),
+        ('﹑', crate::Replacement(1538)),
+        ('𐞑', crate::Replacement(652)),
+        ('𝑹', crate::Replacement(438)),
+        ('㌟', crate::Replacement(1618)),
+        ('𝑨', crate::Replacement(364)),
+        ('𝜉', crate::Replacement(799)),
+        ('ﰪ', crate::Replacement(1074)),
+        ('㎲', crate::Replacement(797)),
+        ('ￅ', crate::Replacement(1359)),
+        ('㏐', crate::Replacement(539)),
+        ('𝔗', crate::Replacement(446)),
+        ('𝑏', crate::Replacement(479)),
+        ('ﾚ', crate::Replacement(1707)),
+        ('𝕷', crate::Replacement(410)),
+        ('㌺', crate::Replacement(1664)),
+        ('𝑄', crate::Replacement(437)),
+        ('🯹', crate::Replacement(346)),
+        ('⽝', crate::Replacement(2324)),
+        ('𝔮', crate::Replacement(579)),
+        ('ﾣ', crate::Replacement(1385)),
+        ('ﾽ', crate::Replacement(1326)),
+        ('ﴟ', crate::Replacement(996)),
+        ('ϑ', crate::Replacement(781)),
+        ('𝔣', crate::Replacement(501)),
+        ('ﭗ', crate::Replacement(1216)),
+        ('㎦', crate::Replacement(534)),
+        ('Ⓑ', crate::Replacement(368)),
+        ('ﶚ', crate::Replacement(1171)),
+        ('ⅅ', crate::Replacement(374)),
+        ('⾡', crate::Replacement(2639)),
+        ('𝒟', crate::Replacement(374)),
+        ('ﲥ', crate::Replacement(961)),
+        ('ﵹ', crate::Replacement(1083)),
+        ('𝞒', crate::Replacement(725)),
+        ('ﱓ', crate::Replacement(1181)),
+        ('ᶽ', crate::Replacement(692)),
+        ('ﯗ', crate::Replacement(1243)),
+        ('𞁈', crate::Replacement(866)),
+        ('ﶸ', crate::Replacement(1158)),
+        ('㈺', crate::Replacement(132)),
+        ('ﶭ', crate::Replacement(1132)),
+        ('ﶶ', crate::Replacement(1077)),
+        ('𝛏', crate::Replacement(799)),
+        ('ᵞ', crate::Replacement(773)),
+        ('ⅶ', crate::Replacement(593)),
+        ('ｅ', crate::Replacement(498)),
+        ('Ⓜ', crate::Replacement(415)),
+        ('㈏', crate::Replacement(89)),
+        ('𝐵', crate::Replacement(368)),
+        ('𝛢', crate::Replacement(720)),
+        ('𝞠', crate::Replacement(746)),
+        ('㈇', crate::Replacement(100)),
+        ('𐞗', crate::Replacement(655)),
+        ('𐞁', crate::Replacement(715)),
+        ('ﺏ', crate::Replacement(926)),
+        ('ꟴ', crate::Replacement(437)),
+        ('ⓗ', crate::Replacement(510)),
+        ('㏮', crate::Replacement(206)),
+        ('🉆', crate::Replacement(1561)),
+        ('ㅅ', crate::Replacement(1309)),
+        ('𝖴', crate::Replacement(451)),
+        ('㌪', crate::Replacement(1649)),
+        ('⾿', crate::Replacement(2741)),
+        ('㊱', crate::Replacement(287)),
+        ('𝝤', crate::Replacement(744)),
+        ('ﶠ', crate::Replacement(943)),
+        ('ﷱ', crate::Replacement(1100)),
+        ('ﵧ', crate::Replacement(1025)),
+        ('𝘃', crate::Replacement(591)),
+        ('𝔃', crate::Replacement(602)),
+        ('𝒚', crate::Replacement(600)),
+        ('ˣ', crate::Replacement(597)),
+        ('™', crate::Replacement(449)),
+        ('𝜣', crate::Replacement(733)),
+        ('ﳎ', crate::Replacement(1138)),
+        ('Ⓣ', crate::Replacement(446)),
+        ('𝑝', crate::Replacement(571)),
+        ('𝜩', crate::Replacement(743)),
+        ('𝑎', crate::Replacement(474)),
+        ('🈭', crate::Replacement(1796)),
+        ('🄻', crate::Replacement(410)),
+        ('ᶯ', crate::Replacement(667)),
+        ('Ｘ', crate::Replacement(462)),
+        ('𞸍', crate::Replacement(1156)),
+        ('ꟳ', crate::Replacement(379)),
+        ('🈕', crate::Replacement(1991)),
+        ('𝓿', crate::Replacement(591)),
+        ('ﶗ', crate::Replacement(1159)),
+        ('𝟴', crate::Replacement(340)),
+        ('ㄷ', crate::Replacement(1299)),
+        ('ㄿ', crate::Replacement(1393)),
+        ('𞁒', crate::Replacement(840)),
+        ('ﱂ', crate::Replacement(1130)),
+        ('𝝙', crate::Replacement(726)),
+        ('㏃', crate::Replacement(369)),
+        ('ᶩ', crate::Replacement(661)),
+        ('㎰', crate::Replacement(578)),
+        ('𝒋', crate::Replacement(521)),
+        ('𞸉', crate::Replacement(1187)),
+        ('𐞍', crate::Replacement(1414)),
+        ('ﺍ', crate::Replacement(922)),
+        ('㈐', crate::Replacement(91)),
+        ('ａ', crate::Replacement(474)),
+        ('𝑀', crate::Replacement(415)),
+        ('ﮒ', crate::Replacement(1234)),
+        ('𝘣', crate::Replacement(479)),
+        ('𝓟', crate::Replacement(430)),
+        ('𝑌', crate::Replacement(465)),
+        ('㆓', crate::Replacement(1809)),
+        ('⽲', crate::Replacement(2418)),
+        ('⩴', crate::Replacement(353)),
+        ('ᵢ', crate::Replacement(514)),
+        ('∯', crate::Replacement(1498)),
+        ('ﲹ', crate::Replacement(1069)),
+        ('𝐭', crate::Replacement(588)),
+        ('㈊', crate::Replacement(109)),
+        ('㊌', crate::Replacement(2246)),
+        ('𝙆', crate::Replacement(406)),
+        ('ﲡ', crate::Replacement(941)),
+        ('⼂', crate::Replacement(1798)),
+        ('ﮀ', crate::Replacement(1222)),
+        ('𞀻', crate::Replacement(850)),
+        ('🉅', crate::Replacement(1557)),
+        ('𝔂', crate::Replacement(600)),
+        ('ﾵ', crate::Replacement(1309)),
+        ('⼙', crate::Replacement(1909)),
+        ('𝕥', crate::Replacement(588)),
+        ('𝘋', crate::Replacement(374)),
+        ('𝚣', crate::Replacement(602)),
+        ('ㄳ', crate::Replacement(1385)),
+        ('㈫', crate::Replacement(142)),
+        ('㈰', crate::Replacement(136)),
+        ('ﱖ', crate::Replacement(1191)),
+        ('🄠', crate::Replacement(50)),
+        ('ㅄ', crate::Replacement(1337)),
+        ('㎒', crate::Replacement(419)),
+        ('ǉ', crate::Replacement(538)),
+        ('𝒒', crate::Replacement(579)),
+        ('﹝', crate::Replacement(1551)),
+        ('ﰓ', crate::Replacement(971)),
+        ('Ⅵ', crate::Replacement(453)),
+        ('𝓢', crate::Replacement(440)),
+        ('⼩', crate::Replacement(2027)),
+        ('⽴', crate::Replacement(2430)),
+        ('𝞈', crate::Replacement(819)),
+        ('﹟', crate::Replacement(8)),
+        ('Ŀ', crate::Replacement(414)),
+        ('𝚵', crate::Replacement(743)),
+        ('ⓡ', crate::Replacement(580)),
+        ('⒔', crate::Replacement(197)),
+        ('𝘾', crate::Replacement(370)),
+        ('𞁕', crate::Replacement(843)),
+        ('𝑐', crate::Replacement(481)),
+        ('🄆', crate::Replacement(316)),
+        ('𝗳', crate::Replacement(501)),
+        ('⁾', crate::Replacement(153)),
+        ('㎼', crate::Replacement(793)),
+        ('ﻄ', crate::Replacement(1060)),
+        ('𐞩', crate::Replacement(677)),
+        ('ﰾ', crate::Replacement(1117)),
+        ('𞀾', crate::Replacement(854)),
+        ('🉈', crate::Replacement(1558)),
+        ('𝕪', crate::Replacement(600)),
+        ('𝕹', crate::Replacement(425)),
+        ('ﷇ', crate::Replacement(1161)),
+        ('𝚖', crate::Replacement(544)),
+        ('𝐂', crate::Replacement(370)),
+        ('㌦', crate::Replacement(1636)),
+        ('ᶷ', crate::Replacement(685)),
+        ('⼰', crate::Replacement(2052)),
+        ('ﭦ', crate::Replacement(1213)),
+        ('㉵', crate::Replacement(1313)),
+        ('ﯤ', crate::Replacement(1249)),
+        ('𝚴', crate::Replacement(742)),
+        ('𝙍', crate::Replacement(438)),
+        ('𝖙', crate::Replacement(588)),
+        ('ℌ', crate::Replacement(391)),
+        ('㋦', crate::Replacement(1641)),
+        ('𝓆', crate::Replacement(579)),
+        ('𞸜', crate::Replacement(1210)),
+        ('𝜈', crate::Replacement(798)),
+        ('𝖂', crate::Replacement(457)),
+        ('ㄾ', crate::Replacement(1392)),
+        ('𞸎', crate::Replacement(1004)),
+        ('ﶯ', crate::Replacement(1190)),
+        ('ᶝ', crate::Replacement(639)),
+        ('𞸇', crate::Replacement(984)),
+        ('𝚋', crate::Replacement(479)),
+        ('🄤', crate::Replacement(54)),
+        ('ⁿ', crate::Replacement(561)),
+        ('㏗', crate::Replacement(431)),
+        ('Ｚ', crate::Replacement(467)),
+        ('⁉', crate::Replacement(6)),
+        ('𝙨', crate::Replacement(584)),
+        ('㌢', crate::Replacement(1624)),
+        ('ﬡ', crate::Replacement(895)),
+        ('𝘙', crate::Replacement(438)),
+        ('🄱', crate::Replacement(368)),
+        ('𝓶', crate::Replacement(544)),
+        ('ꟸ', crate::Replacement(620)),
+        ('㎁', crate::Replacement(562)),
+        ('𝓃', crate::Replacement(561)),
+        ('㏷', crate::Replacement(256)),
+        ('ﶁ', crate::Replacement(1127)),
+        ('𝐚', crate::Replacement(474)),
+        ('𝝺', crate::Replacement(788)),
+        ('🄰', crate::Replacement(364)),
+        ('𝜟', crate::Replacement(726)),
+        ('𝑬', crate::Replacement(378)),
+        ('𝘅', crate::Replacement(597)),
+        ('⼮', crate::Replacement(2047)),
+        ('𝜹', crate::Replacement(774)),
+        ('㌹', crate::Replacement(1667)),
+        ('ｿ', crate::Replacement(1626)),
+        ('㈗', crate::Replacement(108)),
+        ('ﴂ', crate::Replacement(983)),
+        ('ﵲ', crate::Replacement(1063)),
+        ('𝙈', crate::Replacement(415)),
+        ('𝝾', crate::Replacement(800)),
+        ('𝔅', crate::Replacement(368)),
+        ('㍒', crate::Replacement(1703)),
+        ('ﰺ', crate::Replacement(1111)),
+        ('﹍', crate::Replacement(472)),
+        ('ﰮ', crate::Replacement(1091)),
+        ('𝚈', crate::Replacement(465)),
+        ('㉀', crate::Replacement(147)),
+        ('㌒', crate::Replacement(1600)),
+        ('𝑼', crate::Replacement(451)),
+        ('⒨', crate::Replacement(72)),
+        ('🈜', crate::Replacement(1877)),
+        ('\u{202f}', crate::Replacement(1)),
+        ('㍈', crate::Replacement(1682)),
+        ('⑱', crate::Replacement(216)),
+        ('㊸', crate::Replacement(304)),
+        ('𐞶', crate::Replacement(629)),
+        ('𝘝', crate::Replacement(452)),
+        ('𝗽', crate::Replacement(571)),
+        ('𝘯', crate::Replacement(561)),
+        ('ﺨ', crate::Replacement(992)),
+        ('ₙ', crate::Replacement(561)),
+        ('𞺏', crate::Replacement(1070)),
+        ('⅔', crate::Replacement(268)),
+        ('ㅙ', crate::Replacement(1366)),
+        ('⑾', crate::Replacement(16)),
+        ('ﺗ', crate::Replacement(940)),
+        ('𝙓', crate::Replacement(462)),
+        ('𝔬', crate::Replacement(569)),
+        ('𝐄', crate::Replacement(378)),
+        ('𞀶', crate::Replacement(845)),
+        ('ｙ', crate::Replacement(600)),
+        ('\u{ff9f}', crate::Replacement(1568)),
+        ('⾚', crate::Replacement(2620)),
+        ('㎏', crate::Replacement(530)),
+        ('¼', crate::Replacement(228)),
+        ('𝓯', crate::Replacement(501)),
+        ('﹙', crate::Replacement(13)),
+        ('㋊', crate::Replacement(189)),
+        ('ｼ', crate::Replacement(1620)),
+        ('𝗵', crate::Replacement(510)),
+        ('㋠', crate::Replacement(1629)),
+        ('㏪', crate::Replacement(188)),
+        ('ㅿ', crate::Replacement(1349)),
+        ('ﮋ', crate::Replacement(1228)),
+        ('︑', crate::Replacement(1538)),
+        ('𞸚', crate::Replacement(1068)),
+        ('⿂', crate::Replacement(2744)),
+        ('⒯', crate::Replacement(79)),
+        ('ﲮ', crate::Replacement(1008)),
+        ('ﶀ', crate::Replacement(1125)),
+        ('🈬', crate::Replacement(2051)),
+        ('ٶ', crate::Replacement(1185)),
+        ('Ⓒ', crate::Replacement(370)),
+        ('𞸤', crate::Replacement(1176)),
+        ('㉑', crate::Replacement(246)),
+        ('ﱄ', crate::Replacement(1135)),
+        ('𝓑', crate::Replacement(368)),
+        ('㊛', crate::Replacement(2002)),
+        ('𝓖', crate::Replacement(386)),
+        ('ﮓ', crate::Replacement(1234)),
+        ('𞺮', crate::Replacement(1004)),
+        ('ﻌ', crate::Replacement(1070)),
+        ('ⓕ', crate::Replacement(501)),
+        ('𝑸', crate::Replacement(437)),
+        ('ﱈ', crate::Replacement(1152)),
+        ('ﺞ', crate::Replacement(973)),
+        ('㎆', crate::Replacement(416)),
+        ('𝛤', crate::Replacement(725)),
+        ('𝞌', crate::Replacement(787)),
+        ('ㄼ', crate::Replacement(1390)),
+        ('𝒀', crate::Replacement(465)),
+        ('ﻠ', crate::Replacement(1118)),
+        ('ﻋ', crate::Replacement(1070)),
+        ('⼽', crate::Replacement(2123)),
+        ('𝕞', crate::Replacement(544)),
+        ('ﾫ', crate::Replacement(1389)),
+        ('ﵕ', crate::Replacement(955)),
+        ('ㅬ', crate::Replacement(1399)),
+        ('Ⓖ', crate::Replacement(386)),
+        ('㍿', crate::Replacement(2211)),
+        ('𝓌', crate::Replacement(595)),
+        ('㏟', crate::Replacement(367)),
+        ('ﷲ', crate::Replacement(924)),
+        ('𝛥', crate::Replacement(726)),
+        ('𞺲', crate::Replacement(1098)),
+        ('ﯕ', crate::Replacement(1233)),
+        ('ﵞ', crate::Replacement(1007)),
+        ('𝘴', crate::Replacement(584)),
+        ('𝟿', crate::Replacement(346)),
+        ('⒓', crate::Replacement(192)),
+        ('𞀼', crate::Replacement(852)),
+        ('ｲ', crate::Replacement(1576)),
+        ('𝙴', crate::Replacement(378)),
+        ('ﵢ', crate::Replacement(1017)),
+        ('𝟑', crate::Replacement(273)),
+        ('ﰆ', crate::Replacement(928)),
+        ('ﰍ', crate::Replacement(948)),
+        ('𝐒', crate::Replacement(440)),
+        ('𝗥', crate::Replacement(438)),
+        ('Ⅿ', crate::Replacement(415)),
+        ('𝞢', crate::Replacement(748)),
+        ('﹡', crate::Replacement(154)),
+        ('𞺕', crate::Replacement(940)),
+        ('ᶥ', crate::Replacement(657)),
+        ('㍤', crate::Replacement(195)),
+        ('𝘜', crate::Replacement(451)),
+        ('𝞱', crate::Replacement(781)),
+        ('𝞐', crate::Replacement(720)),
+        ('㋳', crate::Replacement(1692)),
+        ('𞁛', crate::Replacement(849)),
+        ('𝜸', crate::Replacement(773)),
+        ('𝐦', crate::Replacement(544)),
+        ('∭', crate::Replacement(1496)),
+        ('𞹼', crate::Replacement(1210)),
+        ('ﱳ', crate::Replacement(960)),
+        ('ⓚ', crate::Replacement(523)),
+        ('𝞥', crate::Replacement(759)),
+        ('𝒈', crate::Replacement(508)),
+        ('ﲤ', crate::Replacement(954)),
+        ('﬩', crate::Replacement(155)),
+        ('🄥', crate::Replacement(55)),
+        ('ᵇ', crate::Replacement(479)),
+        ('⼢', crate::Replacement(1989)),
+        ('⽵', crate::Replacement(2432)),
+        ('ⓛ', crate::Replacement(537)),
+        ('㈖', crate::Replacement(105)),
+        ('𞹭', crate::Replacement(1156)),
+        ('𝒷', crate::Replacement(479)),
+        ('⾻', crate::Replacement(2736)),
+        ('𝝿', crate::Replacement(801)),
+        ('𞀽', crate::Replacement(853)),
+        ('𝗓', crate::Replacement(602)),
+        ('𝙶', crate::Replacement(386)),
+        ('ﯡ', crate::Replacement(1241)),
+        ('ﭘ', crate::Replacement(1216)),
+        ('ﵙ', crate::Replacement(979)),
+        ('＠', crate::Replacement(363)),
+        ('㍑', crate::Replacement(1702)),
+        ('𞺎', crate::Replacement(1004)),
+        ('㊩', crate::Replacement(1899)),
+        ('＼', crate::Replacement(469)),
+        ('ⅾ', crate::Replacement(490)),
+        ('⽓', crate::Replacement(2245)),
+        ('ﭷ', crate::Replacement(1219)),
+        ('㊡', crate::Replacement(1820)),
+        ('ﺲ', crate::Replacement(1004)),
+        ('㏁', crate::Replacement(424)),
+        ('𞹪', crate::Replacement(1107)),
+        ('𝓞', crate::Replacement(429)),
+        ('⒎', crate::Replacement(334)),
+        ('𝓅', crate::Replacement(571)),
+        ('𝖖', crate::Replacement(579)),
+        ('𝑁', crate::Replacement(425)),
+        ('ﻎ', crate::Replacement(1080)),
+        ('ﶅ', crate::Replacement(1129)),
+        ('ﺳ', crate::Replacement(1004)),
+        ('⑨', crate::Replacement(346)),
+        ('㏩', crate::Replacement(183)),
+        ('㈋', crate::Replacement(111)),
+        ('ﲭ', crate::Replacement(1005)),
+        ('⼏', crate::Replacement(1866)),
+        ('Ｖ', crate::Replacement(452)),
+        ('㊏', crate::Replacement(1966)),
+        ('㊇', crate::Replacement(1846)),
+        ('∰', crate::Replacement(1499)),
+        ('ﲈ', crate::Replacement(1137)),
+        ('ﲢ', crate::Replacement(945)),
+        ('𝓽', crate::Replacement(588)),
+        ('𝔉', crate::Replacement(379)),
+        ('㌏', crate::Replacement(1592)),
+        ('ﶩ', crate::Replacement(1038)),
+        ('ﯽ', crate::Replacement(1248)),
+        ('ｷ', crate::Replacement(1596)),
+        ('ﭶ', crate::Replacement(1219)),
+        ('㍛', crate::Replacement(296)),
+        ('ⅿ', crate::Replacement(544)),
+        ('⽸', crate::Replacement(2466)),
+        ('ﵺ', crate::Replacement(1085)),
+        ('🄽', crate::Replacement(425)),
+        ('𞺫', crate::Replacement(1118)),
+        ('𝑧', crate::Replacement(602)),
+        ('㉥', crate::Replacement(1306)),
+        ('ㄹ', crate::Replacement(1302)),
+        ('𝑮', crate::Replacement(386)),
+        ('𝔐', crate::Replacement(415)),
+        ('𝔸', crate::Replacement(364)),
+        ('𝓏', crate::Replacement(602)),
+        ('ﷄ', crate::Replacement(1072)),
+        ('⾇', crate::Replacement(2506)),
+        ('ﺡ', crate::Replacement(984)),
+        ('ﰯ', crate::Replacement(1092)),
+        ('ㅧ', crate::Replacement(1394)),
+        ('𞹰', crate::Replacement(1089)),
+        ('㈑', crate::Replacement(93)),
+        ('ﻫ', crate::Replacement(1176)),
+        ('𞺡', crate::Replacement(926)),
+        ('🈚', crate::Replacement(2300)),
+        ('ﺦ', crate::Replacement(992)),
+        ('ﵣ', crate::Replacement(1017)),
+        ('ｕ', crate::Replacement(590)),
+        ('⽛', crate::Replacement(2318)),
+        ('𝚸', crate::Replacement(746)),
+        ('㉩', crate::Replacement(1319)),
+        ('ㄺ', crate::Replacement(1388)),
+        ('ᴬ', crate::Replacement(364)),
+        ('ｄ', crate::Replacement(490)),
+        ('⒄', crate::Replacement(22)),
+        ('𐞰', crate::Replacement(1536)),
+        ('㍙', crate::Replacement(236)),
+        ('㎳', crate::Replacement(558)),
+        ('ﴬ', crate::Replacement(1056)),
+        ('ㅍ', crate::Replacement(1326)),
+        ('㎫', crate::Replacement(420)),
+        ('ﰉ', crate::Replacement(937)),
+        ('℡', crate::Replacement(447)),
+        ('﹗', crate::Replacement(4)),
+        ('㍠', crate::Replacement(345)),
+        ('𝐉', crate::Replacement(404)),
+        ('ㅶ', crate::Replacement(1340)),
+        ('ㅝ', crate::Replacement(1370)),
+        ('ϐ', crate::Replacement(772)),
+        ('ㆃ', crate::Replacement(1404)),
+        ('𞺍', crate::Replacement(1156)),
+        ('𝓦', crate::Replacement(457)),
+        ('ﱑ', crate::Replacement(1177)),
+        ('🄓', crate::Replacement(37)),
+        ('㈸', crate::Replacement(128)),
+        ('⿐', crate::Replacement(2773)),
+        ('𝕳', crate::Replacement(391)),
+        ('𝖄', crate::Replacement(465)),
+        ('㊔', crate::Replacement(1930)),
+        ('㉖', crate::Replacement(260)),
+        ('ﱲ', crate::Replacement(954)),
+        ('𞺨', crate::Replacement(1060)),
+        ('𝞍', crate::Replacement(816)),
+        ('ﰭ', crate::Replacement(1090)),
+        ('㌽', crate::Replacement(1670)),
+        ('ﷃ', crate::Replacement(1114)),
+        ('ﶢ', crate::Replacement(950)),
+        ('㍪', crate::Replacement(219)),
+        ('Ｕ', crate::Replacement(451)),
+        ('Ǳ', crate::Replacement(376)),
+        ('𝒂', crate::Replacement(474)),
+        ('𝟂', crate::Replacement(819)),
+        ('ﳡ', crate::Replacement(934)),
+        ('ﲃ', crate::Replacement(1116)),
+        ('㍄', crate::Replacement(1677)),
+        ('𞺆', crate::Replacement(1003)),
+        ('㍬', crate::Replacement(245)),
+        ('𝓒', crate::Replacement(370)),
+        ('⁼', crate::Replacement(356)),
+        ('🯶', crate::Replacement(326)),
+        ('𝔜', crate::Replacement(465)),
+        ('⑯', crate::Replacement(208)),
+        ('ｍ', crate::Replacement(544)),
+        ('𝝄', crate::Replacement(800)),
+        ('⾰', crate::Replacement(2706)),
+        ('𝞹', crate::Replacement(801)),
+        ('⒟', crate::Replacement(63)),
+        ('ﯟ', crate::Replacement(1247)),
+        ('𞺴', crate::Replacement(1021)),
+        ('ᵜ', crate::Replacement(1411)),
+        ('ﵔ', crate::Replacement(949)),
+        ('₃', crate::Replacement(273)),
+        ('ﬦ', crate::Replacement(908)),
+        ('ﶷ', crate::Replacement(1115)),
+        ('ﺧ', crate::Replacement(992)),
+        ('⼒', crate::Replacement(1881)),
+        ('⼗', crate::Replacement(1901)),
+        ('ᵚ', crate::Replacement(663)),
+        ('ₒ', crate::Replacement(569)),
+        ('９', crate::Replacement(346)),
+        ('𐞸', crate::Replacement(631)),
+        ('㎊', crate::Replacement(574)),
+        ('🄙', crate::Replacement(43)),
+        ('ㅋ', crate::Replacement(1322)),
+        ('⾑', crate::Replacement(2583)),
+        ('ﭻ', crate::Replacement(1221)),
+        ('𝚚', crate::Replacement(579)),
+        ('㍓', crate::Replacement(1705)),
+        ('𝟧', crate::Replacement(315)),
+        ('𝐅', crate::Replacement(379)),
+        ('ｑ', crate::Replacement(579)),
+        ('𝜖', crate::Replacement(775)),
+        ('Ⓔ', crate::Replacement(378)),
+        ('𐞳', crate::Replacement(701)),
+        ('⽦', crate::Replacement(2365)),
+        ('🈑', crate::Replacement(2014)),
+        ('⑦', crate::Replacement(332)),
+        ('🈐', crate::Replacement(2129)),
+        ('ﰱ', crate::Replacement(1096)),
+        ('𝞯', crate::Replacement(776)),
+        ('𝟭', crate::Replacement(168)),
+        ('𝒇', crate::Replacement(501)),
+        ('𝔳', crate::Replacement(591)),
+        ('㍢', crate::Replacement(185)),
+        ('𞁝', crate::Replacement(853)),
+        ('𝗉', crate::Replacement(571)),
+        ('Ⅴ', crate::Replacement(452)),
+        ('𝝆', crate::Replacement(802)),
+        ('ﺔ', crate::Replacement(939)),
+        ('𞹒', crate::Replacement(1098)),
+        ('𝜕', crate::Replacement(1486)),
+        ('ⅸ', crate::Replacement(520)),
+        ('🄇', crate::Replacement(327)),
+        ('ﻆ', crate::Replacement(1068)),
+        ('㋷', crate::Replacement(1701)),
+        ('ﳿ', crate::Replacement(990)),
+        ('ㅛ', crate::Replacement(1368)),
+        ('ﶪ', crate::Replacement(1026)),
+        ('ﭟ', crate::Replacement(1214)),
+        ('ﱵ', crate::Replacement(963)),
+        ('㋞', crate::Replacement(1626)),
+        ('㉪', crate::Replacement(1322)),
+        ('𝙲', crate::Replacement(370)),
+        ('🯱', crate::Replacement(168)),
+        ('㉲', crate::Replacement(1305)),
+        ('﹫', crate::Replacement(363)),
+        ('⼹', crate::Replacement(2080)),
+        ('３', crate::Replacement(273)),
+        ('㉳', crate::Replacement(1307)),
+        ('⾽', crate::Replacement(2738)),
+        ('ﴙ', crate::Replacement(1033)),
+        ('㋤', crate::Replacement(1638)),
+        ('ᶦ', crate::Replacement(658)),
+        ('𞀿', crate::Replacement(855)),
+        ('ㆍ', crate::Replacement(1383)),
+        ('ᶾ', crate::Replacement(693)),
+        ('ⅽ', crate::Replacement(481)),
+        ('⑻', crate::Replacement(32)),
+        ('ﴪ', crate::Replacement(1013)),
+        ('ﲪ', crate::Replacement(987)),
+        ('ﮘ', crate::Replacement(1236)),
+        ('ﺮ', crate::Replacement(1000)),
+        ('ﳰ', crate::Replacement(1196)),
+        ('𐞇', crate::Replacement(703)),
+        ('𝕕', crate::Replacement(490)),
+        ('ⅉ', crate::Replacement(521)),
+        ('𞸞', crate::Replacement(1229)),
+        ('𝔁', crate::Replacement(597)),
+        ('㌼', crate::Replacement(1661)),
+        ('ﰽ', crate::Replacement(1116)),
+        ('⨌', crate::Replacement(1497)),
+        ('ｪ', crate::Replacement(1582)),
+        ('⾐', crate::Replacement(2572)),
+        ('﹂', crate::Replacement(1545)),
+        ('ﯝ', crate::Replacement(1244)),
+        ('𝐤', crate::Replacement(523)),
+        ('ﳒ', crate::Replacement(1157)),
+        ('㏌', crate::Replacement(518)),
+        ('𝑚', crate::Replacement(544)),
+        ('⼥', crate::Replacement(2002)),
+        ('𝘑', crate::Replacement(404)),
+        ('ﱀ', crate::Replacement(1124)),
+        ('︰', crate::Replacement(159)),
+        ('𝞲', crate::Replacement(782)),
+        ('𝔥', crate::Replacement(510)),
+        ('ﴧ', crate::Replacement(1027)),
+        ('𞸢', crate::Replacement(973)),
+        ('ﱰ', crate::Replacement(952)),
+        ('㋝', crate::Replacement(1623)),
+        ('𝒖', crate::Replacement(590)),
+        ('⅝', crate::Replacement(322)),
+        ('𝗖', crate::Replacement(370)),
+        ('㏈', crate::Replacement(491)),
+        ('ｰ', crate::Replacement(1718)),
+        ('ﯼ', crate::Replacement(1248)),
+        ('ﻊ', crate::Replacement(1070)),
+        ('㏭', crate::Replacement(202)),
+        ('ﻭ', crate::Replacement(1183)),
+        ('𝒙', crate::Replacement(597)),
+        ('⽀', crate::Replacement(2161)),
+        ('ﴝ', crate::Replacement(982)),
+        ('ₘ', crate::Replacement(544)),
+        ('⼻', crate::Replacement(2086)),
+        ('𞹻', crate::Replacement(1080)),
+        ('ℒ', crate::Replacement(410)),
+        ('𝓚', crate::Replacement(406)),
+        ('𞸴', crate::Replacement(1021)),
+        ('ㅭ', crate::Replacement(1400)),
+        ('𞁎', crate::Replacement(882)),
+        ('ᶿ', crate::Replacement(781)),
+        ('ﾱ', crate::Replacement(1304)),
+        ('ﴺ', crate::Replacement(1062)),
+        ('⒆', crate::Replacement(24)),
+        ('ﻓ', crate::Replacement(1089)),
+        ('𞁉', crate::Replacement(867)),
+        ('ㅌ', crate::Replacement(1324)),
+        ('ㅞ', crate::Replacement(1371)),
+        ('𝛡', crate::Replacement(801)),
+        ('𞺳', crate::Replacement(1000)),
+        ('㏽', crate::Replacement(280)),
+        ('𝐿', crate::Replacement(410)),
+        ('㋖', crate::Replacement(1596)),
+        ('𝛁', crate::Replacement(1488)),
+        ('𝛷', crate::Replacement(759)),
+        ('｠', crate::Replacement(1534)),
+        ('𞁭', crate::Replacement(877)),
+        ('ﵼ', crate::Replacement(1093)),
+        ('ⅱ', crate::Replacement(515)),
+        ('𝞄', crate::Replacement(807)),
+        ('㌅', crate::Replacement(1578)),
+        ('𝚱', crate::Replacement(739)),
+        ('㈩', crate::Replacement(129)),
+        ('𞁙', crate::Replacement(847)),
+        ('𞺘', crate::Replacement(999)),
+        ('𝜦', crate::Replacement(740)),
+        ('𞺖', crate::Replacement(964)),
+        ('Ϲ', crate::Replacement(748)),
+        ('ₕ', crate::Replacement(510)),
+        ('ﭪ', crate::Replacement(1230)),
+        ('𝞃', crate::Replacement(806)),
+        ('🄭', crate::Replacement(371)),
+        ('㋃', crate::Replacement(313)),
+        ('𝚑', crate::Replacement(510)),
+        ('𞺚', crate::Replacement(1068)),
+        ('ﲎ', crate::Replacement(1174)),
+        ('ⅻ', crate::Replacement(599)),
+        ('𝜊', crate::Replacement(800)),
+        ('Ⅱ', crate::Replacement(398)),
+        ('𝙒', crate::Replacement(457)),
+        ('𝙎', crate::Replacement(440)),
+        ('𝖋', crate::Replacement(501)),
+        ('㋗', crate::Replacement(1605)),
+        ('⿉', crate::Replacement(2761)),
+        ('🅌', crate::Replacement(441)),
+        ('ϖ', crate::Replacement(801)),
+        ('𞹩', crate::Replacement(1187)),
+        ('🄉', crate::Replacement(341)),
+        ('𝛣', crate::Replacement(724)),
+        ('𝟩', crate::Replacement(332)),
+        ('⾀', crate::Replacement(2488)),
+        ('⾦', crate::Replacement(2664)),
+        ('⾧', crate::Replacement(2675)),
+        ('𝑖', crate::Replacement(514)),
+        ('Ⓐ', crate::Replacement(364)),
+        ('㊾', crate::Replacement(310)),
+        ('𝜎', crate::Replacement(805)),
+        ('ﴸ', crate::Replacement(1024)),
+        ('﹪', crate::Replacement(10)),
+        ('⽁', crate::Replacement(2162)),
+        ('ﰗ', crate::Replacement(985)),
+        ('⽪', crate::Replacement(2375)),
+        ('㎿', crate::Replacement(423)),
+        ('㋲', crate::Replacement(1690)),
+        ('⼤', crate::Replacement(1994)),
+        ('ﺚ', crate::Replacement(964)),
+        ('𝚺', crate::Replacement(748)),
+        ('𝙢', crate::Replacement(544)),
+        ('ٷ', crate::Replacement(1244)),
+        ('🈙', crate::Replacement(2180)),
+        ('⑸', crate::Replacement(29)),
+        ('⑶', crate::Replacement(27)),
+        ('𝖩', crate::Replacement(404)),
+        ('ﰡ', crate::Replacement(1045)),
+        ('𝒘', crate::Replacement(595)),
+        ('ᶶ', crate::Replacement(684)),
+        ('𝔱', crate::Replacement(588)),
+        ('㈄', crate::Replacement(94)),
+        ('㊠', crate::Replacement(2714)),
+        ('ﶲ', crate::Replacement(1104)),
+        ('ﲆ', crate::Replacement(1134)),
+        ('𝕻', crate::Replacement(430)),
+        ('㍎', crate::Replacement(1693)),
+        ('㍣', crate::Replacement(190)),
+        ('ﰷ', crate::Replacement(1108)),
+        ('〶', crate::Replacement(1550)),
+        ('𝟰', crate::Replacement(297)),
+        ('𝛰', crate::Replacement(744)),
+        ('𝐎', crate::Replacement(429)),
+        ('𞸔', crate::Replacement(1021)),
+        ('𝕎', crate::Replacement(457)),
+        ('ᵝ', crate::Replacement(772)),
+        ('㏆', crate::Replacement(373)),
+        ('𝙌', crate::Replacement(437)),
+        ('︹', crate::Replacement(1551)),
+        ('ﭮ', crate::Replacement(1231)),
+        ('㉰', crate::Replacement(1300)),
+        ('𝔹', crate::Replacement(368)),
+        ('🄂', crate::Replacement(169)),
+        ('𝗩', crate::Replacement(452)),
+        ('ﱏ', crate::Replacement(1174)),
+        ('𝖡', crate::Replacement(368)),
+        ('㆔', crate::Replacement(1789)),
+        ('𝔯', crate::Replacement(580)),
+        ('ﯔ', crate::Replacement(1233)),
+        ('ﭕ', crate::Replacement(1215)),
+        ('ￌ', crate::Replacement(1364)),
+        ('𞸃', crate::Replacement(998)),
+        ('𝑾', crate::Replacement(457)),
+        ('𝘰', crate::Replacement(569)),
+        ('⽿', crate::Replacement(2482)),
+        ('𝛸', crate::Replacement(760)),
+        ('㈤', crate::Replacement(122)),
+        ('ｂ', crate::Replacement(479)),
+        ('ﴰ', crate::Replacement(1029)),
+        ('𝐗', crate::Replacement(462)),
+        ('𝖥', crate::Replacement(379)),
+        ('ﴃ', crate::Replacement(996)),
+        ('㊹', crate::Replacement(305)),
+        ('𝐫', crate::Replacement(580)),
+        ('𝔵', crate::Replacement(597)),
+        ('𝕰', crate::Replacement(378)),
+        ('𝑉', crate::Replacement(452)),
+        ('⾕', crate::Replacement(2604)),
+        ('ﲵ', crate::Replacement(1051)),
+        ('ﴥ', crate::Replacement(1022)),
+        ('③', crate::Replacement(273)),
+        ('𝛒', crate::Replacement(802)),
+        ('ﳘ', crate::Replacement(1178)),
+        ('⼀', crate::Replacement(1786)),
+        ('𝙊', crate::Replacement(429)),
+        ('ﰶ', crate::Replacement(1106)),
+        ('ᵨ', crate::Replacement(802)),
+        ('𝜪', crate::Replacement(744)),
+        ('𐞺', crate::Replacement(2800)),
+        ('ᵪ', crate::Replacement(817)),
+        ('ﬅ', crate::Replacement(587)),
+        ('𝒉', crate::Replacement(510)),
+        ('𝛅', crate::Replacement(774)),
+        ('ﴱ', crate::Replacement(1018)),
+        ('㋺', crate::Replacement(1710)),
+        ('𞁂', crate::Replacement(858)),
+        ('\u{2004}', crate::Replacement(1)),
+        ('ﶃ', crate::Replacement(1121)),
+        ('𞹧', crate::Replacement(984)),
+        ('ⅈ', crate::Replacement(514)),
+        ('ﳪ', crate::Replacement(1032)),
+        ('ﯚ', crate::Replacement(1242)),
+        ('⼾', crate::Replacement(2128)),
+        ('𝑙', crate::Replacement(537)),
+        ('Ĳ', crate::Replacement(400)),
+        ('𝖶', crate::Replacement(457)),
+        ('㏡', crate::Replacement(270)),
+        ('𝞛', crate::Replacement(741)),
+        ('𝖓', crate::Replacement(561)),
+        ('𞁪', crate::Replacement(871)),
+        ('ﮨ', crate::Replacement(1240)),
+        ('ﰳ', crate::Replacement(1099)),
+        ('𝔾', crate::Replacement(386)),
+        ('㎍', crate::Replacement(794)),
+        ('𝞎', crate::Replacement(802)),
+        ('𝖫', crate::Replacement(410)),
+        ('ℬ', crate::Replacement(368)),
+        ('㊲', crate::Replacement(288)),
+        ('𝐲', crate::Replacement(600)),
+        ('⒲', crate::Replacement(82)),
+        ('ﾕ', crate::Replacement(1696)),
+        ('㈁', crate::Replacement(88)),
+        ('０', crate::Replacement(162)),
+        ('﹜', crate::Replacement(605)),
+        ('𝞊', crate::Replacement(775)),
+        ('𝜚', crate::Replacement(802)),
+        ('㎤', crate::Replacement(489)),
+        ('⽾', crate::Replacement(2481)),
+        ('⿕', crate::Replacement(2781)),
+        ('ﮍ', crate::Replacement(1227)),
+        ('ﲺ', crate::Replacement(1071)),
+        ('ﭾ', crate::Replacement(1222)),
+        ('ᵡ', crate::Replacement(817)),
+        ('ﭡ', crate::Replacement(1214)),
+        ('𝑩', crate::Replacement(368)),
+        ('ﱇ', crate::Replacement(1148)),
+        ('㋏', crate::Replacement(412)),
+        ('𝔓', crate::Replacement(430)),
+        ('𞺋', crate::Replacement(1118)),
+        ('𝑈', crate::Replacement(451)),
+        ('ﶇ', crate::Replacement(1131)),
+        ('𝔲', crate::Replacement(590)),
+        ('𝗀', crate::Replacement(508)),
+        ('㌞', crate::Replacement(1616)),
+        ('ㆇ', crate::Replacement(1377)),
+        ('㋢', crate::Replacement(1632)),
+        ('⽗', crate::Replacement(2313)),
+        ('㉇', crate::Replacement(2434)),
+        ('⁈', crate::Replacement(361)),
+        ('𝚆', crate::Replacement(457)),
+        ('𐞖', crate::Replacement(698)),
+        ('ﰩ', crate::Replacement(1071)),
+        ('𝜑', crate::Replacement(816)),
+        ('𝟅', crate::Replacement(781)),
+        ('ﳮ', crate::Replacement(1169)),
+        ('𝜌', crate::Replacement(802)),
+        ('㍦', crate::Replacement(203)),
+        ('㆖', crate::Replacement(1790)),
+        ('𝑿', crate::Replacement(462)),
+        ('ⁱ', crate::Replacement(514)),
+        ('ﯿ', crate::Replacement(1248)),
+        ('㎎', crate::Replacement(551)),
+        ('⅛', crate::Replacement(232)),
+        ('㎸', crate::Replacement(527)),
+        ('𝝕', crate::Replacement(801)),
+        ('ٸ', crate::Replacement(1204)),
+        ('﹩', crate::Replacement(9)),
+        ('🄔', crate::Replacement(38)),
+        ('𝙼', crate::Replacement(415)),
+        ('𝒶', crate::Replacement(474)),
+        ('𞹝', crate::Replacement(1237)),
+        ('𝙙', crate::Replacement(490)),
+        ('㈷', crate::Replacement(146)),
+        ('𝗃', crate::Replacement(521)),
+        ('ℾ', crate::Replacement(725)),
+        ('ﴘ', crate::Replacement(1020)),
+        ('ﴅ', crate::Replacement(1047)),
+        ('𝑻', crate::Replacement(446)),
+        ('𝓧', crate::Replacement(462)),
+        ('𝛻', crate::Replacement(1488)),
+        ('🈷', crate::Replacement(2193)),
+        ('㍜', crate::Replacement(314)),
+        ('⅞', crate::Replacement(336)),
+        ('㌜', crate::Replacement(1611)),
+        ('𝛭', crate::Replacement(741)),
+        ('𝖤', crate::Replacement(378)),
+        ('𐞈', crate::Replacement(2789)),
+        ('𝚲', crate::Replacement(740)),
+        ('ﶿ', crate::Replacement(986)),
+        ('⅘', crate::Replacement(311)),
+        ('⼱', crate::Replacement(2054)),
+        ('ﾧ', crate::Replacement(1299)),
+        ('ﻖ', crate::Replacement(1098)),
+        ('𝛋', crate::Replacement(787)),
+        ('🄝', crate::Replacement(47)),
+        ('ﳢ', crate::Replacement(936)),
+        ('ﾎ', crate::Replacement(1668)),
+        ('𝕦', crate::Replacement(590)),
+        ('ヿ', crate::Replacement(1614)),
+        ('㎂', crate::Replacement(790)),
+        ('ℇ', crate::Replacement(626)),
+        ('ﺱ', crate::Replacement(1004)),
+        ('𝝌', crate::Replacement(817)),
+        ('ｽ', crate::Replacement(1622)),
+        ('𝜂', crate::Replacement(777)),
+        ('⽨', crate::Replacement(2373)),
+        ('𞺺', crate::Replacement(1068)),
+        ('ᶮ', crate::Replacement(666)),
+        ('𝙞', crate::Replacement(514)),
+        ('Ｗ', crate::Replacement(457)),
+        ('𞹶', crate::Replacement(964)),
+        ('ㆉ', crate::Replacement(1379)),
+        ('𝙬', crate::Replacement(595)),
+        ('𝖜', crate::Replacement(595)),
+        ('㋆', crate::Replacement(338)),
+        ('Ⅲ', crate::Replacement(399)),
+        ('㋟', crate::Replacement(1627)),
+        ('🄘', crate::Replacement(42)),
+        ('ﭵ', crate::Replacement(1220)),
+        ('ﴑ', crate::Replacement(1066)),
+        ('𝗈', crate::Replacement(569)),
+        ('𝗪', crate::Replacement(457)),
+        ('ﯖ', crate::Replacement(1233)),
+        ('𞁃', crate::Replacement(859)),
+        ('ﴣ', crate::Replacement(1058)),
+        ('𝜯', crate::Replacement(749)),
+        ('㈀', crate::Replacement(86)),
+        ('𝟵', crate::Replacement(346)),
+        ('𝐡', crate::Replacement(510)),
+        ('𝒿', crate::Replacement(521)),
+        ('𝖕', crate::Replacement(571)),
+        ('𝕓', crate::Replacement(479)),
+        ('ﾪ', crate::Replacement(1388)),
+        ('ㅘ', crate::Replacement(1365)),
+        ('ﮧ', crate::Replacement(1240)),
+        ('ﴋ', crate::Replacement(1027)),
+        ('ٵ', crate::Replacement(925)),
+        ('ﶒ', crate::Replacement(1140)),
+        ('ẚ', crate::Replacement(478)),
+        ('ﺺ', crate::Replacement(1035)),
+        ('㈔', crate::Replacement(99)),
+        ('𝙟', crate::Replacement(521)),
+        ('㋈', crate::Replacement(350)),
+        ('㊵', crate::Replacement(301)),
+        ('ﱉ', crate::Replacement(1154)),
+        ('Ｈ', crate::Replacement(391)),
+        ('㊗', crate::Replacement(2408)),
+        ('𝕌', crate::Replacement(451)),
+        ('𞹯', crate::Replacement(1070)),
+        ('ㅇ', crate::Replacement(1312)),
+        ('ﵛ', crate::Replacement(988)),
+        ('㊓', crate::Replacement(2403)),
+        ('𝙐', crate::Replacement(451)),
+        ('ʰ', crate::Replacement(510)),
+        ('ﾥ', crate::Replacement(1386)),
+        ('ﵿ', crate::Replacement(1103)),
+        ('‴', crate::Replacement(1473)),
+        ('⾘', crate::Replacement(2608)),
+        ('🄑', crate::Replacement(35)),
+        ('𝝪', crate::Replacement(750)),
+        ('𝓲', crate::Replacement(514)),
+        ('ﴯ', crate::Replacement(1027)),
+        ('ｉ', crate::Replacement(514)),
+        ('₍', crate::Replacement(13)),
+        ('ｌ', crate::Replacement(537)),
+        ('⒧', crate::Replacement(71)),
+        ('Ｐ', crate::Replacement(430)),
+        ('𝛵', crate::Replacement(749)),
+        ('𝒹', crate::Replacement(490)),
+        ('𝚘', crate::Replacement(569)),
+        ('𝓷', crate::Replacement(561)),
+        ('⼭', crate::Replacement(2036)),
+        ('ﲲ', crate::Replacement(1039)),
+        ('ㅥ', crate::Replacement(1330)),
+        ('㏹', crate::Replacement(261)),
+        ('𝛳', crate::Replacement(733)),
+        ('𝚤', crate::Replacement(622)),
+        ('㊶', crate::Replacement(302)),
+        ('ﭏ', crate::Replacement(896)),
+        ('⽬', crate::Replacement(2381)),
+        ('🅏', crate::Replacement(458)),
+        ('＃', crate::Replacement(8)),
+        ('ｆ', crate::Replacement(501)),
+        ('𝔏', crate::Replacement(410)),
+        ('𝞽', crate::Replacement(806)),
+        ('ﺤ', crate::Replacement(984)),
+        ('ﳯ', crate::Replacement(1173)),
+        ('ﾨ', crate::Replacement(1301)),
+        ('ﬤ', crate::Replacement(906)),
+        ('𝖈', crate::Replacement(481)),
+        ('𝙻', crate::Replacement(410)),
+        ('𝐯', crate::Replacement(591)),
+        ('．', crate::Replacement(158)),
+        ('ﳦ', crate::Replacement(970)),
+        ('𝞭', crate::Replacement(774)),
+        ('㉆', crate::Replacement(2167)),
+        ('ₓ', crate::Replacement(597)),
+        ('ﰹ', crate::Replacement(1110)),
+        ('🄃', crate::Replacement(238)),
+        ('₈', crate::Replacement(340)),
+        ('ㅉ', crate::Replacement(1318)),
+        ('ﮖ', crate::Replacement(1236)),
+        ('ｳ', crate::Replacement(1580)),
+        ('𝒰', crate::Replacement(451)),
+        ('𝓱', crate::Replacement(510)),
+        ('𝘕', crate::Replacement(425)),
+        ('㋣', crate::Replacement(1635)),
+        ('𝜿', crate::Replacement(787)),
+        ('𝟻', crate::Replacement(315)),
+        ('𝐝', crate::Replacement(490)),
+        ('㋎', crate::Replacement(499)),
+        ('㍉', crate::Replacement(1683)),
+        ('𝛔', crate::Replacement(805)),
+        ('ﴉ', crate::Replacement(1022)),
+        ('𝜐', crate::Replacement(807)),
+        ('𐞮', crate::Replacement(707)),
+        ('ﳔ', crate::Replacement(1166)),
+        ('𝗆', crate::Replacement(544)),
+        ('㊤', crate::Replacement(1790)),
+        ('🄕', crate::Replacement(39)),
+        ('㋒', crate::Replacement(1580)),
+        ('⒛', crate::Replacement(243)),
+        ('ㅷ', crate::Replacement(1341)),
+        ('ﬖ', crate::Replacement(894)),
+        ('𝛪', crate::Replacement(734)),
+        ('𝝚', crate::Replacement(727)),
+        ('𝑠', crate::Replacement(584)),
+        ('⾓', crate::Replacement(2587)),
+        ('㋭', crate::Replacement(1668)),
+        ('𝛬', crate::Replacement(740)),
+        ('𝟛', crate::Replacement(273)),
+        ('ℸ', crate::Replacement(899)),
+        ('𝕒', crate::Replacement(474)),
+        ('ﶽ', crate::Replacement(1158)),
+        ('𝝹', crate::Replacement(787)),
+        ('𝕺', crate::Replacement(429)),
+        ('𝐆', crate::Replacement(386)),
+        ('ℷ', crate::Replacement(898)),
+        ('𐞭', crate::Replacement(2790)),
+        ('𝕙', crate::Replacement(510)),
+        ('ﻢ', crate::Replacement(1136)),
+        ('ﭳ', crate::Replacement(1220)),
+        ('𝜭', crate::Replacement(733)),
+        ('ﺓ', crate::Replacement(939)),
+        ('𞺭', crate::Replacement(1156)),
+        ('⽽', crate::Replacement(2480)),
+        ('𝚽', crate::Replacement(759)),
+        ('\u{2007}', crate::Replacement(1)),
+        ('ﾸ', crate::Replacement(1315)),
+        ('𝝂', crate::Replacement(798)),
+        ('ﴜ', crate::Replacement(991)),
+        ('𝐨', crate::Replacement(569)),
+        ('𞹬', crate::Replacement(1136)),
+        ('ﯩ', crate::Replacement(1186)),
+        ('ﵴ', crate::Replacement(1065)),
+        ('𝟖', crate::Replacement(340)),
+        ('𞀱', crate::Replacement(840)),
+        ('🯵', crate::Replacement(315)),
+        ('𝒬', crate::Replacement(437)),
+        ('ｚ', crate::Replacement(602)),
+        ('⾨', crate::Replacement(2676)),
+        ('ﯠ', crate::Replacement(1241)),
+        ('㋀', crate::Replacement(235)),
+        ('ﮟ', crate::Replacement(1237)),
+        ('ᵖ', crate::Replacement(571)),
+        ('🈔', crate::Replacement(1809)),
+        ('ﰔ', crate::Replacement(972)),
+        ('ﲁ', crate::Replacement(1112)),
+        ('㌰', crate::Replacement(1654)),
+        ('⼓', crate::Replacement(1891)),
+        ('𝗙', crate::Replacement(379)),
+        ('㍚', crate::Replacement(272)),
+        ('⼜', crate::Replacement(1918)),
+        ('🈥', crate::Replacement(1934)),
+        ('⼫', crate::Replacement(2029)),
+        ('𝐼', crate::Replacement(397)),
+        ('﹖', crate::Replacement(360)),
+        ('⅀', crate::Replacement(1491)),
+        ('㌙', crate::Replacement(1607)),
+        ('𝙰', crate::Replacement(364)),
+        ('𝞁', crate::Replacement(804)),
+        ('𝔀', crate::Replacement(595)),
+        ('🅋', crate::Replacement(422)),
+        ('㋜', crate::Replacement(1622)),
+        ('Ｂ', crate::Replacement(368)),
+        ('𞸮', crate::Replacement(1004)),
+        ('🈀', crate::Replacement(1565)),
+        ('𝟼', crate::Replacement(326)),
+        ('𝙏', crate::Replacement(446)),
+        ('㉺', crate::Replacement(1327)),
+        ('𝚞', crate::Replacement(590)),
+        ('ﰥ', crate::Replacement(1057)),
+        ('₆', crate::Replacement(326)),
+        ('ᵋ', crate::Replacement(644)),
+        ('ﱱ', crate::Replacement(953)),
+        ('㎐', crate::Replacement(395)),
+        ('𞺌', crate::Replacement(1136)),
+        ('⽉', crate::Replacement(2193)),
+        ('🈁', crate::Replacement(1613)),
+        ('Ⅾ', crate::Replacement(374)),
+        ('𝛈', crate::Replacement(777)),
+        ('𝔈', crate::Replacement(378)),
+        ('㍕', crate::Replacement(1708)),
+        ('𝒍', crate::Replacement(537)),
+        ('⽩', crate::Replacement(2374)),
+        ('𐞊', crate::Replacement(704)),
+        ('𝟚', crate::Replacement(237)),
+        ('Ⅽ', crate::Replacement(370)),
+        ('ﾤ', crate::Replacement(1297)),
+        ('𝞼', crate::Replacement(805)),
+        ('𝘩', crate::Replacement(510)),
+        ('𞺥', crate::Replacement(1183)),
+        ('ꝰ', crate::Replacement(2785)),
+        ('𝚀', crate::Replacement(437)),
+        ('𝙾', crate::Replacement(429)),
+        ('㊂', crate::Replacement(1789)),
+        ('﹨', crate::Replacement(469)),
+        ('⼖', crate::Replacement(1898)),
+        ('𝑽', crate::Replacement(452)),
+        ('𞁦', crate::Replacement(864)),
+        ('﷼', crate::Replacement(1002)),
+        ('ㅔ', crate::Replacement(1361)),
+        ('ｹ', crate::Replacement(1610)),
+        ('㏇', crate::Replacement(372)),
+        ('ℼ', crate::Replacement(801)),
+        ('⒏', crate::Replacement(342)),
+        ('𝞇', crate::Replacement(818)),
+        ('㌳', crate::Replacement(1658)),
+        ('🄩', crate::Replacement(59)),
+        ('𞁋', crate::Replacement(880)),
+        ('𝚢', crate::Replacement(600)),
+        ('⽟', crate::Replacement(2333)),
+        ('𝟄', crate::Replacement(775)),
+        ('ㅈ', crate::Replacement(1315)),
+        ('🅇', crate::Replacement(462)),
+        ('𝕟', crate::Replacement(561)),
+        ('︳', crate::Replacement(472)),
+        ('𝑋', crate::Replacement(462)),
+        ('㆚', crate::Replacement(1804)),
+        ('ﷂ', crate::Replacement(929)),
+        ('ﴩ', crate::Replacement(1028)),
+        ('ﺥ', crate::Replacement(992)),
+        ('㍖', crate::Replacement(1709)),
+        ('𝜅', crate::Replacement(787)),
+        ('⾝', crate::Replacement(2628)),
+        ('ﷆ', crate::Replacement(1012)),
+        ('ㄵ', crate::Replacement(1386)),
+        ('₀', crate::Replacement(162)),
+        ('㏕', crate::Replacement(552)),
+        ('𝐐', crate::Replacement(437)),
+        ('ℜ', crate::Replacement(438)),
+        ('ﬁ', crate::Replacement(505)),
+        ('ￋ', crate::Replacement(1363)),
+        ('㌃', crate::Replacement(1574)),
+        ('ﳸ', crate::Replacement(1079)),
+        ('𝐠', crate::Replacement(508)),
+        ('𝐕', crate::Replacement(452)),
+        ('ﵜ', crate::Replacement(1009)),
+        ('𝘍', crate::Replacement(379)),
+        ('𝛊', crate::Replacement(782)),
+        ('㈛', crate::Replacement(116)),
+        ('ﱅ', crate::Replacement(1138)),
+        ('ﷳ', crate::Replacement(923)),
+        ('𝑘', crate::Replacement(523)),
+        ('ﾗ', crate::Replacement(1700)),
+        ('𝕣', crate::Replacement(580)),
+        ('𞸷', crate::Replacement(992)),
+        ('㊻', crate::Replacement(307)),
+        ('ϵ', crate::Replacement(775)),
+        ('ℯ', crate::Replacement(498)),
+        ('㎴', crate::Replacement(575)),
+        ('４', crate::Replacement(297)),
+        ('ᶻ', crate::Replacement(602)),
+        ('𝜙', crate::Replacement(816)),
+        ('￫', crate::Replacement(1480)),
+        ('Ⓟ', crate::Replacement(430)),
+        ('⒋', crate::Replacement(299)),
+        ('𝗡', crate::Replacement(425)),
+        ('𝕔', crate::Replacement(481)),
+        ('ﾠ', crate::Replacement(1355)),
+        ('∬', crate::Replacement(1495)),
+        ('ㆌ', crate::Replacement(1382)),
+        ('𞹙', crate::Replacement(1049)),
+        ('𝗯', crate::Replacement(479)),
+        ('ｐ', crate::Replacement(571)),
+        ('ᵍ', crate::Replacement(508)),
+        ('ⅰ', crate::Replacement(514)),
+        ('🈒', crate::Replacement(1920)),
+        ('𝙁', crate::Replacement(379)),
+        ('🄲', crate::Replacement(370)),
+        ('🄁', crate::Replacement(163)),
+        ('𝒽', crate::Replacement(510)),
+        ('⑵', crate::Replacement(25)),
+        ('𝙋', crate::Replacement(430)),
+        ('ﭖ', crate::Replacement(1216)),
+        ('𞁌', crate::Replacement(869)),
+        ('ⓢ', crate::Replacement(584)),
+        ('𝚬', crate::Replacement(727)),
+        ('ﾖ', crate::Replacement(1699)),
+        ('𝔇', crate::Replacement(374)),
+        ('ｓ', crate::Replacement(584)),
+        ('𞹏', crate::Replacement(1070)),
+        ('𝙩', crate::Replacement(588)),
+        ('ￓ', crate::Replacement(1369)),
+        ('ㅖ', crate::Replacement(1363)),
+        ('㏅', crate::Replacement(486)),
+        ('𞸬', crate::Replacement(1136)),
+        ('㏚', crate::Replacement(434)),
+        ('🉀', crate::Replacement(1559)),
+        ('㌓', crate::Replacement(1599)),
+        ('Ａ', crate::Replacement(364)),
+        ('༌', crate::Replacement(1285)),
+        ('𝓋', crate::Replacement(591)),
+        ('𞸰', crate::Replacement(1089)),
+        ('𝞝', crate::Replacement(743)),
+        ('⾆', crate::Replacement(2504)),
+        ('ﰜ', crate::Replacement(1005)),
+        ('𝜍', crate::Replacement(804)),
+        ('ﶫ', crate::Replacement(1053)),
+        ('㊰', crate::Replacement(1992)),
+        ('⼬', crate::Replacement(2035)),
+        ('㉁', crate::Replacement(125)),
+        ('𝟞', crate::Replacement(326)),
+        ('ᵐ', crate::Replacement(544)),
+        ('ᶼ', crate::Replacement(691)),
+        ('㌲', crate::Replacement(1657)),
+        ('ₔ', crate::Replacement(643)),
+        ('Ｋ', crate::Replacement(406)),
+        ('ﮠ', crate::Replacement(1238)),
+        ('𝓂', crate::Replacement(544)),
+        ('𝜜', crate::Replacement(720)),
+        ('⾜', crate::Replacement(2623)),
+        ('ﳃ', crate::Replacement(1101)),
+        ('ﾐ', crate::Replacement(1681)),
+        ('𝓠', crate::Replacement(437)),
+        ('ﾾ', crate::Replacement(1328)),
+        ('⑬', crate::Replacement(196)),
+        ('︿', crate::Replacement(1540)),
+        ('⾳', crate::Replacement(2711)),
+        ('ᴸ', crate::Replacement(410)),
+        ('𝓬', crate::Replacement(481)),
+        ('𞁆', crate::Replacement(862)),
+        ('𝔻', crate::Replacement(374)),
+        ('𝟆', crate::Replacement(787)),
+        ('𞁀', crate::Replacement(856)),
+        ('ﾀ', crate::Replacement(1627)),
+        ('ﮢ', crate::Replacement(1238)),
+        ('𝐏', crate::Replacement(430)),
+        ('ﶂ', crate::Replacement(1126)),
+        ('𞺗', crate::Replacement(992)),
+        ('㆕', crate::Replacement(1962)),
+        ('ﬠ', crate::Replacement(912)),
+        ('㋁', crate::Replacement(271)),
+        ('ㅮ', crate::Replacement(1333)),
+        ('𝛼', crate::Replacement(768)),
+        ('⽮', crate::Replacement(2391)),
+        ('㏱', crate::Replacement(218)),
+        ('𝔢', crate::Replacement(498)),
+        ('ㅺ', crate::Replacement(1344)),
+        ('𝓇', crate::Replacement(580)),
+        ('⼉', crate::Replacement(1836)),
+        ('ﮐ', crate::Replacement(1232)),
+        ('𝗠', crate::Replacement(415)),
+        ('\u{2006}', crate::Replacement(1)),
+        ('㋌', crate::Replacement(394)),
+        ('ｎ', crate::Replacement(561)),
+        ('𝕀', crate::Replacement(397)),
+        ('𝟨', crate::Replacement(326)),
+        ('ﵤ', crate::Replacement(1037)),
+        ('¾', crate::Replacement(291)),
+        ('₅', crate::Replacement(315)),
+        ('㊈', crate::Replacement(1805)),
+        ('𞁄', crate::Replacement(860)),
+        ('㌡', crate::Replacement(1621)),
+        ('½', crate::Replacement(226)),
+        ('𝝋', crate::Replacement(816)),
+        ('𝙮', crate::Replacement(600)),
+        ('𝝠', crate::Replacement(740)),
+        ('㆞', crate::Replacement(1967)),
+        ('𝗤', crate::Replacement(437)),
+        ('𝗦', crate::Replacement(440)),
+        ('ﻴ', crate::Replacement(1187)),
+        ('ﺣ', crate::Replacement(984)),
+        ('℁', crate::Replacement(477)),
+        ('ﶾ', crate::Replacement(976)),
+        ('ᵉ', crate::Replacement(498)),
+        ('ﵦ', crate::Replacement(1046)),
+        ('𝑪', crate::Replacement(370)),
+        ('🉑', crate::Replacement(1925)),
+        ('𝗅', crate::Replacement(537)),
+        ('﹄', crate::Replacement(1547)),
+        ('𝕏', crate::Replacement(462)),
+        ('ㄴ', crate::Replacement(1297)),
+        ('㉙', crate::Replacement(266)),
+        ('𝕱', crate::Replacement(379)),
+        ('ﰛ', crate::Replacement(995)),
+        ('ﾲ', crate::Replacement(1306)),
+        ('ʸ', crate::Replacement(600)),
+        ('𝛍', crate::Replacement(789)),
+        ('ﲌ', crate::Replacement(1169)),
+        ('ﴛ', crate::Replacement(990)),
+        ('ₐ', crate::Replacement(474)),
+        ('㌶', crate::Replacement(1666)),
+        ('𝖐', crate::Replacement(523)),
+        ('🈨', crate::Replacement(2143)),
+        ('ᶣ', crate::Replacement(653)),
+        ('Ⓚ', crate::Replacement(406)),
+        ('ﮦ', crate::Replacement(1240)),
+        ('Ⓡ', crate::Replacement(438)),
+        ('ﶎ', crate::Replacement(1149)),
+        ('🈰', crate::Replacement(2621)),
+        ('𝞅', crate::Replacement(816)),
+        ('𝟫', crate::Replacement(346)),
+        ('ϒ', crate::Replacement(750)),
+        ('𝗏', crate::Replacement(591)),
+        ('ﻝ', crate::Replacement(1118)),
+        ('𞀷', crate::Replacement(846)),
+        ('ﲔ', crate::Replacement(1199)),
+        ('ﾯ', crate::Replacement(1393)),
+        ('𝓎', crate::Replacement(600)),
+        ('𝙳', crate::Replacement(374)),
+        ('㍁', crate::Replacement(1673)),
+        ('㈯', crate::Replacement(134)),
+        ('ᶤ', crate::Replacement(656)),
+        ('⑥', crate::Replacement(326)),
+        ('𝗹', crate::Replacement(537)),
+        ('ﮚ', crate::Replacement(1235)),
+        ('㊯', crate::Replacement(1906)),
+        ('ﷻ', crate::Replacement(977)),
+        ('𝘥', crate::Replacement(490)),
+        ('𝙕', crate::Replacement(467)),
+        ('㈳', crate::Replacement(145)),
+        ('𝚶', crate::Replacement(744)),
+        ('㉣', crate::Replacement(1302)),
+        ('𝚉', crate::Replacement(467)),
+        ('𝙫', crate::Replacement(591)),
+        ('𐞎', crate::Replacement(642)),
+        ('𝛚', crate::Replacement(819)),
+        ('ￎ', crate::Replacement(1366)),
+        ('ㅚ', crate::Replacement(1367)),
+        ('𝘂', crate::Replacement(590)),
+        ('ﶬ', crate::Replacement(1123)),
+        ('ﻉ', crate::Replacement(1070)),
+        ('𝕮', crate::Replacement(370)),
+        ('ﳄ', crate::Replacement(1109)),
+        ('𝗄', crate::Replacement(523)),
+        ('𝘡', crate::Replacement(467)),
+        ('🄿', crate::Replacement(430)),
+        ('㋕', crate::Replacement(1590)),
+        ('𝛛', crate::Replacement(1486)),
+        ('𝐢', crate::Replacement(514)),
+        ('ᶞ', crate::Replacement(618)),
+        ('𝕠', crate::Replacement(569)),
+        ('ﲝ', crate::Replacement(928)),
+        ('ㅃ', crate::Replacement(1308)),
+        ('ᴺ', crate::Replacement(425)),
+        ('⼠', crate::Replacement(1982)),
+        ('Ⓢ', crate::Replacement(440)),
+        ('⒪', crate::Replacement(74)),
+        ('ﮫ', crate::Replacement(1239)),
+        ('𝙥', crate::Replacement(571)),
+        ('ﵷ', crate::Replacement(1075)),
+        ('𝙯', crate::Replacement(602)),
+        ('ﭯ', crate::Replacement(1231)),
+        ('㍐', crate::Replacement(1697)),
+        ('𝔤', crate::Replacement(508)),
+        ('𞸓', crate::Replacement(1000)),
+        ('㏲', crate::Replacement(222)),
+        ('㏯', crate::Replacement(210)),
+        ('𝘞', crate::Replacement(457)),
+        ('𐞹', crate::Replacement(2799)),
+        ('⒞', crate::Replacement(62)),
+        ('㎠', crate::Replacement(488)),
+        ('𞸟', crate::Replacement(1211)),
+        ('𞺰', crate::Replacement(1089)),
+        ('𝗗', crate::Replacement(374)),
+        ('𞸌', crate::Replacement(1136)),
+        ('𝗑', crate::Replacement(597)),
+        ('㍹', crate::Replacement(496)),
+        ('㈂', crate::Replacement(90)),
+        ('‑', crate::Replacement(1469)),
+        ('⽯', crate::Replacement(2392)),
+        ('﹘', crate::Replacement(1471)),
+        ('ᵘ', crate::Replacement(590)),
+        ('𝗚', crate::Replacement(386)),
+        ('〺', crate::Replacement(1903)),
+        ('⒑', crate::Replacement(182)),
+        ('ﴭ', crate::Replacement(1022)),
+        ('㏬', crate::Replacement(198)),
+        ('ﻒ', crate::Replacement(1089)),
+        ('𝐍', crate::Replacement(425)),
+        ('³', crate::Replacement(273)),
+        ('𝐱', crate::Replacement(597)),
+        ('ᵊ', crate::Replacement(643)),
+        ('ﶉ', crate::Replacement(1144)),
+        ('𞁇', crate::Replacement(864)),
+        ('𝙛', crate::Replacement(501)),
+        ('⼲', crate::Replacement(2058)),
+        ('𝒩', crate::Replacement(425)),
+        ('ｮ', crate::Replacement(1698)),
+        ('㎱', crate::Replacement(568)),
+        ('𝛝', crate::Replacement(781)),
+        ('㌛', crate::Replacement(1609)),
+        ('𝝑', crate::Replacement(781)),
+        ('Ⅻ', crate::Replacement(464)),
+        ('𝟉', crate::Replacement(801)),
+        ('ﭓ', crate::Replacement(1215)),
+        ('㊅', crate::Replacement(1847)),
+        ('⾪', crate::Replacement(2689)),
+        ('㎀', crate::Replacement(573)),
+        ('𝝜', crate::Replacement(729)),
+        ('𝒫', crate::Replacement(430)),
+        ('Ｅ', crate::Replacement(378)),
+        ('㌮', crate::Replacement(1652)),
+        ('𝜧', crate::Replacement(741)),
+        ('ｖ', crate::Replacement(591)),
+        ('Ｏ', crate::Replacement(429)),
+        ('𝘳', crate::Replacement(580)),
+        ('㋻', crate::Replacement(1711)),
+        ('﹒', crate::Replacement(158)),
+        ('㍰', crate::Replacement(257)),
+        ('ﵬ', crate::Replacement(1031)),
+        ('𝟜', crate::Replacement(297)),
+        ('𝚼', crate::Replacement(750)),
+        ('ﾮ', crate::Replacement(1392)),
+        ('ﳭ', crate::Replacement(1130)),
+        ('𝔦', crate::Replacement(514)),
+        ('𝝵', crate::Replacement(776)),
+        ('𞁨', crate::Replacement(869)),
+        ('ﻤ', crate::Replacement(1136)),
+        ('゛', crate::Replacement(2)),
+        ('𝖷', crate::Replacement(462)),
+        ('ª', crate::Replacement(474)),
+        ('㋓', crate::Replacement(1583)),
+        ('🉂', crate::Replacement(1554)),
+        ('㊪', crate::Replacement(2018)),
+        ('𐞂', crate::Replacement(716)),
+        ('ﱴ', crate::Replacement(962)),
+        ('⅒', crate::Replacement(225)),
+        ('ﭐ', crate::Replacement(1212)),
+        ('ㅰ', crate::Replacement(1402)),
+        ('𝔧', crate::Replacement(521)),
+        ('𝝽', crate::Replacement(799)),
+        ('🈯', crate::Replacement(2140)),
+        ('𝐪', crate::Replacement(579)),
+        ('𞸱', crate::Replacement(1035)),
+        ('𝕗', crate::Replacement(501)),
+        ('𝞘', crate::Replacement(734)),
+        ('ﺘ', crate::Replacement(940)),
+        ('ﾏ', crate::Replacement(1675)),
+        ('ᴴ', crate::Replacement(391)),
+        ('𝜓', crate::Replacement(818)),
+        ('𝘈', crate::Replacement(364)),
+        ('ﳺ', crate::Replacement(1087)),
+        ('ﬄ', crate::Replacement(504)),
+        ('𝘊', crate::Replacement(370)),
+        ('ﱮ', crate::Replacement(937)),
+        ('𝗊', crate::Replacement(579)),
+        ('︼', crate::Replacement(1549)),
+        ('ﾍ', crate::Replacement(1660)),
+        ('﹇', crate::Replacement(468)),
+        ('ﲕ', crate::Replacement(1202)),
+        ('ﴌ', crate::Replacement(1029)),
+        ('￩', crate::Replacement(1478)),
+        ('ᴮ', crate::Replacement(368)),
+        ('🄜', crate::Replacement(46)),
+        ('𝐮', crate::Replacement(590)),
+        ('𝞔', crate::Replacement(727)),
+        ('𝒴', crate::Replacement(465)),
+        ('⼟', crate::Replacement(1966)),
+        ('㎺', crate::Replacement(576)),
+        ('𝚁', crate::Replacement(438)),
+        ('𝛾', crate::Replacement(773)),
+        ('⒐', crate::Replacement(348)),
+        ('𝓫', crate::Replacement(479)),
+        ('ﳓ', crate::Replacement(1162)),
+        ('⑫', crate::Replacement(191)),
+        ('ʳ', crate::Replacement(580)),
+        ('𝓭', crate::Replacement(490)),
+        ('𝛎', crate::Replacement(798)),
+        ('𝚰', crate::Replacement(734)),
+        ('㉄', crate::Replacement(1943)),
+        ('⾠', crate::Replacement(2638)),
+        ('𝑫', crate::Replacement(374)),
+        ('ㅳ', crate::Replacement(1336)),
+        ('𝐀', crate::Replacement(364)),
+        ('⾞', crate::Replacement(2629)),
+        ('𝗨', crate::Replacement(451)),
+        ('𝖽', crate::Replacement(490)),
+        ('𝝃', crate::Replacement(799)),
+        ('㎚', crate::Replacement(567)),
+        ('𝝝', crate::Replacement(733)),
+        ('⾍', crate::Replacement(2556)),
+        ('ﲱ', crate::Replacement(1036)),
+        ('Ǌ', crate::Replacement(426)),
+        ('𝝻', crate::Replacement(789)),
+        ('⼈', crate::Replacement(1814)),
+        ('⽄', crate::Replacement(2170)),
+        ('㋑', crate::Replacement(1576)),
+        ('🄒', crate::Replacement(36)),
+        ('𝜤', crate::Replacement(734)),
+        ('㍧', crate::Replacement(207)),
+        ('𝚙', crate::Replacement(571)),
+        ('）', crate::Replacement(153)),
+        ('⽹', crate::Replacement(2468)),
+        ('ｬ', crate::Replacement(1691)),
+        ('ㆅ', crate::Replacement(1353)),
+        ('ǋ', crate::Replacement(427)),
+        ('︵', crate::Replacement(13)),
+        ('𝘷', crate::Replacement(591)),
+        ('ﮕ', crate::Replacement(1234)),
+        ('ﶮ', crate::Replacement(1192)),
+        ('𝝟', crate::Replacement(739)),
+        ('ﳆ', crate::Replacement(1111)),
+        ('ﾢ', crate::Replacement(1296)),
+        ('㌿', crate::Replacement(1672)),
+        ('㌠', crate::Replacement(1619)),
+        ('ᵸ', crate::Replacement(851)),
+        ('𝑴', crate::Replacement(415)),
+        ('ﮈ', crate::Replacement(1223)),
+        ('𞀸', crate::Replacement(847)),
+        ('㋨', crate::Replacement(1643)),
+        ('ꭞ', crate::Replacement(659)),
+        ('ﺫ', crate::Replacement(999)),
+        ('ⅲ', crate::Replacement(516)),
+        ('ㅵ', crate::Replacement(1339)),
+        ('𝘬', crate::Replacement(523)),
+        ('ᴱ', crate::Replacement(378)),
+        ('㌀', crate::Replacement(1571)),
+        ('ℱ', crate::Replacement(379)),
+        ('㍸', crate::Replacement(495)),
+        ('ﰵ', crate::Replacement(1105)),
+        ('ჼ', crate::Replacement(1293)),
+        ('ﰖ', crate::Replacement(978)),
+        ('𝝯', crate::Replacement(1488)),
+        ('㉟', crate::Replacement(286)),
+        ('﹈', crate::Replacement(470)),
+        ('㎾', crate::Replacement(528)),
+        ('Ⓠ', crate::Replacement(437)),
+        ('𞹗', crate::Replacement(992)),
+        ('ﻰ', crate::Replacement(1186)),
+        ('𝔪', crate::Replacement(544)),
+        ('ﱽ', crate::Replacement(1097)),
+        ('ⅹ', crate::Replacement(597)),
+        ('𞺅', crate::Replacement(1183)),
+        ('ﭙ', crate::Replacement(1216)),
+        ('𝞧', crate::Replacement(761)),
+        ('⑰', crate::Replacement(212)),
+        ('𝘲', crate::Replacement(579)),
+        ('🄫', crate::Replacement(370)),
+        ('𝟐', crate::Replacement(237)),
+        ('𝞣', crate::Replacement(749)),
+        ('ﬨ', crate::Replacement(919)),
+        ('ﻼ', crate::Replacement(1119)),
+        ('㉘', crate::Replacement(264)),
+        ('⽙', crate::Replacement(2315)),
+        ('㈣', crate::Replacement(133)),
+        ('⁰', crate::Replacement(162)),
+        ('Ｙ', crate::Replacement(465)),
+        ('ⓠ', crate::Replacement(579)),
+        ('ﶧ', crate::Replacement(980)),
+        ('㋶', crate::Replacement(1700)),
+        ('ｗ', crate::Replacement(595)),
+        ('𝝲', crate::Replacement(773)),
+        ('𝙭', crate::Replacement(597)),
+        ('𝔙', crate::Replacement(452)),
+        ('𝓪', crate::Replacement(474)),
+        ('℀', crate::Replacement(476)),
+        ('⅗', crate::Replacement(292)),
+        ('ﾝ', crate::Replacement(1716)),
+        ('𝒢', crate::Replacement(386)),
+        ('𝓝', crate::Replacement(425)),
+        ('𝘔', crate::Replacement(415)),
+        ('㏻', crate::Replacement(265)),
+        ('𝛙', crate::Replacement(818)),
+        ('ﷹ', crate::Replacement(1042)),
+        ('㌴', crate::Replacement(1656)),
+        ('⼺', crate::Replacement(2082)),
+        ('𝞜', crate::Replacement(742)),
+        ('𝚠', crate::Replacement(595)),
+        ('ⅇ', crate::Replacement(498)),
+        ('⾉', crate::Replacement(2508)),
+        ('𝜴', crate::Replacement(762)),
+        ('ㅪ', crate::Replacement(1397)),
+        ('𞸖', crate::Replacement(964)),
+        ('ᵈ', crate::Replacement(490)),
+        ('\u{2008}', crate::Replacement(1)),
+        ('𝔠', crate::Replacement(481)),
+        ('㉓', crate::Replacement(252)),
+        ('𝛆', crate::Replacement(775)),
+        ('𐞤', crate::Replacement(671)),
+        ('㏂', crate::Replacement(475)),
+        ('ﰞ', crate::Replacement(1010)),
+        ('ㅆ', crate::Replacement(1311)),
+        ('🅎', crate::Replacement(433)),
+        ('ￃ', crate::Replacement(1357)),
+        ('㋍', crate::Replacement(500)),
+        ('𞺯', crate::Replacement(1070)),
+        ('𝖌', crate::Replacement(508)),
+        ('ㄱ', crate::Replacement(1294)),
+        ('ﾳ', crate::Replacement(1308)),
+        ('ﭹ', crate::Replacement(1219)),
+        ('\u{205f}', crate::Replacement(1)),
+        ('ﶌ', crate::Replacement(1139)),
+        ('ﷷ', crate::Replacement(1073)),
+        ('🄟', crate::Replacement(49)),
+        ('ﻗ', crate::Replacement(1098)),
+        ('𝕨', crate::Replacement(595)),
+        ('㌣', crate::Replacement(1625)),
+        ('𝟒', crate::Replacement(297)),
+        ('ﱙ', crate::Replacement(1202)),
+        ('⒘', crate::Replacement(213)),
+        ('⾺', crate::Replacement(2731)),
+        ('𐞞', crate::Replacement(662)),
+        ('𝔶', crate::Replacement(600)),
+        ('ｦ', crate::Replacement(1715)),
+        ('🄺', crate::Replacement(406)),
+        ('⼁', crate::Replacement(1795)),
+        ('₋', crate::Replacement(1492)),
+        ('𝑇', crate::Replacement(446)),
+        ('ᶢ', crate::Replacement(649)),
+        ('㈜', crate::Replacement(106)),
+        ('⽕', crate::Replacement(2292)),
+        ('🯴', crate::Replacement(297)),
+        ('⽏', crate::Replacement(2240)),
+        ('ﳾ', crate::Replacement(1034)),
+        ('ℰ', crate::Replacement(378)),
+        ('㎬', crate::Replacement(389)),
+        ('㈚', crate::Replacement(114)),
+        ('ꚝ', crate::Replacement(865)),
+        ('𞺶', crate::Replacement(964)),
+        ('ᶭ', crate::Replacement(664)),
+        ('㉱', crate::Replacement(1303)),
+        ('ᴷ', crate::Replacement(406)),
+        ('⒰', crate::Replacement(80)),
+        ('ﬃ', crate::Replacement(503)),
+        ('ﵵ', crate::Replacement(1072)),
+        ('𝙽', crate::Replacement(425)),
+        ('ᶪ', crate::Replacement(1413)),
+        ('㎟', crate::Replacement(555)),
+        ('㋵', crate::Replacement(1699)),
+        ('⅓', crate::Replacement(227)),
+        ('㉬', crate::Replacement(1326)),
+        ('︘', crate::Replacement(1564)),
+        ('Ｒ', crate::Replacement(438)),
+        ('𞸩', crate::Replacement(1187)),
+        ('｟', crate::Replacement(1533)),
+        ('𝖳', crate::Replacement(446)),
+        ('﹐', crate::Replacement(156)),
+        ('ﬗ', crate::Replacement(892)),
+        ('ﾇ', crate::Replacement(1641)),
+        ('𝚐', crate::Replacement(508)),
+        ('ﺜ', crate::Replacement(964)),
+        ('🄶', crate::Replacement(386)),
+        ('ﺀ', crate::Replacement(921)),
+        ('𝐛', crate::Replacement(479)),
+        ('𝚟', crate::Replacement(591)),
+        ('Ⅸ', crate::Replacement(403)),
+        ('𞸵', crate::Replacement(940)),
+        ('𝑱', crate::Replacement(404)),
+        ('𝙵', crate::Replacement(379)),
+        ('⽧', crate::Replacement(2366)),
+        ('ﰿ', crate::Replacement(1120)),
+        ('𞺄', crate::Replacement(1176)),
+        ('ﭿ', crate::Replacement(1222)),
+        ('㊢', crate::Replacement(1856)),
+        ('𝙑', crate::Replacement(452)),
+        ('ㅗ', crate::Replacement(1364)),
+        ('🅁', crate::Replacement(438)),
+        ('🅆', crate::Replacement(457)),
+        ('㈴', crate::Replacement(131)),
+        ('𝑣', crate::Replacement(591)),
+        ('㋔', crate::Replacement(1587)),
+        ('𝛗', crate::Replacement(816)),
+        ('⾵', crate::Replacement(2720)),
+        ('𝒦', crate::Replacement(406)),
+        ('𝜁', crate::Replacement(776)),
+        ('ﱯ', crate::Replacement(938)),
+        ('ﰫ', crate::Replacement(1081)),
+        ('𝖰', crate::Replacement(437)),
+        ('ㅾ', crate::Replacement(1348)),
+        ('㍝', crate::Replacement(325)),
+        ('𞺃', crate::Replacement(998)),
+        ('㌝', crate::Replacement(1615)),
+        ('ﾃ', crate::Replacement(1632)),
+        ('𝚡', crate::Replacement(597)),
+        ('㌑', crate::Replacement(1598)),
+        ('𝞓', crate::Replacement(726)),
+        ('𝙖', crate::Replacement(474)),
+        ('𝑔', crate::Replacement(508)),
+        ('𝖣', crate::Replacement(374)),
+        ('ㆆ', crate::Replacement(1354)),
+        ('ﮩ', crate::Replacement(1240)),
+        ('﹥', crate::Replacement(359)),
+        ('𝑍', crate::Replacement(467)),
+        ('𝗁', crate::Replacement(510)),
+        ('𝛫', crate::Replacement(739)),
+        ('ﾺ', crate::Replacement(1319)),
+        ('𝒥', crate::Replacement(404)),
+        ('𞹍', crate::Replacement(1156)),
+        ('⑺', crate::Replacement(31)),
+        ('ﾘ', crate::Replacement(1701)),
+        ('ﾓ', crate::Replacement(1690)),
+        ('㌖', crate::Replacement(1603)),
+        ('⅚', crate::Replacement(321)),
+        ('？', crate::Replacement(360)),
+        ('𝟽', crate::Replacement(332)),
+        ('𐞉', crate::Replacement(705)),
+        ('ﳞ', crate::Replacement(1200)),
+        ('ᶸ', crate::Replacement(1409)),
+        ('𝟸', crate::Replacement(237)),
+        ('ﷰ', crate::Replacement(1044)),
+        ('ﳬ', crate::Replacement(1113)),
+        ('ϱ', crate::Replacement(802)),
+        ('⼄', crate::Replacement(1804)),
+        ('𝑃', crate::Replacement(430)),
+        ('㉻', crate::Replacement(1329)),
+        ('𞺁', crate::Replacement(926)),
+        ('㌤', crate::Replacement(1628)),
+        ('ﲨ', crate::Replacement(978)),
+        ('𞸅', crate::Replacement(1183)),
+        ('ﳝ', crate::Replacement(1196)),
+        ('ﭱ', crate::Replacement(1231)),
+        ('ﮛ', crate::Replacement(1235)),
+        ('⽳', crate::Replacement(2426)),
+        ('𝖦', crate::Replacement(386)),
+        ('𝙔', crate::Replacement(465)),
+        ('ﯾ', crate::Replacement(1248)),
+        ('⾴', crate::Replacement(2713)),
+        ('⼆', crate::Replacement(1809)),
+        ('𝜫', crate::Replacement(745)),
+        ('🈢', crate::Replacement(2353)),
+        ('⒉', crate::Replacement(239)),
+        ('⽣', crate::Replacement(2353)),
+        ('⑷', crate::Replacement(28)),
+        ('㏧', crate::Replacement(343)),
+        ('𝐥', crate::Replacement(537)),
+        ('ￗ', crate::Replacement(1373)),
+        ('𝛞', crate::Replacement(787)),
+        ('𐞚', crate::Replacement(711)),
+        ('𝖼', crate::Replacement(481)),
+        ('𝝬', crate::Replacement(760)),
+        ('ᶜ', crate::Replacement(481)),
+        ('𝐾', crate::Replacement(406)),
+        ('ｈ', crate::Replacement(510)),
+        ('ﺢ', crate::Replacement(984)),
+        ('𝟗', crate::Replacement(346)),
+        ('ˡ', crate::Replacement(537)),
+        ('𝞦', crate::Replacement(760)),
+        ('🈛', crate::Replacement(2169)),
+        ('⾤', crate::Replacement(2661)),
+        ('㌗', crate::Replacement(1604)),
+        ('ﴆ', crate::Replacement(1048)),
+        ('⒴', crate::Replacement(84)),
+        ('ﶝ', crate::Replacement(1197)),
+        ('ᶬ', crate::Replacement(665)),
+        ('⑭', crate::Replacement(200)),
+        ('⩶', crate::Replacement(358)),
+        ('ϴ', crate::Replacement(733)),
+        ('㍻', crate::Replacement(2059)),
+        ('𝟮', crate::Replacement(237)),
+        ('𞁊', crate::Replacement(2783)),
+        ('ﰘ', crate::Replacement(987)),
+        ('ㅫ', crate::Replacement(1398)),
+        ('㋅', crate::Replacement(330)),
+        ('𝑺', crate::Replacement(440)),
+        ('﹕', crate::Replacement(352)),
+        ('ﭢ', crate::Replacement(1217)),
+        ('ﱔ', crate::Replacement(1182)),
+        ('ℙ', crate::Replacement(430)),
+        ('％', crate::Replacement(10)),
+        ('ᵌ', crate::Replacement(645)),
+        ('㈽', crate::Replacement(124)),
+        ('ⓑ', crate::Replacement(479)),
+        ('𝚃', crate::Replacement(446)),
+        ('ﲀ', crate::Replacement(1108)),
+        ('㌄', crate::Replacement(1577)),
+        ('𝗋', crate::Replacement(580)),
+        ('𝝼', crate::Replacement(798)),
+        ('ℓ', crate::Replacement(537)),
+        ('㆒', crate::Replacement(1786)),
+        ('ⓓ', crate::Replacement(490)),
+        ('ˢ', crate::Replacement(584)),
+        ('＜', crate::Replacement(355)),
+        ('ﾙ', crate::Replacement(1704)),
+        ('𐞥', crate::Replacement(579)),
+        ('𝛮', crate::Replacement(742)),
+        ('𞁓', crate::Replacement(841)),
+        ('ᴾ', crate::Replacement(430)),
+        ('㊺', crate::Replacement(306)),
+        ('⑤', crate::Replacement(315)),
+        ('⒮', crate::Replacement(78)),
+        ('㏾', crate::Replacement(282)),
+        ('ﰠ', crate::Replacement(1036)),
+        ('ﱸ', crate::Replacement(968)),
+        ('ﴞ', crate::Replacement(983)),
+        ('ﯨ', crate::Replacement(1186)),
+        ('ﵫ', crate::Replacement(1030)),
+        ('㎽', crate::Replacement(549)),
+        ('🄢', crate::Replacement(52)),
+        ('𝖺', crate::Replacement(474)),
+        ('🅬', crate::Replacement(421)),
+        ('Ⓦ', crate::Replacement(457)),
+        ('⼍', crate::Replacement(1854)),
+        ('𝑊', crate::Replacement(457)),
+        ('𞺑', crate::Replacement(1035)),
+        ('ᴹ', crate::Replacement(415)),
+        ('𞸂', crate::Replacement(973)),
+        ('𞹵', crate::Replacement(940)),
+        ('𝞡', crate::Replacement(733)),
+        ('ﴄ', crate::Replacement(997)),
+        ('ﶱ', crate::Replacement(1153)),
+        ('⼞', crate::Replacement(1961)),
+        ('㊎', crate::Replacement(2664)),
+        ('ｋ', crate::Replacement(523)),
+        ('⽈', crate::Replacement(2189)),
+        ('²', crate::Replacement(237)),
+        ('㎕', crate::Replacement(795)),
+        ('🄳', crate::Replacement(374)),
+        ('⿑', crate::Replacement(2775)),
+        ('Ｑ', crate::Replacement(437)),
+        ('𝐟', crate::Replacement(501)),
+        ('㊿', crate::Replacement(320)),
+        ('ﳑ', crate::Replacement(1152)),
+        ('𝐶', crate::Replacement(370)),
+        ('𞹴', crate::Replacement(1021)),
+        ('⼡', crate::Replacement(1987)),
+        ('ﺠ', crate::Replacement(973)),
+        ('︙', crate::Replacement(160)),
+        ('𞸆', crate::Replacement(1003)),
+        ('㊮', crate::Replacement(2615)),
+        ('ￊ', crate::Replacement(1362)),
+        ('ﷅ', crate::Replacement(1046)),
+        ('㆙', crate::Replacement(2357)),
+        ('𝞮', crate::Replacement(775)),
+        ('𞁁', crate::Replacement(857)),
+        ('𝚓', crate::Replacement(521)),
+        ('㉕', crate::Replacement(258)),
+    ],
+};
+
+pub const FRACTION_MAPPING: crate::phf::CharMap<crate::Replacement> = crate::phf::CharMap {
+    range: '¼'..='↉',
+    key: 12913932095322966823,
+    disps: &[(3, 1), (0, 12), (3, 9), (2, 5), (0, 0)],
+    entries: &[
+        ('⅐', crate::Replacement(178)),
+        ('⅙', crate::Replacement(177)),
+        ('⅑', crate::Replacement(180)),
+        ('⅜', crate::Replacement(278)),
+        ('⅕', crate::Replacement(176)),
+        ('⅚', crate::Replacement(318)),
+        ('⅛', crate::Replacement(179)),
+        ('⅝', crate::Replacement(319)),
+        ('↉', crate::Replacement(165)),
+        ('⅓', crate::Replacement(174)),
+        ('¼', crate::Replacement(175)),
+        ('½', crate::Replacement(173)),
+        ('⅘', crate::Replacement(300)),
+        ('⁄', crate::Replacement(161)),
+        ('⅔', crate::Replacement(240)),
+        ('⅟', crate::Replacement(171)),
+        ('¾', crate::Replacement(276)),
+        ('⅗', crate::Replacement(277)),
+        ('⅖', crate::Replacement(241)),
+        ('⅒', crate::Replacement(172)),
+        ('⅞', crate::Replacement(335)),
+    ],
+};
+
+pub const MATH_ALPHA_MAPPING: crate::phf::CharMap<crate::Replacement> = crate::phf::CharMap {
+    range: 'ℂ'..='𝟿',
+    key: 12913932095322966823,
+    disps: &[
+        (0, 52),
+        (0, 340),
+        (0, 422),
+        (0, 1),
+        (0, 273),
+        (0, 113),
+        (0, 40),
+        (0, 555),
+        (0, 2),
+        (0, 6),
+        (0, 27),
+        (0, 64),
+        (0, 6),
+        (0, 51),
+        (0, 40),
+        (0, 19),
+        (0, 30),
+        (0, 10),
+        (0, 45),
+        (0, 1),
+        (0, 319),
+        (1, 9),
+        (0, 181),
+        (0, 17),
+        (0, 7),
+        (0, 14),
+        (0, 6),
+        (0, 0),
+        (0, 446),
+        (0, 207),
+        (0, 26),
+        (0, 335),
+        (0, 1),
+        (0, 379),
+        (0, 54),
+        (0, 337),
+        (2, 0),
+        (0, 1),
+        (0, 31),
+        (3, 210),
+        (1, 22),
+        (1, 1),
+        (0, 2),
+        (0, 0),
+        (0, 17),
+        (0, 272),
+        (0, 41),
+        (0, 260),
+        (0, 2),
+        (0, 245),
+        (1, 455),
+        (0, 5),
+        (0, 200),
+        (0, 3),
+        (0, 690),
+        (0, 33),
+        (0, 230),
+        (0, 24),
+        (0, 321),
+        (0, 208),
+        (0, 620),
+        (0, 201),
+        (0, 677),
+        (0, 586),
+        (0, 0),
+        (1, 198),
+        (0, 9),
+        (0, 172),
+        (0, 15),
+        (0, 30),
+        (0, 819),
+        (0, 67),
+        (1, 432),
+        (0, 72),
+        (0, 9),
+        (0, 943),
+        (0, 443),
+        (0, 0),
+        (4, 763),
+        (0, 1),
+        (0, 313),
+        (0, 0),
+        (0, 166),
+        (0, 107),
+        (0, 15),
+        (0, 71),
+        (0, 10),
+        (0, 19),
+        (0, 12),
+        (0, 0),
+        (0, 131),
+        (0, 2),
+        (0, 569),
+        (0, 521),
+        (0, 352),
+        (2, 803),
+        (1, 653),
+        (0, 23),
+        (0, 296),
+        (0, 727),
+        (0, 7),
+        (0, 652),
+        (0, 302),
+        (0, 486),
+        (0, 266),
+        (0, 19),
+        (3, 1008),
+        (0, 2),
+        (0, 10),
+        (0, 343),
+        (0, 4),
+        (0, 8),
+        (0, 3),
+        (0, 7),
+        (1, 139),
+        (3, 427),
+        (0, 9),
+        (0, 24),
+        (0, 0),
+        (0, 29),
+        (0, 10),
+        (0, 35),
+        (0, 25),
+        (0, 102),
+        (0, 47),
+        (4, 597),
+        (2, 119),
+        (1, 637),
+        (0, 1),
+        (0, 556),
+        (1, 409),
+        (0, 11),
+        (0, 85),
+        (0, 271),
+        (0, 12),
+        (0, 0),
+        (0, 1),
+        (3, 874),
+        (0, 0),
+        (8, 309),
+        (0, 372),
+        (0, 141),
+        (1, 531),
+        (0, 56),
+        (0, 48),
+        (1, 759),
+        (0, 33),
+        (2, 190),
+        (0, 8),
+        (1, 761),
+        (0, 13),
+        (0, 2),
+        (6, 216),
+        (0, 0),
+        (0, 352),
+        (0, 20),
+        (0, 584),
+        (0, 42),
+        (1, 124),
+        (0, 674),
+        (0, 30),
+        (0, 28),
+        (0, 2),
+        (3, 480),
+        (1, 992),
+        (0, 5),
+        (2, 1015),
+        (0, 158),
+        (0, 486),
+        (0, 370),
+        (0, 8),
+        (0, 19),
+        (1, 673),
+        (8, 352),
+        (0, 338),
+        (0, 0),
+        (0, 16),
+        (1, 33),
+        (0, 853),
+        (0, 138),
+        (1, 267),
+        (2, 1000),
+        (1, 71),
+        (0, 446),
+        (0, 27),
+        (0, 5),
+        (4, 298),
+        (1, 843),
+        (3, 755),
+        (2, 71),
+        (0, 489),
+        (0, 669),
+        (0, 31),
+        (9, 312),
+        (1, 359),
+        (0, 94),
+        (0, 579),
+        (6, 990),
+        (0, 261),
+        (0, 375),
+        (0, 447),
+        (27, 490),
+        (0, 30),
+        (0, 69),
+        (6, 732),
+        (0, 0),
+    ],
+    entries: &[
+        ('𝜡', crate::Replacement(728)),
+        ('𝒏', crate::Replacement(561)),
+        ('𝚰', crate::Replacement(734)),
+        ('𝜾', crate::Replacement(782)),
+        ('𝑼', crate::Replacement(451)),
+        ('𝖽', crate::Replacement(490)),
+        ('𝙸', crate::Replacement(397)),
+        ('𝜪', crate::Replacement(744)),
+        ('𝟡', crate::Replacement(346)),
+        ('𝛣', crate::Replacement(724)),
+        ('𝕾', crate::Replacement(440)),
+        ('𝙘', crate::Replacement(481)),
+        ('𝓝', crate::Replacement(425)),
+        ('𝗬', crate::Replacement(465)),
+        ('𝛌', crate::Replacement(788)),
+        ('𝟵', crate::Replacement(346)),
+        ('𝐳', crate::Replacement(602)),
+        ('𝒌', crate::Replacement(523)),
+        ('𝗊', crate::Replacement(579)),
+        ('𝘓', crate::Replacement(410)),
+        ('𝞻', crate::Replacement(804)),
+        ('𝑦', crate::Replacement(600)),
+        ('𝔏', crate::Replacement(410)),
+        ('𝘳', crate::Replacement(580)),
+        ('𝕋', crate::Replacement(446)),
+        ('𝝘', crate::Replacement(725)),
+        ('𝗻', crate::Replacement(561)),
+        ('𝖫', crate::Replacement(410)),
+        ('𝕊', crate::Replacement(440)),
+        ('𝐤', crate::Replacement(523)),
+        ('𝐼', crate::Replacement(397)),
+        ('𝔲', crate::Replacement(590)),
+        ('𝜸', crate::Replacement(773)),
+        ('𝜍', crate::Replacement(804)),
+        ('𝙻', crate::Replacement(410)),
+        ('𝞣', crate::Replacement(749)),
+        ('𝜵', crate::Replacement(1488)),
+        ('𝓜', crate::Replacement(415)),
+        ('𝙮', crate::Replacement(600)),
+        ('𝗉', crate::Replacement(571)),
+        ('𝟂', crate::Replacement(819)),
+        ('𝟮', crate::Replacement(237)),
+        ('𝞐', crate::Replacement(720)),
+        ('𝔠', crate::Replacement(481)),
+        ('𝙶', crate::Replacement(386)),
+        ('𝛄', crate::Replacement(773)),
+        ('𝚠', crate::Replacement(595)),
+        ('𝚊', crate::Replacement(474)),
+        ('𝐑', crate::Replacement(438)),
+        ('𝗣', crate::Replacement(430)),
+        ('ℒ', crate::Replacement(410)),
+        ('𝕃', crate::Replacement(410)),
+        ('𝛯', crate::Replacement(743)),
+        ('𝛽', crate::Replacement(772)),
+        ('𝐏', crate::Replacement(430)),
+        ('𝚓', crate::Replacement(521)),
+        ('𝓧', crate::Replacement(462)),
+        ('𝘜', crate::Replacement(451)),
+        ('𝕲', crate::Replacement(386)),
+        ('𝞶', crate::Replacement(798)),
+        ('𝕣', crate::Replacement(580)),
+        ('ℯ', crate::Replacement(498)),
+        ('𝙯', crate::Replacement(602)),
+        ('𝖓', crate::Replacement(561)),
+        ('𝞮', crate::Replacement(775)),
+        ('𝛐', crate::Replacement(800)),
+        ('𝔒', crate::Replacement(429)),
+        ('𝕎', crate::Replacement(457)),
+        ('𝘩', crate::Replacement(510)),
+        ('𝔦', crate::Replacement(514)),
+        ('𝔴', crate::Replacement(595)),
+        ('𝔟', crate::Replacement(479)),
+        ('𝜱', crate::Replacement(759)),
+        ('𝓾', crate::Replacement(590)),
+        ('𝐵', crate::Replacement(368)),
+        ('𝙗', crate::Replacement(479)),
+        ('𝗒', crate::Replacement(600)),
+        ('𝖇', crate::Replacement(479)),
+        ('𝟔', crate::Replacement(326)),
+        ('𝕶', crate::Replacement(406)),
+        ('𝛺', crate::Replacement(762)),
+        ('𝗥', crate::Replacement(438)),
+        ('𝞥', crate::Replacement(759)),
+        ('𝟑', crate::Replacement(273)),
+        ('𝓉', crate::Replacement(588)),
+        ('𝙍', crate::Replacement(438)),
+        ('𝐩', crate::Replacement(571)),
+        ('𝛆', crate::Replacement(775)),
+        ('𝙵', crate::Replacement(379)),
+        ('𝟙', crate::Replacement(168)),
+        ('𝛏', crate::Replacement(799)),
+        ('𝖌', crate::Replacement(508)),
+        ('ℹ', crate::Replacement(514)),
+        ('𝞰', crate::Replacement(777)),
+        ('𝘉', crate::Replacement(368)),
+        ('𝒪', crate::Replacement(429)),
+        ('𝟄', crate::Replacement(775)),
+        ('𝕘', crate::Replacement(508)),
+        ('𝐄', crate::Replacement(378)),
+        ('𝟤', crate::Replacement(237)),
+        ('𝘎', crate::Replacement(386)),
+        ('𝘂', crate::Replacement(590)),
+        ('𝕬', crate::Replacement(364)),
+        ('𝖖', crate::Replacement(579)),
+        ('𝕒', crate::Replacement(474)),
+        ('𝒾', crate::Replacement(514)),
+        ('𝞛', crate::Replacement(741)),
+        ('𝘵', crate::Replacement(588)),
+        ('𝘕', crate::Replacement(425)),
+        ('𝚼', crate::Replacement(750)),
+        ('𝞂', crate::Replacement(805)),
+        ('𝚞', crate::Replacement(590)),
+        ('𝑾', crate::Replacement(457)),
+        ('𝕔', crate::Replacement(481)),
+        ('𝓩', crate::Replacement(467)),
+        ('ℚ', crate::Replacement(437)),
+        ('𝐛', crate::Replacement(479)),
+        ('𝗀', crate::Replacement(508)),
+        ('𝗯', crate::Replacement(479)),
+        ('ℎ', crate::Replacement(510)),
+        ('𝟩', crate::Replacement(332)),
+        ('𝗙', crate::Replacement(379)),
+        ('ℤ', crate::Replacement(467)),
+        ('𝑟', crate::Replacement(580)),
+        ('𝓛', crate::Replacement(410)),
+        ('𝗱', crate::Replacement(490)),
+        ('𝕻', crate::Replacement(430)),
+        ('𝘌', crate::Replacement(378)),
+        ('𝒬', crate::Replacement(437)),
+        ('𝕄', crate::Replacement(415)),
+        ('𝜎', crate::Replacement(805)),
+        ('𝖎', crate::Replacement(514)),
+        ('𝐙', crate::Replacement(467)),
+        ('𝛡', crate::Replacement(801)),
+        ('𝛇', crate::Replacement(776)),
+        ('𝔗', crate::Replacement(446)),
+        ('𝖗', crate::Replacement(580)),
+        ('𝒮', crate::Replacement(440)),
+        ('𝒟', crate::Replacement(374)),
+        ('𝐴', crate::Replacement(364)),
+        ('𝙤', crate::Replacement(569)),
+        ('𝐟', crate::Replacement(501)),
+        ('ℐ', crate::Replacement(397)),
+        ('𝝻', crate::Replacement(789)),
+        ('𝞃', crate::Replacement(806)),
+        ('𝖄', crate::Replacement(465)),
+        ('𝙬', crate::Replacement(595)),
+        ('𝖤', crate::Replacement(378)),
+        ('𝙈', crate::Replacement(415)),
+        ('𝘊', crate::Replacement(370)),
+        ('𝓶', crate::Replacement(544)),
+        ('𝐗', crate::Replacement(462)),
+        ('𝞆', crate::Replacement(817)),
+        ('𝑷', crate::Replacement(430)),
+        ('ℙ', crate::Replacement(430)),
+        ('𝜊', crate::Replacement(800)),
+        ('𝙙', crate::Replacement(490)),
+        ('𝗪', crate::Replacement(457)),
+        ('𝒊', crate::Replacement(514)),
+        ('𝐁', crate::Replacement(368)),
+        ('𝟦', crate::Replacement(297)),
+        ('𝞧', crate::Replacement(761)),
+        ('𝝲', crate::Replacement(773)),
+        ('𝞘', crate::Replacement(734)),
+        ('𝞱', crate::Replacement(781)),
+        ('𝙴', crate::Replacement(378)),
+        ('𝙂', crate::Replacement(386)),
+        ('𝚲', crate::Replacement(740)),
+        ('𝚔', crate::Replacement(523)),
+        ('𝜿', crate::Replacement(787)),
+        ('𝝭', crate::Replacement(761)),
+        ('𝒐', crate::Replacement(569)),
+        ('𝞙', crate::Replacement(739)),
+        ('𝑹', crate::Replacement(438)),
+        ('𝟬', crate::Replacement(162)),
+        ('𝞁', crate::Replacement(804)),
+        ('𝛷', crate::Replacement(759)),
+        ('𝛶', crate::Replacement(750)),
+        ('𝕷', crate::Replacement(410)),
+        ('𝝊', crate::Replacement(807)),
+        ('𝖮', crate::Replacement(429)),
+        ('𝐥', crate::Replacement(537)),
+        ('𝛑', crate::Replacement(801)),
+        ('𝓕', crate::Replacement(379)),
+        ('𝗝', crate::Replacement(404)),
+        ('𝔫', crate::Replacement(561)),
+        ('𝝜', crate::Replacement(729)),
+        ('𝟘', crate::Replacement(162)),
+        ('𝒚', crate::Replacement(600)),
+        ('𝗸', crate::Replacement(523)),
+        ('𝕼', crate::Replacement(437)),
+        ('𝐯', crate::Replacement(591)),
+        ('𝝥', crate::Replacement(745)),
+        ('𝓺', crate::Replacement(579)),
+        ('𝕕', crate::Replacement(490)),
+        ('𝓨', crate::Replacement(465)),
+        ('𝜭', crate::Replacement(733)),
+        ('𝛝', crate::Replacement(781)),
+        ('𝙊', crate::Replacement(429)),
+        ('𝘈', crate::Replacement(364)),
+        ('𝔯', crate::Replacement(580)),
+        ('𝜬', crate::Replacement(746)),
+        ('𝑵', crate::Replacement(425)),
+        ('𝓖', crate::Replacement(386)),
+        ('𝚟', crate::Replacement(591)),
+        ('𝐲', crate::Replacement(600)),
+        ('𝝐', crate::Replacement(775)),
+        ('𝔍', crate::Replacement(404)),
+        ('𝟚', crate::Replacement(237)),
+        ('𝘫', crate::Replacement(521)),
+        ('𝝱', crate::Replacement(772)),
+        ('𝑀', crate::Replacement(415)),
+        ('𝖰', crate::Replacement(437)),
+        ('𝚌', crate::Replacement(481)),
+        ('𝜼', crate::Replacement(777)),
+        ('𝑑', crate::Replacement(490)),
+        ('𝖹', crate::Replacement(467)),
+        ('𝛳', crate::Replacement(733)),
+        ('𝘝', crate::Replacement(452)),
+        ('𝑓', crate::Replacement(501)),
+        ('𝛖', crate::Replacement(807)),
+        ('𝓭', crate::Replacement(490)),
+        ('𝔙', crate::Replacement(452)),
+        ('𝞀', crate::Replacement(802)),
+        ('𝗌', crate::Replacement(584)),
+        ('𝕏', crate::Replacement(462)),
+        ('𝐸', crate::Replacement(378)),
+        ('𝛍', crate::Replacement(789)),
+        ('𝙾', crate::Replacement(429)),
+        ('𝗺', crate::Replacement(544)),
+        ('𝔁', crate::Replacement(597)),
+        ('𝒁', crate::Replacement(467)),
+        ('𝗁', crate::Replacement(510)),
+        ('𝟏', crate::Replacement(168)),
+        ('𝗡', crate::Replacement(425)),
+        ('𝗖', crate::Replacement(370)),
+        ('𝚚', crate::Replacement(579)),
+        ('𝔮', crate::Replacement(579)),
+        ('𝙄', crate::Replacement(397)),
+        ('𝗏', crate::Replacement(591)),
+        ('𝒕', crate::Replacement(588)),
+        ('𝕍', crate::Replacement(452)),
+        ('𝐰', crate::Replacement(595)),
+        ('𝜰', crate::Replacement(750)),
+        ('ℱ', crate::Replacement(379)),
+        ('𝘟', crate::Replacement(462)),
+        ('𝑋', crate::Replacement(462)),
+        ('𝗋', crate::Replacement(580)),
+        ('𝖿', crate::Replacement(501)),
+        ('𝘨', crate::Replacement(508)),
+        ('𝟫', crate::Replacement(346)),
+        ('𝘯', crate::Replacement(561)),
+        ('𝞨', crate::Replacement(762)),
+        ('𝚉', crate::Replacement(467)),
+        ('𝝀', crate::Replacement(788)),
+        ('𝕓', crate::Replacement(479)),
+        ('𝓗', crate::Replacement(391)),
+        ('𝔛', crate::Replacement(462)),
+        ('𝒑', crate::Replacement(571)),
+        ('𝛰', crate::Replacement(744)),
+        ('𝜕', crate::Replacement(1486)),
+        ('𝖼', crate::Replacement(481)),
+        ('𝔉', crate::Replacement(379)),
+        ('𝙹', crate::Replacement(404)),
+        ('𝖉', crate::Replacement(490)),
+        ('𝜞', crate::Replacement(725)),
+        ('𝖒', crate::Replacement(544)),
+        ('𝟴', crate::Replacement(340)),
+        ('𝛃', crate::Replacement(772)),
+        ('𝖈', crate::Replacement(481)),
+        ('𝝓', crate::Replacement(816)),
+        ('𝝡', crate::Replacement(741)),
+        ('𝟶', crate::Replacement(162)),
+        ('𝕡', crate::Replacement(571)),
+        ('𝕗', crate::Replacement(501)),
+        ('𝑯', crate::Replacement(391)),
+        ('𝘢', crate::Replacement(474)),
+        ('𝞊', crate::Replacement(775)),
+        ('𝒲', crate::Replacement(457)),
+        ('𝙑', crate::Replacement(452)),
+        ('ℂ', crate::Replacement(370)),
+        ('𝛦', crate::Replacement(727)),
+        ('𝝼', crate::Replacement(798)),
+        ('𝗳', crate::Replacement(501)),
+        ('𝔨', crate::Replacement(523)),
+        ('𝜐', crate::Replacement(807)),
+        ('𝔸', crate::Replacement(364)),
+        ('𝓠', crate::Replacement(437)),
+        ('𝗷', crate::Replacement(521)),
+        ('𝐞', crate::Replacement(498)),
+        ('ℛ', crate::Replacement(438)),
+        ('𝗿', crate::Replacement(580)),
+        ('𝘞', crate::Replacement(457)),
+        ('ℕ', crate::Replacement(425)),
+        ('𝜁', crate::Replacement(776)),
+        ('𝟎', crate::Replacement(162)),
+        ('𝔞', crate::Replacement(474)),
+        ('𝒿', crate::Replacement(521)),
+        ('𝗄', crate::Replacement(523)),
+        ('𝑊', crate::Replacement(457)),
+        ('𝑘', crate::Replacement(523)),
+        ('𝘤', crate::Replacement(481)),
+        ('𝑡', crate::Replacement(588)),
+        ('𝟀', crate::Replacement(817)),
+        ('𝗕', crate::Replacement(368)),
+        ('𝕞', crate::Replacement(544)),
+        ('𝖵', crate::Replacement(452)),
+        ('ⅈ', crate::Replacement(514)),
+        ('𝖜', crate::Replacement(595)),
+        ('𝞬', crate::Replacement(773)),
+        ('𝑬', crate::Replacement(378)),
+        ('𝚕', crate::Replacement(537)),
+        ('𝚱', crate::Replacement(739)),
+        ('𝐠', crate::Replacement(508)),
+        ('ⅉ', crate::Replacement(521)),
+        ('𝑌', crate::Replacement(465)),
+        ('𝚃', crate::Replacement(446)),
+        ('𝛭', crate::Replacement(741)),
+        ('𝛩', crate::Replacement(733)),
+        ('𝒦', crate::Replacement(406)),
+        ('𝘷', crate::Replacement(591)),
+        ('𝞽', crate::Replacement(806)),
+        ('𝔹', crate::Replacement(368)),
+        ('𝛠', crate::Replacement(802)),
+        ('𝑔', crate::Replacement(508)),
+        ('𝟒', crate::Replacement(297)),
+        ('𝟣', crate::Replacement(168)),
+        ('𝔾', crate::Replacement(386)),
+        ('𝔣', crate::Replacement(501)),
+        ('𝓦', crate::Replacement(457)),
+        ('𝗑', crate::Replacement(597)),
+        ('𝐌', crate::Replacement(415)),
+        ('𝚬', crate::Replacement(727)),
+        ('𝛉', crate::Replacement(781)),
+        ('𝖾', crate::Replacement(498)),
+        ('𝞯', crate::Replacement(776)),
+        ('𝖢', crate::Replacement(370)),
+        ('𝛁', crate::Replacement(1488)),
+        ('𝙃', crate::Replacement(391)),
+        ('𝘚', crate::Replacement(440)),
+        ('𝞤', crate::Replacement(750)),
+        ('𝘺', crate::Replacement(600)),
+        ('𝛔', crate::Replacement(805)),
+        ('𝛓', crate::Replacement(804)),
+        ('𝞠', crate::Replacement(746)),
+        ('𝚩', crate::Replacement(724)),
+        ('𝒒', crate::Replacement(579)),
+        ('𝑤', crate::Replacement(595)),
+        ('𝗴', crate::Replacement(508)),
+        ('𝜥', crate::Replacement(739)),
+        ('𝝸', crate::Replacement(782)),
+        ('𝛕', crate::Replacement(806)),
+        ('𝖅', crate::Replacement(467)),
+        ('𝘴', crate::Replacement(584)),
+        ('𝗇', crate::Replacement(561)),
+        ('𝚾', crate::Replacement(760)),
+        ('𝓙', crate::Replacement(404)),
+        ('𝝁', crate::Replacement(789)),
+        ('𝜛', crate::Replacement(801)),
+        ('𝐫', crate::Replacement(580)),
+        ('𝝠', crate::Replacement(740)),
+        ('𝚳', crate::Replacement(741)),
+        ('𝒇', crate::Replacement(501)),
+        ('𝗎', crate::Replacement(590)),
+        ('𝓼', crate::Replacement(584)),
+        ('𝙡', crate::Replacement(537)),
+        ('𝞫', crate::Replacement(772)),
+        ('𝛵', crate::Replacement(749)),
+        ('𝖛', crate::Replacement(591)),
+        ('𝕿', crate::Replacement(446)),
+        ('𝐨', crate::Replacement(569)),
+        ('𝕛', crate::Replacement(521)),
+        ('𝙧', crate::Replacement(580)),
+        ('𝑿', crate::Replacement(462)),
+        ('𝖀', crate::Replacement(451)),
+        ('𝟺', crate::Replacement(297)),
+        ('𝐔', crate::Replacement(451)),
+        ('𝘠', crate::Replacement(465)),
+        ('𝐪', crate::Replacement(579)),
+        ('𝛙', crate::Replacement(818)),
+        ('𝚅', crate::Replacement(452)),
+        ('𝞢', crate::Replacement(748)),
+        ('𝜋', crate::Replacement(801)),
+        ('𝝉', crate::Replacement(806)),
+        ('𝛢', crate::Replacement(720)),
+        ('𝔡', crate::Replacement(490)),
+        ('𝐕', crate::Replacement(452)),
+        ('𝗘', crate::Replacement(378)),
+        ('𝘑', crate::Replacement(404)),
+        ('𝜉', crate::Replacement(799)),
+        ('𝛚', crate::Replacement(819)),
+        ('𝙨', crate::Replacement(584)),
+        ('𝒓', crate::Replacement(580)),
+        ('𝙿', crate::Replacement(430)),
+        ('𝜆', crate::Replacement(788)),
+        ('𝗐', crate::Replacement(595)),
+        ('𝒛', crate::Replacement(602)),
+        ('ⅆ', crate::Replacement(490)),
+        ('𝐾', crate::Replacement(406)),
+        ('𝔘', crate::Replacement(451)),
+        ('𝙭', crate::Replacement(597)),
+        ('𝝦', crate::Replacement(746)),
+        ('𝞳', crate::Replacement(787)),
+        ('𝛬', crate::Replacement(740)),
+        ('𝟳', crate::Replacement(332)),
+        ('𝖁', crate::Replacement(452)),
+        ('𝓐', crate::Replacement(364)),
+        ('𝙷', crate::Replacement(391)),
+        ('𝗈', crate::Replacement(569)),
+        ('𝒜', crate::Replacement(364)),
+        ('𝝔', crate::Replacement(802)),
+        ('𝒙', crate::Replacement(597)),
+        ('𝐬', crate::Replacement(584)),
+        ('𝜘', crate::Replacement(787)),
+        ('𝝢', crate::Replacement(742)),
+        ('𝛂', crate::Replacement(768)),
+        ('𝙟', crate::Replacement(521)),
+        ('𝙜', crate::Replacement(508)),
+        ('𝙺', crate::Replacement(406)),
+        ('𝟽', crate::Replacement(332)),
+        ('𝘸', crate::Replacement(595)),
+        ('𝟞', crate::Replacement(326)),
+        ('𝖳', crate::Replacement(446)),
+        ('𝟅', crate::Replacement(781)),
+        ('𝛿', crate::Replacement(774)),
+        ('𝞉', crate::Replacement(1486)),
+        ('𝛘', crate::Replacement(817)),
+        ('𝚈', crate::Replacement(465)),
+        ('𝑁', crate::Replacement(425)),
+        ('𝚑', crate::Replacement(510)),
+        ('𝑨', crate::Replacement(364)),
+        ('𝙒', crate::Replacement(457)),
+        ('ℭ', crate::Replacement(370)),
+        ('𝕂', crate::Replacement(406)),
+        ('𝞝', crate::Replacement(743)),
+        ('𝜲', crate::Replacement(760)),
+        ('𝟲', crate::Replacement(326)),
+        ('𝔇', crate::Replacement(374)),
+        ('𝟼', crate::Replacement(326)),
+        ('𝑐', crate::Replacement(481)),
+        ('𝕥', crate::Replacement(588)),
+        ('𝑸', crate::Replacement(437)),
+        ('𝑈', crate::Replacement(451)),
+        ('𝓅', crate::Replacement(571)),
+        ('𝘒', crate::Replacement(406)),
+        ('𝐆', crate::Replacement(386)),
+        ('𝜟', crate::Replacement(726)),
+        ('𝘧', crate::Replacement(501)),
+        ('𝞖', crate::Replacement(729)),
+        ('𝜅', crate::Replacement(787)),
+        ('𝒶', crate::Replacement(474)),
+        ('𝗹', crate::Replacement(537)),
+        ('𝚥', crate::Replacement(633)),
+        ('𝜩', crate::Replacement(743)),
+        ('𝙛', crate::Replacement(501)),
+        ('𝜠', crate::Replacement(727)),
+        ('𝞏', crate::Replacement(801)),
+        ('𝔎', crate::Replacement(406)),
+        ('𝑛', crate::Replacement(561)),
+        ('𝜔', crate::Replacement(819)),
+        ('𝔈', crate::Replacement(378)),
+        ('ℌ', crate::Replacement(391)),
+        ('𝓫', crate::Replacement(479)),
+        ('𝓑', crate::Replacement(368)),
+        ('𝘋', crate::Replacement(374)),
+        ('𝔤', crate::Replacement(508)),
+        ('𝝣', crate::Replacement(743)),
+        ('𝘃', crate::Replacement(591)),
+        ('𝜗', crate::Replacement(781)),
+        ('𝒆', crate::Replacement(498)),
+        ('𝝇', crate::Replacement(804)),
+        ('𝝌', crate::Replacement(817)),
+        ('𝙞', crate::Replacement(514)),
+        ('𝛧', crate::Replacement(728)),
+        ('𝕢', crate::Replacement(579)),
+        ('𝚣', crate::Replacement(602)),
+        ('𝞜', crate::Replacement(742)),
+        ('𝖬', crate::Replacement(415)),
+        ('𝜀', crate::Replacement(775)),
+        ('ℊ', crate::Replacement(508)),
+        ('𝑝', crate::Replacement(571)),
+        ('𝓴', crate::Replacement(523)),
+        ('𝐘', crate::Replacement(465)),
+        ('𝕸', crate::Replacement(415)),
+        ('𝘄', crate::Replacement(595)),
+        ('𝝶', crate::Replacement(777)),
+        ('𝟋', crate::Replacement(825)),
+        ('𝚡', crate::Replacement(597)),
+        ('𝗶', crate::Replacement(514)),
+        ('𝘔', crate::Replacement(415)),
+        ('𝒗', crate::Replacement(591)),
+        ('𝛤', crate::Replacement(725)),
+        ('𝐿', crate::Replacement(410)),
+        ('𝗍', crate::Replacement(588)),
+        ('𝖡', crate::Replacement(368)),
+        ('𝛹', crate::Replacement(761)),
+        ('𝓽', crate::Replacement(588)),
+        ('𝚶', crate::Replacement(744)),
+        ('𝟛', crate::Replacement(273)),
+        ('𝟉', crate::Replacement(801)),
+        ('𝟊', crate::Replacement(824)),
+        ('𝝷', crate::Replacement(781)),
+        ('𝛀', crate::Replacement(762)),
+        ('𝞼', crate::Replacement(805)),
+        ('𝙣', crate::Replacement(561)),
+        ('𝛨', crate::Replacement(729)),
+        ('𝕫', crate::Replacement(602)),
+        ('𝜢', crate::Replacement(729)),
+        ('𝕳', crate::Replacement(391)),
+        ('𝜇', crate::Replacement(789)),
+        ('𝕯', crate::Replacement(374)),
+        ('𝙫', crate::Replacement(591)),
+        ('𝙳', crate::Replacement(374)),
+        ('𝓇', crate::Replacement(580)),
+        ('𝐂', crate::Replacement(370)),
+        ('𝑙', crate::Replacement(537)),
+        ('𝞍', crate::Replacement(816)),
+        ('𝟃', crate::Replacement(1486)),
+        ('𝟿', crate::Replacement(346)),
+        ('𝖲', crate::Replacement(440)),
+        ('𝞔', crate::Replacement(727)),
+        ('𝗵', crate::Replacement(510)),
+        ('𝓂', crate::Replacement(544)),
+        ('𝒢', crate::Replacement(386)),
+        ('𝒔', crate::Replacement(584)),
+        ('𝕩', crate::Replacement(597)),
+        ('𝖟', crate::Replacement(602)),
+        ('𝐱', crate::Replacement(597)),
+        ('𝕪', crate::Replacement(600)),
+        ('𝖙', crate::Replacement(588)),
+        ('ℨ', crate::Replacement(467)),
+        ('𝒵', crate::Replacement(467)),
+        ('𝗮', crate::Replacement(474)),
+        ('𝐻', crate::Replacement(391)),
+        ('𝜙', crate::Replacement(816)),
+        ('𝟨', crate::Replacement(326)),
+        ('𝛲', crate::Replacement(746)),
+        ('ℓ', crate::Replacement(537)),
+        ('𝟜', crate::Replacement(297)),
+        ('𝗰', crate::Replacement(481)),
+        ('𝖃', crate::Replacement(462)),
+        ('𝓷', crate::Replacement(561)),
+        ('𝓣', crate::Replacement(446)),
+        ('𝐈', crate::Replacement(397)),
+        ('𝒷', crate::Replacement(479)),
+        ('𝐺', crate::Replacement(386)),
+        ('𝐝', crate::Replacement(490)),
+        ('𝗽', crate::Replacement(571)),
+        ('𝔳', crate::Replacement(591)),
+        ('𝕙', crate::Replacement(510)),
+        ('𝝾', crate::Replacement(800)),
+        ('𝕟', crate::Replacement(561)),
+        ('𝓯', crate::Replacement(501)),
+        ('𝕤', crate::Replacement(584)),
+        ('𝝨', crate::Replacement(748)),
+        ('𝜃', crate::Replacement(781)),
+        ('𝙅', crate::Replacement(404)),
+        ('𝘅', crate::Replacement(597)),
+        ('𝝫', crate::Replacement(759)),
+        ('𝙠', crate::Replacement(523)),
+        ('𝟱', crate::Replacement(315)),
+        ('𝞚', crate::Replacement(740)),
+        ('𝛅', crate::Replacement(774)),
+        ('𝑱', crate::Replacement(404)),
+        ('𝙇', crate::Replacement(410)),
+        ('𝖩', crate::Replacement(404)),
+        ('𝓊', crate::Replacement(590)),
+        ('𝝒', crate::Replacement(787)),
+        ('𝑂', crate::Replacement(429)),
+        ('𝞄', crate::Replacement(807)),
+        ('𝔼', crate::Replacement(378)),
+        ('𝘰', crate::Replacement(569)),
+        ('𝓎', crate::Replacement(600)),
+        ('𝖧', crate::Replacement(391)),
+        ('𝞹', crate::Replacement(801)),
+        ('𝓍', crate::Replacement(597)),
+        ('𝝆', crate::Replacement(802)),
+        ('ℋ', crate::Replacement(391)),
+        ('𝘗', crate::Replacement(430)),
+        ('𝙌', crate::Replacement(437)),
+        ('𝑺', crate::Replacement(440)),
+        ('𝘐', crate::Replacement(397)),
+        ('𝜂', crate::Replacement(777)),
+        ('𝘥', crate::Replacement(490)),
+        ('𝛪', crate::Replacement(734)),
+        ('𝚐', crate::Replacement(508)),
+        ('𝘭', crate::Replacement(537)),
+        ('𝔬', crate::Replacement(569)),
+        ('𝙰', crate::Replacement(364)),
+        ('𝓌', crate::Replacement(595)),
+        ('𝑠', crate::Replacement(584)),
+        ('𝓁', crate::Replacement(537)),
+        ('𝖑', crate::Replacement(537)),
+        ('𝔭', crate::Replacement(571)),
+        ('𝖘', crate::Replacement(584)),
+        ('ℰ', crate::Replacement(378)),
+        ('𝐃', crate::Replacement(374)),
+        ('𝚫', crate::Replacement(726)),
+        ('𝒞', crate::Replacement(370)),
+        ('𝕧', crate::Replacement(591)),
+        ('𝙚', crate::Replacement(498)),
+        ('𝓲', crate::Replacement(514)),
+        ('𝞑', crate::Replacement(724)),
+        ('𝚵', crate::Replacement(743)),
+        ('𝑰', crate::Replacement(397)),
+        ('𝖶', crate::Replacement(457)),
+        ('𝒹', crate::Replacement(490)),
+        ('𝝄', crate::Replacement(800)),
+        ('𝑗', crate::Replacement(521)),
+        ('𝟖', crate::Replacement(340)),
+        ('𝗛', crate::Replacement(391)),
+        ('𝑒', crate::Replacement(498)),
+        ('𝒳', crate::Replacement(462)),
+        ('𝓿', crate::Replacement(591)),
+        ('𝑃', crate::Replacement(430)),
+        ('𝓘', crate::Replacement(397)),
+        ('𝜯', crate::Replacement(749)),
+        ('𝔃', crate::Replacement(602)),
+        ('𝖦', crate::Replacement(386)),
+        ('𝚪', crate::Replacement(725)),
+        ('𝝹', crate::Replacement(787)),
+        ('𝒈', crate::Replacement(508)),
+        ('𝘬', crate::Replacement(523)),
+        ('ℳ', crate::Replacement(415)),
+        ('𝗦', crate::Replacement(440)),
+        ('𝚢', crate::Replacement(600)),
+        ('𝒰', crate::Replacement(451)),
+        ('𝕽', crate::Replacement(438)),
+        ('𝗆', crate::Replacement(544)),
+        ('𝜣', crate::Replacement(733)),
+        ('𝗼', crate::Replacement(569)),
+        ('𝟻', crate::Replacement(315)),
+        ('𝙢', crate::Replacement(544)),
+        ('𝖞', crate::Replacement(600)),
+        ('𝜦', crate::Replacement(740)),
+        ('𝑮', crate::Replacement(386)),
+        ('𝚍', crate::Replacement(490)),
+        ('𝞋', crate::Replacement(781)),
+        ('𝔱', crate::Replacement(588)),
+        ('𝝚', crate::Replacement(727)),
+        ('𝑴', crate::Replacement(415)),
+        ('𝐡', crate::Replacement(510)),
+        ('𝜹', crate::Replacement(774)),
+        ('ⅇ', crate::Replacement(498)),
+        ('𝟟', crate::Replacement(332)),
+        ('𝒖', crate::Replacement(590)),
+        ('𝓹', crate::Replacement(571)),
+        ('𝕠', crate::Replacement(569)),
+        ('𝕐', crate::Replacement(465)),
+        ('𝑍', crate::Replacement(467)),
+        ('𝔑', crate::Replacement(425)),
+        ('𝗭', crate::Replacement(467)),
+        ('𝕆', crate::Replacement(429)),
+        ('𝑪', crate::Replacement(370)),
+        ('𝓥', crate::Replacement(452)),
+        ('𝒴', crate::Replacement(465)),
+        ('𝟯', crate::Replacement(273)),
+        ('𝔷', crate::Replacement(602)),
+        ('𝞦', crate::Replacement(760)),
+        ('𝝽', crate::Replacement(799)),
+        ('𝝙', crate::Replacement(726)),
+        ('𝐅', crate::Replacement(379)),
+        ('𝐇', crate::Replacement(391)),
+        ('𝘹', crate::Replacement(597)),
+        ('𝗜', crate::Replacement(397)),
+        ('𝝰', crate::Replacement(768)),
+        ('𝝅', crate::Replacement(801)),
+        ('𝕀', crate::Replacement(397)),
+        ('𝓱', crate::Replacement(510)),
+        ('𝔰', crate::Replacement(584)),
+        ('𝗲', crate::Replacement(498)),
+        ('𝗢', crate::Replacement(429)),
+        ('𝙼', crate::Replacement(415)),
+        ('𝓡', crate::Replacement(438)),
+        ('𝞈', crate::Replacement(819)),
+        ('𝞸', crate::Replacement(800)),
+        ('𝜷', crate::Replacement(772)),
+        ('𝓳', crate::Replacement(521)),
+        ('𝐧', crate::Replacement(561)),
+        ('𝙏', crate::Replacement(446)),
+        ('𝛴', crate::Replacement(748)),
+        ('𝓟', crate::Replacement(430)),
+        ('𝒎', crate::Replacement(544)),
+        ('𝚿', crate::Replacement(761)),
+        ('𝔶', crate::Replacement(600)),
+        ('𝟷', crate::Replacement(168)),
+        ('𝗗', crate::Replacement(374)),
+        ('𝗩', crate::Replacement(452)),
+        ('𝞕', crate::Replacement(728)),
+        ('𝓪', crate::Replacement(474)),
+        ('𝘆', crate::Replacement(600)),
+        ('𝐮', crate::Replacement(590)),
+        ('𝓚', crate::Replacement(406)),
+        ('𝝯', crate::Replacement(1488)),
+        ('𝚘', crate::Replacement(569)),
+        ('𝜌', crate::Replacement(802)),
+        ('𝓮', crate::Replacement(498)),
+        ('𝜫', crate::Replacement(745)),
+        ('𝐭', crate::Replacement(588)),
+        ('𝓒', crate::Replacement(370)),
+        ('𝛻', crate::Replacement(1488)),
+        ('𝖻', crate::Replacement(479)),
+        ('𝕭', crate::Replacement(368)),
+        ('𝕰', crate::Replacement(378)),
+        ('𝛋', crate::Replacement(787)),
+        ('𝛞', crate::Replacement(787)),
+        ('𝐒', crate::Replacement(440)),
+        ('𝛜', crate::Replacement(775)),
+        ('𝛊', crate::Replacement(782)),
+        ('𝝪', crate::Replacement(750)),
+        ('𝚋', crate::Replacement(479)),
+        ('𝚹', crate::Replacement(733)),
+        ('𝖂', crate::Replacement(457)),
+        ('𝖴', crate::Replacement(451)),
+        ('𝑶', crate::Replacement(429)),
+        ('𝑏', crate::Replacement(479)),
+        ('𝕁', crate::Replacement(404)),
+        ('𝞡', crate::Replacement(733)),
+        ('𝔢', crate::Replacement(498)),
+        ('𝘪', crate::Replacement(514)),
+        ('𝖋', crate::Replacement(501)),
+        ('𝙦', crate::Replacement(579)),
+        ('𝑥', crate::Replacement(597)),
+        ('𝜻', crate::Replacement(776)),
+        ('𝑜', crate::Replacement(569)),
+        ('𝟠', crate::Replacement(340)),
+        ('𝛸', crate::Replacement(760)),
+        ('𝙆', crate::Replacement(406)),
+        ('𝑆', crate::Replacement(440)),
+        ('𝙉', crate::Replacement(425)),
+        ('𝗠', crate::Replacement(415)),
+        ('𝓬', crate::Replacement(481)),
+        ('𝒉', crate::Replacement(510)),
+        ('𝘀', crate::Replacement(584)),
+        ('𝖪', crate::Replacement(406)),
+        ('𝞿', crate::Replacement(816)),
+        ('𝝧', crate::Replacement(733)),
+        ('𝞇', crate::Replacement(818)),
+        ('𝖏', crate::Replacement(521)),
+        ('𝙓', crate::Replacement(462)),
+        ('𝐋', crate::Replacement(410)),
+        ('𝟇', crate::Replacement(816)),
+        ('𝛱', crate::Replacement(745)),
+        ('𝗫', crate::Replacement(462)),
+        ('𝘲', crate::Replacement(579)),
+        ('𝒅', crate::Replacement(490)),
+        ('𝗓', crate::Replacement(602)),
+        ('𝑫', crate::Replacement(374)),
+        ('𝕜', crate::Replacement(523)),
+        ('𝟆', crate::Replacement(787)),
+        ('𝜤', crate::Replacement(734)),
+        ('𝘦', crate::Replacement(498)),
+        ('𝓵', crate::Replacement(537)),
+        ('𝟈', crate::Replacement(802)),
+        ('𝙁', crate::Replacement(379)),
+        ('𝜏', crate::Replacement(806)),
+        ('ℍ', crate::Replacement(391)),
+        ('𝚇', crate::Replacement(462)),
+        ('𝔽', crate::Replacement(379)),
+        ('𝐀', crate::Replacement(364)),
+        ('𝙀', crate::Replacement(378)),
+        ('𝞺', crate::Replacement(802)),
+        ('𝟭', crate::Replacement(168)),
+        ('𝛾', crate::Replacement(773)),
+        ('𝝂', crate::Replacement(798)),
+        ('𝖊', crate::Replacement(498)),
+        ('𝚨', crate::Replacement(720)),
+        ('𝘡', crate::Replacement(467)),
+        ('𝜜', crate::Replacement(720)),
+        ('𝞌', crate::Replacement(787)),
+        ('𝛗', crate::Replacement(816)),
+        ('𝒍', crate::Replacement(537)),
+        ('𝒫', crate::Replacement(430)),
+        ('𝙱', crate::Replacement(368)),
+        ('𝝬', crate::Replacement(760)),
+        ('𝘛', crate::Replacement(446)),
+        ('𝛮', crate::Replacement(742)),
+        ('𝘣', crate::Replacement(479)),
+        ('𝔄', crate::Replacement(364)),
+        ('𝖕', crate::Replacement(571)),
+        ('𝟕', crate::Replacement(332)),
+        ('𝛟', crate::Replacement(816)),
+        ('𝞟', crate::Replacement(745)),
+        ('𝐎', crate::Replacement(429)),
+        ('𝐣', crate::Replacement(521)),
+        ('𝛼', crate::Replacement(768)),
+        ('𝞵', crate::Replacement(789)),
+        ('𝙝', crate::Replacement(510)),
+        ('𝞭', crate::Replacement(774)),
+        ('𝘇', crate::Replacement(602)),
+        ('𝞷', crate::Replacement(799)),
+        ('𝓻', crate::Replacement(580)),
+        ('𝝛', crate::Replacement(728)),
+        ('𝝖', crate::Replacement(720)),
+        ('𝖥', crate::Replacement(379)),
+        ('𝑖', crate::Replacement(514)),
+        ('𝝳', crate::Replacement(774)),
+        ('𝟐', crate::Replacement(237)),
+        ('𝚄', crate::Replacement(451)),
+        ('𝒂', crate::Replacement(474)),
+        ('𝐚', crate::Replacement(474)),
+        ('𝘮', crate::Replacement(544)),
+        ('𝜓', crate::Replacement(818)),
+        ('𝓋', crate::Replacement(591)),
+        ('𝗾', crate::Replacement(579)),
+        ('𝘽', crate::Replacement(368)),
+        ('𝑽', crate::Replacement(452)),
+        ('𝕚', crate::Replacement(514)),
+        ('𝝈', crate::Replacement(805)),
+        ('𝕦', crate::Replacement(590)),
+        ('𝑲', crate::Replacement(406)),
+        ('𝔵', crate::Replacement(597)),
+        ('𝖆', crate::Replacement(474)),
+        ('𝞴', crate::Replacement(788)),
+        ('𝘁', crate::Replacement(588)),
+        ('𝙪', crate::Replacement(590)),
+        ('𝚯', crate::Replacement(733)),
+        ('𝘿', crate::Replacement(374)),
+        ('𝕴', crate::Replacement(397)),
+        ('𝐐', crate::Replacement(437)),
+        ('𝚙', crate::Replacement(571)),
+        ('𝙕', crate::Replacement(467)),
+        ('𝔅', crate::Replacement(368)),
+        ('𝜒', crate::Replacement(817)),
+        ('𝛎', crate::Replacement(798)),
+        ('𝒋', crate::Replacement(521)),
+        ('𝔻', crate::Replacement(374)),
+        ('𝚎', crate::Replacement(498)),
+        ('𝖍', crate::Replacement(510)),
+        ('𝚂', crate::Replacement(440)),
+        ('𝟝', crate::Replacement(315)),
+        ('𝚭', crate::Replacement(728)),
+        ('𝖨', crate::Replacement(397)),
+        ('𝔥', crate::Replacement(510)),
+        ('𝔀', crate::Replacement(595)),
+        ('𝖷', crate::Replacement(462)),
+        ('𝓓', crate::Replacement(374)),
+        ('𝔩', crate::Replacement(537)),
+        ('𝞗', crate::Replacement(733)),
+        ('𝚒', crate::Replacement(514)),
+        ('𝖔', crate::Replacement(569)),
+        ('𝐷', crate::Replacement(374)),
+        ('𝖸', crate::Replacement(465)),
+        ('𝒯', crate::Replacement(446)),
+        ('𝔊', crate::Replacement(386)),
+        ('𝓤', crate::Replacement(451)),
+        ('𝚛', crate::Replacement(580)),
+        ('ℝ', crate::Replacement(438)),
+        ('𝒃', crate::Replacement(479)),
+        ('𝒄', crate::Replacement(481)),
+        ('𝑣', crate::Replacement(591)),
+        ('𝚁', crate::Replacement(438)),
+        ('𝜧', crate::Replacement(741)),
+        ('𝗅', crate::Replacement(537)),
+        ('𝖱', crate::Replacement(438)),
+        ('𝓈', crate::Replacement(584)),
+        ('𝗟', crate::Replacement(410)),
+        ('𝟧', crate::Replacement(315)),
+        ('𝜴', crate::Replacement(762)),
+        ('𝚷', crate::Replacement(745)),
+        ('𝓀', crate::Replacement(523)),
+        ('𝔚', crate::Replacement(457)),
+        ('𝓞', crate::Replacement(429)),
+        ('𝟾', crate::Replacement(340)),
+        ('𝞅', crate::Replacement(816)),
+        ('𝐖', crate::Replacement(457)),
+        ('𝟢', crate::Replacement(162)),
+        ('𝝩', crate::Replacement(749)),
+        ('𝗚', crate::Replacement(386)),
+        ('𝕌', crate::Replacement(451)),
+        ('𝐓', crate::Replacement(446)),
+        ('𝝤', crate::Replacement(744)),
+        ('𝜨', crate::Replacement(742)),
+        ('𝞞', crate::Replacement(744)),
+        ('𝐉', crate::Replacement(404)),
+        ('𝛥', crate::Replacement(726)),
+        ('𝜳', crate::Replacement(761)),
+        ('𝗃', crate::Replacement(521)),
+        ('𝓔', crate::Replacement(378)),
+        ('𝔔', crate::Replacement(437)),
+        ('𝐍', crate::Replacement(425)),
+        ('𝛫', crate::Replacement(739)),
+        ('𝒩', crate::Replacement(425)),
+        ('𝑎', crate::Replacement(474)),
+        ('𝐶', crate::Replacement(370)),
+        ('𝚗', crate::Replacement(561)),
+        ('𝟰', crate::Replacement(297)),
+        ('𝐹', crate::Replacement(379)),
+        ('𝒱', crate::Replacement(452)),
+        ('𝜺', crate::Replacement(775)),
+        ('𝛈', crate::Replacement(777)),
+        ('𝘱', crate::Replacement(571)),
+        ('𝘏', crate::Replacement(391)),
+        ('𝑢', crate::Replacement(590)),
+        ('𝜚', crate::Replacement(802)),
+        ('𝒻', crate::Replacement(501)),
+        ('𝘖', crate::Replacement(429)),
+        ('𝒽', crate::Replacement(510)),
+        ('𝔓', crate::Replacement(430)),
+        ('𝛒', crate::Replacement(802)),
+        ('𝝟', crate::Replacement(739)),
+        ('𝞩', crate::Replacement(1488)),
+        ('𝟁', crate::Replacement(818)),
+        ('𝙐', crate::Replacement(451)),
+        ('𝖭', crate::Replacement(425)),
+        ('𝘘', crate::Replacement(437)),
+        ('𝗧', crate::Replacement(446)),
+        ('𝜝', crate::Replacement(724)),
+        ('𝝝', crate::Replacement(733)),
+        ('𝝕', crate::Replacement(801)),
+        ('𝙩', crate::Replacement(588)),
+        ('𝗔', crate::Replacement(364)),
+        ('𝓏', crate::Replacement(602)),
+        ('𝝃', crate::Replacement(799)),
+        ('𝖝', crate::Replacement(597)),
+        ('𝝑', crate::Replacement(781)),
+        ('𝟹', crate::Replacement(273)),
+        ('𝜶', crate::Replacement(768)),
+        ('𝐊', crate::Replacement(406)),
+        ('𝐜', crate::Replacement(481)),
+        ('𝟗', crate::Replacement(346)),
+        ('𝙲', crate::Replacement(370)),
+        ('𝖣', crate::Replacement(374)),
+        ('𝚸', crate::Replacement(746)),
+        ('𝕹', crate::Replacement(425)),
+        ('𝔐', crate::Replacement(415)),
+        ('𝔂', crate::Replacement(600)),
+        ('𝙥', crate::Replacement(571)),
+        ('𝗨', crate::Replacement(451)),
+        ('𝑉', crate::Replacement(452)),
+        ('𝖺', crate::Replacement(474)),
+        ('𝕱', crate::Replacement(379)),
+        ('𝗤', crate::Replacement(437)),
+        ('𝒸', crate::Replacement(481)),
+        ('𝚺', crate::Replacement(748)),
+        ('𝐦', crate::Replacement(544)),
+        ('ⅅ', crate::Replacement(374)),
+        ('𝙋', crate::Replacement(430)),
+        ('𝕝', crate::Replacement(537)),
+        ('𝞾', crate::Replacement(807)),
+        ('𝜮', crate::Replacement(748)),
+        ('𝖯', crate::Replacement(430)),
+        ('𝟸', crate::Replacement(237)),
+        ('𝝍', crate::Replacement(818)),
+        ('ℬ', crate::Replacement(368)),
+        ('𝝗', crate::Replacement(724)),
+        ('𝚖', crate::Replacement(544)),
+        ('𝛛', crate::Replacement(1486)),
+        ('𝑳', crate::Replacement(410)),
+        ('𝜈', crate::Replacement(798)),
+        ('𝓸', crate::Replacement(569)),
+        ('𝑚', crate::Replacement(544)),
+        ('ℑ', crate::Replacement(397)),
+        ('𝔖', crate::Replacement(440)),
+        ('𝞎', crate::Replacement(802)),
+        ('𝝎', crate::Replacement(819)),
+        ('𝒥', crate::Replacement(404)),
+        ('𝞲', crate::Replacement(782)),
+        ('𝝿', crate::Replacement(801)),
+        ('𝝺', crate::Replacement(788)),
+        ('𝑅', crate::Replacement(438)),
+        ('𝕨', crate::Replacement(595)),
+        ('𝝵', crate::Replacement(776)),
+        ('𝙖', crate::Replacement(474)),
+        ('𝕖', crate::Replacement(498)),
+        ('𝒀', crate::Replacement(465)),
+        ('𝙔', crate::Replacement(465)),
+        ('𝚜', crate::Replacement(584)),
+        ('𝕺', crate::Replacement(429)),
+        ('𝙽', crate::Replacement(425)),
+        ('𝞓', crate::Replacement(726)),
+        ('𝜄', crate::Replacement(782)),
+        ('𝚻', crate::Replacement(749)),
+        ('𝒘', crate::Replacement(595)),
+        ('𝚝', crate::Replacement(588)),
+        ('𝖚', crate::Replacement(590)),
+        ('𝟓', crate::Replacement(315)),
+        ('𝖠', crate::Replacement(364)),
+        ('𝘼', crate::Replacement(364)),
+        ('𝗞', crate::Replacement(406)),
+        ('𝓰', crate::Replacement(508)),
+        ('𝝮', crate::Replacement(762)),
+        ('𝑄', crate::Replacement(437)),
+        ('𝚽', crate::Replacement(759)),
+        ('𝓆', crate::Replacement(579)),
+        ('𝑩', crate::Replacement(368)),
+        ('𝐢', crate::Replacement(514)),
+        ('𝜖', crate::Replacement(775)),
+        ('𝓃', crate::Replacement(561)),
+        ('𝟥', crate::Replacement(273)),
+        ('𝗂', crate::Replacement(514)),
+        ('𝝋', crate::Replacement(816)),
+        ('𝕮', crate::Replacement(370)),
+        ('𝘻', crate::Replacement(602)),
+        ('𝕵', crate::Replacement(404)),
+        ('𝓢', crate::Replacement(440)),
+        ('𝑞', crate::Replacement(579)),
+        ('ℜ', crate::Replacement(438)),
+        ('𝔜', crate::Replacement(465)),
+        ('𝞪', crate::Replacement(768)),
+        ('𝚤', crate::Replacement(622)),
+        ('𝚴', crate::Replacement(742)),
+        ('𝐽', crate::Replacement(404)),
+        ('𝔪', crate::Replacement(544)),
+        ('𝚏', crate::Replacement(501)),
+        ('𝝞', crate::Replacement(734)),
+        ('𝚀', crate::Replacement(437)),
+        ('𝘾', crate::Replacement(370)),
+        ('𝚮', crate::Replacement(729)),
+        ('𝝴', crate::Replacement(775)),
+        ('𝔧', crate::Replacement(521)),
+        ('𝘍', crate::Replacement(379)),
+        ('𝞒', crate::Replacement(725)),
+        ('𝜽', crate::Replacement(781)),
+        ('𝑭', crate::Replacement(379)),
+        ('𝝏', crate::Replacement(1486)),
+        ('𝖐', crate::Replacement(523)),
+        ('𝘙', crate::Replacement(438)),
+        ('𝙎', crate::Replacement(440)),
+        ('𝑇', crate::Replacement(446)),
+        ('𝜑', crate::Replacement(816)),
+        ('𝑻', crate::Replacement(446)),
+        ('𝚆', crate::Replacement(457)),
+        ('𝟪', crate::Replacement(340)),
+        ('𝑧', crate::Replacement(602)),
+        ('ℴ', crate::Replacement(569)),
+        ('𝘶', crate::Replacement(590)),
+    ],
+};
+
+pub const FULLWIDTH_MAPPING: crate::phf::CharMap<crate::Replacement> = crate::phf::CharMap {
+    range: '\u{3000}'..='～',
+    key: 12913932095322966823,
+    disps: &[
+        (0, 0),
+        (0, 3),
+        (4, 94),
+        (0, 40),
+        (1, 34),
+        (0, 12),
+        (0, 10),
+        (8, 5),
+        (0, 17),
+        (0, 23),
+        (31, 14),
+        (0, 0),
+        (2, 3),
+        (44, 64),
+        (0, 30),
+        (6, 12),
+        (0, 15),
+        (0, 73),
+        (1, 55),
+    ],
+    entries: &[
+        ('Ａ', crate::Replacement(364)),
+        ('－', crate::Replacement(157)),
+        ('５', crate::Replacement(315)),
+        ('Ｃ', crate::Replacement(370)),
+        ('ｕ', crate::Replacement(590)),
+        ('＜', crate::Replacement(355)),
+        ('，', crate::Replacement(156)),
+        ('Ｕ', crate::Replacement(451)),
+        ('ｆ', crate::Replacement(501)),
+        ('．', crate::Replacement(158)),
+        ('！', crate::Replacement(4)),
+        ('｜', crate::Replacement(604)),
+        ('Ｖ', crate::Replacement(452)),
+        ('９', crate::Replacement(346)),
+        ('ｑ', crate::Replacement(579)),
+        ('Ｋ', crate::Replacement(406)),
+        ('ｚ', crate::Replacement(602)),
+        ('＊', crate::Replacement(154)),
+        ('＂', crate::Replacement(7)),
+        ('＃', crate::Replacement(8)),
+        ('Ｚ', crate::Replacement(467)),
+        ('（', crate::Replacement(13)),
+        ('ｔ', crate::Replacement(588)),
+        ('ｇ', crate::Replacement(508)),
+        ('＞', crate::Replacement(359)),
+        ('ａ', crate::Replacement(474)),
+        ('Ｌ', crate::Replacement(410)),
+        ('６', crate::Replacement(326)),
+        ('）', crate::Replacement(153)),
+        ('＆', crate::Replacement(11)),
+        ('Ｈ', crate::Replacement(391)),
+        ('\u{3000}', crate::Replacement(1)),
+        ('ｃ', crate::Replacement(481)),
+        ('ｍ', crate::Replacement(544)),
+        ('ｌ', crate::Replacement(537)),
+        ('ｓ', crate::Replacement(584)),
+        ('７', crate::Replacement(332)),
+        ('｀', crate::Replacement(473)),
+        ('ｄ', crate::Replacement(490)),
+        ('ｒ', crate::Replacement(580)),
+        ('：', crate::Replacement(352)),
+        ('ｐ', crate::Replacement(571)),
+        ('％', crate::Replacement(10)),
+        ('ｊ', crate::Replacement(521)),
+        ('Ｏ', crate::Replacement(429)),
+        ('Ｎ', crate::Replacement(425)),
+        ('｝', crate::Replacement(605)),
+        ('Ｄ', crate::Replacement(374)),
+        ('＠', crate::Replacement(363)),
+        ('Ｗ', crate::Replacement(457)),
+        ('４', crate::Replacement(297)),
+        ('０', crate::Replacement(162)),
+        ('ｙ', crate::Replacement(600)),
+        ('］', crate::Replacement(470)),
+        ('？', crate::Replacement(360)),
+        ('ｎ', crate::Replacement(561)),
+        ('Ｐ', crate::Replacement(430)),
+        ('Ｇ', crate::Replacement(386)),
+        ('３', crate::Replacement(273)),
+        ('／', crate::Replacement(161)),
+        ('；', crate::Replacement(354)),
+        ('ｏ', crate::Replacement(569)),
+        ('Ｆ', crate::Replacement(379)),
+        ('Ｑ', crate::Replacement(437)),
+        ('１', crate::Replacement(168)),
+        ('２', crate::Replacement(237)),
+        ('ｈ', crate::Replacement(510)),
+        ('＼', crate::Replacement(469)),
+        ('ｅ', crate::Replacement(498)),
+        ('＿', crate::Replacement(472)),
+        ('Ｓ', crate::Replacement(440)),
+        ('ｗ', crate::Replacement(595)),
+        ('８', crate::Replacement(340)),
+        ('Ｍ', crate::Replacement(415)),
+        ('Ｘ', crate::Replacement(462)),
+        ('ｖ', crate::Replacement(591)),
+        ('～', crate::Replacement(606)),
+        ('＝', crate::Replacement(356)),
+        ('［', crate::Replacement(468)),
+        ('Ｔ', crate::Replacement(446)),
+        ('Ｉ', crate::Replacement(397)),
+        ('＇', crate::Replacement(12)),
+        ('ｂ', crate::Replacement(479)),
+        ('Ｙ', crate::Replacement(465)),
+        ('ｉ', crate::Replacement(514)),
+        ('Ｅ', crate::Replacement(378)),
+        ('＾', crate::Replacement(471)),
+        ('Ｊ', crate::Replacement(404)),
+        ('Ｒ', crate::Replacement(438)),
+        ('ｋ', crate::Replacement(523)),
+        ('＄', crate::Replacement(9)),
+        ('Ｂ', crate::Replacement(368)),
+        ('＋', crate::Replacement(155)),
+        ('ｘ', crate::Replacement(597)),
+        ('｛', crate::Replacement(603)),
+    ],
+};
+
+pub const LIGATURE_MAPPING: crate::phf::CharMap<crate::Replacement> = crate::phf::CharMap {
+    range: 'Ĳ'..='ﬆ',
+    key: 15467950696543387533,
+    disps: &[(5, 8), (6, 0)],
+    entries: &[
+        ('ﬀ', crate::Replacement(502)),
+        ('ﬂ', crate::Replacement(506)),
+        ('Ĳ', crate::Replacement(400)),
+        ('ﬁ', crate::Replacement(505)),
+        ('ﬅ', crate::Replacement(587)),
+        ('ĳ', crate::Replacement(517)),
+        ('ﬆ', crate::Replacement(587)),
+        ('ﬃ', crate::Replacement(503)),
+        ('ﬄ', crate::Replacement(504)),
+    ],
+};
+
+pub const SPECIAL_UPPERCASE: crate::phf::CharMap<crate::Replacement> = crate::phf::CharMap {
+    range: 'ß'..='ﬗ',
+    key: 12913932095322966823,
+    disps: &[
+        (0, 3),
+        (4, 74),
+        (0, 0),
+        (0, 70),
+        (10, 100),
+        (0, 60),
+        (7, 89),
+        (0, 9),
+        (0, 17),
+        (1, 40),
+        (11, 74),
+        (0, 0),
+        (0, 27),
+        (43, 39),
+        (10, 8),
+        (3, 48),
+        (1, 0),
+        (7, 52),
+        (1, 1),
+        (30, 67),
+        (23, 5),
+    ],
+    entries: &[
+        ('ᾞ', crate::Replacement(1445)),
+        ('ῷ', crate::Replacement(764)),
+        ('ᾳ', crate::Replacement(723)),
+        ('ᾜ', crate::Replacement(1443)),
+        ('ᾓ', crate::Replacement(1442)),
+        ('ᾉ', crate::Replacement(1424)),
+        ('ῂ', crate::Replacement(1467)),
+        ('ẙ', crate::Replacement(466)),
+        ('ﬂ', crate::Replacement(385)),
+        ('ᾎ', crate::Replacement(1429)),
+        ('ẘ', crate::Replacement(461)),
+        ('ß', crate::Replacement(443)),
+        ('ὖ', crate::Replacement(757)),
+        ('ᾀ', crate::Replacement(1423)),
+        ('ᾐ', crate::Replacement(1439)),
+        ('ᾤ', crate::Replacement(1459)),
+        ('ᾡ', crate::Replacement(1456)),
+        ('ῳ', crate::Replacement(765)),
+        ('ᾘ', crate::Replacement(1439)),
+        ('ῦ', crate::Replacement(758)),
+        ('ᾼ', crate::Replacement(723)),
+        ('ﬁ', crate::Replacement(384)),
+        ('ᾬ', crate::Replacement(1459)),
+        ('ΰ', crate::Replacement(752)),
+        ('ῇ', crate::Replacement(731)),
+        ('ῲ', crate::Replacement(1468)),
+        ('ᾫ', crate::Replacement(1458)),
+        ('ᾛ', crate::Replacement(1442)),
+        ('ᾕ', crate::Replacement(1444)),
+        ('ᾂ', crate::Replacement(1425)),
+        ('ῶ', crate::Replacement(763)),
+        ('ᾄ', crate::Replacement(1427)),
+        ('ᾢ', crate::Replacement(1457)),
+        ('ᾩ', crate::Replacement(1456)),
+        ('ᾙ', crate::Replacement(1440)),
+        ('ﬓ', crate::Replacement(887)),
+        ('ﬔ', crate::Replacement(884)),
+        ('ᾒ', crate::Replacement(1441)),
+        ('ᾚ', crate::Replacement(1441)),
+        ('ὒ', crate::Replacement(755)),
+        ('ᾍ', crate::Replacement(1428)),
+        ('ᾃ', crate::Replacement(1426)),
+        ('ᾮ', crate::Replacement(1461)),
+        ('ῃ', crate::Replacement(732)),
+        ('ῖ', crate::Replacement(738)),
+        ('ΐ', crate::Replacement(736)),
+        ('ῆ', crate::Replacement(730)),
+        ('ᾨ', crate::Replacement(1455)),
+        ('ẖ', crate::Replacement(396)),
+        ('ﬕ', crate::Replacement(885)),
+        ('ﬃ', crate::Replacement(382)),
+        ('ﬅ', crate::Replacement(444)),
+        ('ᾈ', crate::Replacement(1423)),
+        ('ᾲ', crate::Replacement(1466)),
+        ('ﬆ', crate::Replacement(444)),
+        ('ᾌ', crate::Replacement(1427)),
+        ('ﬗ', crate::Replacement(886)),
+        ('ῒ', crate::Replacement(735)),
+        ('ẚ', crate::Replacement(366)),
+        ('ᾊ', crate::Replacement(1425)),
+        ('ᾋ', crate::Replacement(1426)),
+        ('ὔ', crate::Replacement(756)),
+        ('ᾧ', crate::Replacement(1462)),
+        ('ᾝ', crate::Replacement(1444)),
+        ('ῢ', crate::Replacement(751)),
+        ('ῴ', crate::Replacement(719)),
+        ('ΐ', crate::Replacement(736)),
+        ('ᾪ', crate::Replacement(1457)),
+        ('ῼ', crate::Replacement(765)),
+        ('ﬖ', crate::Replacement(888)),
+        ('ᾗ', crate::Replacement(1446)),
+        ('ŉ', crate::Replacement(713)),
+        ('ᾑ', crate::Replacement(1440)),
+        ('ᾦ', crate::Replacement(1461)),
+        ('ᾠ', crate::Replacement(1455)),
+        ('ὐ', crate::Replacement(754)),
+        ('ẗ', crate::Replacement(450)),
+        ('ǰ', crate::Replacement(405)),
+        ('ῤ', crate::Replacement(747)),
+        ('ᾖ', crate::Replacement(1445)),
+        ('ᾷ', crate::Replacement(722)),
+        ('ῧ', crate::Replacement(753)),
+        ('ᾆ', crate::Replacement(1429)),
+        ('ᾔ', crate::Replacement(1443)),
+        ('ΰ', crate::Replacement(752)),
+        ('ᾅ', crate::Replacement(1428)),
+        ('ῗ', crate::Replacement(737)),
+        ('ᾶ', crate::Replacement(721)),
+        ('և', crate::Replacement(883)),
+        ('ᾥ', crate::Replacement(1460)),
+        ('ᾯ', crate::Replacement(1462)),
+        ('ᾭ', crate::Replacement(1460)),
+        ('ﬀ', crate::Replacement(381)),
+        ('ᾁ', crate::Replacement(1424)),
+        ('ᾴ', crate::Replacement(717)),
+        ('ﬄ', crate::Replacement(383)),
+        ('ᾇ', crate::Replacement(1430)),
+        ('ῌ', crate::Replacement(732)),
+        ('ᾟ', crate::Replacement(1446)),
+        ('ῄ', crate::Replacement(718)),
+        ('ᾣ', crate::Replacement(1458)),
+        ('ᾏ', crate::Replacement(1430)),
+    ],
+};
+
+pub const SPECIAL_CASE_FOLDING: crate::phf::CharMap<crate::Replacement> = crate::phf::CharMap {
+    range: 'ß'..='ﬗ',
+    key: 12913932095322966823,
+    disps: &[
+        (0, 3),
+        (4, 74),
+        (0, 0),
+        (0, 70),
+        (10, 100),
+        (0, 60),
+        (7, 89),
+        (0, 9),
+        (0, 17),
+        (1, 40),
+        (11, 74),
+        (0, 0),
+        (0, 27),
+        (43, 39),
+        (10, 8),
+        (3, 48),
+        (1, 0),
+        (7, 52),
+        (1, 1),
+        (30, 67),
+        (23, 5),
     ],
     entries: &[
-        ('Ἷ', "Ι"),
-        ('Ὶ', "Ι"),
-        ('ḙ', "e"),
-        ('Ӑ', "А"),
-        ('Ṭ', "T"),
-        ('Ӳ', "У"),
-        ('ó', "o"),
-        ('Ẅ', "W"),
-        ('Ź', "Z"),
-        ('ḧ', "h"),
-        ('ῌ', "Η"),
-        ('ή', "η"),
-        ('ὢ', "ω"),
-        ('ô', "o"),
-        ('ừ', "u"),
-        ('Ų', "U"),
-        ('ї', "і"),
-        ('Ǚ', "U"),
-        ('ἀ', "α"),
-        ('ñ', "n"),
-        ('ӱ', "у"),
-        ('Í', "I"),
-        ('Ö', "O"),
-        ('Ḧ', "H"),
-        ('Ṉ', "N"),
-        ('ṉ', "n"),
-        ('ḩ', "h"),
-        ('Ὤ', "Ω"),
-        ('Ȭ', "O"),
-        ('Ӭ', "Э"),
-        ('΅', " "),
-        ('⊭', "⊨"),
-        ('â', "a"),
-        ('ộ', "o"),
-        ('ḱ', "k"),
-        ('ǿ', "ø"),
-        ('ễ', "e"),
-        ('Ṯ', "T"),
-        ('Ṻ', "U"),
-        ('Ẕ', "Z"),
-        ('ǵ', "g"),
-        ('ὺ', "υ"),
-        ('⊅', "⊃"),
-        ('ὦ', "ω"),
-        ('Ŵ', "W"),
-        ('῾', " "),
-        ('⋫', "⊳"),
-        ('ḍ', "d"),
-        ('⋣', "⊒"),
-        ('Ᾱ', "Α"),
-        ('Ǫ', "O"),
-        ('ỷ', "y"),
-        ('ᾪ', "Ω"),
-        ('ΐ', "ι"),
-        ('ÿ', "y"),
-        ('ḑ', "d"),
-        ('∉', "∈"),
-        ('ќ', "к"),
-        ('ồ', "o"),
-        ('≮', "<"),
-        ('Ă', "A"),
-        ('ḷ', "l"),
-        ('ř', "r"),
-        ('Ạ', "A"),
-        ('ύ', "υ"),
-        ('ḵ', "k"),
-        ('ἵ', "ι"),
-        ('ΰ', "υ"),
-        ('Ὑ', "Υ"),
-        ('ỉ', "i"),
-        ('⫝̸', "⫝"),
-        ('ỗ', "o"),
-        ('Ỹ', "Y"),
-        ('ў', "у"),
-        ('ѷ', "ѵ"),
-        ('Ḣ', "H"),
-        ('⊬', "⊢"),
-        ('Ǭ', "O"),
-        ('ì', "i"),
-        ('Ẋ', "X"),
-        ('ỵ', "y"),
-        ('ᾱ', "α"),
-        ('Ḃ', "B"),
-        ('ἑ', "ε"),
-        ('Ň', "N"),
-        ('Ṃ', "M"),
-        ('ņ', "n"),
-        ('Ḹ', "L"),
-        ('Ḩ', "H"),
-        ('ή', "η"),
-        ('ẳ', "a"),
-        ('Ἶ', "Ι"),
-        ('Ǯ', "Ʒ"),
-        ('Ď', "D"),
-        ('ẫ', "a"),
-        ('Ẽ', "E"),
-        ('Ṓ', "O"),
-        ('Ũ', "U"),
-        ('Ṩ', "S"),
-        ('ὑ', "υ"),
-        ('Ể', "E"),
-        ('﹊', " "),
-        ('ḋ', "d"),
-        ('ᾎ', "Α"),
-        ('ẍ', "x"),
-        ('ṑ', "o"),
-        ('Ӝ', "Ж"),
-        ('Ṟ', "R"),
-        ('Ǹ', "N"),
-        ('ḽ', "l"),
-        ('ḹ', "l"),
-        ('Ŏ', "O"),
-        ('Ẉ', "W"),
-        ('ȩ', "e"),
-        ('⋠', "≼"),
-        ('Ŕ', "R"),
-        ('´', " "),
-        ('Ớ', "O"),
-        ('ś', "s"),
-        ('ἓ', "ε"),
-        ('Ș', "S"),
-        ('ῳ', "ω"),
-        ('ᾷ', "α"),
-        ('Ᾰ', "Α"),
-        ('Ḍ', "D"),
-        ('˜', " "),
-        ('ӗ', "е"),
-        ('Ḽ', "L"),
-        ('Š', "S"),
-        ('Ӵ', "Ч"),
-        ('Ỡ', "O"),
-        ('Ự', "U"),
-        ('≢', "≡"),
-        ('≯', ">"),
-        ('῝', " "),
-        ('ᾭ', "Ω"),
-        ('À', "A"),
-        ('ứ', "u"),
-        ('Ồ', "O"),
-        ('ṫ', "t"),
-        ('ἐ', "ε"),
-        ('Ḟ', "F"),
-        ('ῢ', "υ"),
-        ('↛', "→"),
-        ('ἁ', "α"),
-        ('ᾳ', "α"),
-        ('ḕ', "e"),
-        ('ὓ', "υ"),
-        ('ῃ', "η"),
-        ('Ӯ', "У"),
-        ('Ȧ', "A"),
-        ('Ὺ', "Υ"),
-        ('⊉', "⊇"),
-        ('ȕ', "u"),
-        ('ļ', "l"),
-        ('ί', "ι"),
-        ('Ὂ', "Ο"),
-        ('Ἡ', "Η"),
-        ('Ȑ', "R"),
-        ('Ṫ', "T"),
-        ('Ǖ', "U"),
-        ('ᾌ', "Α"),
-        ('ṣ', "s"),
-        ('ḃ', "b"),
-        ('ẽ', "e"),
-        ('ᾂ', "α"),
-        ('Ừ', "U"),
-        ('ἷ', "ι"),
-        ('ᾕ', "η"),
-        ('ť', "t"),
-        ('Ά', "Α"),
-        ('ӛ', "ә"),
-        ('ᾤ', "ω"),
-        ('Ā', "A"),
-        ('Ḙ', "E"),
-        ('й', "и"),
-        ('ṯ', "t"),
-        ('ḭ', "i"),
-        ('Ń', "N"),
-        ('Ť', "T"),
-        ('Ȳ', "Y"),
-        ('Ḛ', "E"),
-        ('ȃ', "a"),
-        ('Ȩ', "E"),
-        ('Ő', "O"),
-        ('î', "i"),
-        ('ã', "a"),
-        ('⋪', "⊲"),
-        ('Ὥ', "Ω"),
-        ('ỏ', "o"),
-        ('Ì', "I"),
-        ('Ṝ', "R"),
-        ('Ὣ', "Ω"),
-        ('Ѐ', "Е"),
-        ('ǐ', "i"),
-        ('ἣ', "η"),
-        ('ể', "e"),
-        ('ῤ', "ρ"),
-        ('ἱ', "ι"),
-        ('Ủ', "U"),
-        ('ῴ', "ω"),
-        ('Ἔ', "Ε"),
-        ('ḁ', "a"),
-        ('ἥ', "η"),
-        ('Ặ', "A"),
-        ('Ẑ', "Z"),
-        ('ȯ', "o"),
-        ('≄', "≃"),
-        ('ề', "e"),
-        ('ӥ', "и"),
-        ('Ἓ', "Ε"),
-        ('ὕ', "υ"),
-        ('Ǩ', "K"),
-        ('˝', " "),
-        ('∤', "∣"),
-        ('Ῠ', "Υ"),
-        ('Á', "A"),
-        ('ũ', "u"),
-        ('Ἁ', "Α"),
-        ('Ợ', "O"),
-        ('Ḇ', "B"),
-        ('Ṱ', "T"),
-        ('Ñ', "N"),
-        ('ῑ', "ι"),
-        ('Ἇ', "Α"),
-        ('ĉ', "c"),
-        ('ὤ', "ω"),
-        ('í', "i"),
-        ('ӂ', "ж"),
-        ('ὧ', "ω"),
-        ('Ὕ', "Υ"),
-        ('ȁ', "a"),
-        ('Ō', "O"),
-        ('Ȱ', "O"),
-        ('ẁ', "w"),
-        ('ῥ', "ρ"),
-        ('ᾥ', "ω"),
-        ('῀', " "),
-        ('ắ', "a"),
-        ('ḿ', "m"),
-        ('῭', " "),
-        ('ό', "ο"),
-        ('Ẫ', "A"),
-        ('⊁', "≻"),
-        ('Ὅ', "Ο"),
-        ('ȱ', "o"),
-        ('Ỷ', "Y"),
-        ('Ǽ', "Æ"),
-        ('Ṑ', "O"),
-        ('˚', " "),
-        ('´', " "),
-        ('ḡ', "g"),
-        ('ὄ', "ο"),
-        ('΄', " "),
-        ('ṵ', "u"),
-        ('ᾲ', "α"),
-        ('ǘ', "u"),
-        ('Ī', "I"),
-        ('Ṹ', "U"),
-        ('᾿', " "),
-        ('ḉ', "c"),
-        ('ᾙ', "Η"),
-        ('Ǻ', "A"),
-        ('ḏ', "d"),
-        ('ὴ', "η"),
-        ('ậ', "a"),
-        ('ǎ', "a"),
-        ('Ṥ', "S"),
-        ('ӳ', "у"),
-        ('ᾣ', "ω"),
-        ('Ḥ', "H"),
-        ('ş', "s"),
-        ('ạ', "a"),
-        ('ǽ', "æ"),
-        ('ờ', "o"),
-        ('ṇ', "n"),
-        ('ử', "u"),
-        ('Ǐ', "I"),
-        ('ḳ', "k"),
-        ('ȭ', "o"),
-        ('ǭ', "o"),
-        ('˘', " "),
-        ('ń', "n"),
-        ('ṍ', "o"),
-        ('ώ', "ω"),
-        ('ċ', "c"),
-        ('Ȕ', "U"),
-        ('Ύ', "Υ"),
-        ('ȅ', "e"),
-        ('ẘ', "w"),
-        ('⋢', "⊑"),
-        ('Ữ', "U"),
-        ('Ệ', "E"),
-        ('Ĝ', "G"),
-        ('ά', "α"),
-        ('Ļ', "L"),
-        ('ķ', "k"),
-        ('ẛ', "s"),
-        ('≁', "∼"),
-        ('ấ', "a"),
-        ('⊮', "⊩"),
-        ('ẙ', "y"),
-        ('έ', "ε"),
-        ('ӑ', "а"),
-        ('ϋ', "υ"),
-        ('΅', " "),
-        ('Ṅ', "N"),
-        ('Ἧ', "Η"),
-        ('Ĩ', "I"),
-        ('ơ', "o"),
-        ('Ὓ', "Υ"),
-        ('Ǵ', "G"),
-        ('Ὁ', "Ο"),
-        ('ὁ', "ο"),
-        ('ᾨ', "Ω"),
-        ('ṓ', "o"),
-        ('ἄ', "α"),
-        ('ἕ', "ε"),
-        ('Ὗ', "Υ"),
-        ('ȗ', "u"),
-        ('﹉', " "),
-        ('ŏ', "o"),
-        ('Ύ', "Υ"),
-        ('ṁ', "m"),
-        ('Ὲ', "Ε"),
-        ('Ễ', "E"),
-        ('ᾴ', "α"),
-        ('ӝ', "ж"),
-        ('ᾈ', "Α"),
-        ('ᾋ', "Α"),
-        ('Ứ', "U"),
-        ('Ȁ', "A"),
-        ('ᾀ', "α"),
-        ('Ṕ', "P"),
-        ('Ř', "R"),
-        ('ᾅ', "α"),
-        ('ᾜ', "Η"),
-        ('Ở', "O"),
-        ('Ị', "I"),
-        ('Ḡ', "G"),
-        ('Ḝ', "E"),
-        ('ὲ', "ε"),
-        ('Й', "И"),
-        ('ἶ', "ι"),
-        ('Ӣ', "И"),
-        ('∌', "∋"),
-        ('Ț', "T"),
-        ('ị', "i"),
-        ('Ӥ', "И"),
-        ('ḥ', "h"),
-        ('ὖ', "υ"),
-        ('Ȃ', "A"),
-        ('ᾬ', "Ω"),
-        ('Ḷ', "L"),
-        ('Έ', "Ε"),
-        ('ġ', "g"),
-        ('ᾝ', "Η"),
-        ('Ό', "Ο"),
-        ('Ế', "E"),
-        ('ᾩ', "Ω"),
-        ('ë', "e"),
-        ('ű', "u"),
-        ('Ὰ', "Α"),
-        ('Ǡ', "A"),
-        ('Ṏ', "O"),
-        ('∦', "∥"),
-        ('Ë', "E"),
-        ('Ü', "U"),
-        ('Ą', "A"),
-        ('ἧ', "η"),
-        ('ὀ', "ο"),
-        ('Ѝ', "И"),
-        ('ά', "α"),
-        ('↚', "←"),
-        ('ῗ', "ι"),
-        ('ᾖ', "η"),
-        ('ẖ', "h"),
-        ('Ỏ', "O"),
-        ('῎', " "),
-        ('Ả', "A"),
-        ('ŷ', "y"),
-        ('ὥ', "ω"),
-        ('ὐ', "υ"),
-        ('ṭ', "t"),
-        ('˙', " "),
-        ('Ś', "S"),
-        ('Ἄ', "Α"),
-        ('ḫ', "h"),
-        ('Ḱ', "K"),
-        ('Ờ', "O"),
-        ('Ṇ', "N"),
-        ('Ơ', "O"),
-        ('ẕ', "z"),
-        ('Ṗ', "P"),
-        ('ἆ', "α"),
-        ('ĥ', "h"),
-        ('ȧ', "a"),
-        ('Ḿ', "M"),
-        ('ž', "z"),
-        ('Ê', "E"),
-        ('Ņ', "N"),
-        ('Ẓ', "Z"),
-        ('ᾘ', "Η"),
-        ('Ῥ', "Ρ"),
-        ('ṹ', "u"),
-        ('ᾯ', "Ω"),
-        ('ç', "c"),
-        ('Ž', "Z"),
-        ('ύ', "υ"),
-        ('ū', "u"),
-        ('ὰ', "α"),
-        ('≇', "≅"),
-        ('ố', "o"),
-        ('ǻ', "a"),
-        ('Ἒ', "Ε"),
-        ('Î', "I"),
-        ('ň', "n"),
-        ('Ӹ', "Ы"),
-        ('Ù', "U"),
-        ('῟', " "),
-        ('û', "u"),
-        ('ę', "e"),
-        ('Ӧ', "О"),
-        ('Ȇ', "E"),
-        ('Ĭ', "I"),
-        ('ἴ', "ι"),
-        ('ḣ', "h"),
-        ('Ġ', "G"),
-        ('¯', " "),
-        ('ǹ', "n"),
-        ('ḯ', "i"),
-        ('ᾫ', "Ω"),
-        ('Û', "U"),
-        ('É', "E"),
-        ('ȳ', "y"),
-        ('Ẻ', "E"),
-        ('Ṵ', "U"),
-        ('ᾓ', "η"),
-        ('ő', "o"),
-        ('Ῡ', "Υ"),
-        ('Ẹ', "E"),
-        ('Ç', "C"),
-        ('ᾡ', "ω"),
-        ('ĕ', "e"),
-        ('Ǜ', "U"),
-        ('ᾮ', "Ω"),
-        ('ŗ', "r"),
-        ('⊄', "⊂"),
-        ('ò', "o"),
-        ('ú', "u"),
-        ('ğ', "g"),
-        ('ῧ', "υ"),
-        ('έ', "ε"),
-        ('≭', "≍"),
-        ('ą', "a"),
-        ('È', "E"),
-        ('‗', " "),
-        ('ǅ', "Dz"),
-        ('Ĕ', "E"),
-        ('ῖ', "ι"),
-        ('Ǧ', "G"),
-        ('Ķ', "K"),
-        ('ἇ', "α"),
-        ('è', "e"),
-        ('ớ', "o"),
-        ('Č', "C"),
-        ('Ὦ', "Ω"),
-        ('Ĉ', "C"),
-        ('Ἳ', "Ι"),
-        ('ẉ', "w"),
-        ('Ǿ', "Ø"),
-        ('Ǔ', "U"),
-        ('ῷ', "ω"),
-        ('ᾊ', "Α"),
-        ('Ô', "O"),
-        ('ė', "e"),
-        ('ΐ', "ι"),
-        ('Ὠ', "Ω"),
-        ('ǰ', "j"),
-        ('Ἥ', "Η"),
-        ('Õ', "O"),
-        ('¸', " "),
-        ('ᾉ', "Α"),
-        ('Ọ', "O"),
-        ('Ӂ', "Ж"),
-        ('ḻ', "l"),
-        ('ḓ', "d"),
-        ('ῡ', "υ"),
-        ('Ổ', "O"),
-        ('ă', "a"),
-        ('ᾐ', "η"),
-        ('ṋ', "n"),
-        ('⊈', "⊆"),
-        ('ḅ', "b"),
-        ('ῶ', "ω"),
-        ('ẋ', "x"),
-        ('Ė', "E"),
-        ('ῲ', "ω"),
-        ('ằ', "a"),
-        ('Ṙ', "R"),
-        ('Ḳ', "K"),
-        ('Â', "A"),
-        ('ἂ', "α"),
-        ('ḗ', "e"),
-        ('ď', "d"),
-        ('ȉ', "i"),
-        ('ṃ', "m"),
-        ('ọ', "o"),
-        ('ᾗ', "η"),
-        ('ẵ', "a"),
-        ('ϔ', "Υ"),
-        ('ȋ', "i"),
-        ('ṷ', "u"),
-        ('Ӫ', "Ө"),
-        ('ͺ', " "),
-        ('ȑ', "r"),
-        ('Ὸ', "Ο"),
-        ('ῂ', "η"),
-        ('ϓ', "Υ"),
-        ('Ş', "S"),
-        ('ᾏ', "Α"),
-        ('İ', "I"),
-        ('Ǒ', "O"),
-        ('ῦ', "υ"),
-        ('Å', "A"),
-        ('ổ', "o"),
-        ('ᾃ', "α"),
-        ('ȏ', "o"),
-        ('≸', "≶"),
-        ('ĵ', "j"),
-        ('ώ', "ω"),
-        ('ὠ', "ω"),
-        ('ṥ', "s"),
-        ('ӯ', "у"),
-        ('Ӛ', "Ә"),
-        ('ῒ', "ι"),
-        ('ῼ', "Ω"),
-        ('ų', "u"),
-        ('ἳ', "ι"),
-        ('ᾔ', "η"),
-        ('ό', "ο"),
-        ('‾', " "),
-        ('ᾁ', "α"),
-        ('Ῑ', "Ι"),
-        ('ẻ', "e"),
-        ('∄', "∃"),
-        ('Ἑ', "Ε"),
-        ('ΰ', "υ"),
-        ('ǜ', "u"),
-        ('å', "a"),
-        ('Ṧ', "S"),
-        ('ǖ', "u"),
-        ('Ȏ', "O"),
-        ('῞', " "),
-        ('ý', "y"),
-        ('ć', "c"),
-        ('Ϋ', "Υ"),
-        ('﹌', " "),
-        ('Ȫ', "O"),
-        ('Ṳ', "U"),
-        ('Ӓ', "А"),
-        ('ù', "u"),
-        ('ľ', "l"),
-        ('ṙ', "r"),
-        ('Ώ', "Ω"),
-        ('ἠ', "η"),
-        ('Ǘ', "U"),
-        ('ḇ', "b"),
-        ('ӵ', "ч"),
-        ('ӓ', "а"),
-        ('ǒ', "o"),
-        ('≠', "="),
-        ('Ư', "U"),
-        ('ẹ', "e"),
-        ('Ử', "U"),
-        ('ż', "z"),
-        ('ĺ', "l"),
-        ('ѝ', "и"),
-        ('ṡ', "s"),
-        ('ŭ', "u"),
-        ('Ӱ', "У"),
-        ('ṩ', "s"),
-        ('⋭', "⊵"),
-        ('ṧ', "s"),
-        ('Ή', "Η"),
-        ('Ὡ', "Ω"),
-        ('Į', "I"),
-        ('Ḑ', "D"),
-        ('Ḭ', "I"),
-        ('Å', "A"),
-        ('Ǎ', "A"),
-        ('Ṿ', "V"),
-        ('Ѷ', "Ѵ"),
-        ('Ȉ', "I"),
-        ('Ἐ', "Ε"),
-        ('Ṷ', "U"),
-        ('Ṁ', "M"),
-        ('Ḫ', "H"),
-        ('Ỵ', "Y"),
-        ('￣', " "),
-        ('ź', "z"),
-        ('á', "a"),
-        ('Ἣ', "Η"),
-        ('Ό', "Ο"),
-        ('Ἰ', "Ι"),
-        ('ᾒ', "η"),
-        ('˛', " "),
-        ('Ḵ', "K"),
-        ('ě', "e"),
-        ('à', "a"),
-        ('Ȯ', "O"),
-        ('ǆ', "dz"),
-        ('ĭ', "i"),
-        ('Ẍ', "X"),
-        ('ἢ', "η"),
-        ('ṛ', "r"),
-        ('ᾆ', "α"),
-        ('Ǣ', "Æ"),
-        ('Ï', "I"),
-        ('Ἀ', "Α"),
-        ('Ů', "U"),
-        ('ṿ', "v"),
-        ('ἒ', "ε"),
-        ('ἔ', "ε"),
-        ('ṝ', "r"),
-        ('ȟ', "h"),
-        ('Ȅ', "E"),
-        ('õ', "o"),
-        ('Ά', "Α"),
-        ('Ċ', "C"),
-        ('Ğ', "G"),
-        ('Ў', "У"),
-        ('ẑ', "z"),
-        ('Ŝ', "S"),
-        ('ǫ', "o"),
-        ('ӟ', "з"),
-        ('į', "i"),
-        ('ё', "е"),
-        ('Ę', "E"),
-        ('ů', "u"),
-        ('ŝ', "s"),
-        ('Ӗ', "Е"),
-        ('ᾑ', "η"),
-        ('Ȓ', "R"),
-        ('Ẩ', "A"),
-        ('Ẏ', "Y"),
-        ('Ṛ', "R"),
-        ('Ŗ', "R"),
-        ('≱', "≥"),
-        ('Ấ', "A"),
-        ('Ḉ', "C"),
-        ('Ὴ', "Η"),
-        ('ỡ', "o"),
-        ('Ó', "O"),
-        ('ᾠ', "ω"),
-        ('ẇ', "w"),
-        ('῍', " "),
-        ('ǩ', "k"),
-        ('ὸ', "ο"),
-        ('≉', "≈"),
-        ('ṱ', "t"),
-        ('š', "s"),
-        ('ǡ', "a"),
-        ('ṽ', "v"),
-        ('ὗ', "υ"),
-        ('Ố', "O"),
-        ('ệ', "e"),
-        ('Ὄ', "Ο"),
-        ('Ǆ', "DZ"),
-        ('Ľ', "L"),
-        ('ὂ', "ο"),
-        ('Ÿ', "Y"),
-        ('ѓ', "г"),
-        ('Ằ', "A"),
-        ('ê', "e"),
-        ('ὶ', "ι"),
-        ('Ṽ', "V"),
-        ('ῆ', "η"),
-        ('ἃ', "α"),
-        ('ὔ', "υ"),
-        ('Ḯ', "I"),
-        ('ӭ', "э"),
-        ('ṟ', "r"),
-        ('ὃ', "ο"),
-        ('ș', "s"),
-        ('ἤ', "η"),
-        ('ὒ', "υ"),
-        ('ȍ', "o"),
-        ('Ὢ', "Ω"),
-        ('Ὃ', "Ο"),
-        ('ᾦ', "ω"),
-        ('ī', "i"),
-        ('≵', "≳"),
-        ('﹋', " "),
-        ('Ḓ', "D"),
-        ('ḟ', "f"),
-        ('Ἂ', "Α"),
-        ('Ỳ', "Y"),
-        ('ǯ', "ʒ"),
-        ('Ã', "A"),
-        ('Ű', "U"),
-        ('ᾞ', "Η"),
-        ('ở', "o"),
-        ('Ḕ', "E"),
-        ('ᾄ', "α"),
-        ('Ậ', "A"),
-        ('¨', " "),
-        ('Ý', "Y"),
-        ('Ú', "U"),
-        ('Ụ', "U"),
-        ('Ἃ', "Α"),
-        ('ẗ', "t"),
-        ('ᾛ', "Η"),
-        ('ẅ', "w"),
-        ('ϊ', "ι"),
-        ('Ŭ', "U"),
-        ('ĝ', "g"),
-        ('Ż', "Z"),
-        ('Ĥ', "H"),
-        ('Ќ', "К"),
-        ('ῇ', "η"),
-        ('Ἅ', "Α"),
-        ('ῠ', "υ"),
-        ('Ё', "Е"),
-        ('῏', " "),
-        ('⋡', "≽"),
-        ('⇎', "⇔"),
-        ('Ἆ', "Α"),
-        ('Ӟ', "З"),
-        ('≹', "≷"),
-        ('Ẇ', "W"),
-        ('ἡ', "η"),
-        ('᾽', " "),
-        ('Ẃ', "W"),
-        ('Ģ', "G"),
-        ('Ŷ', "Y"),
-        ('Ἤ', "Η"),
-        ('ự', "u"),
-        ('ᾧ', "ω"),
-        ('Ἦ', "Η"),
-        ('Ώ', "Ω"),
-        ('Ắ', "A"),
-        ('ὼ', "ω"),
-        ('ἅ', "α"),
-        ('ẃ', "w"),
-        ('ӣ', "и"),
-        ('ầ', "a"),
-        ('ȇ', "e"),
-        ('ẏ', "y"),
-        ('Ȟ', "H"),
-        ('ä', "a"),
-        ('ţ', "t"),
-        ('ü', "u"),
-        ('ṻ', "u"),
-        ('Ỉ', "I"),
-        ('ṕ', "p"),
-        ('Ї', "І"),
-        ('ἰ', "ι"),
-        ('⊀', "≺"),
-        ('ē', "e"),
-        ('ṳ', "u"),
-        ('ᾰ', "α"),
-        ('⇍', "⇐"),
-        ('Ẁ', "W"),
-        ('ᾇ', "α"),
-        ('Ἢ', "Η"),
-        ('⊯', "⊫"),
-        ('ữ', "u"),
-        ('ǔ', "u"),
-        ('ế', "e"),
-        ('ᾚ', "Η"),
-        ('ủ', "u"),
-        ('Ţ', "T"),
-        ('ḝ', "e"),
-        ('ὣ', "ω"),
-        ('ẓ', "z"),
-        ('Ἠ', "Η"),
-        ('Ū', "U"),
-        ('Ē', "E"),
-        ('Ầ', "A"),
-        ('ā', "a"),
-        ('ẩ', "a"),
-        ('ĩ', "i"),
-        ('ὡ', "ω"),
-        ('ǟ', "a"),
-        ('Ϊ', "Ι"),
-        ('ӫ', "ө"),
-        ('Ȍ', "O"),
-        ('Ṍ', "O"),
-        ('ἲ', "ι"),
-        ('⇏', "⇒"),
-        ('Ò', "O"),
-        ('ṏ', "o"),
-        ('Ộ', "O"),
-        ('ǧ', "g"),
-        ('Ἲ', "Ι"),
-        ('Ῐ', "Ι"),
-        ('Ḗ', "E"),
-        ('ṗ', "p"),
-        ('ǣ', "æ"),
-        ('ᾢ', "ω"),
-        ('ợ', "o"),
-        ('Ĺ', "L"),
-        ('Ě', "E"),
-        ('ȫ', "o"),
-        ('é', "e"),
-        ('Ć', "C"),
-        ('ģ', "g"),
-        ('ṅ', "n"),
-        ('ö', "o"),
-        ('Ḏ', "D"),
-        ('ỹ', "y"),
-        ('ȓ', "r"),
-        ('⋬', "⊴"),
-        ('Ĵ', "J"),
-        ('Ὀ', "Ο"),
-        ('↮', "↔"),
-        ('ῄ', "η"),
-        ('ŵ', "w"),
-        ('Ỗ', "O"),
-        ('Ἱ', "Ι"),
-        ('ḛ', "e"),
-        ('ὅ', "ο"),
-        ('ư', "u"),
-        ('Έ', "Ε"),
-        ('ặ', "a"),
-        ('ᾼ', "Α"),
-        ('ᾍ', "Α"),
-        ('ᾶ', "α"),
-        ('Ṣ', "S"),
-        ('≴', "≲"),
-        ('ï', "i"),
-        ('Ί', "Ι"),
-        ('Ȋ', "I"),
-        ('Ề', "E"),
-        ('Ί', "Ι"),
-        ('ả', "a"),
-        ('ŕ', "r"),
-        ('Ὼ', "Ω"),
-        ('Ἴ', "Ι"),
-        ('Ḋ', "D"),
-        ('ί', "ι"),
-        ('Ѓ', "Г"),
-        ('Ḻ', "L"),
-        ('Ȗ', "U"),
-        ('ӹ', "ы"),
-        ('ῐ', "ι"),
-        ('č', "c"),
-        ('ӧ', "о"),
-        ('Ḁ', "A"),
-        ('Ǟ', "A"),
-        ('Ḅ', "B"),
-        ('Ṋ', "N"),
-        ('ѐ', "е"),
-        ('Ẵ', "A"),
-        ('ō', "o"),
-        ('ᾟ', "Η"),
-        ('ț', "t"),
-        ('Ἕ', "Ε"),
-        ('ụ', "u"),
-        ('ǚ', "u"),
-        ('ἦ', "η"),
-        ('῁', " "),
-        ('≰', "≤"),
-        ('ỳ', "y"),
-        ('Ή', "Η"),
-        ('Ẳ', "A"),
-        ('Ἵ', "Ι"),
-        ('Ä', "A"),
-        ('Ὧ', "Ω"),
-        ('Ṡ', "S"),
+        ('ᾞ', crate::Replacement(1437)),
+        ('ῷ', crate::Replacement(821)),
+        ('ᾳ', crate::Replacement(771)),
+        ('ᾜ', crate::Replacement(1435)),
+        ('ᾓ', crate::Replacement(1434)),
+        ('ᾉ', crate::Replacement(1416)),
+        ('ῂ', crate::Replacement(1464)),
+        ('ẙ', crate::Replacement(601)),
+        ('ﬂ', crate::Replacement(506)),
+        ('ᾎ', crate::Replacement(1421)),
+        ('ẘ', crate::Replacement(596)),
+        ('ß', crate::Replacement(586)),
+        ('ὖ', crate::Replacement(814)),
+        ('ᾀ', crate::Replacement(1415)),
+        ('ᾐ', crate::Replacement(1431)),
+        ('ᾤ', crate::Replacement(1451)),
+        ('ᾡ', crate::Replacement(1448)),
+        ('ῳ', crate::Replacement(822)),
+        ('ᾘ', crate::Replacement(1431)),
+        ('ῦ', crate::Replacement(815)),
+        ('ᾼ', crate::Replacement(771)),
+        ('ﬁ', crate::Replacement(505)),
+        ('ᾬ', crate::Replacement(1451)),
+        ('ΰ', crate::Replacement(809)),
+        ('ῇ', crate::Replacement(779)),
+        ('ῲ', crate::Replacement(1465)),
+        ('ᾫ', crate::Replacement(1450)),
+        ('ᾛ', crate::Replacement(1434)),
+        ('ᾕ', crate::Replacement(1436)),
+        ('ᾂ', crate::Replacement(1417)),
+        ('ῶ', crate::Replacement(820)),
+        ('ᾄ', crate::Replacement(1419)),
+        ('ᾢ', crate::Replacement(1449)),
+        ('ᾩ', crate::Replacement(1448)),
+        ('ᾙ', crate::Replacement(1432)),
+        ('ﬓ', crate::Replacement(893)),
+        ('ﬔ', crate::Replacement(890)),
+        ('ᾒ', crate::Replacement(1433)),
+        ('ᾚ', crate::Replacement(1433)),
+        ('ὒ', crate::Replacement(812)),
+        ('ᾍ', crate::Replacement(1420)),
+        ('ᾃ', crate::Replacement(1418)),
+        ('ᾮ', crate::Replacement(1453)),
+        ('ῃ', crate::Replacement(780)),
+        ('ῖ', crate::Replacement(786)),
+        ('ΐ', crate::Replacement(784)),
+        ('ῆ', crate::Replacement(778)),
+        ('ᾨ', crate::Replacement(1447)),
+        ('ẖ', crate::Replacement(513)),
+        ('ﬕ', crate::Replacement(891)),
+        ('ﬃ', crate::Replacement(503)),
+        ('ﬅ', crate::Replacement(587)),
+        ('ᾈ', crate::Replacement(1415)),
+        ('ᾲ', crate::Replacement(1463)),
+        ('ﬆ', crate::Replacement(587)),
+        ('ᾌ', crate::Replacement(1419)),
+        ('ﬗ', crate::Replacement(892)),
+        ('ῒ', crate::Replacement(783)),
+        ('ẚ', crate::Replacement(478)),
+        ('ᾊ', crate::Replacement(1417)),
+        ('ᾋ', crate::Replacement(1418)),
+        ('ὔ', crate::Replacement(813)),
+        ('ᾧ', crate::Replacement(1454)),
+        ('ᾝ', crate::Replacement(1436)),
+        ('ῢ', crate::Replacement(808)),
+        ('ῴ', crate::Replacement(823)),
+        ('ΐ', crate::Replacement(784)),
+        ('ᾪ', crate::Replacement(1449)),
+        ('ῼ', crate::Replacement(822)),
+        ('ﬖ', crate::Replacement(894)),
+        ('ᾗ', crate::Replacement(1438)),
+        ('ŉ', crate::Replacement(714)),
+        ('ᾑ', crate::Replacement(1432)),
+        ('ᾦ', crate::Replacement(1453)),
+        ('ᾠ', crate::Replacement(1447)),
+        ('ὐ', crate::Replacement(811)),
+        ('ẗ', crate::Replacement(589)),
+        ('ǰ', crate::Replacement(522)),
+        ('ῤ', crate::Replacement(803)),
+        ('ᾖ', crate::Replacement(1437)),
+        ('ᾷ', crate::Replacement(770)),
+        ('ῧ', crate::Replacement(810)),
+        ('ᾆ', crate::Replacement(1421)),
+        ('ᾔ', crate::Replacement(1435)),
+        ('ΰ', crate::Replacement(809)),
+        ('ᾅ', crate::Replacement(1420)),
+        ('ῗ', crate::Replacement(785)),
+        ('ᾶ', crate::Replacement(769)),
+        ('և', crate::Replacement(889)),
+        ('ᾥ', crate::Replacement(1452)),
+        ('ᾯ', crate::Replacement(1454)),
+        ('ᾭ', crate::Replacement(1452)),
+        ('ﬀ', crate::Replacement(502)),
+        ('ᾁ', crate::Replacement(1416)),
+        ('ᾴ', crate::Replacement(766)),
+        ('ﬄ', crate::Replacement(504)),
+        ('ᾇ', crate::Replacement(1422)),
+        ('ῌ', crate::Replacement(780)),
+        ('ᾟ', crate::Replacement(1438)),
+        ('ῄ', crate::Replacement(767)),
+        ('ᾣ', crate::Replacement(1450)),
+        ('ᾏ', crate::Replacement(1422)),
     ],
 };
+
+// The conjoining jamo of every Hangul syllable in order, 3 UTF-8
+// bytes each: L V for the first syllable of each row, L V T after.
+#[cfg(feature = "hangul")]
+const HANGUL_JAMO: &str = "\
+가각갂갃간갅갆갇갈갉갊갋갌갍갎갏감갑값갓갔강갖갗갘같갚갛\
+개객갞갟갠갡갢갣갤갥갦갧갨갩갪갫갬갭갮갯갰갱갲갳갴갵갶갷\
+갸갹갺갻갼갽갾갿걀걁걂걃걄걅걆걇걈걉걊걋걌걍걎걏걐걑걒걓\
+걔걕걖걗걘걙걚걛걜걝걞걟걠걡걢걣걤걥걦걧걨걩걪걫걬걭걮걯\
+거걱걲걳건걵걶걷걸걹걺걻걼걽걾걿검겁겂것겄겅겆겇겈겉겊겋\
+게겍겎겏겐겑겒겓겔겕겖겗겘겙겚겛겜겝겞겟겠겡겢겣겤겥겦겧\
+겨격겪겫견겭겮겯결겱겲겳겴겵겶겷겸겹겺겻겼경겾겿곀곁곂곃\
+계곅곆곇곈곉곊곋곌곍곎곏곐곑곒곓곔곕곖곗곘곙곚곛곜곝곞곟\
+고곡곢곣곤곥곦곧골곩곪곫곬곭곮곯곰곱곲곳곴공곶곷곸곹곺곻\
+과곽곾곿관괁괂괃괄괅괆괇괈괉괊괋괌괍괎괏괐광괒괓괔괕괖괗\
+괘괙괚괛괜괝괞괟괠괡괢괣괤괥괦괧괨괩괪괫괬괭괮괯괰괱괲괳\
+괴괵괶괷괸괹괺괻괼괽괾괿굀굁굂굃굄굅굆굇굈굉굊굋굌굍굎굏\
+교굑굒굓굔굕굖굗굘굙굚굛굜굝굞굟굠굡굢굣굤굥굦굧굨굩굪굫\
+구국굮굯군굱굲굳굴굵굶굷굸굹굺굻굼굽굾굿궀궁궂궃궄궅궆궇\
+궈궉궊궋권궍궎궏궐궑궒궓궔궕궖궗궘궙궚궛궜궝궞궟궠궡궢궣\
+궤궥궦궧궨궩궪궫궬궭궮궯궰궱궲궳궴궵궶궷궸궹궺궻궼궽궾궿\
+귀귁귂귃귄귅귆귇귈귉귊귋귌귍귎귏귐귑귒귓귔귕귖귗귘귙귚귛\
+규귝귞귟균귡귢귣귤귥귦귧귨귩귪귫귬귭귮귯귰귱귲귳귴귵귶귷\
+그극귺귻근귽귾귿글긁긂긃긄긅긆긇금급긊긋긌긍긎긏긐긑긒긓\
+긔긕긖긗긘긙긚긛긜긝긞긟긠긡긢긣긤긥긦긧긨긩긪긫긬긭긮긯\
+기긱긲긳긴긵긶긷길긹긺긻긼긽긾긿김깁깂깃깄깅깆깇깈깉깊깋\
+까깍깎깏깐깑깒깓깔깕깖깗깘깙깚깛깜깝깞깟깠깡깢깣깤깥깦깧\
+깨깩깪깫깬깭깮깯깰깱깲깳깴깵깶깷깸깹깺깻깼깽깾깿꺀꺁꺂꺃\
+꺄꺅꺆꺇꺈꺉꺊꺋꺌꺍꺎꺏꺐꺑꺒꺓꺔꺕꺖꺗꺘꺙꺚꺛꺜꺝꺞꺟\
+꺠꺡꺢꺣꺤꺥꺦꺧꺨꺩꺪꺫꺬꺭꺮꺯꺰꺱꺲꺳꺴꺵꺶꺷꺸꺹꺺꺻\
+꺼꺽꺾꺿껀껁껂껃껄껅껆껇껈껉껊껋껌껍껎껏껐껑껒껓껔껕껖껗\
+께껙껚껛껜껝껞껟껠껡껢껣껤껥껦껧껨껩껪껫껬껭껮껯껰껱껲껳\
+껴껵껶껷껸껹껺껻껼껽껾껿꼀꼁꼂꼃꼄꼅꼆꼇꼈꼉꼊꼋꼌꼍꼎꼏\
+꼐꼑꼒꼓꼔꼕꼖꼗꼘꼙꼚꼛꼜꼝꼞꼟꼠꼡꼢꼣꼤꼥꼦꼧꼨꼩꼪꼫\
+꼬꼭꼮꼯꼰꼱꼲꼳꼴꼵꼶꼷꼸꼹꼺꼻꼼꼽꼾꼿꽀꽁꽂꽃꽄꽅꽆꽇\
+꽈꽉꽊꽋꽌꽍꽎꽏꽐꽑꽒꽓꽔꽕꽖꽗꽘꽙꽚꽛꽜꽝꽞꽟꽠꽡꽢꽣\
+꽤꽥꽦꽧꽨꽩꽪꽫꽬꽭꽮꽯꽰꽱꽲꽳꽴꽵꽶꽷꽸꽹꽺꽻꽼꽽꽾꽿\
+꾀꾁꾂꾃꾄꾅꾆꾇꾈꾉꾊꾋꾌꾍꾎꾏꾐꾑꾒꾓꾔꾕꾖꾗꾘꾙꾚꾛\
+꾜꾝꾞꾟꾠꾡꾢꾣꾤꾥꾦꾧꾨꾩꾪꾫꾬꾭꾮꾯꾰꾱꾲꾳꾴꾵꾶꾷\
+꾸꾹꾺꾻꾼꾽꾾꾿꿀꿁꿂꿃꿄꿅꿆꿇꿈꿉꿊꿋꿌꿍꿎꿏꿐꿑꿒꿓\
+꿔꿕꿖꿗꿘꿙꿚꿛꿜꿝꿞꿟꿠꿡꿢꿣꿤꿥꿦꿧꿨꿩꿪꿫꿬꿭꿮꿯\
+꿰꿱꿲꿳꿴꿵꿶꿷꿸꿹꿺꿻꿼꿽꿾꿿뀀뀁뀂뀃뀄뀅뀆뀇뀈뀉뀊뀋\
+뀌뀍뀎뀏뀐뀑뀒뀓뀔뀕뀖뀗뀘뀙뀚뀛뀜뀝뀞뀟뀠뀡뀢뀣뀤뀥뀦뀧\
+뀨뀩뀪뀫뀬뀭뀮뀯뀰뀱뀲뀳뀴뀵뀶뀷뀸뀹뀺뀻뀼뀽뀾뀿끀끁끂끃\
+끄끅끆끇끈끉끊끋끌끍끎끏끐끑끒끓끔끕끖끗끘끙끚끛끜끝끞끟\
+끠끡끢끣끤끥끦끧끨끩끪끫끬끭끮끯끰끱끲끳끴끵끶끷끸끹끺끻\
+끼끽끾끿낀낁낂낃낄낅낆낇낈낉낊낋낌낍낎낏낐낑낒낓낔낕낖낗\
+나낙낚낛난낝낞낟날낡낢낣낤낥낦낧남납낪낫났낭낮낯낰낱낲낳\
+내낵낶낷낸낹낺낻낼낽낾낿냀냁냂냃냄냅냆냇냈냉냊냋냌냍냎냏\
+냐냑냒냓냔냕냖냗냘냙냚냛냜냝냞냟냠냡냢냣냤냥냦냧냨냩냪냫\
+냬냭냮냯냰냱냲냳냴냵냶냷냸냹냺냻냼냽냾냿넀넁넂넃넄넅넆넇\
+너넉넊넋넌넍넎넏널넑넒넓넔넕넖넗넘넙넚넛넜넝넞넟넠넡넢넣\
+네넥넦넧넨넩넪넫넬넭넮넯넰넱넲넳넴넵넶넷넸넹넺넻넼넽넾넿\
+녀녁녂녃년녅녆녇녈녉녊녋녌녍녎녏념녑녒녓녔녕녖녗녘녙녚녛\
+녜녝녞녟녠녡녢녣녤녥녦녧녨녩녪녫녬녭녮녯녰녱녲녳녴녵녶녷\
+노녹녺녻논녽녾녿놀놁놂놃놄놅놆놇놈놉놊놋놌농놎놏놐놑높놓\
+놔놕놖놗놘놙놚놛놜놝놞놟놠놡놢놣놤놥놦놧놨놩놪놫놬놭놮놯\
+놰놱놲놳놴놵놶놷놸놹놺놻놼놽놾놿뇀뇁뇂뇃뇄뇅뇆뇇뇈뇉뇊뇋\
+뇌뇍뇎뇏뇐뇑뇒뇓뇔뇕뇖뇗뇘뇙뇚뇛뇜뇝뇞뇟뇠뇡뇢뇣뇤뇥뇦뇧\
+뇨뇩뇪뇫뇬뇭뇮뇯뇰뇱뇲뇳뇴뇵뇶뇷뇸뇹뇺뇻뇼뇽뇾뇿눀눁눂눃\
+누눅눆눇눈눉눊눋눌눍눎눏눐눑눒눓눔눕눖눗눘눙눚눛눜눝눞눟\
+눠눡눢눣눤눥눦눧눨눩눪눫눬눭눮눯눰눱눲눳눴눵눶눷눸눹눺눻\
+눼눽눾눿뉀뉁뉂뉃뉄뉅뉆뉇뉈뉉뉊뉋뉌뉍뉎뉏뉐뉑뉒뉓뉔뉕뉖뉗\
+뉘뉙뉚뉛뉜뉝뉞뉟뉠뉡뉢뉣뉤뉥뉦뉧뉨뉩뉪뉫뉬뉭뉮뉯뉰뉱뉲뉳\
+뉴뉵뉶뉷뉸뉹뉺뉻뉼뉽뉾뉿늀늁늂늃늄늅늆늇늈늉늊늋늌늍늎늏\
+느늑늒늓는늕늖늗늘늙늚늛늜늝늞늟늠늡늢늣늤능늦늧늨늩늪늫\
+늬늭늮늯늰늱늲늳늴늵늶늷늸늹늺늻늼늽늾늿닀닁닂닃닄닅닆닇\
+니닉닊닋닌닍닎닏닐닑닒닓닔닕닖닗님닙닚닛닜닝닞닟닠닡닢닣\
+다닥닦닧단닩닪닫달닭닮닯닰닱닲닳담답닶닷닸당닺닻닼닽닾닿\
+대댁댂댃댄댅댆댇댈댉댊댋댌댍댎댏댐댑댒댓댔댕댖댗댘댙댚댛\
+댜댝댞댟댠댡댢댣댤댥댦댧댨댩댪댫댬댭댮댯댰댱댲댳댴댵댶댷\
+댸댹댺댻댼댽댾댿덀덁덂덃덄덅덆덇덈덉덊덋덌덍덎덏덐덑덒덓\
+더덕덖덗던덙덚덛덜덝덞덟덠덡덢덣덤덥덦덧덨덩덪덫덬덭덮덯\
+데덱덲덳덴덵덶덷델덹덺덻덼덽덾덿뎀뎁뎂뎃뎄뎅뎆뎇뎈뎉뎊뎋\
+뎌뎍뎎뎏뎐뎑뎒뎓뎔뎕뎖뎗뎘뎙뎚뎛뎜뎝뎞뎟뎠뎡뎢뎣뎤뎥뎦뎧\
+뎨뎩뎪뎫뎬뎭뎮뎯뎰뎱뎲뎳뎴뎵뎶뎷뎸뎹뎺뎻뎼뎽뎾뎿돀돁돂돃\
+도독돆돇돈돉돊돋돌돍돎돏돐돑돒돓돔돕돖돗돘동돚돛돜돝돞돟\
+돠돡돢돣돤돥돦돧돨돩돪돫돬돭돮돯돰돱돲돳돴돵돶돷돸돹돺돻\
+돼돽돾돿됀됁됂됃됄됅됆됇됈됉됊됋됌됍됎됏됐됑됒됓됔됕됖됗\
+되됙됚됛된됝됞됟될됡됢됣됤됥됦됧됨됩됪됫됬됭됮됯됰됱됲됳\
+됴됵됶됷됸됹됺됻됼됽됾됿둀둁둂둃둄둅둆둇둈둉둊둋둌둍둎둏\
+두둑둒둓둔둕둖둗둘둙둚둛둜둝둞둟둠둡둢둣둤둥둦둧둨둩둪둫\
+둬둭둮둯둰둱둲둳둴둵둶둷둸둹둺둻둼둽둾둿뒀뒁뒂뒃뒄뒅뒆뒇\
+뒈뒉뒊뒋뒌뒍뒎뒏뒐뒑뒒뒓뒔뒕뒖뒗뒘뒙뒚뒛뒜뒝뒞뒟뒠뒡뒢뒣\
+뒤뒥뒦뒧뒨뒩뒪뒫뒬뒭뒮뒯뒰뒱뒲뒳뒴뒵뒶뒷뒸뒹뒺뒻뒼뒽뒾뒿\
+듀듁듂듃듄듅듆듇듈듉듊듋듌듍듎듏듐듑듒듓듔듕듖듗듘듙듚듛\
+드득듞듟든듡듢듣들듥듦듧듨듩듪듫듬듭듮듯듰등듲듳듴듵듶듷\
+듸듹듺듻듼듽듾듿딀딁딂딃딄딅딆딇딈딉딊딋딌딍딎딏딐딑딒딓\
+디딕딖딗딘딙딚딛딜딝딞딟딠딡딢딣딤딥딦딧딨딩딪딫딬딭딮딯\
+따딱딲딳딴딵딶딷딸딹딺딻딼딽딾딿땀땁땂땃땄땅땆땇땈땉땊땋\
+때땍땎땏땐땑땒땓땔땕땖땗땘땙땚땛땜땝땞땟땠땡땢땣땤땥땦땧\
+땨땩땪땫땬땭땮땯땰땱땲땳땴땵땶땷땸땹땺땻땼땽땾땿떀떁떂떃\
+떄떅떆떇떈떉떊떋떌떍떎떏떐떑떒떓떔떕떖떗떘떙떚떛떜떝떞떟\
+떠떡떢떣떤떥떦떧떨떩떪떫떬떭떮떯떰떱떲떳떴떵떶떷떸떹떺떻\
+떼떽떾떿뗀뗁뗂뗃뗄뗅뗆뗇뗈뗉뗊뗋뗌뗍뗎뗏뗐뗑뗒뗓뗔뗕뗖뗗\
+뗘뗙뗚뗛뗜뗝뗞뗟뗠뗡뗢뗣뗤뗥뗦뗧뗨뗩뗪뗫뗬뗭뗮뗯뗰뗱뗲뗳\
+뗴뗵뗶뗷뗸뗹뗺뗻뗼뗽뗾뗿똀똁똂똃똄똅똆똇똈똉똊똋똌똍똎똏\
+또똑똒똓똔똕똖똗똘똙똚똛똜똝똞똟똠똡똢똣똤똥똦똧똨똩똪똫\
+똬똭똮똯똰똱똲똳똴똵똶똷똸똹똺똻똼똽똾똿뙀뙁뙂뙃뙄뙅뙆뙇\
+뙈뙉뙊뙋뙌뙍뙎뙏뙐뙑뙒뙓뙔뙕뙖뙗뙘뙙뙚뙛뙜뙝뙞뙟뙠뙡뙢뙣\
+뙤뙥뙦뙧뙨뙩뙪뙫뙬뙭뙮뙯뙰뙱뙲뙳뙴뙵뙶뙷뙸뙹뙺뙻뙼뙽뙾뙿\
+뚀뚁뚂뚃뚄뚅뚆뚇뚈뚉뚊뚋뚌뚍뚎뚏뚐뚑뚒뚓뚔뚕뚖뚗뚘뚙뚚뚛\
+뚜뚝뚞뚟뚠뚡뚢뚣뚤뚥뚦뚧뚨뚩뚪뚫뚬뚭뚮뚯뚰뚱뚲뚳뚴뚵뚶뚷\
+뚸뚹뚺뚻뚼뚽뚾뚿뛀뛁뛂뛃뛄뛅뛆뛇뛈뛉뛊뛋뛌뛍뛎뛏뛐뛑뛒뛓\
+뛔뛕뛖뛗뛘뛙뛚뛛뛜뛝뛞뛟뛠뛡뛢뛣뛤뛥뛦뛧뛨뛩뛪뛫뛬뛭뛮뛯\
+뛰뛱뛲뛳뛴뛵뛶뛷뛸뛹뛺뛻뛼뛽뛾뛿뜀뜁뜂뜃뜄뜅뜆뜇뜈뜉뜊뜋\
+뜌뜍뜎뜏뜐뜑뜒뜓뜔뜕뜖뜗뜘뜙뜚뜛뜜뜝뜞뜟뜠뜡뜢뜣뜤뜥뜦뜧\
+뜨뜩뜪뜫뜬뜭뜮뜯뜰뜱뜲뜳뜴뜵뜶뜷뜸뜹뜺뜻뜼뜽뜾뜿띀띁띂띃\
+띄띅띆띇띈띉띊띋띌띍띎띏띐띑띒띓띔띕띖띗띘띙띚띛띜띝띞띟\
+띠띡띢띣띤띥띦띧띨띩띪띫띬띭띮띯띰띱띲띳띴띵띶띷띸띹띺띻\
+라락띾띿란랁랂랃랄랅랆랇랈랉랊랋람랍랎랏랐랑랒랓랔랕랖랗\
+래랙랚랛랜랝랞랟랠랡랢랣랤랥랦랧램랩랪랫랬랭랮랯랰랱랲랳\
+랴략랶랷랸랹랺랻랼랽랾랿럀럁럂럃럄럅럆럇럈량럊럋럌럍럎럏\
+럐럑럒럓럔럕럖럗럘럙럚럛럜럝럞럟럠럡럢럣럤럥럦럧럨럩럪럫\
+러럭럮럯런럱럲럳럴럵럶럷럸럹럺럻럼럽럾럿렀렁렂렃렄렅렆렇\
+레렉렊렋렌렍렎렏렐렑렒렓렔렕렖렗렘렙렚렛렜렝렞렟렠렡렢렣\
+려력렦렧련렩렪렫렬렭렮렯렰렱렲렳렴렵렶렷렸령렺렻렼렽렾렿\
+례롁롂롃롄롅롆롇롈롉롊롋롌롍롎롏롐롑롒롓롔롕롖롗롘롙롚롛\
+로록롞롟론롡롢롣롤롥롦롧롨롩롪롫롬롭롮롯롰롱롲롳롴롵롶롷\
+롸롹롺롻롼롽롾롿뢀뢁뢂뢃뢄뢅뢆뢇뢈뢉뢊뢋뢌뢍뢎뢏뢐뢑뢒뢓\
+뢔뢕뢖뢗뢘뢙뢚뢛뢜뢝뢞뢟뢠뢡뢢뢣뢤뢥뢦뢧뢨뢩뢪뢫뢬뢭뢮뢯\
+뢰뢱뢲뢳뢴뢵뢶뢷뢸뢹뢺뢻뢼뢽뢾뢿룀룁룂룃룄룅룆룇룈룉룊룋\
+료룍룎룏룐룑룒룓룔룕룖룗룘룙룚룛룜룝룞룟룠룡룢룣룤룥룦룧\
+루룩룪룫룬룭룮룯룰룱룲룳룴룵룶룷룸룹룺룻룼룽룾룿뤀뤁뤂뤃\
+뤄뤅뤆뤇뤈뤉뤊뤋뤌뤍뤎뤏뤐뤑뤒뤓뤔뤕뤖뤗뤘뤙뤚뤛뤜뤝뤞뤟\
+뤠뤡뤢뤣뤤뤥뤦뤧뤨뤩뤪뤫뤬뤭뤮뤯뤰뤱뤲뤳뤴뤵뤶뤷뤸뤹뤺뤻\
+뤼뤽뤾뤿륀륁륂륃륄륅륆륇륈륉륊륋륌륍륎륏륐륑륒륓륔륕륖륗\
+류륙륚륛륜륝륞륟률륡륢륣륤륥륦륧륨륩륪륫륬륭륮륯륰륱륲륳\
+르륵륶륷른륹륺륻를륽륾륿릀릁릂릃름릅릆릇릈릉릊릋릌릍릎릏\
+릐릑릒릓릔릕릖릗릘릙릚릛릜릝릞릟릠릡릢릣릤릥릦릧릨릩릪릫\
+리릭릮릯린릱릲릳릴릵릶릷릸릹릺릻림립릾릿맀링맂맃맄맅맆맇\
+마막맊맋만맍많맏말맑맒맓맔맕맖맗맘맙맚맛맜망맞맟맠맡맢맣\
+매맥맦맧맨맩맪맫맬맭맮맯맰맱맲맳맴맵맶맷맸맹맺맻맼맽맾맿\
+먀먁먂먃먄먅먆먇먈먉먊먋먌먍먎먏먐먑먒먓먔먕먖먗먘먙먚먛\
+먜먝먞먟먠먡먢먣먤먥먦먧먨먩먪먫먬먭먮먯먰먱먲먳먴먵먶먷\
+머먹먺먻먼먽먾먿멀멁멂멃멄멅멆멇멈멉멊멋멌멍멎멏멐멑멒멓\
+메멕멖멗멘멙멚멛멜멝멞멟멠멡멢멣멤멥멦멧멨멩멪멫멬멭멮멯\
+며멱멲멳면멵멶멷멸멹멺멻멼멽멾멿몀몁몂몃몄명몆몇몈몉몊몋\
+몌몍몎몏몐몑몒몓몔몕몖몗몘몙몚몛몜몝몞몟몠몡몢몣몤몥몦몧\
+모목몪몫몬몭몮몯몰몱몲몳몴몵몶몷몸몹몺못몼몽몾몿뫀뫁뫂뫃\
+뫄뫅뫆뫇뫈뫉뫊뫋뫌뫍뫎뫏뫐뫑뫒뫓뫔뫕뫖뫗뫘뫙뫚뫛뫜뫝뫞뫟\
+뫠뫡뫢뫣뫤뫥뫦뫧뫨뫩뫪뫫뫬뫭뫮뫯뫰뫱뫲뫳뫴뫵뫶뫷뫸뫹뫺뫻\
+뫼뫽뫾뫿묀묁묂묃묄묅묆묇묈묉묊묋묌묍묎묏묐묑묒묓묔묕묖묗\
+묘묙묚묛묜묝묞묟묠묡묢묣묤묥묦묧묨묩묪묫묬묭묮묯묰묱묲묳\
+무묵묶묷문묹묺묻물묽묾묿뭀뭁뭂뭃뭄뭅뭆뭇뭈뭉뭊뭋뭌뭍뭎뭏\
+뭐뭑뭒뭓뭔뭕뭖뭗뭘뭙뭚뭛뭜뭝뭞뭟뭠뭡뭢뭣뭤뭥뭦뭧뭨뭩뭪뭫\
+뭬뭭뭮뭯뭰뭱뭲뭳뭴뭵뭶뭷뭸뭹뭺뭻뭼뭽뭾뭿뮀뮁뮂뮃뮄뮅뮆뮇\
+뮈뮉뮊뮋뮌뮍뮎뮏뮐뮑뮒뮓뮔뮕뮖뮗뮘뮙뮚뮛뮜뮝뮞뮟뮠뮡뮢뮣\
+뮤뮥뮦뮧뮨뮩뮪뮫뮬뮭뮮뮯뮰뮱뮲뮳뮴뮵뮶뮷뮸뮹뮺뮻뮼뮽뮾뮿\
+므믁믂믃믄믅믆믇믈믉믊믋믌믍믎믏믐믑믒믓믔믕믖믗믘믙믚믛\
+믜믝믞믟믠믡믢믣믤믥믦믧믨믩믪믫믬믭믮믯믰믱믲믳믴믵믶믷\
+미믹믺믻민믽믾믿밀밁밂밃밄밅밆밇밈밉밊밋밌밍밎및밐밑밒밓\
+바박밖밗반밙밚받발밝밞밟밠밡밢밣밤밥밦밧밨방밪밫밬밭밮밯\
+배백밲밳밴밵밶밷밸밹밺밻밼밽밾밿뱀뱁뱂뱃뱄뱅뱆뱇뱈뱉뱊뱋\
+뱌뱍뱎뱏뱐뱑뱒뱓뱔뱕뱖뱗뱘뱙뱚뱛뱜뱝뱞뱟뱠뱡뱢뱣뱤뱥뱦뱧\
+뱨뱩뱪뱫뱬뱭뱮뱯뱰뱱뱲뱳뱴뱵뱶뱷뱸뱹뱺뱻뱼뱽뱾뱿벀벁벂벃\
+버벅벆벇번벉벊벋벌벍벎벏벐벑벒벓범법벖벗벘벙벚벛벜벝벞벟\
+베벡벢벣벤벥벦벧벨벩벪벫벬벭벮벯벰벱벲벳벴벵벶벷벸벹벺벻\
+벼벽벾벿변볁볂볃별볅볆볇볈볉볊볋볌볍볎볏볐병볒볓볔볕볖볗\
+볘볙볚볛볜볝볞볟볠볡볢볣볤볥볦볧볨볩볪볫볬볭볮볯볰볱볲볳\
+보복볶볷본볹볺볻볼볽볾볿봀봁봂봃봄봅봆봇봈봉봊봋봌봍봎봏\
+봐봑봒봓봔봕봖봗봘봙봚봛봜봝봞봟봠봡봢봣봤봥봦봧봨봩봪봫\
+봬봭봮봯봰봱봲봳봴봵봶봷봸봹봺봻봼봽봾봿뵀뵁뵂뵃뵄뵅뵆뵇\
+뵈뵉뵊뵋뵌뵍뵎뵏뵐뵑뵒뵓뵔뵕뵖뵗뵘뵙뵚뵛뵜뵝뵞뵟뵠뵡뵢뵣\
+뵤뵥뵦뵧뵨뵩뵪뵫뵬뵭뵮뵯뵰뵱뵲뵳뵴뵵뵶뵷뵸뵹뵺뵻뵼뵽뵾뵿\
+부북붂붃분붅붆붇불붉붊붋붌붍붎붏붐붑붒붓붔붕붖붗붘붙붚붛\
+붜붝붞붟붠붡붢붣붤붥붦붧붨붩붪붫붬붭붮붯붰붱붲붳붴붵붶붷\
+붸붹붺붻붼붽붾붿뷀뷁뷂뷃뷄뷅뷆뷇뷈뷉뷊뷋뷌뷍뷎뷏뷐뷑뷒뷓\
+뷔뷕뷖뷗뷘뷙뷚뷛뷜뷝뷞뷟뷠뷡뷢뷣뷤뷥뷦뷧뷨뷩뷪뷫뷬뷭뷮뷯\
+뷰뷱뷲뷳뷴뷵뷶뷷뷸뷹뷺뷻뷼뷽뷾뷿븀븁븂븃븄븅븆븇븈븉븊븋\
+브븍븎븏븐븑븒븓블븕븖븗븘븙븚븛븜븝븞븟븠븡븢븣븤븥븦븧\
+븨븩븪븫븬븭븮븯븰븱븲븳븴븵븶븷븸븹븺븻븼븽븾븿빀빁빂빃\
+비빅빆빇빈빉빊빋빌빍빎빏빐빑빒빓빔빕빖빗빘빙빚빛빜빝빞빟\
+빠빡빢빣빤빥빦빧빨빩빪빫빬빭빮빯빰빱빲빳빴빵빶빷빸빹빺빻\
+빼빽빾빿뺀뺁뺂뺃뺄뺅뺆뺇뺈뺉뺊뺋뺌뺍뺎뺏뺐뺑뺒뺓뺔뺕뺖뺗\
+뺘뺙뺚뺛뺜뺝뺞뺟뺠뺡뺢뺣뺤뺥뺦뺧뺨뺩뺪뺫뺬뺭뺮뺯뺰뺱뺲뺳\
+뺴뺵뺶뺷뺸뺹뺺뺻뺼뺽뺾뺿뻀뻁뻂뻃뻄뻅뻆뻇뻈뻉뻊뻋뻌뻍뻎뻏\
+뻐뻑뻒뻓뻔뻕뻖뻗뻘뻙뻚뻛뻜뻝뻞뻟뻠뻡뻢뻣뻤뻥뻦뻧뻨뻩뻪뻫\
+뻬뻭뻮뻯뻰뻱뻲뻳뻴뻵뻶뻷뻸뻹뻺뻻뻼뻽뻾뻿뼀뼁뼂뼃뼄뼅뼆뼇\
+뼈뼉뼊뼋뼌뼍뼎뼏뼐뼑뼒뼓뼔뼕뼖뼗뼘뼙뼚뼛뼜뼝뼞뼟뼠뼡뼢뼣\
+뼤뼥뼦뼧뼨뼩뼪뼫뼬뼭뼮뼯뼰뼱뼲뼳뼴뼵뼶뼷뼸뼹뼺뼻뼼뼽뼾뼿\
+뽀뽁뽂뽃뽄뽅뽆뽇뽈뽉뽊뽋뽌뽍뽎뽏뽐뽑뽒뽓뽔뽕뽖뽗뽘뽙뽚뽛\
+뽜뽝뽞뽟뽠뽡뽢뽣뽤뽥뽦뽧뽨뽩뽪뽫뽬뽭뽮뽯뽰뽱뽲뽳뽴뽵뽶뽷\
+뽸뽹뽺뽻뽼뽽뽾뽿뾀뾁뾂뾃뾄뾅뾆뾇뾈뾉뾊뾋뾌뾍뾎뾏뾐뾑뾒뾓\
+뾔뾕뾖뾗뾘뾙뾚뾛뾜뾝뾞뾟뾠뾡뾢뾣뾤뾥뾦뾧뾨뾩뾪뾫뾬뾭뾮뾯\
+뾰뾱뾲뾳뾴뾵뾶뾷뾸뾹뾺뾻뾼뾽뾾뾿뿀뿁뿂뿃뿄뿅뿆뿇뿈뿉뿊뿋\
+뿌뿍뿎뿏뿐뿑뿒뿓뿔뿕뿖뿗뿘뿙뿚뿛뿜뿝뿞뿟뿠뿡뿢뿣뿤뿥뿦뿧\
+뿨뿩뿪뿫뿬뿭뿮뿯뿰뿱뿲뿳뿴뿵뿶뿷뿸뿹뿺뿻뿼뿽뿾뿿쀀쀁쀂쀃\
+쀄쀅쀆쀇쀈쀉쀊쀋쀌쀍쀎쀏쀐쀑쀒쀓쀔쀕쀖쀗쀘쀙쀚쀛쀜쀝쀞쀟\
+쀠쀡쀢쀣쀤쀥쀦쀧쀨쀩쀪쀫쀬쀭쀮쀯쀰쀱쀲쀳쀴쀵쀶쀷쀸쀹쀺쀻\
+쀼쀽쀾쀿쁀쁁쁂쁃쁄쁅쁆쁇쁈쁉쁊쁋쁌쁍쁎쁏쁐쁑쁒쁓쁔쁕쁖쁗\
+쁘쁙쁚쁛쁜쁝쁞쁟쁠쁡쁢쁣쁤쁥쁦쁧쁨쁩쁪쁫쁬쁭쁮쁯쁰쁱쁲쁳\
+쁴쁵쁶쁷쁸쁹쁺쁻쁼쁽쁾쁿삀삁삂삃삄삅삆삇삈삉삊삋삌삍삎삏\
+삐삑삒삓삔삕삖삗삘삙삚삛삜삝삞삟삠삡삢삣삤삥삦삧삨삩삪삫\
+사삭삮삯산삱삲삳살삵삶삷삸삹삺삻삼삽삾삿샀상샂샃샄샅샆샇\
+새색샊샋샌샍샎샏샐샑샒샓샔샕샖샗샘샙샚샛샜생샞샟샠샡샢샣\
+샤샥샦샧샨샩샪샫샬샭샮샯샰샱샲샳샴샵샶샷샸샹샺샻샼샽샾샿\
+섀섁섂섃섄섅섆섇섈섉섊섋섌섍섎섏섐섑섒섓섔섕섖섗섘섙섚섛\
+서석섞섟선섡섢섣설섥섦섧섨섩섪섫섬섭섮섯섰성섲섳섴섵섶섷\
+세섹섺섻센섽섾섿셀셁셂셃셄셅셆셇셈셉셊셋셌셍셎셏셐셑셒셓\
+셔셕셖셗션셙셚셛셜셝셞셟셠셡셢셣셤셥셦셧셨셩셪셫셬셭셮셯\
+셰셱셲셳셴셵셶셷셸셹셺셻셼셽셾셿솀솁솂솃솄솅솆솇솈솉솊솋\
+소속솎솏손솑솒솓솔솕솖솗솘솙솚솛솜솝솞솟솠송솢솣솤솥솦솧\
+솨솩솪솫솬솭솮솯솰솱솲솳솴솵솶솷솸솹솺솻솼솽솾솿쇀쇁쇂쇃\
+쇄쇅쇆쇇쇈쇉쇊쇋쇌쇍쇎쇏쇐쇑쇒쇓쇔쇕쇖쇗쇘쇙쇚쇛쇜쇝쇞쇟\
+쇠쇡쇢쇣쇤쇥쇦쇧쇨쇩쇪쇫쇬쇭쇮쇯쇰쇱쇲쇳쇴쇵쇶쇷쇸쇹쇺쇻\
+쇼쇽쇾쇿숀숁숂숃숄숅숆숇숈숉숊숋숌숍숎숏숐숑숒숓숔숕숖숗\
+수숙숚숛순숝숞숟술숡숢숣숤숥숦숧숨숩숪숫숬숭숮숯숰숱숲숳\
+숴숵숶숷숸숹숺숻숼숽숾숿쉀쉁쉂쉃쉄쉅쉆쉇쉈쉉쉊쉋쉌쉍쉎쉏\
+쉐쉑쉒쉓쉔쉕쉖쉗쉘쉙쉚쉛쉜쉝쉞쉟쉠쉡쉢쉣쉤쉥쉦쉧쉨쉩쉪쉫\
+쉬쉭쉮쉯쉰쉱쉲쉳쉴쉵쉶쉷쉸쉹쉺쉻쉼쉽쉾쉿슀슁슂슃슄슅슆슇\
+슈슉슊슋슌슍슎슏슐슑슒슓슔슕슖슗슘슙슚슛슜슝슞슟슠슡슢슣\
+스슥슦슧슨슩슪슫슬슭슮슯슰슱슲슳슴습슶슷슸승슺슻슼슽슾슿\
+싀싁싂싃싄싅싆싇싈싉싊싋싌싍싎싏싐싑싒싓싔싕싖싗싘싙싚싛\
+시식싞싟신싡싢싣실싥싦싧싨싩싪싫심십싮싯싰싱싲싳싴싵싶싷\
+싸싹싺싻싼싽싾싿쌀쌁쌂쌃쌄쌅쌆쌇쌈쌉쌊쌋쌌쌍쌎쌏쌐쌑쌒쌓\
+쌔쌕쌖쌗쌘쌙쌚쌛쌜쌝쌞쌟쌠쌡쌢쌣쌤쌥쌦쌧쌨쌩쌪쌫쌬쌭쌮쌯\
+쌰쌱쌲쌳쌴쌵쌶쌷쌸쌹쌺쌻쌼쌽쌾쌿썀썁썂썃썄썅썆썇썈썉썊썋\
+썌썍썎썏썐썑썒썓썔썕썖썗썘썙썚썛썜썝썞썟썠썡썢썣썤썥썦썧\
+써썩썪썫썬썭썮썯썰썱썲썳썴썵썶썷썸썹썺썻썼썽썾썿쎀쎁쎂쎃\
+쎄쎅쎆쎇쎈쎉쎊쎋쎌쎍쎎쎏쎐쎑쎒쎓쎔쎕쎖쎗쎘쎙쎚쎛쎜쎝쎞쎟\
+쎠쎡쎢쎣쎤쎥쎦쎧쎨쎩쎪쎫쎬쎭쎮쎯쎰쎱쎲쎳쎴쎵쎶쎷쎸쎹쎺쎻\
+쎼쎽쎾쎿쏀쏁쏂쏃쏄쏅쏆쏇쏈쏉쏊쏋쏌쏍쏎쏏쏐쏑쏒쏓쏔쏕쏖쏗\
+쏘쏙쏚쏛쏜쏝쏞쏟쏠쏡쏢쏣쏤쏥쏦쏧쏨쏩쏪쏫쏬쏭쏮쏯쏰쏱쏲쏳\
+쏴쏵쏶쏷쏸쏹쏺쏻쏼쏽쏾쏿쐀쐁쐂쐃쐄쐅쐆쐇쐈쐉쐊쐋쐌쐍쐎쐏\
+쐐쐑쐒쐓쐔쐕쐖쐗쐘쐙쐚쐛쐜쐝쐞쐟쐠쐡쐢쐣쐤쐥쐦쐧쐨쐩쐪쐫\
+쐬쐭쐮쐯쐰쐱쐲쐳쐴쐵쐶쐷쐸쐹쐺쐻쐼쐽쐾쐿쑀쑁쑂쑃쑄쑅쑆쑇\
+쑈쑉쑊쑋쑌쑍쑎쑏쑐쑑쑒쑓쑔쑕쑖쑗쑘쑙쑚쑛쑜쑝쑞쑟쑠쑡쑢쑣\
+쑤쑥쑦쑧쑨쑩쑪쑫쑬쑭쑮쑯쑰쑱쑲쑳쑴쑵쑶쑷쑸쑹쑺쑻쑼쑽쑾쑿\
+쒀쒁쒂쒃쒄쒅쒆쒇쒈쒉쒊쒋쒌쒍쒎쒏쒐쒑쒒쒓쒔쒕쒖쒗쒘쒙쒚쒛\
+쒜쒝쒞쒟쒠쒡쒢쒣쒤쒥쒦쒧쒨쒩쒪쒫쒬쒭쒮쒯쒰쒱쒲쒳쒴쒵쒶쒷\
+쒸쒹쒺쒻쒼쒽쒾쒿쓀쓁쓂쓃쓄쓅쓆쓇쓈쓉쓊쓋쓌쓍쓎쓏쓐쓑쓒쓓\
+쓔쓕쓖쓗쓘쓙쓚쓛쓜쓝쓞쓟쓠쓡쓢쓣쓤쓥쓦쓧쓨쓩쓪쓫쓬쓭쓮쓯\
+쓰쓱쓲쓳쓴쓵쓶쓷쓸쓹쓺쓻쓼쓽쓾쓿씀씁씂씃씄씅씆씇씈씉씊씋\
+씌씍씎씏씐씑씒씓씔씕씖씗씘씙씚씛씜씝씞씟씠씡씢씣씤씥씦씧\
+씨씩씪씫씬씭씮씯씰씱씲씳씴씵씶씷씸씹씺씻씼씽씾씿앀앁앂앃\
+아악앆앇안앉않앋알앍앎앏앐앑앒앓암압앖앗았앙앚앛앜앝앞앟\
+애액앢앣앤앥앦앧앨앩앪앫앬앭앮앯앰앱앲앳앴앵앶앷앸앹앺앻\
+야약앾앿얀얁얂얃얄얅얆얇얈얉얊얋얌얍얎얏얐양얒얓얔얕얖얗\
+얘얙얚얛얜얝얞얟얠얡얢얣얤얥얦얧얨얩얪얫얬얭얮얯얰얱얲얳\
+어억얶얷언얹얺얻얼얽얾얿엀엁엂엃엄업없엇었엉엊엋엌엍엎엏\
+에엑엒엓엔엕엖엗엘엙엚엛엜엝엞엟엠엡엢엣엤엥엦엧엨엩엪엫\
+여역엮엯연엱엲엳열엵엶엷엸엹엺엻염엽엾엿였영옂옃옄옅옆옇\
+예옉옊옋옌옍옎옏옐옑옒옓옔옕옖옗옘옙옚옛옜옝옞옟옠옡옢옣\
+오옥옦옧온옩옪옫올옭옮옯옰옱옲옳옴옵옶옷옸옹옺옻옼옽옾옿\
+와왁왂왃완왅왆왇왈왉왊왋왌왍왎왏왐왑왒왓왔왕왖왗왘왙왚왛\
+왜왝왞왟왠왡왢왣왤왥왦왧왨왩왪왫왬왭왮왯왰왱왲왳왴왵왶왷\
+외왹왺왻왼왽왾왿욀욁욂욃욄욅욆욇욈욉욊욋욌욍욎욏욐욑욒욓\
+요욕욖욗욘욙욚욛욜욝욞욟욠욡욢욣욤욥욦욧욨용욪욫욬욭욮욯\
+우욱욲욳운욵욶욷울욹욺욻욼욽욾욿움웁웂웃웄웅웆웇웈웉웊웋\
+워웍웎웏원웑웒웓월웕웖웗웘웙웚웛웜웝웞웟웠웡웢웣웤웥웦웧\
+웨웩웪웫웬웭웮웯웰웱웲웳웴웵웶웷웸웹웺웻웼웽웾웿윀윁윂윃\
+위윅윆윇윈윉윊윋윌윍윎윏윐윑윒윓윔윕윖윗윘윙윚윛윜윝윞윟\
+유육윢윣윤윥윦윧율윩윪윫윬윭윮윯윰윱윲윳윴융윶윷윸윹윺윻\
+으윽윾윿은읁읂읃을읅읆읇읈읉읊읋음읍읎읏읐응읒읓읔읕읖읗\
+의읙읚읛읜읝읞읟읠읡읢읣읤읥읦읧읨읩읪읫읬읭읮읯읰읱읲읳\
+이익읶읷인읹읺읻일읽읾읿잀잁잂잃임입잆잇있잉잊잋잌잍잎잏\
+자작잒잓잔잕잖잗잘잙잚잛잜잝잞잟잠잡잢잣잤장잦잧잨잩잪잫\
+재잭잮잯잰잱잲잳잴잵잶잷잸잹잺잻잼잽잾잿쟀쟁쟂쟃쟄쟅쟆쟇\
+쟈쟉쟊쟋쟌쟍쟎쟏쟐쟑쟒쟓쟔쟕쟖쟗쟘쟙쟚쟛쟜쟝쟞쟟쟠쟡쟢쟣\
+쟤쟥쟦쟧쟨쟩쟪쟫쟬쟭쟮쟯쟰쟱쟲쟳쟴쟵쟶쟷쟸쟹쟺쟻쟼쟽쟾쟿\
+저적젂젃전젅젆젇절젉젊젋젌젍젎젏점접젒젓젔정젖젗젘젙젚젛\
+제젝젞젟젠젡젢젣젤젥젦젧젨젩젪젫젬젭젮젯젰젱젲젳젴젵젶젷\
+져젹젺젻젼젽젾젿졀졁졂졃졄졅졆졇졈졉졊졋졌졍졎졏졐졑졒졓\
+졔졕졖졗졘졙졚졛졜졝졞졟졠졡졢졣졤졥졦졧졨졩졪졫졬졭졮졯\
+조족졲졳존졵졶졷졸졹졺졻졼졽졾졿좀좁좂좃좄종좆좇좈좉좊좋\
+좌좍좎좏좐좑좒좓좔좕좖좗좘좙좚좛좜좝좞좟좠좡좢좣좤좥좦좧\
+좨좩좪좫좬좭좮좯좰좱좲좳좴좵좶좷좸좹좺좻좼좽좾좿죀죁죂죃\
+죄죅죆죇죈죉죊죋죌죍죎죏죐죑죒죓죔죕죖죗죘죙죚죛죜죝죞죟\
+죠죡죢죣죤죥죦죧죨죩죪죫죬죭죮죯죰죱죲죳죴죵죶죷죸죹죺죻\
+주죽죾죿준줁줂줃줄줅줆줇줈줉줊줋줌줍줎줏줐중줒줓줔줕줖줗\
+줘줙줚줛줜줝줞줟줠줡줢줣줤줥줦줧줨줩줪줫줬줭줮줯줰줱줲줳\
+줴줵줶줷줸줹줺줻줼줽줾줿쥀쥁쥂쥃쥄쥅쥆쥇쥈쥉쥊쥋쥌쥍쥎쥏\
+쥐쥑쥒쥓쥔쥕쥖쥗쥘쥙쥚쥛쥜쥝쥞쥟쥠쥡쥢쥣쥤쥥쥦쥧쥨쥩쥪쥫\
+쥬쥭쥮쥯쥰쥱쥲쥳쥴쥵쥶쥷쥸쥹쥺쥻쥼쥽쥾쥿즀즁즂즃즄즅즆즇\
+즈즉즊즋즌즍즎즏즐즑즒즓즔즕즖즗즘즙즚즛즜증즞즟즠즡즢즣\
+즤즥즦즧즨즩즪즫즬즭즮즯즰즱즲즳즴즵즶즷즸즹즺즻즼즽즾즿\
+지직짂짃진짅짆짇질짉짊짋짌짍짎짏짐집짒짓짔징짖짗짘짙짚짛\
+짜짝짞짟짠짡짢짣짤짥짦짧짨짩짪짫짬짭짮짯짰짱짲짳짴짵짶짷\
+째짹짺짻짼짽짾짿쨀쨁쨂쨃쨄쨅쨆쨇쨈쨉쨊쨋쨌쨍쨎쨏쨐쨑쨒쨓\
+쨔쨕쨖쨗쨘쨙쨚쨛쨜쨝쨞쨟쨠쨡쨢쨣쨤쨥쨦쨧쨨쨩쨪쨫쨬쨭쨮쨯\
+쨰쨱쨲쨳쨴쨵쨶쨷쨸쨹쨺쨻쨼쨽쨾쨿쩀쩁쩂쩃쩄쩅쩆쩇쩈쩉쩊쩋\
+쩌쩍쩎쩏쩐쩑쩒쩓쩔쩕쩖쩗쩘쩙쩚쩛쩜쩝쩞쩟쩠쩡쩢쩣쩤쩥쩦쩧\
+쩨쩩쩪쩫쩬쩭쩮쩯쩰쩱쩲쩳쩴쩵쩶쩷쩸쩹쩺쩻쩼쩽쩾쩿쪀쪁쪂쪃\
+쪄쪅쪆쪇쪈쪉쪊쪋쪌쪍쪎쪏쪐쪑쪒쪓쪔쪕쪖쪗쪘쪙쪚쪛쪜쪝쪞쪟\
+쪠쪡쪢쪣쪤쪥쪦쪧쪨쪩쪪쪫쪬쪭쪮쪯쪰쪱쪲쪳쪴쪵쪶쪷쪸쪹쪺쪻\
+쪼쪽쪾쪿쫀쫁쫂쫃쫄쫅쫆쫇쫈쫉쫊쫋쫌쫍쫎쫏쫐쫑쫒쫓쫔쫕쫖쫗\
+쫘쫙쫚쫛쫜쫝쫞쫟쫠쫡쫢쫣쫤쫥쫦쫧쫨쫩쫪쫫쫬쫭쫮쫯쫰쫱쫲쫳\
+쫴쫵쫶쫷쫸쫹쫺쫻쫼쫽쫾쫿쬀쬁쬂쬃쬄쬅쬆쬇쬈쬉쬊쬋쬌쬍쬎쬏\
+쬐쬑쬒쬓쬔쬕쬖쬗쬘쬙쬚쬛쬜쬝쬞쬟쬠쬡쬢쬣쬤쬥쬦쬧쬨쬩쬪쬫\
+쬬쬭쬮쬯쬰쬱쬲쬳쬴쬵쬶쬷쬸쬹쬺쬻쬼쬽쬾쬿쭀쭁쭂쭃쭄쭅쭆쭇\
+쭈쭉쭊쭋쭌쭍쭎쭏쭐쭑쭒쭓쭔쭕쭖쭗쭘쭙쭚쭛쭜쭝쭞쭟쭠쭡쭢쭣\
+쭤쭥쭦쭧쭨쭩쭪쭫쭬쭭쭮쭯쭰쭱쭲쭳쭴쭵쭶쭷쭸쭹쭺쭻쭼쭽쭾쭿\
+쮀쮁쮂쮃쮄쮅쮆쮇쮈쮉쮊쮋쮌쮍쮎쮏쮐쮑쮒쮓쮔쮕쮖쮗쮘쮙쮚쮛\
+쮜쮝쮞쮟쮠쮡쮢쮣쮤쮥쮦쮧쮨쮩쮪쮫쮬쮭쮮쮯쮰쮱쮲쮳쮴쮵쮶쮷\
+쮸쮹쮺쮻쮼쮽쮾쮿쯀쯁쯂쯃쯄쯅쯆쯇쯈쯉쯊쯋쯌쯍쯎쯏쯐쯑쯒쯓\
+쯔쯕쯖쯗쯘쯙쯚쯛쯜쯝쯞쯟쯠쯡쯢쯣쯤쯥쯦쯧쯨쯩쯪쯫쯬쯭쯮쯯\
+쯰쯱쯲쯳쯴쯵쯶쯷쯸쯹쯺쯻쯼쯽쯾쯿찀찁찂찃찄찅찆찇찈찉찊찋\
+찌찍찎찏찐찑찒찓찔찕찖찗찘찙찚찛찜찝찞찟찠찡찢찣찤찥찦찧\
+차착찪찫찬찭찮찯찰찱찲찳찴찵찶찷참찹찺찻찼창찾찿챀챁챂챃\
+채책챆챇챈챉챊챋챌챍챎챏챐챑챒챓챔챕챖챗챘챙챚챛챜챝챞챟\
+챠챡챢챣챤챥챦챧챨챩챪챫챬챭챮챯챰챱챲챳챴챵챶챷챸챹챺챻\
+챼챽챾챿첀첁첂첃첄첅첆첇첈첉첊첋첌첍첎첏첐첑첒첓첔첕첖첗\
+처척첚첛천첝첞첟철첡첢첣첤첥첦첧첨첩첪첫첬청첮첯첰첱첲첳\
+체첵첶첷첸첹첺첻첼첽첾첿쳀쳁쳂쳃쳄쳅쳆쳇쳈쳉쳊쳋쳌쳍쳎쳏\
+쳐쳑쳒쳓쳔쳕쳖쳗쳘쳙쳚쳛쳜쳝쳞쳟쳠쳡쳢쳣쳤쳥쳦쳧쳨쳩쳪쳫\
+쳬쳭쳮쳯쳰쳱쳲쳳쳴쳵쳶쳷쳸쳹쳺쳻쳼쳽쳾쳿촀촁촂촃촄촅촆촇\
+초촉촊촋촌촍촎촏촐촑촒촓촔촕촖촗촘촙촚촛촜총촞촟촠촡촢촣\
+촤촥촦촧촨촩촪촫촬촭촮촯촰촱촲촳촴촵촶촷촸촹촺촻촼촽촾촿\
+쵀쵁쵂쵃쵄쵅쵆쵇쵈쵉쵊쵋쵌쵍쵎쵏쵐쵑쵒쵓쵔쵕쵖쵗쵘쵙쵚쵛\
+최쵝쵞쵟쵠쵡쵢쵣쵤쵥쵦쵧쵨쵩쵪쵫쵬쵭쵮쵯쵰쵱쵲쵳쵴쵵쵶쵷\
+쵸쵹쵺쵻쵼쵽쵾쵿춀춁춂춃춄춅춆춇춈춉춊춋춌춍춎춏춐춑춒춓\
+추축춖춗춘춙춚춛출춝춞춟춠춡춢춣춤춥춦춧춨충춪춫춬춭춮춯\
+춰춱춲춳춴춵춶춷춸춹춺춻춼춽춾춿췀췁췂췃췄췅췆췇췈췉췊췋\
+췌췍췎췏췐췑췒췓췔췕췖췗췘췙췚췛췜췝췞췟췠췡췢췣췤췥췦췧\
+취췩췪췫췬췭췮췯췰췱췲췳췴췵췶췷췸췹췺췻췼췽췾췿츀츁츂츃\
+츄츅츆츇츈츉츊츋츌츍츎츏츐츑츒츓츔츕츖츗츘츙츚츛츜츝츞츟\
+츠측츢츣츤츥츦츧츨츩츪츫츬츭츮츯츰츱츲츳츴층츶츷츸츹츺츻\
+츼츽츾츿칀칁칂칃칄칅칆칇칈칉칊칋칌칍칎칏칐칑칒칓칔칕칖칗\
+치칙칚칛친칝칞칟칠칡칢칣칤칥칦칧침칩칪칫칬칭칮칯칰칱칲칳\
+카칵칶칷칸칹칺칻칼칽칾칿캀캁캂캃캄캅캆캇캈캉캊캋캌캍캎캏\
+캐캑캒캓캔캕캖캗캘캙캚캛캜캝캞캟캠캡캢캣캤캥캦캧캨캩캪캫\
+캬캭캮캯캰캱캲캳캴캵캶캷캸캹캺캻캼캽캾캿컀컁컂컃컄컅컆컇\
+컈컉컊컋컌컍컎컏컐컑컒컓컔컕컖컗컘컙컚컛컜컝컞컟컠컡컢컣\
+커컥컦컧컨컩컪컫컬컭컮컯컰컱컲컳컴컵컶컷컸컹컺컻컼컽컾컿\
+케켁켂켃켄켅켆켇켈켉켊켋켌켍켎켏켐켑켒켓켔켕켖켗켘켙켚켛\
+켜켝켞켟켠켡켢켣켤켥켦켧켨켩켪켫켬켭켮켯켰켱켲켳켴켵켶켷\
+켸켹켺켻켼켽켾켿콀콁콂콃콄콅콆콇콈콉콊콋콌콍콎콏콐콑콒콓\
+코콕콖콗콘콙콚콛콜콝콞콟콠콡콢콣콤콥콦콧콨콩콪콫콬콭콮콯\
+콰콱콲콳콴콵콶콷콸콹콺콻콼콽콾콿쾀쾁쾂쾃쾄쾅쾆쾇쾈쾉쾊쾋\
+쾌쾍쾎쾏쾐쾑쾒쾓쾔쾕쾖쾗쾘쾙쾚쾛쾜쾝쾞쾟쾠쾡쾢쾣쾤쾥쾦쾧\
+쾨쾩쾪쾫쾬쾭쾮쾯쾰쾱쾲쾳쾴쾵쾶쾷쾸쾹쾺쾻쾼쾽쾾쾿쿀쿁쿂쿃\
+쿄쿅쿆쿇쿈쿉쿊쿋쿌쿍쿎쿏쿐쿑쿒쿓쿔쿕쿖쿗쿘쿙쿚쿛쿜쿝쿞쿟\
+쿠쿡쿢쿣쿤쿥쿦쿧쿨쿩쿪쿫쿬쿭쿮쿯쿰쿱쿲쿳쿴쿵쿶쿷쿸쿹쿺쿻\
+쿼쿽쿾쿿퀀퀁퀂퀃퀄퀅퀆퀇퀈퀉퀊퀋퀌퀍퀎퀏퀐퀑퀒퀓퀔퀕퀖퀗\
+퀘퀙퀚퀛퀜퀝퀞퀟퀠퀡퀢퀣퀤퀥퀦퀧퀨퀩퀪퀫퀬퀭퀮퀯퀰퀱퀲퀳\
+퀴퀵퀶퀷퀸퀹퀺퀻퀼퀽퀾퀿큀큁큂큃큄큅큆큇큈큉큊큋큌큍큎큏\
+큐큑큒큓큔큕큖큗큘큙큚큛큜큝큞큟큠큡큢큣큤큥큦큧큨큩큪큫\
+크큭큮큯큰큱큲큳클큵큶큷큸큹큺큻큼큽큾큿킀킁킂킃킄킅킆킇\
+킈킉킊킋킌킍킎킏킐킑킒킓킔킕킖킗킘킙킚킛킜킝킞킟킠킡킢킣\
+키킥킦킧킨킩킪킫킬킭킮킯킰킱킲킳킴킵킶킷킸킹킺킻킼킽킾킿\
+타탁탂탃탄탅탆탇탈탉탊탋탌탍탎탏탐탑탒탓탔탕탖탗탘탙탚탛\
+태택탞탟탠탡탢탣탤탥탦탧탨탩탪탫탬탭탮탯탰탱탲탳탴탵탶탷\
+탸탹탺탻탼탽탾탿턀턁턂턃턄턅턆턇턈턉턊턋턌턍턎턏턐턑턒턓\
+턔턕턖턗턘턙턚턛턜턝턞턟턠턡턢턣턤턥턦턧턨턩턪턫턬턭턮턯\
+터턱턲턳턴턵턶턷털턹턺턻턼턽턾턿텀텁텂텃텄텅텆텇텈텉텊텋\
+테텍텎텏텐텑텒텓텔텕텖텗텘텙텚텛템텝텞텟텠텡텢텣텤텥텦텧\
+텨텩텪텫텬텭텮텯텰텱텲텳텴텵텶텷텸텹텺텻텼텽텾텿톀톁톂톃\
+톄톅톆톇톈톉톊톋톌톍톎톏톐톑톒톓톔톕톖톗톘톙톚톛톜톝톞톟\
+토톡톢톣톤톥톦톧톨톩톪톫톬톭톮톯톰톱톲톳톴통톶톷톸톹톺톻\
+톼톽톾톿퇀퇁퇂퇃퇄퇅퇆퇇퇈퇉퇊퇋퇌퇍퇎퇏퇐퇑퇒퇓퇔퇕퇖퇗\
+퇘퇙퇚퇛퇜퇝퇞퇟퇠퇡퇢퇣퇤퇥퇦퇧퇨퇩퇪퇫퇬퇭퇮퇯퇰퇱퇲퇳\
+퇴퇵퇶퇷퇸퇹퇺퇻퇼퇽퇾퇿툀툁툂툃툄툅툆툇툈툉툊툋툌툍툎툏\
+툐툑툒툓툔툕툖툗툘툙툚툛툜툝툞툟툠툡툢툣툤툥툦툧툨툩툪툫\
+투툭툮툯툰툱툲툳툴툵툶툷툸툹툺툻툼툽툾툿퉀퉁퉂퉃퉄퉅퉆퉇\
+퉈퉉퉊퉋퉌퉍퉎퉏퉐퉑퉒퉓퉔퉕퉖퉗퉘퉙퉚퉛퉜퉝퉞퉟퉠퉡퉢퉣\
+퉤퉥퉦퉧퉨퉩퉪퉫퉬퉭퉮퉯퉰퉱퉲퉳퉴퉵퉶퉷퉸퉹퉺퉻퉼퉽퉾퉿\
+튀튁튂튃튄튅튆튇튈튉튊튋튌튍튎튏튐튑튒튓튔튕튖튗튘튙튚튛\
+튜튝튞튟튠튡튢튣튤튥튦튧튨튩튪튫튬튭튮튯튰튱튲튳튴튵튶튷\
+트특튺튻튼튽튾튿틀틁틂틃틄틅틆틇틈틉틊틋틌틍틎틏틐틑틒틓\
+틔틕틖틗틘틙틚틛틜틝틞틟틠틡틢틣틤틥틦틧틨틩틪틫틬틭틮틯\
+티틱틲틳틴틵틶틷틸틹틺틻틼틽틾틿팀팁팂팃팄팅팆팇팈팉팊팋\
+파팍팎팏판팑팒팓팔팕팖팗팘팙팚팛팜팝팞팟팠팡팢팣팤팥팦팧\
+패팩팪팫팬팭팮팯팰팱팲팳팴팵팶팷팸팹팺팻팼팽팾팿퍀퍁퍂퍃\
+퍄퍅퍆퍇퍈퍉퍊퍋퍌퍍퍎퍏퍐퍑퍒퍓퍔퍕퍖퍗퍘퍙퍚퍛퍜퍝퍞퍟\
+퍠퍡퍢퍣퍤퍥퍦퍧퍨퍩퍪퍫퍬퍭퍮퍯퍰퍱퍲퍳퍴퍵퍶퍷퍸퍹퍺퍻\
+퍼퍽퍾퍿펀펁펂펃펄펅펆펇펈펉펊펋펌펍펎펏펐펑펒펓펔펕펖펗\
+페펙펚펛펜펝펞펟펠펡펢펣펤펥펦펧펨펩펪펫펬펭펮펯펰펱펲펳\
+펴펵펶펷편펹펺펻펼펽펾펿폀폁폂폃폄폅폆폇폈평폊폋폌폍폎폏\
+폐폑폒폓폔폕폖폗폘폙폚폛폜폝폞폟폠폡폢폣폤폥폦폧폨폩폪폫\
+포폭폮폯폰폱폲폳폴폵폶폷폸폹폺폻폼폽폾폿퐀퐁퐂퐃퐄퐅퐆퐇\
+퐈퐉퐊퐋퐌퐍퐎퐏퐐퐑퐒퐓퐔퐕퐖퐗퐘퐙퐚퐛퐜퐝퐞퐟퐠퐡퐢퐣\
+퐤퐥퐦퐧퐨퐩퐪퐫퐬퐭퐮퐯퐰퐱퐲퐳퐴퐵퐶퐷퐸퐹퐺퐻퐼퐽퐾퐿\
+푀푁푂푃푄푅푆푇푈푉푊푋푌푍푎푏푐푑푒푓푔푕푖푗푘푙푚푛\
+표푝푞푟푠푡푢푣푤푥푦푧푨푩푪푫푬푭푮푯푰푱푲푳푴푵푶푷\
+푸푹푺푻푼푽푾푿풀풁풂풃풄풅풆풇품풉풊풋풌풍풎풏풐풑풒풓\
+풔풕풖풗풘풙풚풛풜풝풞풟풠풡풢풣풤풥풦풧풨풩풪풫풬풭풮풯\
+풰풱풲풳풴풵풶풷풸풹풺풻풼풽풾풿퓀퓁퓂퓃퓄퓅퓆퓇퓈퓉퓊퓋\
+퓌퓍퓎퓏퓐퓑퓒퓓퓔퓕퓖퓗퓘퓙퓚퓛퓜퓝퓞퓟퓠퓡퓢퓣퓤퓥퓦퓧\
+퓨퓩퓪퓫퓬퓭퓮퓯퓰퓱퓲퓳퓴퓵퓶퓷퓸퓹퓺퓻퓼퓽퓾퓿픀픁픂픃\
+프픅픆픇픈픉픊픋플픍픎픏픐픑픒픓픔픕픖픗픘픙픚픛픜픝픞픟\
+픠픡픢픣픤픥픦픧픨픩픪픫픬픭픮픯픰픱픲픳픴픵픶픷픸픹픺픻\
+피픽픾픿핀핁핂핃필핅핆핇핈핉핊핋핌핍핎핏핐핑핒핓핔핕핖핗\
+하학핚핛한핝핞핟할핡핢핣핤핥핦핧함합핪핫핬항핮핯핰핱핲핳\
+해핵핶핷핸핹핺핻핼핽핾핿햀햁햂햃햄햅햆햇했행햊햋햌햍햎햏\
+햐햑햒햓햔햕햖햗햘햙햚햛햜햝햞햟햠햡햢햣햤향햦햧햨햩햪햫\
+햬햭햮햯햰햱햲햳햴햵햶햷햸햹햺햻햼햽햾햿헀헁헂헃헄헅헆헇\
+허헉헊헋헌헍헎헏헐헑헒헓헔헕헖헗험헙헚헛헜헝헞헟헠헡헢헣\
+헤헥헦헧헨헩헪헫헬헭헮헯헰헱헲헳헴헵헶헷헸헹헺헻헼헽헾헿\
+혀혁혂혃현혅혆혇혈혉혊혋혌혍혎혏혐협혒혓혔형혖혗혘혙혚혛\
+혜혝혞혟혠혡혢혣혤혥혦혧혨혩혪혫혬혭혮혯혰혱혲혳혴혵혶혷\
+호혹혺혻혼혽혾혿홀홁홂홃홄홅홆홇홈홉홊홋홌홍홎홏홐홑홒홓\
+화확홖홗환홙홚홛활홝홞홟홠홡홢홣홤홥홦홧홨황홪홫홬홭홮홯\
+홰홱홲홳홴홵홶홷홸홹홺홻홼홽홾홿횀횁횂횃횄횅횆횇횈횉횊횋\
+회획횎횏횐횑횒횓횔횕횖횗횘횙횚횛횜횝횞횟횠횡횢횣횤횥횦횧\
+효횩횪횫횬횭횮횯횰횱횲횳횴횵횶횷횸횹횺횻횼횽횾횿훀훁훂훃\
+후훅훆훇훈훉훊훋훌훍훎훏훐훑훒훓훔훕훖훗훘훙훚훛훜훝훞훟\
+훠훡훢훣훤훥훦훧훨훩훪훫훬훭훮훯훰훱훲훳훴훵훶훷훸훹훺훻\
+훼훽훾훿휀휁휂휃휄휅휆휇휈휉휊휋휌휍휎휏휐휑휒휓휔휕휖휗\
+휘휙휚휛휜휝휞휟휠휡휢휣휤휥휦휧휨휩휪휫휬휭휮휯휰휱휲휳\
+휴휵휶휷휸휹휺휻휼휽휾휿흀흁흂흃흄흅흆흇흈흉흊흋흌흍흎흏\
+흐흑흒흓흔흕흖흗흘흙흚흛흜흝흞흟흠흡흢흣흤흥흦흧흨흩흪흫\
+희흭흮흯흰흱흲흳흴흵흶흷흸흹흺흻흼흽흾흿힀힁힂힃힄힅힆힇\
+히힉힊힋힌힍힎힏힐힑힒힓힔힕힖힗힘힙힚힛힜힝힞힟힠힡힢힣\
+";
+
+/// The conjoining jamo (`U+1100..=U+11FF`) that the precomposed Hangul
+/// syllable `ch` decomposes to, `None` outside `U+AC00..=U+D7A3`.
+#[cfg(feature = "hangul")]
+pub fn decompose_hangul(ch: char) -> Option<&'static str> {
+    let s = (ch as u32).checked_sub(0xAC00)?;
+    if s >= 11172 {
+        return None;
+    }
+    // Every LV syllable before this one is 3 bytes shorter
+    let start = (s * 9 - s.div_ceil(28) * 3) as usize;
+    let len = if s % 28 == 0 { 6 } else { 9 };
+    Some(&HANGUL_JAMO[start..start + len])
+}
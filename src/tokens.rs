@@ -0,0 +1,85 @@
+use alloc::borrow::Cow;
+use core::{fmt, iter::FusedIterator};
+
+use crate::StrDiacriticExt;
+
+/// Stripped tokens, see
+/// [`StrDiacriticExt::strip_diacritics_tokens`](crate::StrDiacriticExt::strip_diacritics_tokens).
+#[derive(Clone)]
+pub struct Tokens<'a, F> {
+    rest: &'a str,
+    is_sep: F,
+}
+
+impl<'a, F: Fn(char) -> bool> Tokens<'a, F> {
+    #[inline]
+    pub(crate) fn new(s: &'a str, is_sep: F) -> Self {
+        Self { rest: s, is_sep }
+    }
+
+    fn next_token(&mut self) -> Option<Cow<'a, str>> {
+        let s = self.rest.trim_start_matches(&self.is_sep);
+        if s.is_empty() {
+            self.rest = s;
+            return None;
+        }
+
+        let end = s.find(&self.is_sep).unwrap_or(s.len());
+        self.rest = &s[end..];
+        // The token's length is known upfront, so an owned one is allocated
+        // once with the right capacity.
+        Some(s[..end].strip_diacritics())
+    }
+}
+
+impl<'a, F: Fn(char) -> bool> Iterator for Tokens<'a, F> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // tokens made only of combining marks strip to nothing
+        while let Some(token) = self.next_token() {
+            if !token.is_empty() {
+                return Some(token);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.rest.len()))
+    }
+}
+
+impl<'a, F: Fn(char) -> bool> FusedIterator for Tokens<'a, F> {}
+
+impl<'a, F> fmt::Debug for Tokens<'a, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tokens").field("rest", &self.rest).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn tokenize() {
+        let is_sep = |c: char| c.is_whitespace() || c.is_ascii_punctuation();
+        assert_eq!(
+            Tokens::new("Crème brûlée!", is_sep).collect::<Vec<_>>(),
+            ["Creme", "brulee"]
+        );
+        assert!(matches!(
+            Tokens::new("  plain,text ", is_sep).next(),
+            Some(Cow::Borrowed("plain"))
+        ));
+        assert_eq!(
+            Tokens::new("a \u{301} b", is_sep).collect::<Vec<_>>(),
+            ["a", "b"]
+        );
+        assert_eq!(Tokens::new(" ! ", is_sep).next(), None);
+    }
+}
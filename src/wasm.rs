@@ -0,0 +1,26 @@
+//! A JavaScript interface, behind the `wasm` feature, for use through
+//! `wasm-pack`.
+
+use alloc::string::String;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::StrDiacriticExt;
+
+/// Strips `input` into a new string, which JavaScript gets as a plain
+/// `string`.
+#[wasm_bindgen]
+pub fn strip_diacritics(input: &str) -> String {
+    input.strip_diacritics().into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip() {
+        assert_eq!(strip_diacritics("Ömer"), "Omer");
+        assert_eq!(strip_diacritics("plain"), "plain");
+    }
+}
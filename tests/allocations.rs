@@ -0,0 +1,148 @@
+#![cfg(feature = "std")]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    io::{self, Read, Write},
+};
+
+use std::ffi::CString;
+
+use strip_diacritics::{strip_diacritics_bounded, CStrDiacriticExt, StrDiacriticExt, StripOptions};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    static REALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|c| c.set(c.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        REALLOCATIONS.with(|c| c.set(c.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn count_allocations<R, F: FnOnce() -> R>(f: F) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    drop(f());
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+fn count_reallocations<R, F: FnOnce() -> R>(f: F) -> usize {
+    let before = REALLOCATIONS.with(Cell::get);
+    drop(f());
+    REALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn borrowed_does_not_allocate() {
+    assert_eq!(count_allocations(|| "plain ascii".strip_diacritics()), 0);
+}
+
+#[test]
+fn to_writer_does_not_allocate() {
+    let mut buf = [0; 32];
+    for s in ["", "plain ascii", "Crème brûlée"] {
+        let n = count_allocations(|| s.strip_diacritics_to_writer(&mut &mut buf[..]).unwrap());
+        assert_eq!(n, 0, "{s:?}");
+    }
+}
+
+#[test]
+fn normalize_write_to_does_not_allocate() {
+    let mut buf = String::with_capacity(64);
+    for s in ["", "plain ascii", "Crème brûlée", "Straße\r\nß"] {
+        let n = count_allocations(|| {
+            buf.clear();
+            s.normalize()
+                .options(StripOptions::new().normalize_line_separators(true))
+                .german_sharp_s(true)
+                .write_to(&mut buf)
+                .unwrap()
+        });
+        assert_eq!(n, 0, "{s:?}");
+    }
+}
+
+#[test]
+fn owned_allocates_once() {
+    assert_eq!(count_allocations(|| "déjà".strip_diacritics()), 1);
+}
+
+#[test]
+fn accents_do_not_reallocate() {
+    let s = "Crème brûlée, déjà vu, naïve coöperation, Ærøskøbing ǆ ".repeat(256);
+    assert_eq!(count_allocations(|| s.strip_diacritics()), 1);
+    assert_eq!(count_reallocations(|| s.strip_diacritics()), 0);
+
+    let mut tokens = 0;
+    let n = count_reallocations(|| {
+        tokens = s.strip_diacritics_tokens(|c| c == ' ' || c == ',').count()
+    });
+    assert_eq!((tokens, n), (256 * 8, 0));
+
+    let n = count_reallocations(|| s.normalize().ascii_only(true).to_cow());
+    assert_eq!(n, 0);
+
+    let c = CString::new(s.as_str()).unwrap();
+    assert_eq!(count_allocations(|| c.strip_diacritics().unwrap()), 1);
+    assert_eq!(count_reallocations(|| c.strip_diacritics().unwrap()), 0);
+}
+
+// Endless "Crème brûlée " without allocating.
+struct Repeat(&'static [u8], usize, usize);
+
+impl Read for Repeat {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = buf.len().min(self.2);
+        for b in &mut buf[..n] {
+            *b = self.0[self.1];
+            self.1 = (self.1 + 1) % self.0.len();
+        }
+        self.2 -= n;
+        Ok(n)
+    }
+}
+
+// Checks the output against "Creme brulee " without buffering it.
+struct Expect(&'static [u8], usize);
+
+impl Write for Expect {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for &b in buf {
+            assert_eq!(b, self.0[self.1]);
+            self.1 = (self.1 + 1) % self.0.len();
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn bounded_stream_allocates_once() {
+    let input = "Crème brûlée ".as_bytes();
+    let len = input.len() * 300_000;
+    assert_eq!(
+        count_allocations(|| {
+            strip_diacritics_bounded(Repeat(input, 0, len), Expect(b"Creme brulee ", 0), 7).unwrap()
+        }),
+        1
+    );
+}
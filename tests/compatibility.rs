@@ -0,0 +1,151 @@
+#![cfg(feature = "alloc")]
+
+use strip_diacritics::{CharDiacriticExt, StrDiacriticExt, StripOptions};
+
+const COMPATIBILITY: StripOptions = StripOptions::new().compatibility(true);
+
+fn fold(s: &str) -> String {
+    s.strip_diacritics_with(COMPATIBILITY).into_owned()
+}
+
+#[test]
+fn enclosed_alphanumerics() {
+    for ch in '\u{2460}'..='\u{24ea}' {
+        let folded = fold(ch.encode_utf8(&mut [0; 4]));
+        assert!(folded.is_ascii(), "{:?} => {:?}", ch, folded);
+    }
+    // negative and double circled numbers have no decomposition
+    for ch in '\u{24eb}'..='\u{24ff}' {
+        let s = ch.to_string();
+        assert_eq!(fold(&s), s);
+    }
+
+    assert_eq!(fold("①②⑳"), "1220");
+    assert_eq!(fold("⑴ ⒇"), "(1) (20)");
+    assert_eq!(fold("⒈ ⒛"), "1. 20.");
+    assert_eq!(fold("⒜ Ⓐ ⓐ ⓪"), "(a) A a 0");
+    assert_eq!(fold("㈱"), "(株)");
+    assert_eq!("①Ⓐ".strip_diacritics(), "①Ⓐ");
+}
+
+#[test]
+fn superscripts_and_subscripts() {
+    assert_eq!(fold("¹²³⁰ⁱ⁴⁵⁶⁷⁸⁹⁺⁻⁼⁽⁾ⁿ"), "1230i456789+\u{2212}=()n");
+    assert_eq!(fold("₀₁₂₃₄₅₆₇₈₉₊₋₌₍₎"), "0123456789+\u{2212}=()");
+    assert_eq!(fold("ₐₑₒₓₔₕₖₗₘₙₚₛₜ"), "aeox\u{259}hklmnpst");
+    assert_eq!(fold("x² + H₂O"), "x2 + H2O");
+
+    // compatibility folding is opt-in
+    assert_eq!("x² + H₂O".strip_diacritics(), "x² + H₂O");
+}
+
+#[test]
+fn roman_numerals() {
+    for ch in '\u{2160}'..='\u{217f}' {
+        let folded = fold(ch.encode_utf8(&mut [0; 4]));
+        assert!(folded.is_ascii(), "{:?} => {:?}", ch, folded);
+    }
+
+    assert_eq!(
+        fold("Ⅰ Ⅱ Ⅲ Ⅳ Ⅴ Ⅵ Ⅶ Ⅷ Ⅸ Ⅹ Ⅺ Ⅻ Ⅼ Ⅽ Ⅾ Ⅿ"),
+        "I II III IV V VI VII VIII IX X XI XII L C D M"
+    );
+    assert_eq!(
+        fold("ⅰ ⅱ ⅲ ⅳ ⅴ ⅵ ⅶ ⅷ ⅸ ⅹ ⅺ ⅻ ⅼ ⅽ ⅾ ⅿ"),
+        "i ii iii iv v vi vii viii ix x xi xii l c d m"
+    );
+    assert_eq!(fold("Chapter Ⅻ"), "Chapter XII");
+    assert_eq!("Chapter Ⅻ".strip_diacritics(), "Chapter Ⅻ");
+}
+
+#[test]
+fn fractions() {
+    const FRACTIONS: StripOptions = StripOptions::new().fractions(true);
+    let s = "¼ cup, ½ tsp, ¾ lb";
+    assert_eq!(s.strip_diacritics(), s);
+    assert_eq!(fold(s), "1⁄4 cup, 1⁄2 tsp, 3⁄4 lb");
+    assert_eq!(
+        s.strip_diacritics_with(FRACTIONS),
+        "1/4 cup, 1/2 tsp, 3/4 lb"
+    );
+    assert_eq!(
+        "crème ⅓".strip_diacritics_with(FRACTIONS.compatibility(true)),
+        "creme 1/3"
+    );
+
+    for ch in '\u{2150}'..='\u{215f}' {
+        let folded = ch
+            .encode_utf8(&mut [0; 4])
+            .strip_diacritics_with(FRACTIONS)
+            .into_owned();
+        let (n, d) = folded.split_once('/').unwrap();
+        assert!(n.parse::<u32>().is_ok(), "{ch:?} -> {folded:?}");
+        assert!(
+            d.is_empty() || d.parse::<u32>().is_ok(),
+            "{ch:?} -> {folded:?}"
+        );
+    }
+    assert_eq!("5\u{2044}8".strip_diacritics_with(FRACTIONS), "5/8");
+}
+
+#[test]
+fn math_alpha() {
+    const MATH_ALPHA: StripOptions = StripOptions::new().math_alpha(true);
+    let s = "𝐇𝐞𝐥𝐥𝐨";
+    assert_eq!(s.strip_diacritics(), s);
+    assert_eq!(s.strip_diacritics_with(MATH_ALPHA), "Hello");
+    assert_eq!(fold(s), "Hello");
+
+    for (styled, plain) in [
+        ('𝐀', "A"), // bold
+        ('𝑥', "x"), // italic
+        ('𝒮', "S"), // script
+        ('ℬ', "B"), // script, in Letterlike Symbols
+        ('𝔉', "F"), // fraktur
+        ('𝕆', "O"), // double-struck
+        ('ℝ', "R"), // double-struck, in Letterlike Symbols
+        ('𝖺', "a"), // sans-serif
+        ('𝚣', "z"), // monospace
+        ('𝟙', "1"), // double-struck digit
+        ('𝛂', "α"), // bold Greek
+        ('ℎ', "h"), // italic, in Letterlike Symbols
+    ] {
+        assert_eq!(styled.strip_diacritics_with(MATH_ALPHA), Some(plain));
+    }
+    assert_eq!("𝓬𝓻𝓮\u{300}𝓶𝓮".strip_diacritics_with(MATH_ALPHA), "creme");
+    // Only the styled letters and digits
+    assert_eq!('½'.strip_diacritics_with(MATH_ALPHA), None);
+    assert_eq!('™'.strip_diacritics_with(MATH_ALPHA), None);
+    for ch in ('\u{1d400}'..='\u{1d7ff}').filter(|c| c.is_alphanumeric()) {
+        assert!(ch.strip_diacritics_with(MATH_ALPHA).is_some(), "{ch:?}");
+    }
+}
+
+#[test]
+fn letterlike_and_number_forms() {
+    use strip_diacritics::tables::{MAX_REPLACEMENT_CHARS, MAX_REPLACEMENT_LEN};
+
+    for (ch, expected) in [
+        ('℀', "a/c"),
+        ('℁', "a/s"),
+        ('℅', "c/o"),
+        ('℆', "c/u"),
+        ('№', "No"),
+        ('℡', "TEL"),
+        ('™', "TM"),
+        ('℠', "SM"),
+    ] {
+        let s = ch.encode_utf8(&mut [0; 4]).to_owned();
+        assert_eq!(fold(&s), expected, "{ch:?}");
+        assert_eq!(s.strip_diacritics(), s, "{ch:?}");
+    }
+
+    for ch in ('\u{2100}'..='\u{214f}').chain('\u{2150}'..='\u{218f}') {
+        if let Some(t) = ch.strip_diacritics_with(COMPATIBILITY) {
+            assert!(t.len() <= MAX_REPLACEMENT_LEN, "{ch:?}");
+            assert!(t.chars().count() <= MAX_REPLACEMENT_CHARS, "{ch:?}");
+        }
+    }
+
+    assert_eq!(fold("Apt. №5, ℅ J. Smith"), "Apt. No5, c/o J. Smith");
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "alloc")]
+
+use strip_diacritics::{CharDiacriticExt, StrDiacriticExt};
+
+fn strip(s: &str) -> String {
+    s.strip_diacritics().into_owned()
+}
+
+#[test]
+fn decomposed_latin() {
+    // NFD, including marks with no precomposed form on their base.
+    let s = "A\u{30a}ngstro\u{308}m, Dvor\u{30c}a\u{301}k, Nguye\u{302}\u{303}n, \
+             q\u{307}\u{323} x\u{1dc4}y\u{1ab2} i\u{20e8}";
+    assert_eq!(strip(s), "Angstrom, Dvorak, Nguyen, q xy i");
+}
+
+#[test]
+fn polytonic_greek() {
+    // Breathings, accents and iota subscripts, precomposed and decomposed.
+    let precomposed = "Ἐν ἀρχῇ ἦν ὁ λόγος, ᾠδή, Ἅιδης";
+    let decomposed = "Ε\u{313}ν α\u{313}ρχη\u{342}\u{345} η\u{313}\u{342}ν ο\u{314} λο\u{301}γος, \
+                      ω\u{313}\u{345}δη\u{301}, Α\u{314}\u{301}ιδης";
+    for s in [precomposed, decomposed] {
+        assert_eq!(strip(s), "Εν αρχη ην ο λογος, ωδη, Αιδης");
+    }
+}
+
+#[test]
+fn outside_combining_diacritical_marks() {
+    // Coptic combining ni above, Cyrillic titlo, Greek musical marks,
+    // Hebrew points and the enclosing Cyrillic hundred thousands sign.
+    let s = "ⲁ\u{2cef}ⲃ\u{2cf1} б\u{483}г ε\u{1d242} ש\u{5c1}\u{5b8} А\u{488}";
+    assert_eq!(strip(s), "ⲁⲃ бг ε ש А");
+}
+
+#[test]
+fn combining_diacritical_mark_blocks() {
+    // Extended: doubled circumflex; Supplement: dotted grave; for Symbols:
+    // enclosing circle and keycap; Half Marks: ligature left and right half.
+    let s = "a\u{1ab0}b\u{1ac0} e\u{1dc0}o\u{1dff} A\u{20dd}1\u{20e3} o\u{fe20}o\u{fe21}";
+    assert_eq!(strip(s), "ab eo A1 oo");
+}
+
+#[test]
+fn keeps_kana_voicing_marks() {
+    // が and ぱ are other kana than か and は, precomposed or not
+    let s = "かがみ ぱん カ\u{3099}ハ\u{309a} ｶﾞ";
+    assert_eq!(strip(s), s);
+    assert!(!'\u{3099}'.is_diacritic() && !'\u{309a}'.is_diacritic());
+}
+
+#[test]
+fn keeps_spacing_marks() {
+    // Devanagari vowel sign aa (Mc) stays, the nukta (Mn) goes.
+    assert_eq!(strip("का क\u{93c}"), "का क");
+}
+
+#[test]
+fn every_mn_and_me() {
+    use strip_diacritics::tables::COMBINING_MARKS;
+
+    // Unicode 15.0.0: 1985 Mn + 13 Me, less the 256 variation selectors
+    // and the 2 kana voicing marks
+    let count = COMBINING_MARKS
+        .iter()
+        .map(|r| *r.end() as usize - *r.start() as usize + 1)
+        .sum::<usize>();
+    assert_eq!(count, 1740);
+    assert!(COMBINING_MARKS
+        .windows(2)
+        .all(|w| w[0].end() < w[1].start()));
+
+    // Tifinagh consonant joiner, Bamum combining high tones, Adlam nukta
+    assert_eq!(strip("ⵣ\u{2d7f}ⵣ ꚠ\u{16af0}\u{16af4} 𞤀\u{1e94a}"), "ⵣⵣ ꚠ 𞤀");
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "alloc")]
+
+use strip_diacritics::{StrDiacriticExt, StripOptions};
+
+// NFKD decomposes compatibility characters and Hangul syllables too
+#[cfg(feature = "hangul")]
+const NFKD: StripOptions = StripOptions::new().compatibility(true).hangul_jamo(true);
+// Without the hangul feature, the cases with Hangul syllables are skipped
+#[cfg(not(feature = "hangul"))]
+const NFKD: StripOptions = StripOptions::new().compatibility(true);
+
+fn parse(field: &str) -> String {
+    field
+        .split_whitespace()
+        .map(|c| char::from_u32(u32::from_str_radix(c, 16).unwrap()).unwrap())
+        .collect()
+}
+
+#[test]
+fn normalization_test() {
+    let mut failures = Vec::new();
+    for line in include_str!("fixtures/normalization.txt").lines() {
+        if line.starts_with('#') {
+            continue;
+        }
+        let (source, expected) = line.split_once(';').unwrap();
+        let (source, expected) = (parse(source), parse(expected));
+        if !cfg!(feature = "hangul")
+            && source
+                .chars()
+                .any(|c| ('\u{ac00}'..='\u{d7a3}').contains(&c))
+        {
+            continue;
+        }
+        let stripped = source.strip_diacritics_with(NFKD);
+        if stripped != expected {
+            failures.push(format!("{source:?} => {stripped:?}, expected {expected:?}"));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
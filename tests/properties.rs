@@ -0,0 +1,71 @@
+#![cfg(feature = "alloc")]
+
+use std::borrow::Cow;
+
+use proptest::prelude::*;
+use strip_diacritics::{
+    restore, CharDiacriticExt, StrDiacriticExt, StringDiacriticExt, StripOptions,
+};
+
+proptest! {
+    #[test]
+    fn no_diacritic_survives(s in "\\PC*") {
+        prop_assert!(s.strip_diacritics().chars().all(|c| !c.is_diacritic()));
+    }
+
+    #[test]
+    fn no_diacritic_survives_marks(s in "[a-zA-Z\u{c0}-\u{24f}\u{300}-\u{36f}\u{1e00}-\u{1fff}]*") {
+        prop_assert!(s.strip_diacritics().chars().all(|c| !c.is_diacritic()));
+    }
+
+    #[test]
+    fn borrowed_iff_unchanged(s in "[a-z \u{c0}-\u{24f}\u{300}-\u{36f}]*|\\PC*") {
+        let unchanged = s.chars().all(|c| c.strip_diacritics().is_none());
+        match s.strip_diacritics() {
+            Cow::Borrowed(b) => {
+                prop_assert!(unchanged);
+                prop_assert!(std::ptr::eq(b, s.as_str()));
+            }
+            Cow::Owned(o) => {
+                prop_assert!(!unchanged);
+                prop_assert_ne!(o, s);
+            }
+        }
+    }
+
+    #[test]
+    fn has_diacritics_iff_changed(s in "[a-z \u{c0}-\u{24f}\u{300}-\u{36f}]*|\\PC*") {
+        prop_assert_eq!(s.has_diacritics(), s.strip_diacritics() != s);
+    }
+
+    #[test]
+    fn scan_strategies_agree(s in "[a-z \u{c0}-\u{24f}\u{300}-\u{36f}]*|[ -~]*[\u{c0}-\u{17f}]?[ -~]*|\\PC*") {
+        let chars = StripOptions::new().char_scan_only(true);
+        prop_assert_eq!(s.strip_diacritics(), s.strip_diacritics_with(chars));
+    }
+
+    #[test]
+    fn in_place_agrees(s in "[a-z \u{c0}-\u{24f}\u{300}-\u{36f}\u{fa6c}]*|\\PC*") {
+        let mut owned = s.clone();
+        owned.strip_diacritics_in_place();
+        prop_assert_eq!(owned, s.strip_diacritics());
+    }
+
+    #[test]
+    fn chars_agree(s in "[a-z \u{c0}-\u{24f}\u{300}-\u{36f}]*|\\PC*") {
+        prop_assert_eq!(s.strip_diacritics_chars().collect::<String>(), s.strip_diacritics());
+    }
+
+    #[test]
+    fn lower_agrees(s in "[a-zA-Z \u{c0}-\u{24f}\u{300}-\u{36f}\u{1e00}-\u{1eff}]*") {
+        let lower = s.to_lowercase();
+        prop_assert_eq!(s.strip_diacritics_lower(), lower.strip_diacritics());
+    }
+
+    #[test]
+    fn reversible_round_trip(s in "[a-z \u{c0}-\u{24f}\u{300}-\u{36f}\u{1e00}-\u{1fff}]*|\\PC*") {
+        let (stripped, info) = s.strip_diacritics_reversible();
+        prop_assert_eq!(&stripped, &s.strip_diacritics());
+        prop_assert_eq!(restore(&stripped, &info), s);
+    }
+}
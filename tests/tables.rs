@@ -0,0 +1,223 @@
+use std::fmt::Write;
+
+use strip_diacritics::{
+    phf::CharMap,
+    tables::{
+        reverse_lookup, strip_latin1, COMPATIBILITY_MAPPING, DIACRITICS_MAPPING, DIACRITICS_SORTED,
+        MAX_REPLACEMENT_CHARS, MAX_REPLACEMENT_LEN, SPECIAL_CASE_FOLDING, SPECIAL_UPPERCASE,
+    },
+    CharDiacriticExt, Replacement,
+};
+
+fn dump(map: &CharMap<Replacement>) -> String {
+    let mut entries = map.entries().collect::<Vec<_>>();
+    entries.sort_unstable_by_key(|&(k, _)| k);
+
+    let mut buf = String::new();
+    for (k, v) in entries {
+        writeln!(buf, "U+{:04X} {:?} => {:?}", k as u32, k, v).unwrap();
+    }
+    buf
+}
+
+#[test]
+fn diacritics_mapping_snapshot() {
+    insta::assert_snapshot!(dump(&DIACRITICS_MAPPING));
+}
+
+#[test]
+fn compatibility_mapping_snapshot() {
+    insta::assert_snapshot!(dump(&COMPATIBILITY_MAPPING));
+}
+
+#[test]
+fn lookup() {
+    for map in [&DIACRITICS_MAPPING, &COMPATIBILITY_MAPPING] {
+        assert_eq!(map.entries().len(), map.len());
+        assert_eq!(map.entries().rev().count(), map.len());
+        for (k, v) in map.entries() {
+            assert_eq!(map.get(k), Some(v));
+        }
+    }
+}
+
+#[test]
+fn max_replacement() {
+    let values = DIACRITICS_MAPPING
+        .values()
+        .chain(COMPATIBILITY_MAPPING.values());
+    assert_eq!(
+        values.clone().map(|v| v.chars().count()).max(),
+        Some(MAX_REPLACEMENT_CHARS)
+    );
+    assert_eq!(values.map(|v| v.len()).max(), Some(MAX_REPLACEMENT_LEN));
+}
+
+#[test]
+fn key_bounds() {
+    for map in [&DIACRITICS_MAPPING, &COMPATIBILITY_MAPPING] {
+        assert_eq!(map.first_key(), map.keys().min());
+        assert_eq!(map.last_key(), map.keys().max());
+    }
+    assert_eq!(DIACRITICS_MAPPING.key_range(), Some('¨'..='\u{2fa1d}'));
+}
+
+#[test]
+fn latin1_match() {
+    const E: Option<char> = strip_latin1('é');
+    assert_eq!(E, Some('e'));
+    for c in '\u{0}'..='\u{17f}' {
+        let expected = DIACRITICS_MAPPING
+            .get(c)
+            .and_then(|v| v.parse::<char>().ok());
+        assert_eq!(strip_latin1(c), expected, "{c:?}");
+    }
+    assert_eq!(strip_latin1('ǎ'), None);
+}
+
+#[test]
+fn reverse() {
+    let a = reverse_lookup("a").collect::<Vec<_>>();
+    for c in ['à', 'á', 'â', 'ã', 'ä', 'å', 'ā', 'ă', 'ą', 'ǎ', 'ạ', 'ả'] {
+        assert!(a.contains(&c), "{c}");
+    }
+    assert!(a
+        .iter()
+        .all(|&c| DIACRITICS_MAPPING.get(c).is_some_and(|v| *v == "a")));
+    assert!(!a.contains(&'A') && !a.contains(&'Á'));
+
+    assert_eq!(reverse_lookup("dz").collect::<Vec<_>>(), ['ǆ']);
+    // Marks are dropped by is_diacritic, not through the table
+    assert_eq!(reverse_lookup("").next(), None);
+    assert_eq!(reverse_lookup("not in the table").next(), None);
+}
+
+#[test]
+fn const_get() {
+    const E: &str = match DIACRITICS_MAPPING.get('é') {
+        Some(r) => r.as_str(),
+        None => "",
+    };
+    const A: Option<&Replacement> = DIACRITICS_MAPPING.get('a');
+    const SS: Option<(char, &Replacement)> = SPECIAL_UPPERCASE.get_entry('ß');
+    assert_eq!(E, "e");
+    assert_eq!(A, None);
+    const { assert!(COMPATIBILITY_MAPPING.contains_key('ﬁ')) };
+    assert_eq!(SS.map(|(k, v)| (k, v.as_str())), Some(('ß', "SS")));
+}
+
+#[test]
+fn map_char() {
+    // Also the dense BMP table and the sorted entries, with the dense-table
+    // and binary-search features
+    for c in ('\0'..='\u{ffff}').chain(['\u{10781}', '\u{1d15e}', '\u{2f800}', '\u{e0100}']) {
+        let expected = if c.is_diacritic() {
+            Some("")
+        } else {
+            DIACRITICS_MAPPING.get(c).map(|r| r.as_str())
+        };
+        assert_eq!(strip_diacritics::map_char(c), expected, "{c:?}");
+    }
+}
+
+#[test]
+fn sorted_entries() {
+    assert!(DIACRITICS_SORTED.windows(2).all(|w| w[0].0 < w[1].0));
+    assert_eq!(DIACRITICS_SORTED.len(), DIACRITICS_MAPPING.len());
+    for c in ('\0'..=char::MAX).step_by(7).chain(['\u{e9}', '\u{2f800}']) {
+        let sorted = DIACRITICS_SORTED
+            .binary_search_by_key(&c, |&(k, _)| k)
+            .ok()
+            .map(|i| DIACRITICS_SORTED[i].1);
+        assert_eq!(
+            sorted,
+            DIACRITICS_MAPPING.get(c).map(|r| r.as_str()),
+            "{c:?}"
+        );
+    }
+}
+
+#[test]
+fn special_casing() {
+    let get = |map: &CharMap<Replacement>, c| map.get(c).map(|r| r.as_str());
+    assert_eq!(get(&SPECIAL_UPPERCASE, 'ß'), Some("SS"));
+    assert_eq!(get(&SPECIAL_CASE_FOLDING, 'ß'), Some("ss"));
+    assert_eq!(get(&SPECIAL_UPPERCASE, 'ﬃ'), Some("FFI"));
+    assert_eq!(get(&SPECIAL_CASE_FOLDING, 'ﬃ'), Some("ffi"));
+    assert_eq!(SPECIAL_UPPERCASE.get('ẞ'), None);
+    assert_eq!(SPECIAL_UPPERCASE.len(), SPECIAL_CASE_FOLDING.len());
+    for (k, v) in SPECIAL_UPPERCASE.entries() {
+        assert!(v.chars().count() > 1, "{k:?}");
+    }
+}
+
+#[cfg(feature = "hangul")]
+#[test]
+fn hangul() {
+    use strip_diacritics::tables::decompose_hangul;
+
+    // Recomposes every syllable from its jamo, UAX #15 style
+    for c in '\u{ac00}'..='\u{d7a3}' {
+        let jamo = decompose_hangul(c).unwrap().chars().collect::<Vec<_>>();
+        let (l, v, t) = match jamo[..] {
+            [l, v] => (l, v, 0),
+            [l, v, t] => (l, v, t as u32 - 0x11a7),
+            _ => panic!("{c:?} => {jamo:?}"),
+        };
+        assert!(('\u{1100}'..='\u{1112}').contains(&l), "{c:?}");
+        assert!(('\u{1161}'..='\u{1175}').contains(&v), "{c:?}");
+        assert!((1..28).contains(&t) || jamo.len() == 2, "{c:?}");
+        let s = 0xac00 + (l as u32 - 0x1100) * 588 + (v as u32 - 0x1161) * 28 + t;
+        assert_eq!(s, c as u32);
+    }
+    assert_eq!(decompose_hangul('\u{abff}'), None);
+    assert_eq!(decompose_hangul('\u{d7a4}'), None);
+    assert_eq!(decompose_hangul('a'), None);
+}
+
+// filter builds a new perfect hash, which takes std
+#[cfg(feature = "std")]
+#[test]
+fn filter_single_char() {
+    let single = DIACRITICS_MAPPING.filter(|_, v| v.chars().count() == 1);
+    assert!(!single.is_empty());
+    assert!(single.len() < DIACRITICS_MAPPING.len());
+    for (k, v) in DIACRITICS_MAPPING.entries() {
+        let expected = (v.chars().count() == 1).then_some(v);
+        assert_eq!(single.get(k), expected, "{k:?}");
+    }
+    assert_eq!(single.get('é').map(|r| r.as_str()), Some("e"));
+    assert_eq!(single.get('ǆ'), None);
+    assert_eq!(single.filter(|k, _| k == 'é').len(), 1);
+}
+
+// Looks up a key following the layout documented on `CharMap`.
+fn raw_lookup<V>(map: &CharMap<V>, c: char) -> Option<&V> {
+    use siphasher::sip128::{Hasher128, SipHasher13};
+    use std::hash::Hasher;
+
+    if !map.raw_range().contains(&c) {
+        return None;
+    }
+    let mut hasher = SipHasher13::new_with_keys(0, *map.raw_key());
+    hasher.write(&(c as u32).to_le_bytes());
+    let h = hasher.finish128();
+    let (g, f1, f2) = ((h.h1 >> 32) as u32, h.h1 as u32, h.h2 as u32);
+    let disps = map.raw_disps();
+    let (d1, d2) = disps[(g % disps.len() as u32) as usize];
+    let index = d2.wrapping_add(f1.wrapping_mul(d1)).wrapping_add(f2) % map.len() as u32;
+    let entry = &map.raw_entries()[index as usize];
+    (entry.0 == c).then_some(&entry.1)
+}
+
+#[test]
+fn raw_layout() {
+    for map in [&DIACRITICS_MAPPING, &COMPATIBILITY_MAPPING] {
+        for (k, v) in map.entries() {
+            assert_eq!(raw_lookup(map, k), Some(v));
+        }
+        for c in ['a', 'ß', '\u{10ffff}'] {
+            assert_eq!(raw_lookup(map, c), map.get(c));
+        }
+    }
+}
@@ -0,0 +1,8 @@
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn strip_diacritics() {
+    assert_eq!(strip_diacritics::wasm::strip_diacritics("Ömer"), "Omer");
+}